@@ -89,6 +89,7 @@ pub struct CompileCommand {
     ///
     /// Page numbers are one-indexed and correspond to real page numbers in the
     /// document (therefore not being affected by the document's page counter).
+    /// Referring to a page beyond the end of the document is an error.
     #[arg(long = "pages", value_delimiter = ',')]
     pub pages: Option<Vec<PageRangeArgument>>,
 
@@ -109,6 +110,14 @@ pub struct CompileCommand {
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
 
+    /// The PDF standard that PDF export should conform to
+    #[arg(long = "pdf-standard", default_value_t = PdfStandardArg::V_1_7)]
+    pub pdf_standard: PdfStandardArg,
+
+    /// How to represent text in SVG export
+    #[arg(long = "svg-fonts", default_value_t = SvgFontsArg::Outline)]
+    pub svg_fonts: SvgFontsArg,
+
     /// Produces performance timings of the compilation process (experimental)
     ///
     /// The resulting JSON file can be loaded into a tracing tool such as
@@ -436,12 +445,57 @@ pub struct UpdateCommand {
     pub backup_path: Option<PathBuf>,
 }
 
+/// A PDF standard that PDF export can conform to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, ValueEnum)]
+#[allow(non_camel_case_types)]
+pub enum PdfStandardArg {
+    /// PDF 1.7
+    #[value(name = "1.7")]
+    V_1_7,
+    /// PDF/A-2b
+    #[value(name = "a-2b")]
+    A_2b,
+    /// PDF/A-3b
+    #[value(name = "a-3b")]
+    A_3b,
+}
+
+impl Display for PdfStandardArg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+/// How to represent text in SVG export.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, ValueEnum)]
+pub enum SvgFontsArg {
+    /// Convert all glyphs to paths
+    Outline,
+    /// Write text as text and embed subsets of the used fonts
+    Embed,
+    /// Write text as text and reference the used fonts by family name
+    Reference,
+}
+
+impl Display for SvgFontsArg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
 /// Which format to use for the generated output file.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, ValueEnum)]
 pub enum OutputFormat {
     Pdf,
     Png,
     Svg,
+    Html,
 }
 
 impl Display for OutputFormat {
@@ -8,16 +8,21 @@ use codespan_reporting::term;
 use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 use parking_lot::RwLock;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use typst::diag::{bail, FileError, Severity, SourceDiagnostic, StrResult, Warned};
+use typst::diag::{
+    bail, At, FileError, Severity, SourceDiagnostic, SourceResult, StrResult, Warned,
+};
 use typst::foundations::{Datetime, Smart};
 use typst::layout::{Frame, PageRanges};
 use typst::model::Document;
 use typst::syntax::{FileId, Source, Span};
 use typst::visualize::Color;
 use typst::{World, WorldExt};
+use typst_pdf::{PdfExportOptions, PdfStandard};
+use typst_svg::{SvgExportOptions, SvgFonts};
 
 use crate::args::{
     CompileCommand, DiagnosticFormat, Input, Output, OutputFormat, PageRangeArgument,
+    PdfStandardArg, SvgFontsArg,
 };
 use crate::timings::Timer;
 use crate::watch::Status;
@@ -39,6 +44,7 @@ impl CompileCommand {
                     OutputFormat::Pdf => "pdf",
                     OutputFormat::Png => "png",
                     OutputFormat::Svg => "svg",
+                    OutputFormat::Html => "html",
                 },
             ))
         })
@@ -55,6 +61,7 @@ impl CompileCommand {
                 Some(ext) if ext.eq_ignore_ascii_case("pdf") => OutputFormat::Pdf,
                 Some(ext) if ext.eq_ignore_ascii_case("png") => OutputFormat::Png,
                 Some(ext) if ext.eq_ignore_ascii_case("svg") => OutputFormat::Svg,
+                Some(ext) if ext.eq_ignore_ascii_case("html") => OutputFormat::Html,
                 _ => bail!("could not infer output format for path {}.\nconsider providing the format manually with `--format/-f`", output.display()),
             }
         } else {
@@ -112,11 +119,11 @@ pub fn compile_once(
     }
 
     let Warned { output, warnings } = typst::compile(world);
+    let result = output.and_then(|document| export(world, &document, command, watching));
 
-    match output {
+    match result {
         // Export the PDF / PNG.
-        Ok(document) => {
-            export(world, &document, command, watching)?;
+        Ok(()) => {
             let duration = start.elapsed();
 
             if watching {
@@ -166,29 +173,79 @@ fn export(
     document: &Document,
     command: &CompileCommand,
     watching: bool,
-) -> StrResult<()> {
-    match command.output_format()? {
+) -> SourceResult<()> {
+    if let Some(ranges) = command.exported_page_ranges() {
+        ranges.check(document.pages.len()).at(Span::detached())?;
+    }
+
+    match command.output_format().at(Span::detached())? {
         OutputFormat::Png => {
+            let ranges = command.exported_page_ranges();
+            for (i, page) in document.pages.iter().enumerate() {
+                if ranges.as_ref().map_or(true, |ranges| ranges.includes_page_index(i)) {
+                    typst_render::check_images(&page.frame)?;
+                }
+            }
             export_image(world, document, command, watching, ImageExportFormat::Png)
+                .at(Span::detached())
         }
         OutputFormat::Svg => {
             export_image(world, document, command, watching, ImageExportFormat::Svg)
+                .at(Span::detached())
         }
         OutputFormat::Pdf => export_pdf(document, command),
+        OutputFormat::Html => {
+            let html = export_html(world, document, command)?;
+            write_html(&html, command).at(Span::detached())
+        }
     }
 }
 
+/// Export to HTML.
+fn export_html(
+    world: &SystemWorld,
+    document: &Document,
+    command: &CompileCommand,
+) -> SourceResult<String> {
+    let Warned { output, warnings } = typst_html::html(world, document);
+    print_diagnostics(world, &[], &warnings, command.common.diagnostic_format)
+        .map_err(|err| eco_format!("failed to print diagnostics ({err})"))
+        .at(Span::detached())?;
+    output
+}
+
+/// Write an exported HTML document to the output.
+fn write_html(html: &str, command: &CompileCommand) -> StrResult<()> {
+    command
+        .output()
+        .write(html.as_bytes())
+        .map_err(|err| eco_format!("failed to write HTML file ({err})"))
+}
+
 /// Export to a PDF.
-fn export_pdf(document: &Document, command: &CompileCommand) -> StrResult<()> {
+fn export_pdf(document: &Document, command: &CompileCommand) -> SourceResult<()> {
     let timestamp = convert_datetime(
         command.common.creation_timestamp.unwrap_or_else(chrono::Utc::now),
     );
     let exported_page_ranges = command.exported_page_ranges();
-    let buffer = typst_pdf::pdf(document, Smart::Auto, timestamp, exported_page_ranges);
+    let standard = match command.pdf_standard {
+        PdfStandardArg::V_1_7 => PdfStandard::V_1_7,
+        PdfStandardArg::A_2b => PdfStandard::A_2b,
+        PdfStandardArg::A_3b => PdfStandard::A_3b,
+    };
+    let options = PdfExportOptions { standard };
+    let buffer = typst_pdf::pdf_with(
+        document,
+        Smart::Auto,
+        timestamp,
+        exported_page_ranges,
+        &options,
+    )?;
     command
         .output()
         .write(&buffer)
-        .map_err(|err| eco_format!("failed to write PDF file ({err})"))?;
+        .map_err(|err| eco_format!("failed to write PDF file ({err})"))
+        .at(Span::detached())?;
     Ok(())
 }
 
@@ -339,7 +396,12 @@ fn export_image_page(
                 .map_err(|err| eco_format!("failed to write PNG file ({err})"))?;
         }
         ImageExportFormat::Svg => {
-            let svg = typst_svg::svg(frame);
+            let fonts = match command.svg_fonts {
+                SvgFontsArg::Outline => SvgFonts::Outline,
+                SvgFontsArg::Embed => SvgFonts::Embed,
+                SvgFontsArg::Reference => SvgFonts::Reference,
+            };
+            let svg = typst_svg::svg_with(frame, &SvgExportOptions { fonts });
             output
                 .write(svg.as_bytes())
                 .map_err(|err| eco_format!("failed to write SVG file ({err})"))?;
@@ -54,8 +54,7 @@ pub fn download(url: &str) -> Result<ureq::Response, ureq::Error> {
     }
 
     // Configure native TLS.
-    let connector =
-        tls.build().map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+    let connector = tls.build().map_err(io::Error::other)?;
     builder = builder.tls_connector(Arc::new(connector));
 
     builder.build().get(url).call()
@@ -161,7 +160,7 @@ impl RemoteReader {
     fn display(&mut self) -> io::Result<()> {
         let sum: usize = self.downloaded_last_few_secs.iter().sum();
         let len = self.downloaded_last_few_secs.len();
-        let speed = if len > 0 { sum / len } else { self.content_len.unwrap_or(0) };
+        let speed = sum.checked_div(len).unwrap_or(self.content_len.unwrap_or(0));
 
         let total_downloaded = as_bytes_unit(self.total_downloaded);
         let speed_h = as_throughput_unit(speed);
@@ -174,11 +173,9 @@ impl RemoteReader {
                 let remaining = content_len - self.total_downloaded;
 
                 let download_size = as_bytes_unit(content_len);
-                let eta = time_suffix(Duration::from_secs(if speed == 0 {
-                    0
-                } else {
-                    (remaining / speed) as u64
-                }));
+                let eta = time_suffix(Duration::from_secs(
+                    remaining.checked_div(speed).unwrap_or(0) as u64,
+                ));
                 writeln!(
                     terminal::out(),
                     "{total_downloaded} / {download_size} ({percent:3.0} %) {speed_h} in {elapsed} ETA: {eta}",
@@ -0,0 +1,645 @@
+//! Exporting of Typst documents into HTML.
+
+use comemo::{Track, Tracked};
+use ecow::{eco_format, EcoString};
+use typst::diag::{warning, SourceResult, Warned};
+use typst::engine::{Engine, Route, Sink, Traced};
+use typst::foundations::{
+    Content, Packed, Selector, SequenceElem, StyleChain, StyledElem,
+};
+use typst::introspection::{Locator, SplitLocator, Tag, TagElem};
+use typst::layout::{
+    BlockChild, BlockElem, ColbreakElem, FlowElem, HElem, PageElem, VElem,
+};
+use typst::model::{
+    Destination, Document, DocumentElem, EnumElem, FigureElem, HeadingElem, LinkElem,
+    ListElem, ParElem,
+};
+use typst::realize::{realize_flow, Arenas};
+use typst::text::{
+    LinebreakElem, RawLine, SmartQuoteElem, SmartQuoter, SmartQuotes, SpaceElem, TextElem,
+};
+use typst::visualize::ImageElem;
+use typst::World;
+
+/// The style sheet that is embedded into every exported document.
+const STYLE: &str = "\
+body { max-width: 42em; margin: 2em auto; padding: 0 1em; line-height: 1.5; }
+figure { margin: 1em 0; text-align: center; }
+img { max-width: 100%; }
+pre { overflow-x: auto; }";
+
+/// Export a document into a standalone HTML document.
+///
+/// The exporter works on the realized content the document's pages were laid
+/// out from rather than on the pages themselves, so set and show rules were
+/// already applied and context was resolved during compilation. Paragraphs,
+/// lists, images, and raw blocks are mapped to their semantic HTML
+/// counterparts. Headings and figures are found through the tags that
+/// realization leaves around them, while strong and emphasized text and links
+/// are recognized by their styles. Elements that end up without an HTML
+/// equivalent are omitted with a warning.
+///
+/// Some content is only realized during layout, like the bodies of list items
+/// and figures. The exporter realizes it itself, locating the elements in
+/// there relative to the closest located element around them.
+///
+/// - Returns `Ok(html)` if all content could be realized.
+/// - Returns `Err(errors)` if a show rule failed.
+///
+/// The returned warnings only concern the export itself. Warnings from
+/// compilation are reported by [`typst::compile`].
+#[typst_macros::time(name = "html")]
+pub fn html(world: &dyn World, document: &Document) -> Warned<SourceResult<String>> {
+    let mut sink = Sink::new();
+    let output = export(world.track(), document, &mut sink);
+    Warned { output, warnings: sink.warnings() }
+}
+
+/// Write the document's realized content as HTML.
+fn export(
+    world: Tracked<dyn World + '_>,
+    document: &Document,
+    sink: &mut Sink,
+) -> SourceResult<String> {
+    let traced = Traced::default();
+    let mut engine = Engine {
+        world,
+        introspector: document.introspector.track(),
+        traced: traced.track(),
+        sink: sink.track_mut(),
+        route: Route::default(),
+    };
+
+    let arenas = Arenas::default();
+    let mut writer = HtmlWriter {
+        engine: &mut engine,
+        locator: Locator::root().split(),
+        arenas: &arenas,
+        buf: String::new(),
+    };
+
+    writer.buf.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
+    writer.buf.push_str("<meta charset=\"utf-8\">\n");
+    if let Some(title) = &document.title {
+        writer.buf.push_str("<title>");
+        escape(&mut writer.buf, title);
+        writer.buf.push_str("</title>\n");
+    }
+    writer.buf.push_str("<style>\n");
+    writer.buf.push_str(STYLE);
+    writer.buf.push_str("\n</style>\n</head>\n<body>\n");
+    writer.document(&document.content, StyleChain::default())?;
+    writer.buf.push_str("</body>\n</html>\n");
+
+    let buf = writer.buf;
+
+    // Like in compilation, errors in show rules are only reported at the end.
+    let delayed = sink.delayed();
+    if !delayed.is_empty() {
+        return Err(delayed);
+    }
+
+    Ok(buf)
+}
+
+/// A realized element together with its styles.
+type Leaf<'a> = (&'a Content, StyleChain<'a>);
+
+/// Writes realized content as HTML.
+struct HtmlWriter<'a, 'v, 'e> {
+    /// The engine for realization.
+    engine: &'v mut Engine<'e>,
+    /// Locates elements in content that isn't part of a located element.
+    locator: SplitLocator<'v>,
+    /// Scratch arenas for realized content.
+    arenas: &'a Arenas<'a>,
+    /// The HTML that was written so far.
+    buf: String,
+}
+
+/// A formatting of inline content that maps to an HTML element.
+#[derive(Debug, Clone, PartialEq)]
+enum Mark {
+    /// Strong emphasis.
+    Strong,
+    /// Emphasis.
+    Emph,
+    /// A link, if it has a target in HTML.
+    Link(Option<EcoString>),
+}
+
+impl<'a> HtmlWriter<'a, '_, '_> {
+    /// Write the pages of the realized document.
+    fn document(
+        &mut self,
+        content: &'a Content,
+        styles: StyleChain<'a>,
+    ) -> SourceResult<()> {
+        if let Some(styled) = content.to_packed::<StyledElem>() {
+            let stored = self.arenas.store(styles);
+            return self.document(styled.child(), stored.chain(styled.styles()));
+        }
+
+        let Some(document) = content.to_packed::<DocumentElem>() else {
+            return Ok(());
+        };
+
+        let stored = self.arenas.store(styles);
+        for (child, styles) in document.children().chain(stored) {
+            if let Some(page) = child.to_packed::<PageElem>() {
+                let locator = self.locator.next(&page.span());
+                let leaves = self.realize(page.body(), styles, locator)?;
+                self.flow(&leaves)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Realize content into a flow and return its block-level leaves.
+    ///
+    /// The flows of the document's pages were already realized during
+    /// compilation and are used as they are.
+    fn realize(
+        &mut self,
+        content: &'a Content,
+        styles: StyleChain<'a>,
+        locator: Locator,
+    ) -> SourceResult<Vec<Leaf<'a>>> {
+        let (flow, styles) = match content.to_packed::<FlowElem>() {
+            Some(flow) => (flow, styles),
+            None => {
+                let (flow, styles) =
+                    realize_flow(self.engine, locator, self.arenas, content, styles)?;
+                let stored = self.arenas.store(flow.pack());
+                (stored.to_packed::<FlowElem>().unwrap(), styles)
+            }
+        };
+
+        // The tags at the end of a paragraph belong to the blocks that follow
+        // it, like the start tag of a heading.
+        let stored = self.arenas.store(styles);
+        let mut leaves = vec![];
+        for (child, styles) in flow.children().chain(stored) {
+            leaves.push((child, styles));
+            if let Some(par) = child.to_packed::<ParElem>() {
+                let children = self.par_children(par, styles);
+                let start = children
+                    .iter()
+                    .rposition(|(child, _)| !child.is::<TagElem>())
+                    .map_or(0, |i| i + 1);
+                leaves.extend_from_slice(&children[start..]);
+            }
+        }
+
+        Ok(leaves)
+    }
+
+    /// The inline leaves of a realized paragraph.
+    fn par_children(
+        &mut self,
+        par: &'a Packed<ParElem>,
+        styles: StyleChain<'a>,
+    ) -> Vec<Leaf<'a>> {
+        let stored = self.arenas.store(styles);
+        par.children().chain(stored).collect()
+    }
+
+    /// Write realized block-level content.
+    fn flow(&mut self, leaves: &[Leaf<'a>]) -> SourceResult<()> {
+        let mut i = 0;
+        while let Some(&(elem, styles)) = leaves.get(i) {
+            i += 1;
+
+            // Lists are shown as blocks that lay out the list themselves.
+            let elem = laid_out(elem);
+
+            if let Some(tag) = elem.to_packed::<TagElem>() {
+                // Headings and figures are written from their tags. What
+                // realization produced for them is found up to the end tag.
+                let Some(elem) = tag.tag().elem() else { continue };
+                if !elem.is::<HeadingElem>() && !elem.is::<FigureElem>() {
+                    continue;
+                }
+
+                let end = Tag::End(elem.location().unwrap());
+                let len = leaves[i..]
+                    .iter()
+                    .position(|(child, _)| {
+                        child.to_packed::<TagElem>().is_some_and(|tag| *tag.tag() == end)
+                    })
+                    .unwrap_or(leaves.len() - i);
+                self.located(elem, styles, &leaves[i..i + len])?;
+                i += len;
+            } else if let Some(par) = elem.to_packed::<ParElem>() {
+                let children = self.par_children(par, styles);
+                self.par(&children)?;
+            } else if let Some(list) = elem.to_packed::<ListElem>() {
+                self.buf.push_str("<ul>\n");
+                for item in list.children() {
+                    self.buf.push_str("<li>");
+                    self.item(item.body(), styles)?;
+                    self.buf.push_str("</li>\n");
+                }
+                self.buf.push_str("</ul>\n");
+            } else if let Some(enum_) = elem.to_packed::<EnumElem>() {
+                self.buf.push_str("<ol");
+                let start = enum_.start(styles);
+                if start != 1 {
+                    self.attr("start", &eco_format!("{start}"));
+                }
+                self.buf.push_str(">\n");
+                for item in enum_.children() {
+                    self.buf.push_str("<li");
+                    if let Some(number) = item.number(styles) {
+                        self.attr("value", &eco_format!("{number}"));
+                    }
+                    self.buf.push('>');
+                    self.item(item.body(), styles)?;
+                    self.buf.push_str("</li>\n");
+                }
+                self.buf.push_str("</ol>\n");
+            } else if let Some(lines) = raw_block(elem) {
+                self.buf.push_str("<pre><code>");
+                for (k, line) in lines.iter().enumerate() {
+                    if k > 0 {
+                        self.buf.push('\n');
+                    }
+                    escape(&mut self.buf, line.text());
+                }
+                self.buf.push_str("</code></pre>\n");
+            } else if let Some(image) = elem.to_packed::<ImageElem>() {
+                self.image(image, styles);
+                self.buf.push('\n');
+            } else if elem.is::<VElem>() || elem.is::<ColbreakElem>() {
+                // These only separate blocks.
+            } else {
+                self.unsupported(elem);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write a heading or figure.
+    ///
+    /// The `shown` leaves are what realization produced for the element.
+    fn located(
+        &mut self,
+        elem: &'a Content,
+        styles: StyleChain<'a>,
+        shown: &[Leaf<'a>],
+    ) -> SourceResult<()> {
+        let mut locator = Locator::synthesize(elem.location().unwrap()).split();
+
+        if let Some(heading) = elem.to_packed::<HeadingElem>() {
+            // The shown heading keeps its numbering and the effects of show
+            // rules.
+            let level = heading.resolve_level(styles).get().min(6);
+            self.buf.push_str(&eco_format!("<h{level}"));
+            if let Some(label) = elem.label() {
+                self.attr("id", label.as_str());
+            }
+            self.buf.push('>');
+            self.shown_inline(shown, &mut locator)?;
+            self.buf.push_str(&eco_format!("</h{level}>\n"));
+        } else if let Some(figure) = elem.to_packed::<FigureElem>() {
+            // The body and caption are taken from the figure itself since the
+            // shown figure merges them.
+            self.buf.push_str("<figure");
+            if let Some(label) = elem.label() {
+                self.attr("id", label.as_str());
+            }
+            self.buf.push_str(">\n");
+            let body = figure.body();
+            let leaves = self.realize(body, styles, locator.next(&body.span()))?;
+            self.flow(&leaves)?;
+            if let Some(caption) = figure.caption(styles) {
+                let body = self.arenas.store(caption.body().clone());
+                self.buf.push_str("<figcaption>");
+                let leaves = self.realize(body, styles, locator.next(&body.span()))?;
+                self.inline_flow(&leaves)?;
+                self.buf.push_str("</figcaption>\n");
+            }
+            self.buf.push_str("</figure>\n");
+        }
+
+        Ok(())
+    }
+
+    /// Write shown block-level content inline, looking into the blocks that
+    /// wrap it.
+    fn shown_inline(
+        &mut self,
+        shown: &[Leaf<'a>],
+        locator: &mut SplitLocator,
+    ) -> SourceResult<()> {
+        for &(elem, styles) in shown {
+            if let Some(par) = elem.to_packed::<ParElem>() {
+                let children = self.par_children(par, styles);
+                self.inline(&children)?;
+            } else if let Some(block) = elem.to_packed::<BlockElem>() {
+                if let Some(BlockChild::Content(body)) = block.body(styles) {
+                    let body = self.arenas.store(body.clone());
+                    let leaves =
+                        self.realize(body, styles, locator.next(&body.span()))?;
+                    self.shown_inline(&leaves, locator)?;
+                }
+            } else if !elem.is::<TagElem>() && !elem.is::<VElem>() {
+                self.unsupported(elem);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the body of a list or enumeration item.
+    fn item(&mut self, body: &'a Content, styles: StyleChain<'a>) -> SourceResult<()> {
+        let locator = self.locator.next(&body.span());
+        let leaves = self.realize(body, styles, locator)?;
+        self.inline_flow(&leaves)
+    }
+
+    /// Write a realized flow that consists of a single paragraph without
+    /// wrapping it in a paragraph.
+    fn inline_flow(&mut self, leaves: &[Leaf<'a>]) -> SourceResult<()> {
+        let mut pars = leaves.iter().filter(|(elem, _)| !elem.is::<TagElem>());
+        match (pars.next(), pars.next()) {
+            (Some(&(elem, styles)), None) if elem.is::<ParElem>() => {
+                let children =
+                    self.par_children(elem.to_packed::<ParElem>().unwrap(), styles);
+                self.inline(&children)
+            }
+            (None, _) => Ok(()),
+            _ => self.flow(leaves),
+        }
+    }
+
+    /// Write a paragraph, unless it is empty.
+    fn par(&mut self, children: &[Leaf<'a>]) -> SourceResult<()> {
+        let start = self.buf.len();
+        self.buf.push_str("<p>");
+        let inner = self.buf.len();
+        self.inline(children)?;
+
+        if self.buf[inner..].trim().is_empty() {
+            self.buf.truncate(start);
+        } else {
+            self.buf.push_str("</p>\n");
+        }
+
+        Ok(())
+    }
+
+    /// Write realized inline content.
+    fn inline(&mut self, children: &[Leaf<'a>]) -> SourceResult<()> {
+        let mut marks = vec![];
+        let mut quoter = SmartQuoter::new();
+        for (i, &(elem, styles)) in children.iter().enumerate() {
+            if elem.is::<TagElem>() {
+                continue;
+            }
+
+            self.mark(&mut marks, elem, styles);
+
+            if let Some(text) = elem.to_packed::<TextElem>() {
+                escape(&mut self.buf, text.text());
+                if let Some(c) = text.text().chars().last() {
+                    quoter.last(c, false);
+                }
+            } else if elem.is::<SpaceElem>() || elem.is::<HElem>() {
+                // Horizontal spacing is a matter of layout, but it still
+                // separates words.
+                if !self.buf.ends_with(' ') {
+                    self.buf.push(' ');
+                }
+                quoter.last(' ', false);
+            } else if elem.is::<LinebreakElem>() {
+                self.buf.push_str("<br>");
+                quoter.last('\n', false);
+            } else if let Some(quote) = elem.to_packed::<SmartQuoteElem>() {
+                let double = quote.double(styles);
+                let substitute = if quote.enabled(styles) {
+                    let quotes = SmartQuotes::new(
+                        quote.quotes(styles),
+                        TextElem::lang_in(styles),
+                        TextElem::region_in(styles),
+                        quote.alternative(styles),
+                    );
+                    quoter.quote(&quotes, double, peek(&children[i + 1..]))
+                } else if double {
+                    "\""
+                } else {
+                    "'"
+                };
+                escape(&mut self.buf, substitute);
+                if let Some(c) = substitute.chars().last() {
+                    quoter.last(c, true);
+                }
+            } else {
+                self.unsupported(elem);
+            }
+        }
+
+        self.close(&mut marks, 0);
+        Ok(())
+    }
+
+    /// Open and close formatting elements so that they match the styles of
+    /// the next inline element.
+    fn mark(&mut self, marks: &mut Vec<Mark>, elem: &Content, styles: StyleChain) {
+        let mut wanted = vec![];
+        if TextElem::delta_in(styles).0 > 0 {
+            wanted.push(Mark::Strong);
+        }
+        if TextElem::emph_in(styles).0 {
+            wanted.push(Mark::Emph);
+        }
+        if let Some(dest) = LinkElem::dests_in(styles).first() {
+            wanted.push(Mark::Link(self.href(elem, dest)));
+        }
+
+        let common = marks.iter().zip(&wanted).take_while(|(a, b)| a == b).count();
+        self.close(marks, common);
+        for mark in wanted.into_iter().skip(common) {
+            match &mark {
+                Mark::Strong => self.buf.push_str("<strong>"),
+                Mark::Emph => self.buf.push_str("<em>"),
+                Mark::Link(Some(href)) => {
+                    self.buf.push_str("<a");
+                    self.attr("href", href);
+                    self.buf.push('>');
+                }
+                Mark::Link(None) => {}
+            }
+            marks.push(mark);
+        }
+    }
+
+    /// Close formatting elements until only `len` of them are open.
+    fn close(&mut self, marks: &mut Vec<Mark>, len: usize) {
+        while marks.len() > len {
+            match marks.pop().unwrap() {
+                Mark::Strong => self.buf.push_str("</strong>"),
+                Mark::Emph => self.buf.push_str("</em>"),
+                Mark::Link(Some(_)) => self.buf.push_str("</a>"),
+                Mark::Link(None) => {}
+            }
+        }
+    }
+
+    /// The target of a link in HTML, if it has one.
+    ///
+    /// Links to elements point to their label. Links to other elements, like
+    /// the entries of footnotes, are written as plain text.
+    fn href(&mut self, elem: &Content, dest: &Destination) -> Option<EcoString> {
+        match dest {
+            Destination::Url(url) => Some(url.as_str().into()),
+            Destination::Location(loc) => {
+                let target =
+                    self.engine.introspector.query_first(&Selector::Location(*loc));
+                Some(eco_format!("#{}", target?.label()?.as_str()))
+            }
+            Destination::Position(_) => {
+                // Positions on a page have no meaning without pages.
+                self.engine.sink.warn(warning!(
+                    elem.span(),
+                    "link to a position was ignored during HTML export";
+                    hint: "link to a URL or a label instead"
+                ));
+                None
+            }
+        }
+    }
+
+    /// Write an image.
+    fn image(&mut self, image: &ImageElem, styles: StyleChain) {
+        self.buf.push_str("<img");
+        self.attr("src", &url_escape(image.path()));
+        self.attr("alt", &image.alt(styles).unwrap_or_default());
+        self.buf.push('>');
+    }
+
+    /// Write an attribute of an opening tag.
+    fn attr(&mut self, name: &str, value: &str) {
+        self.buf.push(' ');
+        self.buf.push_str(name);
+        self.buf.push_str("=\"");
+        for c in value.chars() {
+            match c {
+                '"' => self.buf.push_str("&quot;"),
+                c => escape(&mut self.buf, c.encode_utf8(&mut [0; 4])),
+            }
+        }
+        self.buf.push('"');
+    }
+
+    /// Flag an element that has no HTML equivalent.
+    fn unsupported(&mut self, elem: &Content) {
+        self.engine.sink.warn(warning!(
+            elem.span(),
+            "{} was ignored during HTML export",
+            elem.elem().name();
+            hint: "HTML export only supports basic document elements"
+        ));
+    }
+}
+
+/// The element a block with a layout callback was shown for, or the element
+/// itself.
+fn laid_out(elem: &Content) -> &Content {
+    let Some(block) = elem.to_packed::<BlockElem>() else { return elem };
+    match block.body(StyleChain::default()) {
+        Some(BlockChild::SingleLayouter(callback)) => callback.captured(),
+        Some(BlockChild::MultiLayouter(callback)) => callback.captured(),
+        _ => elem,
+    }
+}
+
+/// The lines of a shown raw block.
+fn raw_block(elem: &Content) -> Option<Vec<&RawLine>> {
+    /// Collect the lines, failing on anything else than line breaks.
+    fn collect<'a>(content: &'a Content, lines: &mut Vec<&'a RawLine>) -> bool {
+        if let Some(styled) = content.to_packed::<StyledElem>() {
+            collect(styled.child(), lines)
+        } else if let Some(sequence) = content.to_packed::<SequenceElem>() {
+            sequence.children().iter().all(|child| collect(child, lines))
+        } else if let Some(line) = content.to_packed::<RawLine>() {
+            lines.push(line);
+            true
+        } else {
+            content.is::<LinebreakElem>()
+        }
+    }
+
+    let block = elem.to_packed::<BlockElem>()?;
+    let Some(BlockChild::Content(body)) = block.body(StyleChain::default()) else {
+        return None;
+    };
+
+    let mut lines = vec![];
+    (collect(body, &mut lines) && !lines.is_empty()).then_some(lines)
+}
+
+/// The character after a smart quote, as seen by layout.
+fn peek(rest: &[Leaf]) -> Option<char> {
+    let (elem, _) = rest.first()?;
+    Some(if let Some(text) = elem.to_packed::<TextElem>() {
+        text.text().chars().next()?
+    } else if elem.is::<SmartQuoteElem>() {
+        '"'
+    } else if elem.is::<SpaceElem>()
+        || elem.is::<HElem>()
+        || elem.is::<LinebreakElem>()
+        || elem.is::<TagElem>()
+    {
+        ' '
+    } else {
+        '\u{FFFC}'
+    })
+}
+
+/// Escape text for use in HTML.
+fn escape(buf: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            _ => buf.push(c),
+        }
+    }
+}
+
+/// Percent-encode a file path for use as a relative URL.
+///
+/// Path separators are kept, but everything that has a special meaning in
+/// URLs, like `#` and `?`, is encoded. Backslashes are treated as separators.
+fn url_escape(path: &str) -> EcoString {
+    let mut url = EcoString::new();
+    for &byte in path.as_bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                url.push(byte as char)
+            }
+            b'/' | b'\\' => url.push('/'),
+            _ => url.push_str(&eco_format!("%{byte:02X}")),
+        }
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_escape() {
+        assert_eq!(url_escape("images/cat.png"), "images/cat.png");
+        assert_eq!(url_escape("images\\cat.png"), "images/cat.png");
+        assert_eq!(url_escape("my cat #1?.png"), "my%20cat%20%231%3F.png");
+        assert_eq!(url_escape("\"a\"&<b>"), "%22a%22%26%3Cb%3E");
+        assert_eq!(url_escape("Katze-ä.png"), "Katze-%C3%A4.png");
+    }
+}
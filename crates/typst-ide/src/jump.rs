@@ -42,7 +42,7 @@ pub fn jump_from_click(
                     Destination::Url(url) => Jump::Url(url.clone()),
                     Destination::Position(pos) => Jump::Position(*pos),
                     Destination::Location(loc) => {
-                        Jump::Position(document.introspector.position(*loc))
+                        Jump::Position(document.introspector.position(*loc)?)
                     }
                 });
             }
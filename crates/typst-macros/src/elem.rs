@@ -631,10 +631,13 @@ fn create_style_chain_access(
 fn create_native_elem_impl(element: &Elem) -> TokenStream {
     let Elem { name, ident, title, scope, keywords, docs, .. } = element;
 
-    let local_name = if element.can("LocalName") {
-        quote! { Some(<#foundations::Packed<#ident> as ::typst::text::LocalName>::local_name) }
+    let (local_name, local_name_plural) = if element.can("LocalName") {
+        (
+            quote! { Some(<#foundations::Packed<#ident> as ::typst::text::LocalName>::local_name) },
+            quote! { Some(<#foundations::Packed<#ident> as ::typst::text::LocalName>::local_name_plural) },
+        )
     } else {
-        quote! { None }
+        (quote! { None }, quote! { None })
     };
 
     let scope = if *scope {
@@ -658,6 +661,7 @@ fn create_native_elem_impl(element: &Elem) -> TokenStream {
             field_name: |id| id.try_into().ok().map(Fields::to_str),
             field_from_styles: <#ident as #foundations::Fields>::field_from_styles,
             local_name: #local_name,
+            local_name_plural: #local_name_plural,
             scope: #foundations::Lazy::new(|| #scope),
             params: #foundations::Lazy::new(|| ::std::vec![#(#params),*])
         }
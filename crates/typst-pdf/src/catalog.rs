@@ -2,7 +2,7 @@ use std::num::NonZeroUsize;
 
 use ecow::eco_format;
 use pdf_writer::{
-    types::Direction, writers::PageLabel, Finish, Name, Pdf, Ref, Str, TextStr,
+    types::Direction, writers::PageLabel, Filter, Finish, Name, Pdf, Ref, Str, TextStr,
 };
 use xmp_writer::{DateTime, LangId, RenditionClass, Timezone, XmpWriter};
 
@@ -10,14 +10,16 @@ use typst::foundations::{Datetime, Smart};
 use typst::layout::Dir;
 use typst::text::Lang;
 
-use crate::WithEverything;
-use crate::{hash_base64, outline, page::PdfPageLabel};
+use crate::color::SRGB_ICC_DEFLATED;
+use crate::{hash_base64, outline, page::PdfPageLabel, structure};
+use crate::{PdfStandard, WithEverything};
 
 /// Write the document catalog.
 pub fn write_catalog(
     ctx: WithEverything,
     ident: Smart<&str>,
     timestamp: Option<Datetime>,
+    standard: PdfStandard,
     pdf: &mut Pdf,
     alloc: &mut Ref,
 ) {
@@ -40,6 +42,9 @@ pub fn write_catalog(
     // Write the page labels.
     let page_labels = write_page_labels(pdf, alloc, &ctx);
 
+    // Write the structure tree.
+    let struct_tree_root = structure::write_structure_tree(pdf, alloc, &ctx);
+
     // Write the document information.
     let info_ref = alloc.bump();
     let mut info = pdf.document_info(info_ref);
@@ -126,12 +131,30 @@ pub fn write_catalog(
     xmp.rendition_class(RenditionClass::Proof);
     xmp.pdf_version("1.7");
 
+    // Identify the file as PDF/A.
+    if let Some((part, conformance)) = standard.pdfa() {
+        xmp.pdfa_part(part);
+        xmp.pdfa_conformance(conformance);
+    }
+
     let xmp_buf = xmp.finish(None);
+
     let meta_ref = alloc.bump();
     pdf.stream(meta_ref, xmp_buf.as_bytes())
         .pair(Name(b"Type"), Name(b"Metadata"))
         .pair(Name(b"Subtype"), Name(b"XML"));
 
+    // PDF/A requires an output intent that describes how device-dependent
+    // colors are to be interpreted. We use the sRGB profile for that.
+    let output_intent_profile = standard.pdfa().map(|_| {
+        let icc_ref = alloc.bump();
+        pdf.icc_profile(icc_ref, &SRGB_ICC_DEFLATED)
+            .n(3)
+            .range([0.0, 1.0, 0.0, 1.0, 0.0, 1.0])
+            .filter(Filter::FlateDecode);
+        icc_ref
+    });
+
     // Write the document catalog.
     let catalog_ref = alloc.bump();
     let mut catalog = pdf.catalog(catalog_ref);
@@ -139,6 +162,20 @@ pub fn write_catalog(
     catalog.viewer_preferences().direction(dir);
     catalog.metadata(meta_ref);
 
+    // Insert the output intent.
+    if let Some(icc_ref) = output_intent_profile {
+        let mut intents = catalog.insert(Name(b"OutputIntents")).array();
+        intents
+            .push()
+            .dict()
+            .pair(Name(b"Type"), Name(b"OutputIntent"))
+            .pair(Name(b"S"), Name(b"GTS_PDFA1"))
+            .pair(Name(b"OutputConditionIdentifier"), TextStr("sRGB"))
+            .pair(Name(b"Info"), TextStr("sRGB IEC61966-2.1"))
+            .pair(Name(b"DestOutputProfile"), icc_ref);
+        intents.finish();
+    }
+
     // Write the named destination tree.
     let mut name_dict = catalog.names();
     let mut dests_name_tree = name_dict.destinations();
@@ -163,6 +200,12 @@ pub fn write_catalog(
         catalog.outlines(outline_root_id);
     }
 
+    // Mark the document as tagged.
+    if let Some(struct_tree_root) = struct_tree_root {
+        catalog.pair(Name(b"StructTreeRoot"), struct_tree_root);
+        catalog.insert(Name(b"MarkInfo")).dict().pair(Name(b"Marked"), true);
+    }
+
     if let Some(lang) = lang {
         catalog.lang(TextStr(lang.as_str()));
     }
@@ -16,7 +16,7 @@ const OKLAB_A: Name<'static> = Name(b"A");
 const OKLAB_B: Name<'static> = Name(b"B");
 
 // The ICC profiles.
-static SRGB_ICC_DEFLATED: Lazy<Vec<u8>> =
+pub static SRGB_ICC_DEFLATED: Lazy<Vec<u8>> =
     Lazy::new(|| deflate(typst_assets::icc::S_RGB_V4));
 static GRAY_ICC_DEFLATED: Lazy<Vec<u8>> =
     Lazy::new(|| deflate(typst_assets::icc::S_GREY_V4));
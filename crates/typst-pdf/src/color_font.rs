@@ -4,22 +4,26 @@
 //!
 //! There are various standards to represent color glyphs, but PDF readers don't
 //! support any of them natively, so Typst has to handle them manually.
+//!
+//! The same mechanism is used for the glyphs of fonts at a non-default optical
+//! size: their font data can't be embedded as it is, so their outlines are
+//! drawn instead.
 
 use std::collections::HashMap;
 
 use ecow::eco_format;
 use indexmap::IndexMap;
 use pdf_writer::Filter;
-use pdf_writer::{types::UnicodeCmap, Finish, Name, Rect, Ref};
-use ttf_parser::name_id;
+use pdf_writer::{types::UnicodeCmap, Content, Finish, Name, Rect, Ref};
+use ttf_parser::{name_id, GlyphId, OutlineBuilder};
 
-use typst::layout::Em;
+use typst::layout::{Abs, Em, Size};
 use typst::text::{color::frame_for_glyph, Font};
 
 use crate::resources::{Resources, ResourcesRefs};
 use crate::WithGlobalRefs;
 use crate::{
-    content,
+    content, deflate_deferred,
     font::{subset_tag, write_font_descriptor, CMAP_NAME, SYSTEM_INFO},
     EmExt, PdfChunk,
 };
@@ -239,11 +243,14 @@ impl ColorFontMap<()> {
                 self.total_slice_count += 1;
             }
 
-            let frame = frame_for_glyph(font, gid);
             let width =
                 font.advance(gid).unwrap_or(Em::new(0.0)).get() * font.units_per_em();
-            let instructions =
-                content::build(&mut self.resources, &frame, Some(width as f32));
+            let instructions = if is_color_glyph(font, gid) {
+                let frame = frame_for_glyph(font, gid);
+                content::build(&mut self.resources, &frame, Some(width as f32), false)
+            } else {
+                outline_glyph(font, gid, width as f32)
+            };
             color_font.glyphs.push(ColorGlyph { gid, instructions });
             color_font.glyph_indices.insert(gid, index);
 
@@ -262,6 +269,86 @@ impl ColorFontMap<()> {
     }
 }
 
+/// Whether a glyph is drawn in color, as opposed to an outline glyph.
+fn is_color_glyph(font: &Font, gid: u16) -> bool {
+    let ttf = font.ttf();
+    let id = GlyphId(gid);
+    ttf.glyph_raster_image(id, u16::MAX).is_some()
+        || ttf.is_color_glyph(id)
+        || ttf.glyph_svg_image(id).is_some()
+}
+
+/// Encode the outline of a glyph as an uncolored Type3 glyph, which is filled
+/// with the fill color of the text it's used in.
+fn outline_glyph(font: &Font, gid: u16, width: f32) -> content::Encoded {
+    // Type3 glyph space is in font units, so the bounding box is too.
+    let bbox = font.ttf().global_bounding_box();
+    let mut content = Content::new();
+    content.start_shape_glyph(
+        width,
+        bbox.x_min.into(),
+        bbox.y_min.into(),
+        bbox.x_max.into(),
+        bbox.y_max.into(),
+    );
+
+    let mut builder = GlyphBuilder { content: &mut content, last: (0.0, 0.0) };
+    if font.ttf().outline_glyph(GlyphId(gid), &mut builder).is_some() {
+        content.fill_nonzero();
+    }
+
+    content::Encoded {
+        size: Size::splat(Abs::pt(font.units_per_em())),
+        content: deflate_deferred(content.finish()),
+        uses_opacities: false,
+        links: vec![],
+        structure: vec![],
+        marked: vec![],
+    }
+}
+
+/// Writes a glyph outline into a content stream.
+struct GlyphBuilder<'a> {
+    content: &'a mut Content,
+    /// The current point, needed to convert quadratic curves.
+    last: (f32, f32),
+}
+
+impl OutlineBuilder for GlyphBuilder<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.content.move_to(x, y);
+        self.last = (x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.content.line_to(x, y);
+        self.last = (x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        // PDF only supports cubic curves, so we elevate the degree.
+        let (x0, y0) = self.last;
+        self.content.cubic_to(
+            x0 + 2.0 / 3.0 * (x1 - x0),
+            y0 + 2.0 / 3.0 * (y1 - y0),
+            x + 2.0 / 3.0 * (x1 - x),
+            y + 2.0 / 3.0 * (y1 - y),
+            x,
+            y,
+        );
+        self.last = (x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.content.cubic_to(x1, y1, x2, y2, x, y);
+        self.last = (x, y);
+    }
+
+    fn close(&mut self) {
+        self.content.close_path();
+    }
+}
+
 impl<R> ColorFontMap<R> {
     /// Iterate over all Type3 fonts.
     ///
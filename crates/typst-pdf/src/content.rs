@@ -4,19 +4,25 @@
 //!
 //! See also [`pdf_writer::Content`].
 
-use ecow::eco_format;
+use ecow::{eco_format, EcoString};
 use pdf_writer::{
     types::{ColorSpaceOperand, LineCapStyle, LineJoinStyle, TextRenderingMode},
     Content, Finish, Name, Rect, Str,
 };
+use typst::foundations::{Content as Elem, StyleChain};
 use typst::layout::{
     Abs, Em, Frame, FrameItem, GroupItem, Point, Ratio, Size, Transform,
 };
-use typst::model::Destination;
+use typst::model::{
+    Destination, EnumElem, EnumItem, FigureElem, HeadingElem, ListElem, ListItem,
+    TableElem,
+};
+use typst::syntax::Span;
 use typst::text::{color::is_color_glyph, Font, TextItem, TextItemView};
 use typst::utils::{Deferred, Numeric, SliceExt};
 use typst::visualize::{
-    FixedStroke, Geometry, Image, LineCap, LineJoin, Paint, Path, PathItem, Shape,
+    FixedStroke, Geometry, Image, ImageElem, LineCap, LineJoin, Paint, Path, PathItem,
+    Shape,
 };
 
 use crate::color_font::ColorFontMap;
@@ -32,14 +38,20 @@ use crate::{deflate_deferred, AbsExt, EmExt};
 /// `color_glyph_width` should be `None` unless the `Frame` represents a [color
 /// glyph].
 ///
+/// If `tagged` is true, the content of structural elements and page artifacts
+/// is wrapped in marked-content sequences, which are described by
+/// [`Encoded::structure`] and [`Encoded::marked`].
+///
 /// [color glyph]: `crate::color_font`
 pub fn build(
     resources: &mut Resources<()>,
     frame: &Frame,
     color_glyph_width: Option<f32>,
+    tagged: bool,
 ) -> Encoded {
     let size = frame.size();
     let mut ctx = Builder::new(resources, size);
+    ctx.tagged = tagged;
 
     if let Some(width) = color_glyph_width {
         ctx.content.start_color_glyph(width);
@@ -55,12 +67,15 @@ pub fn build(
 
     // Encode the frame into the content stream.
     write_frame(&mut ctx, frame);
+    ctx.end_marked_content();
 
     Encoded {
         size,
         content: deflate_deferred(ctx.content.finish()),
         uses_opacities: ctx.uses_opacities,
         links: ctx.links,
+        structure: ctx.structure,
+        marked: ctx.marked,
     }
 }
 
@@ -74,6 +89,34 @@ pub struct Encoded {
     pub uses_opacities: bool,
     /// Links in the PDF coordinate system.
     pub links: Vec<(Destination, Rect)>,
+    /// The structure elements whose content is in this stream.
+    pub structure: Vec<StructNode>,
+    /// The structure element of each marked-content sequence, by the
+    /// sequence's marked-content identifier.
+    pub marked: Vec<usize>,
+}
+
+/// A structure element of a tagged PDF, as found in a content stream.
+pub struct StructNode {
+    /// The standard structure type, like `H1` or `Table`.
+    pub kind: &'static str,
+    /// An alternate description of the element's content.
+    pub alt: Option<EcoString>,
+    /// The element, by which the parts of an element that spans multiple
+    /// pages are joined. This is `None` for loose paragraphs.
+    pub elem: Option<Elem>,
+    /// The index of the enclosing structure element in the same stream.
+    pub parent: Option<usize>,
+    /// The element's marked content and nested elements, in order.
+    pub kids: Vec<StructKid>,
+}
+
+/// A kid of a structure element.
+pub enum StructKid {
+    /// A marked-content sequence, given by its identifier.
+    Content(usize),
+    /// A nested structure element, given by its index.
+    Elem(usize),
 }
 
 /// An exporter for a single PDF content stream.
@@ -96,6 +139,21 @@ pub struct Builder<'a, R = ()> {
     uses_opacities: bool,
     /// All clickable links that are present in this content.
     links: Vec<(Destination, Rect)>,
+    /// Whether to write marked content for the document structure.
+    tagged: bool,
+    /// The structure elements found so far.
+    structure: Vec<StructNode>,
+    /// The structure element of each marked-content sequence so far.
+    marked: Vec<usize>,
+    /// Whether the last marked-content sequence is still open.
+    open: bool,
+    /// The structure element we are currently in.
+    parent: Option<usize>,
+    /// The paragraph that holds the open marked-content sequence if it isn't
+    /// part of any other structure element.
+    loose: Option<usize>,
+    /// Whether we are currently in an artifact.
+    in_artifact: bool,
 }
 
 impl<'a, R> Builder<'a, R> {
@@ -108,8 +166,69 @@ impl<'a, R> Builder<'a, R> {
             state: State::new(size),
             saves: vec![],
             links: vec![],
+            tagged: false,
+            structure: vec![],
+            marked: vec![],
+            open: false,
+            parent: None,
+            loose: None,
+            in_artifact: false,
         }
     }
+
+    /// Mark the content that is written next as part of the structure
+    /// element we are currently in.
+    ///
+    /// Marked-content sequences with identifiers must not be nested, so each
+    /// run of content gets its own flat sequence. Content outside of any
+    /// structure element forms paragraphs of its own, one per run.
+    fn mark_content(&mut self) {
+        if !self.tagged || self.in_artifact {
+            return;
+        }
+
+        if self.open && self.marked.last().copied() == self.parent.or(self.loose) {
+            return;
+        }
+
+        self.end_marked_content();
+        let node = match self.parent {
+            Some(node) => node,
+            None => {
+                let node = self.structure.len();
+                self.structure.push(StructNode {
+                    kind: "P",
+                    alt: None,
+                    elem: None,
+                    parent: None,
+                    kids: vec![],
+                });
+                self.loose = Some(node);
+                node
+            }
+        };
+
+        let mcid = self.marked.len();
+        self.marked.push(node);
+        self.structure[node].kids.push(StructKid::Content(mcid));
+
+        let kind = self.structure[node].kind;
+        let mut marked = self
+            .content
+            .begin_marked_content_with_properties(Name(kind.as_bytes()));
+        marked.properties().pair(Name(b"MCID"), mcid as i32);
+        marked.finish();
+        self.open = true;
+    }
+
+    /// End the open marked-content sequence, if any.
+    fn end_marked_content(&mut self) {
+        if self.open {
+            self.content.end_marked_content();
+            self.open = false;
+        }
+        self.loose = None;
+    }
 }
 
 /// A simulated graphics state used to deduplicate graphics state changes and
@@ -293,7 +412,8 @@ impl Builder<'_, ()> {
                 Some(Paint::Gradient(_))
             )
         {
-            let FixedStroke { paint, thickness, cap, join, dash, miter_limit } = stroke;
+            let FixedStroke { paint, thickness, cap, join, dash, miter_limit, .. } =
+                stroke;
             paint.set_as_stroke(self, on_text, transforms);
 
             self.content.set_line_width(thickness.to_f32());
@@ -346,17 +466,96 @@ pub(crate) fn write_frame(ctx: &mut Builder, frame: &Frame) {
         let y = pos.y.to_f32();
         match item {
             FrameItem::Group(group) => write_group(ctx, pos, group),
-            FrameItem::Text(text) => write_text(ctx, pos, text),
-            FrameItem::Shape(shape, _) => write_shape(ctx, pos, shape),
-            FrameItem::Image(image, size, _) => write_image(ctx, x, y, image, *size),
+            FrameItem::Text(text) => {
+                ctx.mark_content();
+                write_text(ctx, pos, text);
+            }
+            FrameItem::Shape(shape, _) => {
+                ctx.mark_content();
+                write_shape(ctx, pos, shape);
+            }
+            FrameItem::Image(image, size, span) => {
+                write_image(ctx, x, y, image, *size, *span)
+            }
             FrameItem::Link(dest, size) => write_link(ctx, pos, dest, *size),
             FrameItem::Tag(_) => {}
         }
     }
 }
 
+/// Start a structure element for the element whose content a group holds.
+fn start_struct(ctx: &mut Builder, elem: &Elem) {
+    if !ctx.tagged || ctx.in_artifact {
+        return;
+    }
+
+    let Some((kind, alt)) = struct_kind(elem) else { return };
+    let node = ctx.structure.len();
+    if let Some(parent) = ctx.parent {
+        ctx.structure[parent].kids.push(StructKid::Elem(node));
+    }
+
+    ctx.structure.push(StructNode {
+        kind,
+        alt,
+        elem: Some(elem.clone()),
+        parent: ctx.parent,
+        kids: vec![],
+    });
+    ctx.parent = Some(node);
+}
+
 /// Encode a group into the content stream.
 fn write_group(ctx: &mut Builder, pos: Point, group: &GroupItem) {
+    // Marked content must not cross the group's graphics state boundary.
+    ctx.end_marked_content();
+
+    if group.artifact && ctx.tagged && !ctx.in_artifact {
+        // Page furniture is not part of the logical structure.
+        ctx.content.begin_marked_content(Name(b"Artifact"));
+        ctx.in_artifact = true;
+        write_group_impl(ctx, pos, group);
+        ctx.in_artifact = false;
+        ctx.content.end_marked_content();
+    } else if let Some(elem) = &group.structure {
+        // The structure element ends with the group that holds its content.
+        let parent = ctx.parent;
+        start_struct(ctx, elem);
+        write_group_impl(ctx, pos, group);
+        ctx.parent = parent;
+    } else {
+        write_group_impl(ctx, pos, group);
+    }
+}
+
+/// Determine the structure type of an element, if it has one.
+fn struct_kind(elem: &Elem) -> Option<(&'static str, Option<EcoString>)> {
+    let styles = StyleChain::default();
+    let kind = if let Some(heading) = elem.to_packed::<HeadingElem>() {
+        const LEVELS: [&str; 6] = ["H1", "H2", "H3", "H4", "H5", "H6"];
+        LEVELS[(heading.resolve_level(styles).get() - 1).min(5)]
+    } else if let Some(figure) = elem.to_packed::<FigureElem>() {
+        let alt = figure.alt(styles).or_else(|| {
+            figure
+                .body()
+                .to_packed::<ImageElem>()
+                .and_then(|image| image.alt(styles))
+        });
+        return Some(("Figure", alt.filter(|alt| !alt.is_empty())));
+    } else if elem.is::<TableElem>() {
+        "Table"
+    } else if elem.is::<ListElem>() || elem.is::<EnumElem>() {
+        "L"
+    } else if elem.is::<ListItem>() || elem.is::<EnumItem>() {
+        "LI"
+    } else {
+        return None;
+    };
+    Some((kind, None))
+}
+
+/// Encode a group into the content stream, without the artifact marking.
+fn write_group_impl(ctx: &mut Builder, pos: Point, group: &GroupItem) {
     let translation = Transform::translate(pos.x, pos.y);
 
     ctx.save_state();
@@ -380,11 +579,20 @@ fn write_group(ctx: &mut Builder, pos: Point, group: &GroupItem) {
     }
 
     write_frame(ctx, &group.frame);
+    ctx.end_marked_content();
     ctx.restore_state();
 }
 
 /// Encode a text run into the content stream.
 fn write_text(ctx: &mut Builder, pos: Point, text: &TextItem) {
+    // The embedded font data of fonts at a non-default optical size wouldn't
+    // match the instance the text was shaped with, so their glyphs are drawn
+    // like color glyphs.
+    if text.font.optical_size().is_some() {
+        write_color_glyphs(ctx, pos, TextItemView::all_of(text));
+        return;
+    }
+
     let ttf = text.font.ttf();
     let tables = ttf.tables();
 
@@ -542,6 +750,14 @@ fn write_color_glyphs(ctx: &mut Builder, pos: Point, text: TextItemView) {
 
     let mut last_font = None;
 
+    // Outline glyphs of fonts at an optical size are filled with the current
+    // fill color.
+    if text.item.font.optical_size().is_some() {
+        let fill_transform = ctx.state.transforms(Size::zero(), pos);
+        ctx.set_fill(&text.item.fill, true, fill_transform);
+        ctx.set_opacities(None, Some(&text.item.fill));
+    }
+
     ctx.content.begin_text();
     ctx.content.set_text_matrix([1.0, 0.0, 0.0, -1.0, x, y]);
     // So that the next call to ctx.set_font() will change the font to one that
@@ -554,6 +770,7 @@ fn write_color_glyphs(ctx: &mut Builder, pos: Point, text: TextItemView) {
         .entry(text.item.font.clone())
         .or_default();
 
+    let mut cursor = Em::zero();
     for glyph in text.glyphs() {
         // Retrieve the Type3 font reference and the glyph index in the font.
         let color_fonts = ctx
@@ -570,7 +787,12 @@ fn write_color_glyphs(ctx: &mut Builder, pos: Point, text: TextItemView) {
             last_font = Some(font);
         }
 
+        // Position each glyph on its own, so that kerning and offsets from
+        // shaping are kept.
+        let offset = (cursor + glyph.x_offset).at(text.item.size).to_f32();
+        ctx.content.set_text_matrix([1.0, 0.0, 0.0, -1.0, x + offset, y]);
         ctx.content.show(Str(&[index]));
+        cursor += glyph.x_advance;
 
         glyph_set
             .entry(glyph.id)
@@ -581,6 +803,13 @@ fn write_color_glyphs(ctx: &mut Builder, pos: Point, text: TextItemView) {
 
 /// Encode a geometrical shape into the content stream.
 fn write_shape(ctx: &mut Builder, pos: Point, shape: &Shape) {
+    if let Some(shapes) = shape.split_dash_paints() {
+        for shape in &shapes {
+            write_shape(ctx, pos, shape);
+        }
+        return;
+    }
+
     let x = pos.x.to_f32();
     let y = pos.y.to_f32();
 
@@ -661,23 +890,36 @@ fn write_path(ctx: &mut Builder, x: f32, y: f32, path: &Path) {
 }
 
 /// Encode a vector or raster image into the content stream.
-fn write_image(ctx: &mut Builder, x: f32, y: f32, image: &Image, size: Size) {
+fn write_image(ctx: &mut Builder, x: f32, y: f32, image: &Image, size: Size, span: Span) {
     let index = ctx.resources.images.insert(image.clone());
     ctx.resources.deferred_images.entry(index).or_insert_with(|| {
         let (image, color_space) = deferred_image(image.clone());
         if let Some(color_space) = color_space {
             ctx.resources.colors.mark_as_used(color_space);
         }
-        image
+        (image, span)
     });
 
+    // Images with empty alt text are decorative and thus not part of the
+    // structure element's content.
+    let decorative = image.alt() == Some("") && ctx.tagged && !ctx.in_artifact;
+    if decorative {
+        ctx.end_marked_content();
+    } else {
+        ctx.mark_content();
+    }
+
     let name = eco_format!("Im{index}");
     let w = size.x.to_f32();
     let h = size.y.to_f32();
     ctx.content.save_state();
     ctx.content.transform([w, 0.0, 0.0, -h, x, y + h]);
 
-    if let Some(alt) = image.alt() {
+    if decorative {
+        ctx.content.begin_marked_content(Name(b"Artifact"));
+        ctx.content.x_object(Name(name.as_bytes()));
+        ctx.content.end_marked_content();
+    } else if let Some(alt) = image.alt() {
         let mut image_span =
             ctx.content.begin_marked_content_with_properties(Name(b"Span"));
         let mut image_alt = image_span.properties();
@@ -1,15 +1,40 @@
 use std::collections::HashMap;
 use std::io::Cursor;
 
+use ecow::EcoVec;
 use image::{DynamicImage, GenericImageView, Rgba};
 use pdf_writer::{Chunk, Filter, Finish, Ref};
+use typst::diag::{SourceDiagnostic, SourceResult, StrResult};
 use typst::utils::Deferred;
 use typst::visualize::{
     ColorSpace, Image, ImageKind, RasterFormat, RasterImage, SvgImage,
 };
 
+use crate::resources::Resources;
 use crate::{color, deflate, PdfChunk, WithGlobalRefs};
 
+/// Check that all used images could be encoded, waiting for their encoding to
+/// finish.
+pub fn check_images(resources: &Resources<()>) -> SourceResult<()> {
+    let mut errors = EcoVec::new();
+    resources.traverse(&mut |resources| {
+        let mut indices: Vec<_> = resources.deferred_images.keys().collect();
+        indices.sort();
+        for i in indices {
+            let (handle, span) = &resources.deferred_images[i];
+            if let Err(message) = handle.wait() {
+                errors.push(SourceDiagnostic::error(*span, message.clone()));
+            }
+        }
+    });
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 /// Embed all used images into the PDF.
 #[typst_macros::time(name = "write images")]
 pub fn write_images(context: &WithGlobalRefs) -> (PdfChunk, HashMap<Image, Ref>) {
@@ -21,8 +46,11 @@ pub fn write_images(context: &WithGlobalRefs) -> (PdfChunk, HashMap<Image, Ref>)
                 continue;
             }
 
-            let handle = resources.deferred_images.get(&i).unwrap();
-            match handle.wait() {
+            // Images that failed to encode were already reported by
+            // `check_images`.
+            let (handle, _) = resources.deferred_images.get(&i).unwrap();
+            let Ok(encoded) = handle.wait() else { continue };
+            match encoded {
                 EncodedImage::Raster {
                     data,
                     filter,
@@ -108,10 +136,12 @@ pub fn write_images(context: &WithGlobalRefs) -> (PdfChunk, HashMap<Image, Ref>)
 ///
 /// Also starts the deferred encoding of the image.
 #[comemo::memoize]
-pub fn deferred_image(image: Image) -> (Deferred<EncodedImage>, Option<ColorSpace>) {
+pub fn deferred_image(
+    image: Image,
+) -> (Deferred<StrResult<EncodedImage>>, Option<ColorSpace>) {
     let color_space = match image.kind() {
         ImageKind::Raster(raster) if raster.icc().is_none() => {
-            if raster.dynamic().color().channel_count() > 2 {
+            if raster.color().channel_count() > 2 {
                 Some(ColorSpace::Srgb)
             } else {
                 Some(ColorSpace::D65Gray)
@@ -122,19 +152,26 @@ pub fn deferred_image(image: Image) -> (Deferred<EncodedImage>, Option<ColorSpac
 
     let deferred = Deferred::new(move || match image.kind() {
         ImageKind::Raster(raster) => {
-            let raster = raster.clone();
+            let dynamic = raster.dynamic()?;
             let (width, height) = (raster.width(), raster.height());
-            let (data, filter, has_color) = encode_raster_image(&raster);
+            let (data, filter, has_color) = encode_raster_image(raster, &dynamic);
             let icc = raster.icc().map(deflate);
 
-            let alpha =
-                raster.dynamic().color().has_alpha().then(|| encode_alpha(&raster));
-
-            EncodedImage::Raster { data, filter, has_color, width, height, icc, alpha }
+            let alpha = dynamic.color().has_alpha().then(|| encode_alpha(&dynamic));
+
+            Ok(EncodedImage::Raster {
+                data,
+                filter,
+                has_color,
+                width,
+                height,
+                icc,
+                alpha,
+            })
         }
         ImageKind::Svg(svg) => {
             let (chunk, id) = encode_svg(svg);
-            EncodedImage::Svg(chunk, id)
+            Ok(EncodedImage::Svg(chunk, id))
         }
     });
 
@@ -146,8 +183,10 @@ pub fn deferred_image(image: Image) -> (Deferred<EncodedImage>, Option<ColorSpac
 ///
 /// Skips the alpha channel as that's encoded separately.
 #[typst_macros::time(name = "encode raster image")]
-fn encode_raster_image(image: &RasterImage) -> (Vec<u8>, Filter, bool) {
-    let dynamic = image.dynamic();
+fn encode_raster_image(
+    image: &RasterImage,
+    dynamic: &DynamicImage,
+) -> (Vec<u8>, Filter, bool) {
     let channel_count = dynamic.color().channel_count();
     let has_color = channel_count > 2;
 
@@ -171,20 +210,15 @@ fn encode_raster_image(image: &RasterImage) -> (Vec<u8>, Filter, bool) {
 
 /// Encode an image's alpha channel if present.
 #[typst_macros::time(name = "encode alpha")]
-fn encode_alpha(raster: &RasterImage) -> (Vec<u8>, Filter) {
-    let pixels: Vec<_> = raster
-        .dynamic()
-        .pixels()
-        .map(|(_, _, Rgba([_, _, _, a]))| a)
-        .collect();
+fn encode_alpha(dynamic: &DynamicImage) -> (Vec<u8>, Filter) {
+    let pixels: Vec<_> = dynamic.pixels().map(|(_, _, Rgba([_, _, _, a]))| a).collect();
     (deflate(&pixels), Filter::FlateDecode)
 }
 
 /// Encode an SVG into a chunk of PDF objects.
 #[typst_macros::time(name = "encode svg")]
 fn encode_svg(svg: &SvgImage) -> (Chunk, Ref) {
-    // TODO: Don't unwrap once we have export diagostics.
-    svg2pdf::to_chunk(svg.tree(), svg2pdf::ConversionOptions::default()).unwrap()
+    svg2pdf::to_chunk(svg.tree(), svg2pdf::ConversionOptions::default())
 }
 
 /// A pre-encoded image.
@@ -12,7 +12,9 @@ mod named_destination;
 mod outline;
 mod page;
 mod pattern;
+mod pdfa;
 mod resources;
+mod structure;
 
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -20,6 +22,7 @@ use std::ops::{Deref, DerefMut};
 
 use base64::Engine;
 use pdf_writer::{Chunk, Pdf, Ref};
+use typst::diag::SourceResult;
 use typst::foundations::{Datetime, Smart};
 use typst::layout::{Abs, Em, PageRanges, Transform};
 use typst::model::Document;
@@ -33,7 +36,7 @@ use crate::color_font::{write_color_fonts, ColorFontSlice};
 use crate::extg::{write_graphic_states, ExtGState};
 use crate::font::write_fonts;
 use crate::gradient::{write_gradients, PdfGradient};
-use crate::image::write_images;
+use crate::image::{check_images, write_images};
 use crate::named_destination::{write_named_destinations, NamedDestinations};
 use crate::page::{alloc_page_refs, traverse_pages, write_page_tree, EncodedPage};
 use crate::pattern::{write_patterns, PdfPattern};
@@ -63,6 +66,9 @@ use crate::resources::{
 ///
 /// The `page_ranges` option specifies which ranges of pages should be exported
 /// in the PDF. When `None`, all pages should be exported.
+///
+/// Images that fail to decode are left out. Use [`pdf_with`] to get errors
+/// for them instead.
 #[typst_macros::time(name = "pdf")]
 pub fn pdf(
     document: &Document,
@@ -72,23 +78,72 @@ pub fn pdf(
 ) -> Vec<u8> {
     PdfBuilder::new(document, page_ranges)
         .phase(|builder| builder.run(traverse_pages))
-        .phase(|builder| GlobalRefs {
-            color_functions: builder.run(alloc_color_functions_refs),
-            pages: builder.run(alloc_page_refs),
-            resources: builder.run(alloc_resources_refs),
-        })
-        .phase(|builder| References {
-            named_destinations: builder.run(write_named_destinations),
-            fonts: builder.run(write_fonts),
-            color_fonts: builder.run(write_color_fonts),
-            images: builder.run(write_images),
-            gradients: builder.run(write_gradients),
-            patterns: builder.run(write_patterns),
-            ext_gs: builder.run(write_graphic_states),
-        })
-        .phase(|builder| builder.run(write_page_tree))
-        .phase(|builder| builder.run(write_resource_dictionaries))
-        .export_with(ident, timestamp, write_catalog)
+        .finish(ident, timestamp, PdfStandard::default())
+}
+
+/// Export a document into a PDF file with the given options.
+///
+/// The parameters are the same as for [`pdf`]. In contrast to it, this fails
+/// if the document can't be exported in conformance with the requested
+/// standard or if an image fails to decode.
+#[typst_macros::time(name = "pdf")]
+pub fn pdf_with(
+    document: &Document,
+    ident: Smart<&str>,
+    timestamp: Option<Datetime>,
+    page_ranges: Option<PageRanges>,
+    options: &PdfExportOptions,
+) -> SourceResult<Vec<u8>> {
+    if options.standard.pdfa().is_some() {
+        pdfa::validate(document, page_ranges.as_ref())?;
+    }
+
+    let builder: PdfBuilder<WithResources> = PdfBuilder::new(document, page_ranges)
+        .phase(|builder| builder.run(traverse_pages));
+    check_images(&builder.state.resources)?;
+
+    Ok(builder.finish(ident, timestamp, options.standard))
+}
+
+/// Options for PDF export.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PdfExportOptions {
+    /// Which PDF standard the file should conform to.
+    pub standard: PdfStandard,
+}
+
+/// A PDF standard that an exported file conforms to.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum PdfStandard {
+    /// Plain PDF 1.7.
+    #[default]
+    V_1_7,
+    /// PDF/A-2b for long-term archival.
+    ///
+    /// In addition to plain PDF 1.7, this writes an sRGB output intent and
+    /// identifies the file as PDF/A in its XMP metadata. Fonts are always
+    /// embedded. Transparency is permitted by this standard and blended in
+    /// sRGB. Export fails for fonts whose license forbids embedding and for
+    /// CMYK colors, which don't match the sRGB output intent.
+    A_2b,
+    /// PDF/A-3b for long-term archival.
+    ///
+    /// The same as PDF/A-2b, but additionally permits embedded files of any
+    /// kind. Typst doesn't embed files yet, so this only changes the
+    /// identification.
+    A_3b,
+}
+
+impl PdfStandard {
+    /// The PDF/A part and conformance level, if this is a PDF/A standard.
+    fn pdfa(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::V_1_7 => None,
+            Self::A_2b => Some(("2", "B")),
+            Self::A_3b => Some(("3", "B")),
+        }
+    }
 }
 
 /// A struct to build a PDF following a fixed succession of phases.
@@ -293,6 +348,35 @@ impl<'a> PdfBuilder<WithDocument<'a>> {
     }
 }
 
+impl<'a> PdfBuilder<WithResources<'a>> {
+    /// Run the remaining phases once all resources are collected and write
+    /// the finished file.
+    fn finish(
+        self,
+        ident: Smart<&str>,
+        timestamp: Option<Datetime>,
+        standard: PdfStandard,
+    ) -> Vec<u8> {
+        self.phase(|builder| GlobalRefs {
+            color_functions: builder.run(alloc_color_functions_refs),
+            pages: builder.run(alloc_page_refs),
+            resources: builder.run(alloc_resources_refs),
+        })
+        .phase(|builder| References {
+            named_destinations: builder.run(write_named_destinations),
+            fonts: builder.run(write_fonts),
+            color_fonts: builder.run(write_color_fonts),
+            images: builder.run(write_images),
+            gradients: builder.run(write_gradients),
+            patterns: builder.run(write_patterns),
+            ext_gs: builder.run(write_graphic_states),
+        })
+        .phase(|builder| builder.run(write_page_tree))
+        .phase(|builder| builder.run(write_resource_dictionaries))
+        .export_with(ident, timestamp, standard, write_catalog)
+    }
+}
+
 impl<S> PdfBuilder<S> {
     /// Start a new phase, and save its output in the global state.
     fn phase<NS, B, O>(mut self, builder: B) -> PdfBuilder<NS>
@@ -345,12 +429,13 @@ impl<S> PdfBuilder<S> {
         mut self,
         ident: Smart<&str>,
         timestamp: Option<Datetime>,
+        standard: PdfStandard,
         process: P,
     ) -> Vec<u8>
     where
-        P: Fn(S, Smart<&str>, Option<Datetime>, &mut Pdf, &mut Ref),
+        P: Fn(S, Smart<&str>, Option<Datetime>, PdfStandard, &mut Pdf, &mut Ref),
     {
-        process(self.state, ident, timestamp, &mut self.pdf, &mut self.alloc);
+        process(self.state, ident, timestamp, standard, &mut self.pdf, &mut self.alloc);
         self.pdf.finish()
     }
 }
@@ -54,7 +54,9 @@ pub fn write_named_destinations(
     matches.sort_by_key(|&(_, label)| label);
 
     for (loc, label) in matches {
-        let pos = context.document.introspector.position(loc);
+        let Some(pos) = context.document.introspector.position(loc) else {
+            continue;
+        };
         let index = pos.page.get() - 1;
         let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
 
@@ -61,7 +61,7 @@ pub fn traverse_pages(
 /// Construct a page object.
 #[typst_macros::time(name = "construct page")]
 fn construct_page(out: &mut Resources<()>, frame: &Frame) -> EncodedPage {
-    let content = content::build(out, frame, None);
+    let content = content::build(out, frame, None, true);
 
     EncodedPage { content, label: None }
 }
@@ -142,8 +142,10 @@ fn write_page(
                         // `key` must be a `Str`, not a `Name`.
                         .pair(Name(b"D"), Str(key.as_str().as_bytes()));
                     continue;
+                } else if let Some(pos) = ctx.document.introspector.position(*loc) {
+                    pos
                 } else {
-                    ctx.document.introspector.position(*loc)
+                    continue;
                 }
             }
         };
@@ -185,6 +187,11 @@ fn write_page(
 
     page_writer.annotations(annotations);
 
+    // The parent tree of the structure tree is keyed by page index.
+    if !page.content.marked.is_empty() {
+        page_writer.pair(Name(b"StructParents"), i as i32);
+    }
+
     page_writer.finish();
 
     chunk
@@ -52,6 +52,7 @@ pub fn write_patterns(context: &WithGlobalRefs) -> (PdfChunk, HashMap<PdfPattern
             tiling_pattern
                 .matrix(transform_to_array(
                     transform
+                        .pre_concat(pattern.transform())
                         .pre_concat(Transform::scale(Ratio::one(), -Ratio::one()))
                         .post_concat(Transform::translate(
                             Abs::zero(),
@@ -103,7 +104,7 @@ fn register_pattern(
     };
 
     // Render the body.
-    let content = content::build(&mut patterns.resources, pattern.frame(), None);
+    let content = content::build(&mut patterns.resources, pattern.frame(), None, false);
 
     let pdf_pattern = PdfPattern {
         transform,
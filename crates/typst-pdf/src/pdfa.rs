@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use ecow::EcoVec;
+use ttf_parser::Permissions;
+use typst::diag::{error, SourceDiagnostic, SourceResult};
+use typst::layout::{Frame, FrameItem, PageRanges};
+use typst::model::Document;
+use typst::syntax::Span;
+use typst::text::Font;
+use typst::visualize::{
+    Color, ColorSpace, FixedStroke, Image, ImageKind, Paint, RasterFormat,
+};
+
+/// Check that a document can be exported as PDF/A.
+///
+/// Transparency is permitted by PDF/A-2 and PDF/A-3 and its blending space is
+/// the sRGB output intent, so it is fine. This includes the alpha channels of
+/// images, which are written as soft masks. However, all fonts must be
+/// embedded, which their license may forbid, and device-dependent colors must
+/// match the output intent, ruling out CMYK paints and images. Embedded ICC
+/// profiles must also describe the colors an image is written with.
+#[typst_macros::time(name = "validate pdf/a")]
+pub fn validate(
+    document: &Document,
+    page_ranges: Option<&PageRanges>,
+) -> SourceResult<()> {
+    let mut validator = Validator::default();
+    for (i, page) in document.pages.iter().enumerate() {
+        if page_ranges.map_or(true, |ranges| ranges.includes_page_index(i)) {
+            validator.frame(&page.frame);
+        }
+    }
+
+    if validator.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(validator.errors)
+    }
+}
+
+/// Collects the PDF/A violations in frames.
+#[derive(Default)]
+struct Validator {
+    errors: EcoVec<SourceDiagnostic>,
+    fonts: HashSet<Font>,
+    images: HashSet<Image>,
+    cmyk: bool,
+}
+
+impl Validator {
+    /// Check the items of a frame.
+    fn frame(&mut self, frame: &Frame) {
+        for (_, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => self.frame(&group.frame),
+                FrameItem::Text(text) => {
+                    let span = text.glyphs.first().map_or(Span::detached(), |g| g.span.0);
+                    self.font(&text.font, span);
+                    self.paint(&text.fill, span);
+                    self.stroke(text.stroke.as_ref(), span);
+                }
+                FrameItem::Shape(shape, span) => {
+                    if let Some(fill) = &shape.fill {
+                        self.paint(fill, *span);
+                    }
+                    self.stroke(shape.stroke.as_ref(), *span);
+                }
+                FrameItem::Image(image, _, span) => self.image(image, *span),
+                FrameItem::Link(..) | FrameItem::Tag(_) => {}
+            }
+        }
+    }
+
+    /// Check that a font may be embedded.
+    fn font(&mut self, font: &Font, span: Span) {
+        if font.ttf().permissions() == Some(Permissions::Restricted)
+            && self.fonts.insert(font.clone())
+        {
+            self.errors.push(error!(
+                span,
+                "the license of the font \"{}\" forbids embedding it",
+                font.info().family;
+                hint: "PDF/A requires all fonts to be embedded";
+                hint: "try using a different font"
+            ));
+        }
+    }
+
+    /// Check that an image uses no CMYK colors and that its ICC profile, if
+    /// any, matches the colors it is written with.
+    fn image(&mut self, image: &Image, span: Span) {
+        let ImageKind::Raster(raster) = image.kind() else { return };
+        if !self.images.insert(image.clone()) {
+            return;
+        }
+
+        // CMYK JPEGs are converted to RGB when they are decoded, so their
+        // colors can only be told apart from the image's header.
+        if raster.format() == RasterFormat::Jpg
+            && jpeg_components(raster.data()) == Some(4)
+        {
+            self.errors.push(error!(
+                span,
+                "CMYK images are not supported in PDF/A export";
+                hint: "the output intent of the document is sRGB";
+                hint: "try converting the image to RGB"
+            ));
+            return;
+        }
+
+        if let Some(icc) = raster.icc() {
+            // The image is written in RGB if it has colors, else in grayscale.
+            let color = raster.color().channel_count() > 2;
+            let expected: &[u8] = if color { b"RGB " } else { b"GRAY" };
+            if icc.get(16..20) != Some(expected) {
+                self.errors.push(error!(
+                    span,
+                    "the ICC profile of the image does not match its colors";
+                    hint: "PDF/A requires embedded ICC profiles to describe the \
+                           colors of the image";
+                    hint: "try removing the profile or converting the image to sRGB"
+                ));
+            }
+        }
+    }
+
+    /// Check the paints of a stroke.
+    fn stroke(&mut self, stroke: Option<&FixedStroke>, span: Span) {
+        let Some(stroke) = stroke else { return };
+        for paint in stroke.paints() {
+            self.paint(paint, span);
+        }
+    }
+
+    /// Check that a paint uses no CMYK colors.
+    fn paint(&mut self, paint: &Paint, span: Span) {
+        let cmyk = match paint {
+            Paint::Solid(color) => matches!(color, Color::Cmyk(_)),
+            Paint::Gradient(gradient) => gradient.space() == ColorSpace::Cmyk,
+            Paint::Pattern(pattern) => {
+                self.frame(pattern.frame());
+                false
+            }
+        };
+
+        if cmyk && !self.cmyk {
+            self.cmyk = true;
+            self.errors.push(error!(
+                span,
+                "CMYK colors are not supported in PDF/A export";
+                hint: "the output intent of the document is sRGB";
+                hint: "try converting the color with `rgb`"
+            ));
+        }
+    }
+}
+
+/// The number of color components of a JPEG image, read from its frame
+/// header.
+fn jpeg_components(data: &[u8]) -> Option<u8> {
+    let mut i = 2;
+    while data.get(i) == Some(&0xFF) {
+        let marker = *data.get(i + 1)?;
+        let len = u16::from_be_bytes([*data.get(i + 2)?, *data.get(i + 3)?]) as usize;
+
+        // The start of frame markers, except for the ones that define Huffman
+        // tables, arithmetic coding conditions and extensions.
+        if (0xC0..=0xCF).contains(&marker) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+            return data.get(i + 9).copied();
+        }
+
+        i += 2 + len;
+    }
+    None
+}
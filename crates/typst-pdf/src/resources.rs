@@ -12,6 +12,8 @@ use std::hash::Hash;
 use ecow::{eco_format, EcoString};
 use pdf_writer::{Dict, Finish, Name, Ref};
 use subsetter::GlyphRemapper;
+use typst::diag::StrResult;
+use typst::syntax::Span;
 use typst::text::Lang;
 use typst::{text::Font, utils::Deferred, visualize::Image};
 
@@ -57,8 +59,9 @@ pub struct Resources<R = Ref> {
     pub fonts: Remapper<Font>,
     /// Deduplicates images used across the document.
     pub images: Remapper<Image>,
-    /// Handles to deferred image conversions.
-    pub deferred_images: HashMap<usize, Deferred<EncodedImage>>,
+    /// Handles to deferred image conversions, together with the span of the
+    /// image's first use.
+    pub deferred_images: HashMap<usize, (Deferred<StrResult<EncodedImage>>, Span)>,
     /// Deduplicates gradients used across the document.
     pub gradients: Remapper<PdfGradient>,
     /// Deduplicates patterns used across the document.
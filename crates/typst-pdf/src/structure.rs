@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use ecow::EcoString;
+use pdf_writer::{Finish, Name, Pdf, Ref, TextStr};
+use typst::foundations::Content as Elem;
+
+use crate::content::{StructKid, StructNode};
+use crate::WithEverything;
+
+/// A structure element of the whole document.
+struct Node<'a> {
+    /// The standard structure type, like `H1` or `Table`.
+    kind: &'static str,
+    /// An alternate description of the element's content.
+    alt: Option<&'a EcoString>,
+    /// The page on which the element starts.
+    page: Ref,
+    /// The enclosing structure element, if any.
+    parent: Option<usize>,
+    /// The element's marked content and nested elements, in order.
+    kids: Vec<Kid>,
+}
+
+/// A kid of a structure element of the whole document.
+enum Kid {
+    /// A marked-content sequence, given by its page and identifier.
+    Content(Ref, usize),
+    /// A nested structure element, given by its index.
+    Elem(usize),
+}
+
+/// Construct the logical structure tree of the document from the structure
+/// elements and marked content on the pages.
+///
+/// Elements that span multiple pages, like a long list, have parts on each of
+/// their pages. These are joined into a single structure element.
+///
+/// Returns `None` if no exported page contains any structure elements.
+pub(crate) fn write_structure_tree(
+    chunk: &mut Pdf,
+    alloc: &mut Ref,
+    ctx: &WithEverything,
+) -> Option<Ref> {
+    if ctx
+        .pages
+        .iter()
+        .flatten()
+        .all(|page| page.content.structure.is_empty())
+    {
+        return None;
+    }
+
+    let mut nodes: Vec<Node> = vec![];
+    let mut top_level = vec![];
+    let mut marked = vec![];
+
+    // The elements that were open at the end of the previous page, from the
+    // outermost one inwards.
+    let mut trailing: Vec<(&Elem, usize)> = vec![];
+
+    for (i, (page, page_ref)) in ctx.pages.iter().zip(&ctx.globals.pages).enumerate() {
+        let (Some(page), Some(page_ref)) = (page, *page_ref) else { continue };
+        let structure = &page.content.structure;
+
+        // The elements that the page starts with continue those that the
+        // previous page ended with if they are the same.
+        let mut joined = HashMap::new();
+        for (k, &(elem, id)) in path(structure, false).into_iter().zip(&trailing) {
+            if structure[k].elem.as_ref() != Some(elem) {
+                break;
+            }
+            joined.insert(k, id);
+        }
+
+        // Map the page's elements to the document's.
+        let mut ids = Vec::with_capacity(structure.len());
+        for (k, node) in structure.iter().enumerate() {
+            let id = joined.get(&k).copied().unwrap_or_else(|| {
+                nodes.push(Node {
+                    kind: node.kind,
+                    alt: node.alt.as_ref(),
+                    page: page_ref,
+                    parent: node.parent.map(|parent| ids[parent]),
+                    kids: vec![],
+                });
+                nodes.len() - 1
+            });
+            ids.push(id);
+        }
+
+        // Continued elements are already part of their parent.
+        for (k, node) in structure.iter().enumerate() {
+            let id = ids[k];
+            if node.parent.is_none() && !joined.contains_key(&k) {
+                top_level.push(id);
+            }
+
+            for kid in &node.kids {
+                match *kid {
+                    StructKid::Content(mcid) => {
+                        nodes[id].kids.push(Kid::Content(page_ref, mcid));
+                    }
+                    StructKid::Elem(child) if !joined.contains_key(&child) => {
+                        nodes[id].kids.push(Kid::Elem(ids[child]));
+                    }
+                    StructKid::Elem(_) => {}
+                }
+            }
+        }
+
+        trailing = path(structure, true)
+            .into_iter()
+            .map_while(|k| Some((structure[k].elem.as_ref()?, ids[k])))
+            .collect();
+
+        // Maps the page's marked-content identifiers to their elements.
+        let page_marked: Vec<usize> =
+            page.content.marked.iter().map(|&k| ids[k]).collect();
+        if !page_marked.is_empty() {
+            marked.push((i, page_marked));
+        }
+    }
+
+    let root_ref = alloc.bump();
+    let document_ref = alloc.bump();
+    let refs: Vec<Ref> = nodes.iter().map(|_| alloc.bump()).collect();
+
+    for (node, &elem_ref) in nodes.iter().zip(&refs) {
+        let mut elem = chunk.indirect(elem_ref).dict();
+        elem.pair(Name(b"Type"), Name(b"StructElem"));
+        elem.pair(Name(b"S"), Name(node.kind.as_bytes()));
+        elem.pair(Name(b"P"), node.parent.map_or(document_ref, |parent| refs[parent]));
+        elem.pair(Name(b"Pg"), node.page);
+        if let Some(alt) = node.alt {
+            elem.pair(Name(b"Alt"), TextStr(alt));
+        }
+
+        let mut kids = elem.insert(Name(b"K")).array();
+        for kid in &node.kids {
+            match *kid {
+                Kid::Content(page, mcid) if page == node.page => {
+                    kids.item(mcid as i32);
+                }
+                // Content on another page than the element's first one needs
+                // a reference to its page.
+                Kid::Content(page, mcid) => {
+                    let mut reference = kids.push().dict();
+                    reference.pair(Name(b"Type"), Name(b"MCR"));
+                    reference.pair(Name(b"Pg"), page);
+                    reference.pair(Name(b"MCID"), mcid as i32);
+                }
+                Kid::Elem(k) => {
+                    kids.item(refs[k]);
+                }
+            }
+        }
+        kids.finish();
+        elem.finish();
+    }
+
+    let mut parent_tree = vec![];
+    for (i, page_marked) in marked {
+        let array_ref = alloc.bump();
+        chunk
+            .indirect(array_ref)
+            .array()
+            .items(page_marked.iter().map(|&id| refs[id]));
+        parent_tree.push((i as i32, array_ref));
+    }
+
+    let mut document = chunk.indirect(document_ref).dict();
+    document.pair(Name(b"Type"), Name(b"StructElem"));
+    document.pair(Name(b"S"), Name(b"Document"));
+    document.pair(Name(b"P"), root_ref);
+    document
+        .insert(Name(b"K"))
+        .array()
+        .items(top_level.iter().map(|&id| refs[id]));
+    document.finish();
+
+    let mut root = chunk.indirect(root_ref).dict();
+    root.pair(Name(b"Type"), Name(b"StructTreeRoot"));
+    root.pair(Name(b"K"), document_ref);
+    root.pair(Name(b"ParentTreeNextKey"), ctx.pages.len() as i32);
+
+    // Pages are keyed by their index, see `page::write_page`.
+    let mut tree = root.insert(Name(b"ParentTree")).dict();
+    let mut nums = tree.insert(Name(b"Nums")).array();
+    for (key, array_ref) in parent_tree {
+        nums.item(key);
+        nums.item(array_ref);
+    }
+    nums.finish();
+    tree.finish();
+    root.finish();
+
+    Some(root_ref)
+}
+
+/// The elements along the first or last nested elements of a page's structure,
+/// from its first or last top-level element inwards.
+fn path(structure: &[StructNode], last: bool) -> Vec<usize> {
+    let pick = |candidates: Vec<usize>| {
+        if last {
+            candidates.last().copied()
+        } else {
+            candidates.first().copied()
+        }
+    };
+
+    let mut path = vec![];
+    let mut next = pick(
+        (0..structure.len())
+            .filter(|&k| structure[k].parent.is_none())
+            .collect(),
+    );
+    while let Some(k) = next {
+        path.push(k);
+        next = pick(
+            structure[k]
+                .kids
+                .iter()
+                .filter_map(|kid| match *kid {
+                    StructKid::Elem(child) => Some(child),
+                    StructKid::Content(_) => None,
+                })
+                .collect(),
+        );
+    }
+    path
+}
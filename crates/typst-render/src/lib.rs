@@ -6,11 +6,12 @@ mod shape;
 mod text;
 
 use tiny_skia as sk;
+use typst::diag::{At, SourceResult};
 use typst::layout::{
     Abs, Axes, Frame, FrameItem, FrameKind, GroupItem, Point, Size, Transform,
 };
 use typst::model::Document;
-use typst::visualize::Color;
+use typst::visualize::{Color, ImageKind};
 
 /// Export a frame into a raster image.
 ///
@@ -72,6 +73,26 @@ pub fn render_merged(
     canvas
 }
 
+/// Check that the raster images in a frame can be decoded.
+///
+/// Creating an image only reads its headers, so corrupted pixel data is first
+/// noticed during rendering, which skips such images. Call this before
+/// rendering to report them instead.
+pub fn check_images(frame: &Frame) -> SourceResult<()> {
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => check_images(&group.frame)?,
+            FrameItem::Image(image, _, span) => {
+                if let ImageKind::Raster(raster) = image.kind() {
+                    raster.dynamic().at(*span)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 /// Additional metadata carried through the rendering process.
 #[derive(Clone, Copy, Default)]
 struct State<'a> {
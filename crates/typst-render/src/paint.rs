@@ -4,7 +4,7 @@ use tiny_skia as sk;
 use typst::layout::{Axes, Point, Ratio, Size};
 use typst::visualize::{Color, Gradient, Paint, Pattern, RelativeTo};
 
-use crate::{AbsExt, State};
+use crate::{to_sk_transform, AbsExt, State};
 
 /// Trait for sampling of a paint, used as a generic
 /// abstraction over solid colors and gradients.
@@ -97,6 +97,10 @@ impl<'a> PatternSampler<'a> {
             RelativeTo::Parent => state.container_transform.invert().unwrap(),
         };
 
+        // Undo the rotation and shift of the tile grid.
+        let fill_transform = fill_transform
+            .post_concat(to_sk_transform(&pattern.transform()).invert().unwrap());
+
         Self {
             pixmap,
             size: (pattern.size() + pattern.spacing()) * state.pixel_per_pt as f64,
@@ -222,6 +226,10 @@ pub fn to_sk_paint<'a>(
                     .post_concat(state.transform.invert().unwrap()),
             };
 
+            // Rotate and shift the tile grid within the shape.
+            let fill_transform =
+                fill_transform.pre_concat(to_sk_transform(&pattern.transform()));
+
             let canvas = render_pattern_frame(&state, pattern);
             *pixmap = Some(Arc::new(canvas));
 
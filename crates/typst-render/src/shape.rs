@@ -8,6 +8,13 @@ use crate::{paint, AbsExt, State};
 
 /// Render a geometrical shape into the canvas.
 pub fn render_shape(canvas: &mut sk::Pixmap, state: State, shape: &Shape) -> Option<()> {
+    if let Some(shapes) = shape.split_dash_paints() {
+        for shape in &shapes {
+            render_shape(canvas, state, shape);
+        }
+        return Some(());
+    }
+
     let ts = state.transform;
     let path = match shape.geometry {
         Geometry::Line(target) => {
@@ -55,7 +62,7 @@ pub fn render_shape(canvas: &mut sk::Pixmap, state: State, shape: &Shape) -> Opt
         canvas.fill_path(&path, &paint, rule, ts, state.mask);
     }
 
-    if let Some(FixedStroke { paint, thickness, cap, join, dash, miter_limit }) =
+    if let Some(FixedStroke { paint, thickness, cap, join, dash, miter_limit, .. }) =
         &shape.stroke
     {
         let width = thickness.to_f32();
@@ -65,9 +72,11 @@ pub fn render_shape(canvas: &mut sk::Pixmap, state: State, shape: &Shape) -> Opt
             let dash = dash.as_ref().and_then(to_sk_dash_pattern);
 
             let bbox = shape.geometry.bbox_size();
-            let offset_bbox = (!matches!(shape.geometry, Geometry::Line(..)))
-                .then(|| offset_bounding_box(bbox, *thickness))
-                .unwrap_or(bbox);
+            let offset_bbox = if matches!(shape.geometry, Geometry::Line(..)) {
+                bbox
+            } else {
+                offset_bounding_box(bbox, *thickness)
+            };
 
             let fill_transform =
                 (!matches!(shape.geometry, Geometry::Line(..))).then(|| {
@@ -84,8 +84,9 @@ fn render_outline_glyph(
         );
         canvas.fill_path(&path, &paint, rule, ts, state.mask);
 
-        if let Some(FixedStroke { paint, thickness, cap, join, dash, miter_limit }) =
-            &text.stroke
+        if let Some(FixedStroke {
+            paint, thickness, cap, join, dash, miter_limit, ..
+        }) = &text.stroke
         {
             if thickness.to_f32() > 0.0 {
                 let dash = dash.as_ref().and_then(shape::to_sk_dash_pattern);
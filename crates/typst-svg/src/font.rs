@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use subsetter::GlyphRemapper;
+use ttf_parser::{RawFace, Tag};
+use typst::text::Font;
+
+use crate::woff2;
+
+const CMAP: Tag = Tag::from_bytes(b"cmap");
+const NAME: Tag = Tag::from_bytes(b"name");
+const OS2: Tag = Tag::from_bytes(b"OS/2");
+const POST: Tag = Tag::from_bytes(b"post");
+
+/// A font that is embedded into the SVG.
+pub struct EmbeddedFont {
+    /// The font.
+    pub font: Font,
+    /// The characters written with the font, mapped to their glyphs.
+    pub chars: BTreeMap<char, u16>,
+}
+
+/// Subset a font to the glyphs of the given characters and encode it as
+/// WOFF2.
+///
+/// The characters map to their glyph in the original font. Unlike in PDF
+/// export, the viewer lays out the text itself, so the subset gets a new
+/// character map for exactly these characters.
+///
+/// Returns `None` if the font can't be subsetted.
+#[comemo::memoize]
+#[typst_macros::time(name = "subset font")]
+pub fn subset_woff2(font: &Font, chars: &BTreeMap<char, u16>) -> Option<Arc<Vec<u8>>> {
+    let mut remapper = GlyphRemapper::new();
+    remapper.remap(0);
+    let mapping: BTreeMap<char, u16> =
+        chars.iter().map(|(&c, &id)| (c, remapper.remap(id))).collect();
+
+    let subsetted = subsetter::subset(font.data(), font.index(), &remapper).ok()?;
+    let subset = RawFace::parse(&subsetted, 0).ok()?;
+    let original = RawFace::parse(font.data(), font.index()).ok()?;
+
+    let cmap = write_cmap(&mapping);
+    let mut tables: Vec<(Tag, &[u8])> = subset
+        .table_records
+        .into_iter()
+        .filter(|record| record.tag != CMAP)
+        .filter_map(|record| Some((record.tag, subset.table(record.tag)?)))
+        .collect();
+    tables.push((CMAP, &cmap));
+
+    // Browsers reject fonts without these tables. They don't refer to glyphs
+    // by ID, so they can be taken from the original font if necessary.
+    for tag in [NAME, OS2] {
+        if !tables.iter().any(|&(t, _)| t == tag) {
+            tables.push((tag, original.table(tag)?));
+        }
+    }
+
+    // A version 3 post table has no glyph names.
+    let post;
+    if !tables.iter().any(|&(t, _)| t == POST) {
+        let mut header = original.table(POST)?.get(..32)?.to_vec();
+        header[..4].copy_from_slice(&0x00030000_u32.to_be_bytes());
+        post = header;
+        tables.push((POST, &post));
+    }
+
+    tables.sort_by_key(|&(tag, _)| tag.to_bytes());
+
+    let flavor = u32::from_be_bytes(subsetted.get(..4)?.try_into().ok()?);
+    Some(Arc::new(woff2::encode(flavor, &tables)))
+}
+
+/// Write a character map for the given characters.
+///
+/// It has a format 4 subtable for the characters in the Basic Multilingual
+/// Plane and a format 12 subtable for all characters.
+fn write_cmap(mapping: &BTreeMap<char, u16>) -> Vec<u8> {
+    // Runs of consecutive characters that map to consecutive glyphs, given
+    // by their first and last character and the first glyph.
+    let mut runs: Vec<(u32, u32, u16)> = vec![];
+    for (&c, &id) in mapping {
+        let c = c as u32;
+        match runs.last_mut() {
+            Some((start, end, glyph))
+                if *end + 1 == c && u32::from(*glyph) + c - *start == u32::from(id) =>
+            {
+                *end = c;
+            }
+            _ => runs.push((c, c, id)),
+        }
+    }
+
+    let mut subtables = vec![];
+
+    // The format 4 subtable ends with a segment for 0xFFFF and can't have
+    // more segments than fit into its 16-bit length.
+    let bmp: Vec<_> = runs
+        .iter()
+        .filter(|&&(start, _, _)| start < 0xFFFF)
+        .map(|&(start, end, glyph)| (start as u16, end.min(0xFFFE) as u16, glyph))
+        .chain([(0xFFFF, 0xFFFF, 0)])
+        .collect();
+    if 16 + 8 * bmp.len() <= usize::from(u16::MAX) {
+        let count = bmp.len() as u16;
+        let selector = count.ilog2() as u16;
+        let range = 2 << selector;
+        let mut data = vec![];
+        for value in [4, 16 + 8 * count, 0, 2 * count, range, selector, 2 * count - range]
+        {
+            data.extend(value.to_be_bytes());
+        }
+        data.extend(bmp.iter().flat_map(|&(_, end, _)| end.to_be_bytes()));
+        data.extend([0; 2]);
+        data.extend(bmp.iter().flat_map(|&(start, _, _)| start.to_be_bytes()));
+        data.extend(
+            bmp.iter()
+                .flat_map(|&(start, _, glyph)| glyph.wrapping_sub(start).to_be_bytes()),
+        );
+        data.extend(bmp.iter().flat_map(|_| [0; 2]));
+        subtables.push((1_u16, data));
+    }
+
+    let mut data = vec![];
+    data.extend(12_u16.to_be_bytes());
+    data.extend([0; 2]);
+    for value in [16 + 12 * runs.len() as u32, 0, runs.len() as u32] {
+        data.extend(value.to_be_bytes());
+    }
+    for &(start, end, glyph) in &runs {
+        for value in [start, end, u32::from(glyph)] {
+            data.extend(value.to_be_bytes());
+        }
+    }
+    subtables.push((10, data));
+
+    let mut cmap = vec![];
+    cmap.extend(0_u16.to_be_bytes());
+    cmap.extend((subtables.len() as u16).to_be_bytes());
+    let mut offset = 4 + 8 * subtables.len() as u32;
+    for (encoding, data) in &subtables {
+        cmap.extend(3_u16.to_be_bytes());
+        cmap.extend(encoding.to_be_bytes());
+        cmap.extend(offset.to_be_bytes());
+        offset += data.len() as u32;
+    }
+    for (_, data) in subtables {
+        cmap.extend(data);
+    }
+    cmap
+}
+
+#[cfg(test)]
+mod tests {
+    use ttf_parser::PlatformId;
+
+    use super::*;
+
+    #[test]
+    fn test_cmap_round_trip() {
+        let mapping = BTreeMap::from([
+            ('A', 1),
+            ('B', 2),
+            ('C', 3),
+            ('a', 7),
+            ('c', 4),
+            ('\u{FFFE}', 5),
+            ('😀', 6),
+            ('😁', 8),
+        ]);
+
+        let cmap = write_cmap(&mapping);
+        let table = ttf_parser::cmap::Table::parse(&cmap).unwrap();
+        let subtables: Vec<_> = table.subtables.into_iter().collect();
+        assert_eq!(subtables.len(), 2);
+
+        for subtable in subtables {
+            assert_eq!(subtable.platform_id, PlatformId::Windows);
+            let full = subtable.encoding_id == 10;
+            for (&c, &id) in &mapping {
+                let expected = (full || (c as u32) < 0xFFFF).then_some(id);
+                let actual = subtable.glyph_index(c as u32).map(|id| id.0);
+                assert_eq!(
+                    actual, expected,
+                    "{c:?} in subtable {}",
+                    subtable.encoding_id
+                );
+            }
+            assert_eq!(subtable.glyph_index('b' as u32), None);
+            assert_eq!(subtable.glyph_index('D' as u32), None);
+        }
+    }
+}
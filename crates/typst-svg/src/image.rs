@@ -1,7 +1,11 @@
+use std::io::Cursor;
+
 use base64::Engine;
 use ecow::{eco_format, EcoString};
 use typst::layout::{Abs, Axes};
-use typst::visualize::{Image, ImageFormat, RasterFormat, VectorFormat};
+use typst::visualize::{
+    Image, ImageFormat, ImageKind, RasterFormat, RasterImage, VectorFormat,
+};
 
 use crate::SVGRenderer;
 
@@ -22,6 +26,16 @@ impl SVGRenderer {
 /// `data:image/{format};base64,`.
 #[comemo::memoize]
 pub fn convert_image_to_base64_url(image: &Image) -> EcoString {
+    // Viewers apply an image's EXIF rotation on their own. If it should be
+    // ignored, the pixels are re-encoded without it.
+    if let ImageKind::Raster(raster) = image.kind() {
+        if raster.ignores_orientation() {
+            if let Some(png) = encode_png(raster) {
+                return data_url("png", &png);
+            }
+        }
+    }
+
     let format = match image.format() {
         ImageFormat::Raster(f) => match f {
             RasterFormat::Png => "png",
@@ -33,8 +47,21 @@ pub fn convert_image_to_base64_url(image: &Image) -> EcoString {
         },
     };
 
+    data_url(format, image.data())
+}
+
+/// Encode the decoded pixels of a raster image as a PNG.
+fn encode_png(raster: &RasterImage) -> Option<Vec<u8>> {
+    let dynamic = raster.dynamic().ok()?;
+    let mut data = Cursor::new(vec![]);
+    dynamic.write_to(&mut data, image::ImageFormat::Png).ok()?;
+    Some(data.into_inner())
+}
+
+/// Create a data URL with the given format and base64-encoded data.
+fn data_url(format: &str, data: &[u8]) -> EcoString {
     let mut url = eco_format!("data:image/{format};base64,");
-    let data = base64::engine::general_purpose::STANDARD.encode(image.data());
+    let data = base64::engine::general_purpose::STANDARD.encode(data);
     url.push_str(&data);
     url
 }
@@ -1,9 +1,11 @@
 //! Rendering of Typst documents into SVG images.
 
+mod font;
 mod image;
 mod paint;
 mod shape;
 mod text;
+mod woff2;
 
 use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter, Write};
@@ -18,13 +20,19 @@ use typst::utils::hash128;
 use typst::visualize::{Gradient, Pattern};
 use xmlwriter::XmlWriter;
 
+use crate::font::EmbeddedFont;
 use crate::paint::{GradientRef, PatternRef, SVGSubGradient};
 use crate::text::RenderedGlyph;
 
 /// Export a frame into a SVG file.
-#[typst_macros::time(name = "svg")]
 pub fn svg(frame: &Frame) -> String {
-    let mut renderer = SVGRenderer::new();
+    svg_with(frame, &SvgExportOptions::default())
+}
+
+/// Export a frame into a SVG file with the given options.
+#[typst_macros::time(name = "svg")]
+pub fn svg_with(frame: &Frame, options: &SvgExportOptions) -> String {
+    let mut renderer = SVGRenderer::new(*options);
     renderer.write_header(frame.size());
 
     let state = State::new(frame.size(), Transform::identity());
@@ -36,6 +44,18 @@ pub fn svg(frame: &Frame) -> String {
 ///
 /// The padding will be added around and between the individual frames.
 pub fn svg_merged(document: &Document, padding: Abs) -> String {
+    svg_merged_with(document, padding, &SvgExportOptions::default())
+}
+
+/// Export a document with potentially multiple pages into a single SVG file
+/// with the given options.
+///
+/// The padding will be added around and between the individual frames.
+pub fn svg_merged_with(
+    document: &Document,
+    padding: Abs,
+    options: &SvgExportOptions,
+) -> String {
     let width = 2.0 * padding
         + document
             .pages
@@ -50,7 +70,7 @@ pub fn svg_merged(document: &Document, padding: Abs) -> String {
             .map(|page| page.frame.height() + padding)
             .sum::<Abs>();
 
-    let mut renderer = SVGRenderer::new();
+    let mut renderer = SVGRenderer::new(*options);
     renderer.write_header(Size::new(width, height));
 
     let [x, mut y] = [padding; 2];
@@ -64,12 +84,38 @@ pub fn svg_merged(document: &Document, padding: Abs) -> String {
     renderer.finalize()
 }
 
+/// Options for SVG export.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SvgExportOptions {
+    /// How text is represented in the SVG.
+    pub fonts: SvgFonts,
+}
+
+/// How text is represented in an exported SVG.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SvgFonts {
+    /// Convert all glyphs to paths. This looks the same in every viewer, but
+    /// the text can't be selected or searched.
+    #[default]
+    Outline,
+    /// Write text as text and embed subsets of the used fonts into the SVG as
+    /// WOFF2.
+    Embed,
+    /// Write text as text and only reference the used fonts by family name.
+    /// The fonts must then be installed wherever the SVG is viewed.
+    Reference,
+}
+
 /// Renders one or multiple frames to an SVG file.
 struct SVGRenderer {
     /// The internal XML writer.
     xml: XmlWriter,
+    /// The export options.
+    options: SvgExportOptions,
     /// Prepared glyphs.
     glyphs: Deduplicator<RenderedGlyph>,
+    /// Fonts that are embedded into the SVG, in order of first use.
+    fonts: Vec<EmbeddedFont>,
     /// Clip paths are used to clip a group. A clip path is a path that defines
     /// the clipping region. The clip path is referenced by the `clip-path`
     /// attribute of the group. The clip path is in the format of `M x y L x y C
@@ -144,10 +190,12 @@ impl State {
 
 impl SVGRenderer {
     /// Create a new SVG renderer with empty glyph and clip path.
-    fn new() -> Self {
+    fn new(options: SvgExportOptions) -> Self {
         SVGRenderer {
             xml: XmlWriter::new(xmlwriter::Options::default()),
+            options,
             glyphs: Deduplicator::new('g'),
+            fonts: vec![],
             clip_paths: Deduplicator::new('c'),
             gradient_refs: Deduplicator::new('g'),
             gradients: Deduplicator::new('f'),
@@ -243,6 +291,7 @@ impl SVGRenderer {
     /// Finalize the SVG file. This must be called after all rendering is done.
     fn finalize(mut self) -> String {
         self.write_glyph_defs();
+        self.write_font_defs();
         self.write_clip_path_defs();
         self.write_gradients();
         self.write_gradient_refs();
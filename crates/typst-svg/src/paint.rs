@@ -92,6 +92,9 @@ impl SVGRenderer {
             pattern.frame(),
         );
 
+        // Rotate and shift the tile grid within the shape.
+        let ts = ts.pre_concat(pattern.transform());
+
         let pattern_id = self.patterns.insert_with(hash128(pattern), || pattern.clone());
         self.pattern_refs
             .insert_with(hash128(&(pattern_id, ts)), || PatternRef {
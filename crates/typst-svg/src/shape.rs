@@ -11,6 +11,13 @@ use crate::{SVGRenderer, State, SvgPathBuilder};
 impl SVGRenderer {
     /// Render a shape element.
     pub(super) fn render_shape(&mut self, state: State, shape: &Shape) {
+        if let Some(shapes) = shape.split_dash_paints() {
+            for shape in &shapes {
+                self.render_shape(state, shape);
+            }
+            return;
+        }
+
         self.xml.start_element("path");
         self.xml.write_attribute("class", "typst-shape");
 
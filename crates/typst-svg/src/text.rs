@@ -1,20 +1,130 @@
+use std::collections::BTreeMap;
 use std::io::Read;
 
 use base64::Engine;
-use ecow::EcoString;
+use ecow::{eco_format, EcoString};
 use ttf_parser::GlyphId;
 use typst::layout::{Abs, Point, Ratio, Size, Transform};
-use typst::text::{Font, TextItem};
+use typst::text::{Font, FontStyle, TextItem};
 use typst::utils::hash128;
 use typst::visualize::{Image, Paint, RasterFormat, RelativeTo};
 
-use crate::{SVGRenderer, State, SvgMatrix, SvgPathBuilder};
+use crate::font::{subset_woff2, EmbeddedFont};
+use crate::paint::ColorEncode;
+use crate::{SVGRenderer, State, SvgFonts, SvgMatrix, SvgPathBuilder};
 
 impl SVGRenderer {
-    /// Render a text item. The text is rendered as a group of glyphs. We will
-    /// try to render the text as SVG first, then bitmap, then outline. If none
-    /// of them works, we will skip the text.
+    /// Render a text item. Depending on the export options, the text is either
+    /// written as actual text or rendered as a group of glyphs.
     pub(super) fn render_text(&mut self, state: State, text: &TextItem) {
+        if self.options.fonts != SvgFonts::Outline
+            && self.render_text_as_text(state, text).is_some()
+        {
+            return;
+        }
+
+        self.render_text_as_glyphs(state, text);
+    }
+
+    /// Render a text item as a `text` element that references its font.
+    ///
+    /// Returns `None` without writing anything if the text can't be
+    /// represented faithfully in this way. This is the case for color glyphs,
+    /// which are always rendered as images, for non-solid fills, for fonts
+    /// from collections, which can't be referenced from CSS, and for fonts at
+    /// an optical size, whose font data isn't instanced.
+    fn render_text_as_text(&mut self, state: State, text: &TextItem) -> Option<()> {
+        let Paint::Solid(color) = &text.fill else { return None };
+        if text.font.index() != 0
+            || text.font.optical_size().is_some()
+            || text
+                .glyphs
+                .iter()
+                .any(|glyph| is_color_glyph(&text.font, GlyphId(glyph.id)))
+        {
+            return None;
+        }
+
+        let info = text.font.info();
+        let family = match self.options.fonts {
+            SvgFonts::Embed => {
+                let index = match self
+                    .fonts
+                    .iter()
+                    .position(|embedded| embedded.font == text.font)
+                {
+                    Some(index) => index,
+                    None => {
+                        self.fonts.push(EmbeddedFont {
+                            font: text.font.clone(),
+                            chars: BTreeMap::new(),
+                        });
+                        self.fonts.len() - 1
+                    }
+                };
+
+                // Record the glyphs the viewer will pick for the text.
+                let ttf = text.font.ttf();
+                let chars = &mut self.fonts[index].chars;
+                for c in text.text.chars() {
+                    if let Some(id) = ttf.glyph_index(c) {
+                        chars.insert(c, id.0);
+                    }
+                }
+
+                embedded_family(index)
+            }
+            _ => EcoString::from(info.family.as_str()),
+        };
+
+        self.xml.start_element("text");
+        self.xml.write_attribute("class", "typst-text");
+        self.xml.write_attribute("xml:space", "preserve");
+        self.xml.write_attribute("font-family", &family);
+        self.xml.write_attribute("font-size", &text.size.to_pt());
+        if self.options.fonts == SvgFonts::Reference {
+            self.xml
+                .write_attribute("font-weight", &info.variant.weight.to_number());
+            if info.variant.style != FontStyle::Normal {
+                self.xml.write_attribute("font-style", "italic");
+            }
+        }
+        self.xml.write_attribute("fill", &color.encode());
+        if let Some(stroke) = &text.stroke {
+            let size = Size::new(text.width(), text.size);
+            self.write_stroke(
+                stroke,
+                size,
+                self.text_paint_transform(state, &stroke.paint),
+            );
+        }
+
+        // Position each cluster individually so that the text lines up with
+        // the shaped glyphs, even if the viewer's shaping differs.
+        let mut x = Abs::zero();
+        let mut prev = None;
+        for glyph in &text.glyphs {
+            let range = glyph.range();
+            if prev != Some(range.clone()) {
+                let offset = x + glyph.x_offset.at(text.size);
+                self.xml.start_element("tspan");
+                self.xml.write_attribute("x", &offset.to_pt());
+                self.xml.write_text(&text.text[range.clone()]);
+                self.xml.end_element();
+                prev = Some(range);
+            }
+            x += glyph.x_advance.at(text.size);
+        }
+
+        self.xml.end_element();
+
+        Some(())
+    }
+
+    /// Render a text item as a group of glyphs. We will try to render each
+    /// glyph as SVG first, then bitmap, then outline. If none of them works,
+    /// we will skip the glyph.
+    fn render_text_as_glyphs(&mut self, state: State, text: &TextItem) {
         let scale: f64 = text.size.to_pt() / text.font.units_per_em();
 
         self.xml.start_element("g");
@@ -171,6 +281,40 @@ impl SVGRenderer {
         }
     }
 
+    /// Build the `@font-face` definitions for the embedded fonts.
+    pub(super) fn write_font_defs(&mut self) {
+        if self.fonts.is_empty() {
+            return;
+        }
+
+        let mut css = String::new();
+        for (i, embedded) in self.fonts.iter().enumerate() {
+            let font = &embedded.font;
+            let (format, data) = match subset_woff2(font, &embedded.chars) {
+                Some(data) => {
+                    ("woff2", base64::engine::general_purpose::STANDARD.encode(&*data))
+                }
+                // Fall back to the complete font if it can't be subsetted.
+                None => (
+                    if font.ttf().tables().cff.is_some() { "otf" } else { "ttf" },
+                    base64::engine::general_purpose::STANDARD.encode(font.data()),
+                ),
+            };
+            let family = embedded_family(i);
+            css.push_str(&format!("@font-face {{ font-family: \"{family}\"; "));
+            css.push_str(&format!(
+                "src: url(\"data:font/{format};base64,{data}\"); }}\n"
+            ));
+        }
+
+        self.xml.start_element("defs");
+        self.xml.write_attribute("id", "font");
+        self.xml.start_element("style");
+        self.xml.write_text(&css);
+        self.xml.end_element();
+        self.xml.end_element();
+    }
+
     /// Build the glyph definitions.
     pub(super) fn write_glyph_defs(&mut self) {
         if self.glyphs.is_empty() {
@@ -223,6 +367,17 @@ pub enum RenderedGlyph {
     Image { url: EcoString, width: f64, height: f64, ts: Transform },
 }
 
+/// The family name under which the font with the given index is embedded.
+fn embedded_family(index: usize) -> EcoString {
+    eco_format!("typst-font-{index}")
+}
+
+/// Whether the glyph is drawn from an image instead of an outline.
+fn is_color_glyph(font: &Font, id: GlyphId) -> bool {
+    let ttf = font.ttf();
+    ttf.glyph_svg_image(id).is_some() || ttf.glyph_raster_image(id, u16::MAX).is_some()
+}
+
 /// Convert an outline glyph to an SVG path.
 #[comemo::memoize]
 fn convert_outline_glyph_to_path(
@@ -274,16 +429,16 @@ fn convert_svg_glyph_to_base64_url(font: &Font, id: GlyphId) -> Option<EcoString
     for n in xmlparser::Tokenizer::from(svg_str.as_str()) {
         let tok = n.unwrap();
         match tok {
-            xmlparser::Token::ElementStart { span, local, .. } => {
-                if local.as_str() == "svg" {
-                    start_span = Some(span);
-                    break;
-                }
+            xmlparser::Token::ElementStart { span, local, .. }
+                if local.as_str() == "svg" =>
+            {
+                start_span = Some(span);
+                break;
             }
-            xmlparser::Token::Attribute { span, local, value, .. } => {
-                if local.as_str() == "viewBox" {
-                    last_viewbox = Some((span, value));
-                }
+            xmlparser::Token::Attribute { span, local, value, .. }
+                if local.as_str() == "viewBox" =>
+            {
+                last_viewbox = Some((span, value));
             }
             xmlparser::Token::ElementEnd { .. } => break,
             _ => {}
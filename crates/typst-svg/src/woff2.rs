@@ -0,0 +1,180 @@
+use std::io::Write;
+
+use ttf_parser::Tag;
+
+const GLYF: Tag = Tag::from_bytes(b"glyf");
+const LOCA: Tag = Tag::from_bytes(b"loca");
+
+/// Encode the tables of an OpenType font as a WOFF2 file.
+///
+/// The tables are stored without any of the WOFF2 table transformations and
+/// compressed together with Brotli.
+pub fn encode(flavor: u32, tables: &[(Tag, &[u8])]) -> Vec<u8> {
+    // The loca table must directly follow the glyf table.
+    let mut ordered: Vec<_> = tables.iter().collect();
+    if let Some(i) = ordered.iter().position(|(tag, _)| *tag == LOCA) {
+        let loca = ordered.remove(i);
+        let glyf = ordered.iter().position(|(tag, _)| *tag == GLYF);
+        ordered.insert(glyf.map_or(i, |j| j + 1), loca);
+    }
+
+    let mut directory = vec![];
+    let mut stream = vec![];
+    let mut sfnt_size = 12 + 16 * tables.len();
+    for (tag, data) in ordered {
+        // The flag 63 means that the tag is given explicitly. The glyf and
+        // loca tables are only stored as is with transformation version 3,
+        // all others with version 0.
+        let version = if *tag == GLYF || *tag == LOCA { 3 } else { 0 };
+        directory.push(63 | (version << 6));
+        directory.extend(tag.to_bytes());
+        write_base128(&mut directory, data.len() as u32);
+        stream.extend_from_slice(data);
+        sfnt_size += data.len().next_multiple_of(4);
+    }
+
+    let compressed = compress(&stream);
+    let length = (48 + directory.len() + compressed.len()).next_multiple_of(4);
+
+    let mut woff = Vec::with_capacity(length);
+    woff.extend(b"wOF2");
+    woff.extend(flavor.to_be_bytes());
+    woff.extend((length as u32).to_be_bytes());
+    woff.extend((tables.len() as u16).to_be_bytes());
+    woff.extend([0; 2]);
+    woff.extend((sfnt_size as u32).to_be_bytes());
+    woff.extend((compressed.len() as u32).to_be_bytes());
+
+    // Neither a font version nor metadata or private data.
+    woff.extend([0; 24]);
+
+    woff.extend(directory);
+    woff.extend(compressed);
+    woff.resize(length, 0);
+    woff
+}
+
+/// Write a number in the variable-length `UIntBase128` encoding.
+fn write_base128(buf: &mut Vec<u8>, value: u32) {
+    let len = (1..5).find(|&i| value >> (7 * i) == 0).unwrap_or(5);
+    for i in (0..len).rev() {
+        let byte = ((value >> (7 * i)) & 0x7F) as u8;
+        buf.push(if i > 0 { byte | 0x80 } else { byte });
+    }
+}
+
+/// Compress data into a Brotli stream at the highest quality.
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut writer = brotli::CompressorWriter::new(vec![], 4096, 11, 22);
+    // Writing into a vector can't fail.
+    writer.write_all(data).unwrap();
+    writer.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    /// Read a number in the variable-length `UIntBase128` encoding.
+    fn read_base128(data: &mut &[u8]) -> u32 {
+        let mut value = 0;
+        loop {
+            let (&byte, rest) = data.split_first().unwrap();
+            *data = rest;
+            value = (value << 7) | u32::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                return value;
+            }
+        }
+    }
+
+    /// Decode a WOFF2 file into its flavor and its tables with their
+    /// transformation version.
+    fn decode(woff: &[u8]) -> (u32, Vec<(Tag, u8, Vec<u8>)>) {
+        let u16_at = |i: usize| u16::from_be_bytes(woff[i..i + 2].try_into().unwrap());
+        let u32_at = |i: usize| u32::from_be_bytes(woff[i..i + 4].try_into().unwrap());
+
+        assert_eq!(&woff[..4], b"wOF2");
+        assert_eq!(u32_at(8) as usize, woff.len());
+        assert_eq!(woff.len() % 4, 0);
+
+        let count = u16_at(12);
+        let mut directory = &woff[48..];
+        let mut entries = vec![];
+        for _ in 0..count {
+            let flags = directory[0];
+            assert_eq!(flags & 63, 63);
+            let tag = Tag::from_bytes(directory[1..5].try_into().unwrap());
+            directory = &directory[5..];
+            entries.push((tag, flags >> 6, read_base128(&mut directory) as usize));
+        }
+
+        let compressed_len = u32_at(20) as usize;
+        let compressed = &directory[..compressed_len];
+        let mut stream = vec![];
+        brotli::Decompressor::new(compressed, 4096)
+            .read_to_end(&mut stream)
+            .unwrap();
+
+        let mut offset = 0;
+        let tables = entries
+            .into_iter()
+            .map(|(tag, version, len)| {
+                let data = stream[offset..offset + len].to_vec();
+                offset += len;
+                (tag, version, data)
+            })
+            .collect();
+        assert_eq!(offset, stream.len());
+
+        (u32_at(4), tables)
+    }
+
+    #[test]
+    fn test_base128() {
+        for value in [0, 1, 127, 128, 16383, 16384, 0xFFFF_FFFF] {
+            let mut buf = vec![];
+            write_base128(&mut buf, value);
+            assert!(buf[0] != 0x80, "leading zero byte for {value}");
+            let mut data = buf.as_slice();
+            assert_eq!(read_base128(&mut data), value);
+            assert!(data.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let long = vec![7; 300];
+        let tables: [(Tag, &[u8]); 4] = [
+            (Tag::from_bytes(b"cmap"), &[1, 2, 3]),
+            (GLYF, &long),
+            (LOCA, &[0, 0, 1, 44]),
+            (Tag::from_bytes(b"head"), &[4, 5, 6, 7, 8]),
+        ];
+
+        let (flavor, decoded) = decode(&encode(0x00010000, &tables));
+        assert_eq!(flavor, 0x00010000);
+
+        // The loca table directly follows the glyf table and both are stored
+        // with transformation version 3.
+        let tags: Vec<_> = decoded.iter().map(|(tag, ..)| tag.to_bytes()).collect();
+        assert_eq!(tags, [*b"cmap", *b"glyf", *b"loca", *b"head"]);
+        for (tag, version, data) in &decoded {
+            let expected = if *tag == GLYF || *tag == LOCA { 3 } else { 0 };
+            assert_eq!(*version, expected);
+            let original = tables.iter().find(|(t, _)| t == tag).unwrap().1;
+            assert_eq!(data.as_slice(), original);
+        }
+    }
+
+    #[test]
+    fn test_loca_before_glyf() {
+        let tables: [(Tag, &[u8]); 3] =
+            [(LOCA, &[1, 2]), (Tag::from_bytes(b"name"), &[3]), (GLYF, &[4])];
+        let (_, decoded) = decode(&encode(0x00010000, &tables));
+        let tags: Vec<_> = decoded.iter().map(|(tag, ..)| tag.to_bytes()).collect();
+        assert_eq!(tags, [*b"name", *b"glyf", *b"loca"]);
+    }
+}
@@ -621,18 +621,29 @@ impl<'a> Label<'a> {
 }
 
 node! {
-    /// A reference: `@target`, `@target[..]`.
+    /// A reference: `@target`, `@target[..]`, `@(first, second)`.
     Ref
 }
 
 impl<'a> Ref<'a> {
-    /// Get the target.
+    /// Get the target. For a grouped reference, this is the first target.
     pub fn target(self) -> &'a str {
-        self.0
+        self.targets().next().unwrap_or_default()
+    }
+
+    /// Get all targets of the reference.
+    pub fn targets(self) -> impl Iterator<Item = &'a str> {
+        let marker = self
+            .0
             .children()
             .find(|node| node.kind() == SyntaxKind::RefMarker)
             .map(|node| node.text().trim_start_matches('@'))
-            .unwrap_or_default()
+            .unwrap_or_default();
+        marker
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .split(',')
+            .map(str::trim)
     }
 
     /// Get the supplement.
@@ -2123,7 +2134,7 @@ impl<'a> RenamedImportItem<'a> {
         self.0
             .children()
             .filter_map(SyntaxNode::cast)
-            .last()
+            .next_back()
             .unwrap_or_default()
     }
 }
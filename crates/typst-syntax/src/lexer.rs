@@ -420,6 +420,10 @@ impl Lexer<'_> {
     }
 
     fn ref_marker(&mut self) -> SyntaxKind {
+        if self.s.eat_if('(') {
+            return self.ref_group();
+        }
+
         self.s.eat_while(is_valid_in_label_literal);
 
         // Don't include the trailing characters likely to be part of text.
@@ -430,6 +434,24 @@ impl Lexer<'_> {
         SyntaxKind::RefMarker
     }
 
+    fn ref_group(&mut self) -> SyntaxKind {
+        loop {
+            self.s.eat_while([' ', '\t']);
+            if self.s.eat_while(is_valid_in_label_literal).is_empty() {
+                return self.error("expected label");
+            }
+
+            self.s.eat_while([' ', '\t']);
+            if self.s.eat_if(')') {
+                return SyntaxKind::RefMarker;
+            }
+
+            if !self.s.eat_if(',') {
+                return self.error("unclosed reference group");
+            }
+        }
+    }
+
     fn label(&mut self) -> SyntaxKind {
         let label = self.s.eat_while(is_valid_in_label_literal);
         if label.is_empty() {
@@ -243,7 +243,7 @@ fn term_item(p: &mut Parser) {
     p.wrap(m, SyntaxKind::TermItem);
 }
 
-/// Parses a reference: `@target`, `@target[..]`.
+/// Parses a reference: `@target`, `@target[..]`, `@(first, second)`.
 fn reference(p: &mut Parser) {
     let m = p.marker();
     p.assert(SyntaxKind::RefMarker);
@@ -41,6 +41,7 @@ impl Scalar {
 // on stable yet:
 // ([tracking issue](https://github.com/rust-lang/rust/issues/57241))
 #[allow(clippy::unusual_byte_groupings)]
+#[allow(unknown_lints, unnecessary_transmutes)]
 const fn is_nan(x: f64) -> bool {
     // Safety: all bit patterns are valid for u64, and f64 has no padding bits.
     // We cannot use `f64::to_bits` because it is not const.
@@ -219,7 +219,7 @@ impl Eval for ast::Array<'_> {
                 ast::ArrayItem::Pos(expr) => vec.push(expr.eval(vm)?),
                 ast::ArrayItem::Spread(spread) => match spread.expr().eval(vm)? {
                     Value::None => {}
-                    Value::Array(array) => vec.extend(array.into_iter()),
+                    Value::Array(array) => vec.extend(array),
                     v => bail!(spread.span(), "cannot spread {} into array", v.ty()),
                 },
             }
@@ -253,7 +253,7 @@ impl Eval for ast::Dict<'_> {
                 }
                 ast::DictItem::Spread(spread) => match spread.expr().eval(vm)? {
                     Value::None => {}
-                    Value::Dict(dict) => map.extend(dict.into_iter()),
+                    Value::Dict(dict) => map.extend(dict),
                     v => bail!(spread.span(), "cannot spread {} into dictionary", v.ty()),
                 },
             }
@@ -198,6 +198,10 @@ impl Eval for ast::Ref<'_> {
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
         let target = Label::new(self.target());
         let mut elem = RefElem::new(target);
+        let others: Vec<_> = self.targets().skip(1).map(Label::new).collect();
+        if !others.is_empty() {
+            elem.push_others(others);
+        }
         if let Some(supplement) = self.supplement() {
             elem.push_supplement(Smart::Custom(Some(Supplement::Content(
                 supplement.eval(vm)?,
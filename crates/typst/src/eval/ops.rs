@@ -229,8 +229,8 @@ pub fn add(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
         }
 
         (Duration(a), Duration(b)) => Duration(a + b),
-        (Datetime(a), Duration(b)) => Datetime(a + b),
-        (Duration(a), Datetime(b)) => Datetime(b + a),
+        (Datetime(a), Duration(b)) => Datetime((a + b)?),
+        (Duration(a), Datetime(b)) => Datetime((b + a)?),
 
         // Type compatibility.
         (Type(a), Str(b)) => Str(format_str!("{a}{b}")),
@@ -277,7 +277,7 @@ pub fn sub(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
         (Fraction(a), Fraction(b)) => Fraction(a - b),
 
         (Duration(a), Duration(b)) => Duration(a - b),
-        (Datetime(a), Duration(b)) => Datetime(a - b),
+        (Datetime(a), Duration(b)) => Datetime((a - b)?),
         (Datetime(a), Datetime(b)) => Duration((a - b)?),
 
         (a, b) => mismatch!("cannot subtract {1} from {0}", a, b),
@@ -206,6 +206,8 @@ impl<T> Smart<Smart<T>> {
     }
 }
 
+// Deriving would require `T: Default`.
+#[allow(clippy::derivable_impls)]
 impl<T> Default for Smart<T> {
     fn default() -> Self {
         Self::Auto
@@ -4,10 +4,13 @@ use std::cmp;
 use std::cmp::Ordering;
 use std::ops::{Div, Rem};
 
-use crate::diag::{bail, At, SourceResult, StrResult};
+use comemo::Tracked;
+
+use crate::diag::{bail, At, HintedStrResult, SourceResult, StrResult};
 use crate::eval::ops;
-use crate::foundations::{cast, func, IntoValue, Module, Scope, Value};
+use crate::foundations::{cast, func, Context, IntoValue, Module, Scope, Value};
 use crate::layout::{Angle, Fr, Length, Ratio};
+use crate::model::DocumentElem;
 use crate::syntax::{Span, Spanned};
 
 /// A module with calculation definitions.
@@ -49,6 +52,7 @@ pub fn module() -> Module {
     scope.define_func::<div_euclid>();
     scope.define_func::<rem_euclid>();
     scope.define_func::<quo>();
+    scope.define_func::<random>();
     scope.define("inf", f64::INFINITY);
     scope.define("nan", f64::NAN);
     scope.define("pi", std::f64::consts::PI);
@@ -908,6 +912,35 @@ pub fn quo(
     Ok(floor(dividend.apply2(divisor.v, Div::div, Div::div)))
 }
 
+/// Generates a pseudo-random float between `{0.0}` (inclusive) and `{1.0}`
+/// (exclusive).
+///
+/// The number is derived from the document's [`seed`]($document.seed), the
+/// current location, and the given `key`. Calling this function at the same
+/// place in the document thus always yields the same number, no matter how
+/// often or in which order the document is compiled. To get several different
+/// numbers at the same location, pass distinct keys.
+///
+/// ```example
+/// #set document(seed: 42)
+/// #context calc.random() \
+/// #context calc.random("other") \
+/// #context range(3).map(calc.random)
+/// ```
+#[func(contextual)]
+pub fn random(
+    /// The callsite context.
+    context: Tracked<Context>,
+    /// Distinguishes multiple numbers generated at the same location.
+    #[default]
+    key: Value,
+) -> HintedStrResult<f64> {
+    let seed = DocumentElem::seed_in(context.styles()?);
+    let hash = crate::utils::hash128(&(seed, context.location()?, key));
+    // Use the top 53 bits, which a float can represent exactly.
+    Ok((hash >> 75) as f64 / (1u64 << 53) as f64)
+}
+
 /// A value which can be passed to functions that work with integers and floats.
 #[derive(Debug, Copy, Clone)]
 pub enum Num {
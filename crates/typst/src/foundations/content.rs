@@ -10,6 +10,7 @@ use comemo::Tracked;
 use ecow::{eco_format, EcoString};
 use serde::{Serialize, Serializer};
 use smallvec::smallvec;
+use unicode_script::{Script, UnicodeScript};
 
 use crate::diag::{SourceResult, StrResult};
 use crate::engine::Engine;
@@ -19,11 +20,18 @@ use crate::foundations::{
     Value,
 };
 use crate::introspection::Location;
-use crate::layout::{AlignElem, Alignment, Axes, Length, MoveElem, PadElem, Rel, Sides};
-use crate::model::{Destination, EmphElem, LinkElem, StrongElem};
+use crate::layout::{
+    AlignElem, Alignment, Axes, BlockElem, GridCell, Length, MoveElem, PadElem, Rel,
+    Sides,
+};
+use crate::math::EquationElem;
+use crate::model::{
+    Destination, EmphElem, EnumItem, FigureElem, HeadingElem, LinkElem, ListItem,
+    ParbreakElem, StrongElem, TableCell, TermItem,
+};
 use crate::realize::{Behave, Behaviour};
 use crate::syntax::Span;
-use crate::text::UnderlineElem;
+use crate::text::{LinebreakElem, RawElem, UnderlineElem};
 use crate::utils::{fat, LazyHash, SmallBitSet};
 
 /// A piece of document content.
@@ -351,6 +359,18 @@ impl Content {
         sequence.children.is_empty()
     }
 
+    /// The children of this content if it is a sequence or the content itself
+    /// otherwise.
+    ///
+    /// Nested sequences are not expanded, see [`flatten`](Self::flatten) for
+    /// that.
+    pub fn children(&self) -> impl Iterator<Item = &Content> {
+        match self.to_packed::<SequenceElem>() {
+            Some(sequence) => sequence.children.iter(),
+            None => std::slice::from_ref(self).iter(),
+        }
+    }
+
     /// Also auto expands sequence of sequences into flat sequence
     pub fn sequence_recursive_for_each<'a>(&'a self, f: &mut impl FnMut(&'a Self)) {
         if let Some(sequence) = self.to_packed::<SequenceElem>() {
@@ -593,6 +613,104 @@ impl Content {
         dict
     }
 
+    /// Collapses nested sequences of content into a single flat sequence.
+    ///
+    /// Styled content and other elements are kept as they are, so content
+    /// inside of them is not flattened. The result is always a sequence, even
+    /// if it holds just one element. This way, its `children` can be walked
+    /// uniformly.
+    ///
+    /// ```example
+    /// #let body = [a #[b #[c]]]
+    /// #body.flatten().children.len()
+    /// ```
+    #[func]
+    pub fn flatten(&self) -> Content {
+        let mut children = vec![];
+        self.sequence_recursive_for_each(&mut |child| children.push(child.clone()));
+        SequenceElem::new(children).pack().spanned(self.span())
+    }
+
+    /// Extracts the plain text of this content.
+    ///
+    /// Paragraphs, headings, list items, and other block-level elements are
+    /// separated by the `separator`. Within them, runs of whitespace collapse
+    /// into a single space, which makes the result suitable for counting
+    /// words. The text of raw blocks is kept verbatim.
+    ///
+    /// ```example
+    /// #let body = [
+    ///   = Introduction
+    ///   This is *important.*
+    ///
+    ///   - One
+    ///   - Two
+    /// ]
+    ///
+    /// #body.to-string(separator: " | ")
+    /// ```
+    #[func(name = "to-string")]
+    pub fn to_plain_text(
+        &self,
+        /// The string to insert between block-level elements.
+        #[named]
+        #[default(Str::from("\n\n"))]
+        separator: Str,
+        /// Whether to include the text of equations.
+        #[named]
+        #[default(true)]
+        math: bool,
+    ) -> Str {
+        PlainTextWriter::new(&separator, math, true).write(self).into()
+    }
+
+    /// Counts the words in this content.
+    ///
+    /// Words are separated by whitespace, punctuation, and the boundaries of
+    /// block-level elements. Hyphens and apostrophes within a word don't
+    /// separate it, so hyphenated words count once. Each Chinese and Japanese
+    /// character counts as a word of its own. Sequences of punctuation are
+    /// not counted.
+    ///
+    /// ```example
+    /// #show: body => {
+    ///   set page(
+    ///     height: auto,
+    ///     footer: [#body.words() words, #body.characters() characters],
+    ///   )
+    ///   body
+    /// }
+    ///
+    /// = Well-known facts
+    /// The sky is blue.
+    /// ```
+    #[func]
+    pub fn words(
+        &self,
+        /// Whether to count the words in raw text.
+        #[named]
+        #[default(false)]
+        raw: bool,
+    ) -> usize {
+        let text = PlainTextWriter::new(" ", true, raw).write(self);
+        text.split_whitespace().map(count_words).sum()
+    }
+
+    /// Counts the characters in this content, excluding whitespace.
+    ///
+    /// Characters are counted by Unicode codepoint.
+    #[func]
+    pub fn characters(
+        &self,
+        /// Whether to count the characters in raw text.
+        #[named]
+        #[default(false)]
+        raw: bool,
+    ) -> usize {
+        let text = PlainTextWriter::new(" ", true, raw).write(self);
+        text.chars().filter(|c| !c.is_whitespace()).count()
+    }
+
     /// The location of the content. This is only available on content returned
     /// by [query] or provided by a [show rule]($reference/styling/#show-rules),
     /// for other content it will be `{none}`. The resulting location can be
@@ -974,6 +1092,196 @@ pub trait PlainText {
     fn plain_text(&self, text: &mut EcoString);
 }
 
+/// Writes the plain text of content with separators between blocks.
+struct PlainTextWriter<'a> {
+    buf: EcoString,
+    separator: &'a str,
+    math: bool,
+    raw: bool,
+    /// Whether a space is due before the next text.
+    space: bool,
+    /// Whether a space is due before the next text because it belongs to
+    /// another field than the text before it.
+    field: bool,
+    /// Whether a block separator is due before the next text.
+    block: bool,
+}
+
+impl<'a> PlainTextWriter<'a> {
+    /// Create a writer that separates blocks with the given separator.
+    fn new(separator: &'a str, math: bool, raw: bool) -> Self {
+        Self {
+            buf: EcoString::new(),
+            separator,
+            math,
+            raw,
+            space: false,
+            field: false,
+            block: false,
+        }
+    }
+
+    /// Extract the plain text of the content.
+    fn write(mut self, content: &Content) -> EcoString {
+        self.content(content);
+        self.buf
+    }
+
+    /// Write the plain text of an element and its fields.
+    fn content(&mut self, content: &Content) {
+        if let Some(equation) = content.to_packed::<EquationElem>() {
+            if !self.math {
+                self.space = true;
+                return;
+            }
+            if equation.block(StyleChain::default()) {
+                return self.surround(|w| w.fields(content));
+            }
+        }
+
+        if let Some(raw) = content.to_packed::<RawElem>() {
+            if !self.raw {
+                self.space = true;
+                return;
+            }
+            if raw.block(StyleChain::default()) {
+                return self.surround(|w| w.verbatim(&raw.text().get()));
+            }
+        }
+
+        if is_block(content) {
+            return self.surround(|w| w.inline(content));
+        }
+
+        self.inline(content);
+    }
+
+    /// Write an element's text and fields without separators around it.
+    fn inline(&mut self, content: &Content) {
+        if let Some(textable) = content.with::<dyn PlainText>() {
+            let mut text = EcoString::new();
+            textable.plain_text(&mut text);
+            self.text(&text);
+        } else if content.is::<LinebreakElem>() {
+            self.space = true;
+        }
+        self.fields(content);
+    }
+
+    /// Write content surrounded by block separators.
+    fn surround(&mut self, f: impl FnOnce(&mut Self)) {
+        self.block = true;
+        f(self);
+        self.block = true;
+    }
+
+    /// Write the content in an element's fields.
+    fn fields(&mut self, content: &Content) {
+        let start = self.buf.len();
+        let pending = self.field;
+        let mut written = false;
+        for (_, value) in content.inner.elem.fields() {
+            // Separate the text of distinct fields, e.g. the term and the
+            // description of a term list item. Fields without text, like the
+            // styles of styled content, don't separate anything.
+            if written {
+                self.field = true;
+            }
+            let len = self.buf.len();
+            self.value(value);
+            written |= self.buf.len() > len;
+        }
+        self.field = pending && self.buf.len() == start;
+    }
+
+    /// Write the content in a field.
+    fn value(&mut self, value: Value) {
+        match value {
+            Value::Content(content) => self.content(&content),
+            Value::Array(array) => {
+                for value in array {
+                    self.value(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Write text, collapsing whitespace.
+    fn text(&mut self, text: &str) {
+        for c in text.chars() {
+            if c.is_whitespace() {
+                self.space = true;
+            } else {
+                self.separate();
+                self.buf.push(c);
+            }
+        }
+    }
+
+    /// Write text as-is.
+    fn verbatim(&mut self, text: &str) {
+        if !text.is_empty() {
+            self.separate();
+            self.buf.push_str(text);
+        }
+    }
+
+    /// Write a pending separator before text.
+    fn separate(&mut self) {
+        if !self.buf.is_empty() {
+            if self.block {
+                self.buf.push_str(self.separator);
+            } else if self.space || self.field {
+                self.buf.push(' ');
+            }
+        }
+        self.block = false;
+        self.space = false;
+        self.field = false;
+    }
+}
+
+/// Counts the words in a piece of text without whitespace.
+///
+/// A word is a run of alphanumeric characters. Hyphens and apostrophes
+/// between two such characters join them into one word, while any other
+/// character ends the current word.
+fn count_words(text: &str) -> usize {
+    let mut count = 0;
+    let mut word = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if matches!(c.script(), Script::Han | Script::Hiragana | Script::Katakana) {
+            count += 1;
+            word = false;
+        } else if c.is_alphanumeric() {
+            if !word {
+                count += 1;
+            }
+            word = true;
+        } else {
+            let joiner = matches!(c, '-' | '\u{2010}' | '\u{2011}' | '\'' | '\u{2019}');
+            word &= joiner && chars.peek().is_some_and(|next| next.is_alphanumeric());
+        }
+    }
+    count
+}
+
+/// Whether an element is block-level for the purpose of plain text
+/// extraction.
+fn is_block(content: &Content) -> bool {
+    content.is::<ParbreakElem>()
+        || content.is::<BlockElem>()
+        || content.is::<HeadingElem>()
+        || content.is::<ListItem>()
+        || content.is::<EnumItem>()
+        || content.is::<TermItem>()
+        || content.is::<FigureElem>()
+        || content.is::<TableCell>()
+        || content.is::<GridCell>()
+}
+
 /// An error arising when trying to access a field of content.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum FieldAccessError {
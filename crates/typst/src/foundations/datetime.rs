@@ -1,11 +1,12 @@
 use std::cmp::Ordering;
 use std::hash::Hash;
 use std::ops::{Add, Sub};
+use std::str::FromStr;
 
 use ecow::{eco_format, EcoString, EcoVec};
 use time::error::{Format, InvalidFormatDescription};
 use time::macros::format_description;
-use time::{format_description, Month, PrimitiveDateTime};
+use time::{format_description, Month, PrimitiveDateTime, UtcOffset};
 
 use crate::diag::{bail, StrResult};
 use crate::engine::Engine;
@@ -221,6 +222,36 @@ impl Datetime {
         }
     }
 
+    /// Parse a datetime from an ISO 8601 string in extended format.
+    ///
+    /// Accepts a date (`2024-02-29`), a time (`12:00:00`), or both separated
+    /// by a `T` (`2024-02-29T12:00:00`). Times may have fractional seconds,
+    /// which are dropped, and a UTC offset (`Z` or `+02:00`), which is applied
+    /// to yield the time in UTC.
+    pub fn from_iso(string: &str) -> StrResult<Self> {
+        let (date, time) = match string.split_once(['T', 't', ' ']) {
+            Some((date, time)) => (Some(date), Some(time)),
+            None if string.contains(':') => (None, Some(string)),
+            None => (Some(string), None),
+        };
+
+        let date = date.map(parse_iso_date).transpose()?;
+        let time = time.map(parse_iso_time).transpose()?;
+        Ok(match (date, time) {
+            (Some(date), Some((time, offset))) => {
+                let datetime = PrimitiveDateTime::new(date, time)
+                    .assume_offset(offset)
+                    .to_offset(UtcOffset::UTC);
+                Self::Datetime(PrimitiveDateTime::new(datetime.date(), datetime.time()))
+            }
+            (Some(date), None) => Self::Date(date),
+            (None, Some((time, offset))) => {
+                Self::Time(time - time::Duration::seconds(offset.whole_seconds().into()))
+            }
+            (None, None) => unreachable!(),
+        })
+    }
+
     /// Which kind of variant this datetime stores.
     pub fn kind(&self) -> &'static str {
         match self {
@@ -248,12 +279,17 @@ impl Datetime {
     /// Depending on how it is stored, the [`display`]($datetime.display) method
     /// will choose a different formatting by default.
     ///
+    /// Alternatively, you can parse the datetime from an ISO 8601 string with
+    /// the `iso` argument.
+    ///
     /// ```example
     /// #datetime(
     ///   year: 2012,
     ///   month: 8,
     ///   day: 3,
     /// ).display()
+    ///
+    /// #datetime(iso: "2024-02-29T12:00:00Z").display()
     /// ```
     #[func(constructor)]
     pub fn construct(
@@ -275,7 +311,28 @@ impl Datetime {
         /// The second of the datetime.
         #[named]
         second: Option<u8>,
+        /// An ISO 8601 string to parse the datetime from, like
+        /// `{"2024-02-29"}`, `{"12:00:00"}`, or `{"2024-02-29T12:00:00+01:00"}`.
+        ///
+        /// A UTC offset is applied to the time, such that the resulting
+        /// datetime is in UTC. Fractional seconds are dropped. Cannot be
+        /// combined with the individual components.
+        #[named]
+        iso: Option<Str>,
     ) -> StrResult<Datetime> {
+        if let Some(iso) = iso {
+            if year.is_some()
+                || month.is_some()
+                || day.is_some()
+                || hour.is_some()
+                || minute.is_some()
+                || second.is_some()
+            {
+                bail!("cannot combine `iso` with individual components");
+            }
+            return Datetime::from_iso(&iso);
+        }
+
         let time = match (hour, minute, second) {
             (Some(hour), Some(minute), Some(second)) => {
                 match time::Time::from_hms(hour, minute, second) {
@@ -340,6 +397,17 @@ impl Datetime {
     /// `[[hour]:[minute]:[second]]`. In the case of a datetime, it will be
     /// `[[year]-[month]-[day] [hour]:[minute]:[second]]`.
     ///
+    /// The special pattern `{"iso"}` yields the canonical ISO 8601
+    /// representation, which can be parsed again with the `iso` argument of
+    /// the [constructor]($datetime).
+    ///
+    /// ```example
+    /// #datetime(
+    ///   year: 2024, month: 2, day: 29,
+    ///   hour: 12, minute: 0, second: 0,
+    /// ).display("iso")
+    /// ```
+    ///
     /// See the [format syntax]($datetime/#format) for more information.
     #[func]
     pub fn display(
@@ -358,7 +426,16 @@ impl Datetime {
                 }
             },
 
-            Smart::Custom(DisplayPattern(_, format)) => match self {
+            // Dates and times are displayed in ISO 8601 by default.
+            Smart::Custom(DisplayPattern::Iso) => match self {
+                Self::Datetime(datetime) => {
+                    let iso = "[year]-[month]-[day]T[hour]:[minute]:[second]";
+                    datetime.format(&pat(iso))
+                }
+                _ => return self.display(Smart::Auto),
+            },
+
+            Smart::Custom(DisplayPattern::Custom(_, format)) => match self {
                 Self::Date(date) => date.format(&format),
                 Self::Time(time) => time.format(&format),
                 Self::Datetime(datetime) => datetime.format(&format),
@@ -477,28 +554,31 @@ impl PartialOrd for Datetime {
 }
 
 impl Add<Duration> for Datetime {
-    type Output = Self;
+    type Output = StrResult<Self>;
 
     fn add(self, rhs: Duration) -> Self::Output {
         let rhs: time::Duration = rhs.into();
         match self {
-            Self::Datetime(datetime) => Self::Datetime(datetime + rhs),
-            Self::Date(date) => Self::Date(date + rhs),
-            Self::Time(time) => Self::Time(time + rhs),
+            Self::Datetime(datetime) => datetime.checked_add(rhs).map(Self::Datetime),
+            Self::Date(date) => date.checked_add(rhs).map(Self::Date),
+            // Times wrap around at midnight.
+            Self::Time(time) => Some(Self::Time(time + rhs)),
         }
+        .ok_or_else(|| eco_format!("resulting {} is out of range", self.kind()))
     }
 }
 
 impl Sub<Duration> for Datetime {
-    type Output = Self;
+    type Output = StrResult<Self>;
 
     fn sub(self, rhs: Duration) -> Self::Output {
         let rhs: time::Duration = rhs.into();
         match self {
-            Self::Datetime(datetime) => Self::Datetime(datetime - rhs),
-            Self::Date(date) => Self::Date(date - rhs),
-            Self::Time(time) => Self::Time(time - rhs),
+            Self::Datetime(datetime) => datetime.checked_sub(rhs).map(Self::Datetime),
+            Self::Date(date) => date.checked_sub(rhs).map(Self::Date),
+            Self::Time(time) => Some(Self::Time(time - rhs)),
         }
+        .ok_or_else(|| eco_format!("resulting {} is out of range", self.kind()))
     }
 }
 
@@ -516,16 +596,90 @@ impl Sub for Datetime {
 }
 
 /// A format in which a datetime can be displayed.
-pub struct DisplayPattern(Str, format_description::OwnedFormatItem);
+pub enum DisplayPattern {
+    /// The canonical ISO 8601 representation.
+    Iso,
+    /// A custom format description.
+    Custom(Str, format_description::OwnedFormatItem),
+}
 
 cast! {
     DisplayPattern,
-    self => self.0.into_value(),
-    v: Str => {
+    self => match self {
+        Self::Iso => "iso".into_value(),
+        Self::Custom(v, _) => v.into_value(),
+    },
+    v: Str => if v.as_str() == "iso" {
+        Self::Iso
+    } else {
         let item = format_description::parse_owned::<2>(&v)
             .map_err(format_time_invalid_format_description_error)?;
-        Self(v, item)
+        Self::Custom(v, item)
+    }
+}
+
+/// Parse the date part of an ISO 8601 string.
+fn parse_iso_date(string: &str) -> StrResult<time::Date> {
+    let parts: Vec<&str> = string.split('-').collect();
+    let [year, month, day] = parts[..] else {
+        if parts.len() < 3 && !string.is_empty() {
+            bail!("date is incomplete");
+        }
+        bail!("expected date in the format `YYYY-MM-DD`");
+    };
+
+    let year = parse_iso_number(year, 4)?;
+    let month = Month::try_from(parse_iso_number::<u8>(month, 2)?)
+        .map_err(|_| "month is invalid")?;
+    let day = parse_iso_number(day, 2)?;
+    time::Date::from_calendar_date(year, month, day).map_err(|_| "date is invalid".into())
+}
+
+/// Parse the time part of an ISO 8601 string, along with its UTC offset.
+fn parse_iso_time(string: &str) -> StrResult<(time::Time, UtcOffset)> {
+    let (string, offset) = if let Some(rest) = string.strip_suffix(['Z', 'z']) {
+        (rest, UtcOffset::UTC)
+    } else if let Some(i) = string.rfind(['+', '-']) {
+        let (rest, offset) = string.split_at(i);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) =
+            offset[1..].split_once(':').unwrap_or((&offset[1..], "00"));
+        let hours: i8 = parse_iso_number(hours, 2)?;
+        let minutes: i8 = parse_iso_number(minutes, 2)?;
+        let offset = UtcOffset::from_hms(sign * hours, sign * minutes, 0)
+            .map_err(|_| "UTC offset is invalid")?;
+        (rest, offset)
+    } else {
+        (string, UtcOffset::UTC)
+    };
+
+    // Fractional seconds are dropped.
+    let string = string.split_once(['.', ',']).map_or(string, |(whole, _)| whole);
+    let parts: Vec<&str> = string.split(':').collect();
+    let [hour, minute, second] = parts[..] else {
+        bail!("expected time in the format `hh:mm:ss`");
+    };
+
+    let hour = parse_iso_number(hour, 2)?;
+    let minute = parse_iso_number(minute, 2)?;
+    let second = parse_iso_number(second, 2)?;
+    if second == 60 {
+        bail!("leap seconds are not supported");
+    }
+
+    let time =
+        time::Time::from_hms(hour, minute, second).map_err(|_| "time is invalid")?;
+    Ok((time, offset))
+}
+
+/// Parse a number with exactly `digits` decimal digits.
+fn parse_iso_number<T: FromStr>(string: &str, digits: usize) -> StrResult<T> {
+    if string.len() != digits || !string.bytes().all(|b| b.is_ascii_digit()) {
+        bail!("expected {digits} digits, found \"{string}\"");
     }
+    string
+        .parse()
+        .map_err(|_| eco_format!("number {string} is out of range"))
 }
 
 cast! {
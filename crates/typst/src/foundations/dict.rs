@@ -3,13 +3,15 @@ use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign};
 use std::sync::Arc;
 
+use comemo::Tracked;
 use ecow::{eco_format, EcoString};
 use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::diag::{Hint, HintedStrResult, StrResult};
+use crate::diag::{Hint, HintedStrResult, SourceResult, StrResult};
+use crate::engine::Engine;
 use crate::foundations::{
-    array, cast, func, repr, scope, ty, Array, Module, Repr, Str, Value,
+    array, cast, func, repr, scope, ty, Array, Context, Func, Module, Repr, Str, Value,
 };
 use crate::syntax::is_ident;
 use crate::utils::ArcExt;
@@ -114,7 +116,7 @@ impl Dict {
     }
 
     /// Iterate over pairs of references to the contained keys and values.
-    pub fn iter(&self) -> indexmap::map::Iter<Str, Value> {
+    pub fn iter(&self) -> indexmap::map::Iter<'_, Str, Value> {
         self.0.iter()
     }
 
@@ -254,6 +256,64 @@ impl Dict {
             .map(|(k, v)| Value::Array(array![k.clone(), v.clone()]))
             .collect()
     }
+
+    /// Merges another dictionary into this one and returns the result.
+    ///
+    /// Pairs of the other dictionary are added after the pairs of this one.
+    /// For keys present in both dictionaries, the value from the other
+    /// dictionary wins, unless `combine` or `deep` say otherwise. The key keeps
+    /// its original position.
+    ///
+    /// ```example
+    /// #let a = (apples: 2, pears: 1)
+    /// #let b = (pears: 3, plums: 4)
+    /// #a.merge(b) \
+    /// #a.merge(b, combine: (x, y) => x + y)
+    /// ```
+    #[func]
+    pub fn merge(
+        self,
+        /// The engine.
+        engine: &mut Engine,
+        /// The callsite context.
+        context: Tracked<Context>,
+        /// The dictionary to merge into this one.
+        other: Dict,
+        /// A function that resolves a conflict between two values of the same
+        /// key. It receives the value from this dictionary and the value from
+        /// the other one and returns the merged value.
+        #[named]
+        #[default]
+        combine: Option<Func>,
+        /// Whether to recursively merge values that are dictionaries in both
+        /// dictionaries instead of resolving their conflict as a whole.
+        #[named]
+        #[default(false)]
+        deep: bool,
+    ) -> SourceResult<Dict> {
+        let mut map = self.0;
+        for (key, value) in other {
+            let merged = match (map.get(&key), value) {
+                (Some(Value::Dict(prev)), Value::Dict(value)) if deep => {
+                    let prev = prev.clone();
+                    Value::Dict(prev.merge(
+                        engine,
+                        context,
+                        value,
+                        combine.clone(),
+                        deep,
+                    )?)
+                }
+                (Some(prev), value) => match &combine {
+                    Some(func) => func.call(engine, context, [prev.clone(), value])?,
+                    None => value,
+                },
+                (None, value) => value,
+            };
+            Arc::make_mut(&mut map).insert(key, merged);
+        }
+        Ok(Self(map))
+    }
 }
 
 /// A value that can be cast to dictionary.
@@ -4,7 +4,7 @@ use std::ops::{Add, Div, Mul, Neg, Sub};
 use ecow::{eco_format, EcoString};
 use time::ext::NumericalDuration;
 
-use crate::foundations::{func, repr, scope, ty, Repr};
+use crate::foundations::{func, repr, scope, ty, Cast, Repr, Smart};
 
 /// Represents a positive or negative span of time.
 #[ty(scope, cast)]
@@ -108,48 +108,131 @@ impl Duration {
     pub fn weeks(&self) -> f64 {
         self.seconds() / 604_800.0
     }
-}
 
-impl Debug for Duration {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.0.fmt(f)
+    /// Displays the duration in a human-readable way.
+    ///
+    /// By default, the duration is split into weeks, days, hours, minutes, and
+    /// seconds, omitting those that are zero. If a unit is given, the whole
+    /// duration is instead expressed in that unit, rounded to two decimal
+    /// places.
+    ///
+    /// ```example
+    /// #let d = duration(days: 1, hours: 12)
+    /// #d.display() \
+    /// #d.display(unit: "days") \
+    /// #d.display(unit: "hours")
+    /// ```
+    #[func]
+    pub fn display(
+        &self,
+        /// The unit in which to express the duration.
+        #[named]
+        #[default]
+        unit: Smart<DurationUnit>,
+    ) -> EcoString {
+        let unit = match unit {
+            Smart::Custom(unit) => unit,
+            Smart::Auto => {
+                let parts: Vec<_> = self
+                    .components()
+                    .into_iter()
+                    .filter(|&(_, count)| count != 0)
+                    .map(|(unit, count)| unit.format(count as f64))
+                    .collect();
+                if parts.is_empty() {
+                    return DurationUnit::Seconds.format(0.0);
+                }
+                return parts.join(", ").into();
+            }
+        };
+
+        let value = match unit {
+            DurationUnit::Weeks => self.weeks(),
+            DurationUnit::Days => self.days(),
+            DurationUnit::Hours => self.hours(),
+            DurationUnit::Minutes => self.minutes(),
+            DurationUnit::Seconds => self.seconds(),
+        };
+
+        unit.format((value * 100.0).round() / 100.0)
     }
 }
 
-impl Repr for Duration {
-    fn repr(&self) -> EcoString {
+impl Duration {
+    /// Splits the duration into whole weeks, days, hours, minutes, and
+    /// seconds.
+    fn components(&self) -> [(DurationUnit, i64); 5] {
         let mut tmp = self.0;
-        let mut vec = Vec::with_capacity(5);
 
-        let weeks = tmp.whole_seconds() / 604_800.0 as i64;
-        if weeks != 0 {
-            vec.push(eco_format!("weeks: {}", weeks.repr()));
-        }
+        let weeks = tmp.whole_weeks();
         tmp -= weeks.weeks();
-
         let days = tmp.whole_days();
-        if days != 0 {
-            vec.push(eco_format!("days: {}", days.repr()));
-        }
         tmp -= days.days();
-
         let hours = tmp.whole_hours();
-        if hours != 0 {
-            vec.push(eco_format!("hours: {}", hours.repr()));
-        }
         tmp -= hours.hours();
-
         let minutes = tmp.whole_minutes();
-        if minutes != 0 {
-            vec.push(eco_format!("minutes: {}", minutes.repr()));
-        }
         tmp -= minutes.minutes();
-
         let seconds = tmp.whole_seconds();
-        if seconds != 0 {
-            vec.push(eco_format!("seconds: {}", seconds.repr()));
+
+        [
+            (DurationUnit::Weeks, weeks),
+            (DurationUnit::Days, days),
+            (DurationUnit::Hours, hours),
+            (DurationUnit::Minutes, minutes),
+            (DurationUnit::Seconds, seconds),
+        ]
+    }
+}
+
+/// A unit in which a duration can be displayed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum DurationUnit {
+    /// Whole weeks of seven days.
+    Weeks,
+    /// Days of 24 hours.
+    Days,
+    /// Hours.
+    Hours,
+    /// Minutes.
+    Minutes,
+    /// Seconds.
+    Seconds,
+}
+
+impl DurationUnit {
+    /// The plural name of the unit.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Weeks => "weeks",
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
         }
+    }
 
+    /// Formats a number of this unit, e.g. `1 day` or `2.5 hours`.
+    fn format(self, value: f64) -> EcoString {
+        let name = self.name();
+        let name = if value.abs() == 1.0 { &name[..name.len() - 1] } else { name };
+        eco_format!("{} {name}", repr::display_float(value))
+    }
+}
+
+impl Debug for Duration {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Repr for Duration {
+    fn repr(&self) -> EcoString {
+        let vec: Vec<_> = self
+            .components()
+            .into_iter()
+            .filter(|&(_, count)| count != 0)
+            .map(|(unit, count)| eco_format!("{}: {}", unit.name(), count.repr()))
+            .collect();
         eco_format!("duration{}", &repr::pretty_array_like(&vec, false))
     }
 }
@@ -136,6 +136,15 @@ impl Element {
     pub fn local_name(&self, lang: Lang, region: Option<Region>) -> Option<&'static str> {
         (self.0).0.local_name.map(|f| f(lang, region))
     }
+
+    /// The element's localized plural name, if any.
+    pub fn local_name_plural(
+        &self,
+        lang: Lang,
+        region: Option<Region>,
+    ) -> Option<&'static str> {
+        (self.0).0.local_name_plural.map(|f| f(lang, region))
+    }
 }
 
 impl Debug for Element {
@@ -293,6 +302,8 @@ pub struct NativeElementData {
     pub field_from_styles: fn(u8, StyleChain) -> Result<Value, FieldAccessError>,
     /// Gets the localized name for this element (see [`LocalName`][crate::text::LocalName]).
     pub local_name: Option<fn(Lang, Option<Region>) -> &'static str>,
+    /// Gets the localized plural name for this element (see [`LocalName`][crate::text::LocalName]).
+    pub local_name_plural: Option<fn(Lang, Option<Region>) -> &'static str>,
     pub scope: Lazy<Scope>,
     /// A list of parameter information for each field.
     pub params: Lazy<Vec<ParamInfo>>,
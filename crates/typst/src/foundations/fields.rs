@@ -3,7 +3,7 @@
 use ecow::{eco_format, EcoString};
 
 use crate::diag::StrResult;
-use crate::foundations::{IntoValue, Type, Value, Version};
+use crate::foundations::{IntoValue, Smart, Type, Value, Version};
 use crate::layout::{Alignment, Length, Rel};
 use crate::visualize::Stroke;
 
@@ -35,7 +35,11 @@ pub(crate) fn field(value: &Value, field: &str) -> StrResult<Value> {
         Value::Dyn(dynamic) => {
             if let Some(stroke) = dynamic.downcast::<Stroke>() {
                 match field {
-                    "paint" => stroke.paint.clone().into_value(),
+                    "paint" => match stroke.paints() {
+                        Smart::Custom([paint]) => paint.clone().into_value(),
+                        Smart::Custom(paints) => paints.to_vec().into_value(),
+                        Smart::Auto => Value::Auto,
+                    },
                     "thickness" => stroke.thickness.into_value(),
                     "cap" => stroke.cap.into_value(),
                     "join" => stroke.join.into_value(),
@@ -4,6 +4,7 @@ use ecow::{eco_format, EcoString};
 
 use crate::foundations::{cast, func, repr, scope, ty, Repr, Str};
 use crate::layout::Ratio;
+use crate::utils::Scalar;
 
 /// A floating-point number.
 ///
@@ -102,6 +103,12 @@ impl Repr for f64 {
     }
 }
 
+cast! {
+    Scalar,
+    self => self.get().into_value(),
+    v: f64 => Self::new(v),
+}
+
 /// A value that can be cast to a float.
 pub struct ToFloat(f64);
 
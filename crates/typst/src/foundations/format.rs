@@ -0,0 +1,149 @@
+use ecow::EcoString;
+
+use crate::foundations::calc::Num;
+use crate::foundations::repr::{self, MINUS_SIGN};
+use crate::foundations::{func, Str};
+use crate::text::Lang;
+
+/// Formats a number as a string for display.
+///
+/// By default, the number is written without grouping and with a period as
+/// the decimal separator. A `locale` selects the grouping and decimal
+/// separators that are customary for a language, which can in turn be
+/// overridden with `group` and `decimal`.
+///
+/// Floats whose magnitude is at least `{1e21}` or below `{1e-6}` are written
+/// in scientific notation without grouping.
+///
+/// # Example
+/// ```example
+/// #format(1234567.89, group: ",") \
+/// #format(1234567.89, locale: "de") \
+/// #format(-0.5, digits: 3) \
+/// #format(6.022e23, digits: 2)
+/// ```
+#[func(title = "Format Number")]
+pub fn format(
+    /// The number to format.
+    number: Num,
+    /// The number of digits after the decimal separator. The number is
+    /// rounded or padded with zeros as necessary. If `{none}`, integers are
+    /// written without a fractional part and floats with as many digits as
+    /// needed to represent them.
+    #[named]
+    #[default]
+    digits: Option<usize>,
+    /// The string to insert between groups of three digits. If `{none}`, the
+    /// `locale` determines the grouping.
+    #[named]
+    #[default]
+    group: Option<Str>,
+    /// The string to separate the integral and the fractional part with. If
+    /// `{none}`, the `locale` determines the separator.
+    #[named]
+    #[default]
+    decimal: Option<Str>,
+    /// The language whose conventions to follow for grouping and the decimal
+    /// separator.
+    #[named]
+    #[default]
+    locale: Option<Lang>,
+) -> Str {
+    let (default_group, default_decimal) = match locale {
+        Some(lang) => separators(lang),
+        None => ("", "."),
+    };
+    let group = group.as_deref().unwrap_or(default_group);
+    let decimal = decimal.as_deref().unwrap_or(default_decimal);
+
+    let (negative, text) = match number {
+        Num::Int(v) => {
+            let mut s = v.unsigned_abs().to_string();
+            if let Some(n) = digits.filter(|&n| n > 0) {
+                s.push('.');
+                s.extend(std::iter::repeat('0').take(n));
+            }
+            (v < 0, s)
+        }
+        Num::Float(v) if !v.is_finite() => return repr::display_float(v).into(),
+        Num::Float(v) => {
+            let abs = v.abs();
+            let scientific = abs >= 1e21 || (abs != 0.0 && abs < 1e-6);
+            let s = match (scientific, digits) {
+                (true, Some(n)) => format!("{abs:.n$e}"),
+                (true, None) => format!("{abs:e}"),
+                (false, Some(n)) => format!("{abs:.n$}"),
+                (false, None) => format!("{abs}"),
+            };
+            if scientific {
+                let mut buf = EcoString::new();
+                if v < 0.0 {
+                    buf.push_str(MINUS_SIGN);
+                }
+                buf.push_str(&s.replace('.', decimal));
+                return buf.into();
+            }
+            (v < 0.0, s)
+        }
+    };
+
+    let (int, frac) = text.split_once('.').unwrap_or((&text, ""));
+
+    // Don't show a sign if the number was rounded to zero.
+    let mut buf = EcoString::new();
+    if negative && text.bytes().any(|c| matches!(c, b'1'..=b'9')) {
+        buf.push_str(MINUS_SIGN);
+    }
+
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            buf.push_str(group);
+        }
+        buf.push(c);
+    }
+
+    if !frac.is_empty() {
+        buf.push_str(decimal);
+        buf.push_str(frac);
+    }
+
+    buf.into()
+}
+
+/// The customary group and decimal separators of a language.
+fn separators(lang: Lang) -> (&'static str, &'static str) {
+    match lang {
+        Lang::CATALAN
+        | Lang::CROATIAN
+        | Lang::DANISH
+        | Lang::DUTCH
+        | Lang::GALICIAN
+        | Lang::GERMAN
+        | Lang::GREEK
+        | Lang::INDONESIAN
+        | Lang::ITALIAN
+        | Lang::PORTUGUESE
+        | Lang::ROMANIAN
+        | Lang::SERBIAN
+        | Lang::SLOVENIAN
+        | Lang::SPANISH
+        | Lang::TURKISH
+        | Lang::VIETNAMESE => (".", ","),
+        // French uses a narrow no-break space.
+        Lang::FRENCH => ("\u{202F}", ","),
+        Lang::ALBANIAN
+        | Lang::BOKMÅL
+        | Lang::CZECH
+        | Lang::ESTONIAN
+        | Lang::FINNISH
+        | Lang::HUNGARIAN
+        | Lang::LOWER_SORBIAN
+        | Lang::NYNORSK
+        | Lang::POLISH
+        | Lang::RUSSIAN
+        | Lang::SLOVAK
+        | Lang::SWEDISH
+        | Lang::UKRAINIAN => ("\u{A0}", ","),
+        _ => (",", "."),
+    }
+}
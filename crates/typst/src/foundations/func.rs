@@ -411,6 +411,7 @@ impl PartialEq for Func {
 }
 
 impl PartialEq<&NativeFuncData> for Func {
+    #[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
     fn eq(&self, other: &&NativeFuncData) -> bool {
         match &self.repr {
             Repr::Native(native) => native.function == other.function,
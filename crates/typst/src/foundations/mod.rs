@@ -18,6 +18,7 @@ mod duration;
 mod element;
 mod fields;
 mod float;
+mod format;
 mod func;
 mod int;
 mod label;
@@ -107,6 +108,7 @@ pub(super) fn define(global: &mut Scope, inputs: Dict) {
     global.define_type::<Version>();
     global.define_type::<Plugin>();
     global.define_func::<repr::repr>();
+    global.define_func::<format::format>();
     global.define_func::<panic>();
     global.define_func::<assert>();
     global.define_func::<eval>();
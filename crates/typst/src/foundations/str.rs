@@ -380,6 +380,19 @@ impl Str {
     ///   group. The first item of the array contains the first matched
     ///   capturing, not the whole match! This is empty unless the `pattern` was
     ///   a regex with capturing groups.
+    /// - `named`: A dictionary mapping the name of each named capturing group
+    ///   to the text it matched. This is empty unless the `pattern` was a regex
+    ///   with named capturing groups.
+    ///
+    /// Groups that did not participate in the match are `{none}` in both
+    /// `captures` and `named`.
+    ///
+    /// ```example
+    /// #let m = "2024-03-15".match(
+    ///   regex("(?<year>\\d+)-(?<month>\\d+)-(?<day>\\d+)")
+    /// )
+    /// #m.named.year / #m.named.month
+    /// ```
     #[func]
     pub fn match_(
         &self,
@@ -390,7 +403,9 @@ impl Str {
             StrPattern::Str(pat) => {
                 self.0.match_indices(pat.as_str()).next().map(match_to_dict)
             }
-            StrPattern::Regex(re) => re.captures(self).map(captures_to_dict),
+            StrPattern::Regex(re) => {
+                re.captures(self).map(|caps| captures_to_dict(&re, caps))
+            }
         }
     }
 
@@ -412,7 +427,7 @@ impl Str {
                 .collect(),
             StrPattern::Regex(re) => re
                 .captures_iter(self)
-                .map(captures_to_dict)
+                .map(|caps| captures_to_dict(&re, caps))
                 .map(Value::Dict)
                 .collect(),
         }
@@ -477,7 +492,7 @@ impl Str {
                 for caps in re.captures_iter(self).take(count) {
                     // Extract the entire match over all capture groups.
                     let m = caps.get(0).unwrap();
-                    handle_match(m.start()..m.end(), captures_to_dict(caps))?;
+                    handle_match(m.start()..m.end(), captures_to_dict(re, caps))?;
                 }
             }
         }
@@ -793,20 +808,27 @@ fn match_to_dict((start, text): (usize, &str)) -> Dict {
         "end" => start + text.len(),
         "text" => text,
         "captures" => Array::new(),
+        "named" => Dict::new(),
     }
 }
 
 /// Convert regex captures to a dictionary.
-fn captures_to_dict(cap: regex::Captures) -> Dict {
+fn captures_to_dict(re: &Regex, cap: regex::Captures) -> Dict {
     let m = cap.get(0).expect("missing first match");
+    let text =
+        |m: Option<regex::Match>| m.map_or(Value::None, |m| m.as_str().into_value());
     dict! {
         "start" => m.start(),
         "end" => m.end(),
         "text" => m.as_str(),
         "captures" =>  cap.iter()
             .skip(1)
-            .map(|opt| opt.map_or(Value::None, |m| m.as_str().into_value()))
+            .map(text)
             .collect::<Array>(),
+        "named" => re.capture_names()
+            .flatten()
+            .map(|name| (name.into(), text(cap.name(name))))
+            .collect::<Dict>(),
     }
 }
 
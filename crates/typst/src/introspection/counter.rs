@@ -8,12 +8,12 @@ use smallvec::{smallvec, SmallVec};
 use crate::diag::{bail, At, HintedStrResult, SourceResult};
 use crate::engine::{Engine, Route, Sink, Traced};
 use crate::foundations::{
-    cast, elem, func, scope, select_where, ty, Args, Array, Construct, Content, Context,
-    Element, Func, IntoValue, Label, LocatableSelector, NativeElement, Packed, Repr,
-    Selector, Show, Smart, Str, StyleChain, Value,
+    array, cast, elem, func, scope, select_where, ty, Args, Array, Construct, Content,
+    Context, Element, Func, IntoValue, Label, LocatableSelector, NativeElement, Packed,
+    Repr, Selector, Show, Smart, Str, StyleChain, Value,
 };
 use crate::introspection::{Introspector, Locatable, Location};
-use crate::layout::{Frame, FrameItem, PageElem};
+use crate::layout::{Frame, FrameItem, PageElem, Point};
 use crate::math::EquationElem;
 use crate::model::{FigureElem, HeadingElem, Numbering, NumberingPattern};
 use crate::syntax::Span;
@@ -269,6 +269,36 @@ impl Counter {
             .display())
     }
 
+    /// Gets the value of the counter at the given location together with its
+    /// display under the given numbering as plain text.
+    ///
+    /// The counter is only resolved once, which is useful when both are
+    /// needed, e.g. for building custom outlines.
+    pub fn display_at(
+        &self,
+        engine: &mut Engine,
+        location: Location,
+        numbering: &Numbering,
+    ) -> SourceResult<(Vec<usize>, EcoString)> {
+        let (state, display) = self.resolve_at(engine, location, None, numbering)?;
+        Ok((state.0.into_vec(), display.display().plain_text()))
+    }
+
+    /// Gets the value of the counter at the given location together with the
+    /// output of the numbering, which may be content rather than just text.
+    fn resolve_at(
+        &self,
+        engine: &mut Engine,
+        location: Location,
+        styles: Option<StyleChain>,
+        numbering: &Numbering,
+    ) -> SourceResult<(CounterState, Value)> {
+        let state = self.at_loc(engine, location)?;
+        let context = Context::new(Some(location), styles);
+        let display = state.display(engine, context.track(), numbering)?;
+        Ok((state, display))
+    }
+
     /// Produce the whole sequence of counter states.
     ///
     /// This has to happen just once for all counters, cutting down the number
@@ -307,6 +337,7 @@ impl Counter {
         let mut state = CounterState::init(&self.0);
         let mut page = NonZeroUsize::ONE;
         let mut stops = eco_vec![(state.clone(), page)];
+        let mut last_region = None;
 
         for elem in introspector.query(&self.selector()) {
             if self.is_page() {
@@ -323,7 +354,26 @@ impl Counter {
                 Some(countable) => countable.update(),
                 None => Some(CounterUpdate::Step(NonZeroUsize::ONE)),
             } {
-                state.update(&mut engine, update)?;
+                let loc = elem.location().unwrap();
+                match update {
+                    CounterUpdate::StepIn(region, level) => {
+                        let here = region.find(introspector, loc);
+                        if last_region.is_some_and(|prev| prev != here) {
+                            state = CounterState::init(&self.0);
+                        }
+                        last_region = Some(here);
+                        state.step(level, 1);
+                    }
+                    CounterUpdate::Within(parent, level) => {
+                        let parent = parent.at_loc(&mut engine, loc)?;
+                        state = state.within(&parent, level);
+                    }
+                    CounterUpdate::Extend(parent, number) => {
+                        state = parent.at_loc(&mut engine, loc)?;
+                        state.0.push(number);
+                    }
+                    update => state.update(&mut engine, update)?,
+                }
             }
 
             stops.push((state.clone(), page));
@@ -358,7 +408,24 @@ impl Counter {
         both: bool,
         styles: Option<StyleChain>,
     ) -> SourceResult<Value> {
-        let numbering = numbering
+        let numbering = self.resolve_numbering(numbering, styles);
+        if !both {
+            return Ok(self.resolve_at(engine, location, styles, &numbering)?.1);
+        }
+
+        let state = self.both(engine, location)?;
+        let context = Context::new(Some(location), styles);
+        state.display(engine, context.track(), &numbering)
+    }
+
+    /// Resolves the numbering used to display this counter, falling back to
+    /// the numbering of the counted element or `{"1.1"}`.
+    fn resolve_numbering(
+        &self,
+        numbering: Smart<Numbering>,
+        styles: Option<StyleChain>,
+    ) -> Numbering {
+        numbering
             .custom()
             .or_else(|| {
                 let styles = styles?;
@@ -376,16 +443,7 @@ impl Counter {
                     None
                 }
             })
-            .unwrap_or_else(|| NumberingPattern::from_str("1.1").unwrap().into());
-
-        let state = if both {
-            self.both(engine, location)?
-        } else {
-            self.at_loc(engine, location)?
-        };
-
-        let context = Context::new(Some(location), styles);
-        state.display(engine, context.track(), &numbering)
+            .unwrap_or_else(|| NumberingPattern::from_str("1.1").unwrap().into())
     }
 }
 
@@ -497,6 +555,51 @@ impl Counter {
         self.at_loc(engine, loc)
     }
 
+    /// Retrieves the value of the counter at the given location together with
+    /// its display under a numbering.
+    ///
+    /// Returns an array with two items: The value as an array of integers,
+    /// like [`at`]($counter.at) returns it, and the formatted output, like
+    /// [`display`]($counter.display) returns it. This is useful when both are
+    /// needed, for instance when building a custom outline.
+    ///
+    /// ```example
+    /// #set heading(numbering: "I.")
+    /// #show heading: it => block(context {
+    ///   let (value, number) = counter(heading).display-at(here())
+    ///   [#number #it.body (#value.first() of #counter(heading).final().first())]
+    /// })
+    ///
+    /// = Introduction
+    /// = Background
+    /// ```
+    #[func(contextual, name = "display-at")]
+    pub fn display_at_selector(
+        &self,
+        /// The engine.
+        engine: &mut Engine,
+        /// The callsite context.
+        context: Tracked<Context>,
+        /// The callsite span.
+        span: Span,
+        /// The place at which the counter should be displayed.
+        selector: LocatableSelector,
+        /// A [numbering pattern or a function]($numbering), which specifies how
+        /// to display the counter.
+        ///
+        /// If this is omitted or set to `{auto}`, displays the counter with the
+        /// numbering style for the counted element or with the pattern
+        /// `{"1.1"}` if no such style exists.
+        #[default]
+        numbering: Smart<Numbering>,
+    ) -> SourceResult<Array> {
+        let loc = selector.resolve_unique(engine.introspector, context).at(span)?;
+        let styles = context.styles().ok();
+        let numbering = self.resolve_numbering(numbering, styles);
+        let (state, display) = self.resolve_at(engine, loc, styles, &numbering)?;
+        Ok(array![state, display])
+    }
+
     /// Retrieves the value of the counter at the end of the document. Always
     /// returns an array of integers, even if the counter has just one number.
     #[func(contextual)]
@@ -526,7 +629,7 @@ impl Counter {
         Ok(state)
     }
 
-    /// Increases the value of the counter by one.
+    /// Increases the value of the counter by one or by the given amount.
     ///
     /// The update will be in effect at the position where the returned content
     /// is inserted into the document. If you don't put the output into the
@@ -543,8 +646,25 @@ impl Counter {
         #[named]
         #[default(NonZeroUsize::ONE)]
         level: NonZeroUsize,
+        /// The amount by which to increase the counter. Defaults to `{1}`.
+        ///
+        /// ```example
+        /// #let c = counter("reserved")
+        /// #c.step()
+        /// #context c.display() \
+        /// #c.step(by: 3)
+        /// #context c.display()
+        /// ```
+        #[named]
+        #[default(NonZeroUsize::ONE)]
+        by: NonZeroUsize,
     ) -> Content {
-        self.update(span, CounterUpdate::Step(level))
+        let update = if by == NonZeroUsize::ONE {
+            CounterUpdate::Step(level)
+        } else {
+            CounterUpdate::StepBy(level, by)
+        };
+        self.update(span, update)
     }
 
     /// Updates the value of the counter.
@@ -557,9 +677,10 @@ impl Counter {
         /// The call span of the update.
         span: Span,
         /// If given an integer or array of integers, sets the counter to that
-        /// value. If given a function, that function receives the previous
-        /// counter value (with each number as a separate argument) and has to
-        /// return the new value (integer or array).
+        /// value, replacing all of its levels. If given a function, that
+        /// function receives the previous counter value (with each number as
+        /// a separate argument) and has to return the new value (integer or
+        /// array).
         update: CounterUpdate,
     ) -> Content {
         CounterUpdateElem::new(self.0, update).pack().spanned(span)
@@ -621,8 +742,19 @@ pub enum CounterUpdate {
     Set(CounterState),
     /// Increase the number for the given level by one.
     Step(NonZeroUsize),
+    /// Increase the number for the given level by the given amount.
+    StepBy(NonZeroUsize, NonZeroUsize),
     /// Apply the given function to the counter's state.
     Func(Func),
+    /// Increase the number for the given level by one, restarting the counter
+    /// first if the previous such step happened in another region.
+    StepIn(CounterRegion, NonZeroUsize),
+    /// Step the number after the given level of another counter's state at
+    /// the same location. See [`CounterState::within`].
+    Within(Counter, NonZeroUsize),
+    /// Set the counter to another counter's state at the same location,
+    /// extended by the given number.
+    Extend(Counter, usize),
 }
 
 cast! {
@@ -631,6 +763,30 @@ cast! {
     v: Func => Self::Func(v),
 }
 
+/// A part of the document in which a counter can restart.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CounterRegion {
+    /// Each page.
+    Page,
+    /// Each column of a multi-column layout, or each page outside of one.
+    Column,
+}
+
+impl CounterRegion {
+    /// Identify the region that contains the element with the given location.
+    fn find(
+        self,
+        introspector: Tracked<Introspector>,
+        location: Location,
+    ) -> (NonZeroUsize, Option<Point>) {
+        let page = introspector.page(location);
+        match self {
+            Self::Page => (page, None),
+            Self::Column => (page, introspector.column(location)),
+        }
+    }
+}
+
 /// Elements that have special counting behaviour.
 pub trait Count {
     /// Get the counter update for this element.
@@ -660,12 +816,17 @@ impl CounterState {
         match update {
             CounterUpdate::Set(state) => *self = state,
             CounterUpdate::Step(level) => self.step(level, 1),
+            CounterUpdate::StepBy(level, by) => self.step(level, by.get()),
             CounterUpdate::Func(func) => {
                 *self = func
                     .call(engine, Context::none().track(), self.0.iter().copied())?
                     .cast()
                     .at(func.span())?
             }
+            // These depend on where the update happens and are resolved by the
+            // counter's sequence.
+            CounterUpdate::StepIn(_, level) => self.step(level, 1),
+            CounterUpdate::Within(..) | CounterUpdate::Extend(..) => {}
         }
         Ok(())
     }
@@ -680,10 +841,30 @@ impl CounterState {
         }
 
         while self.0.len() < level {
-            self.0.push(1);
+            // The stepped level starts at the amount, the ones above it at one.
+            let first = if self.0.len() + 1 == level { by.max(1) } else { 1 };
+            self.0.push(first);
         }
     }
 
+    /// Step the number after the given level of a parent counter's state,
+    /// restarting it if the parent's numbers up to that level changed.
+    ///
+    /// Missing parent numbers are taken to be zero.
+    pub fn within(&self, parent: &CounterState, level: NonZeroUsize) -> Self {
+        let level = level.get();
+        let mut numbers: SmallVec<[usize; 3]> =
+            (0..level).map(|i| parent.0.get(i).copied().unwrap_or(0)).collect();
+        let count = match self.0.get(level) {
+            Some(&n) if self.0.len() == level + 1 && self.0[..level] == numbers[..] => {
+                n + 1
+            }
+            _ => 1,
+        };
+        numbers.push(count);
+        Self(numbers)
+    }
+
     /// Get the first number of the state.
     pub fn first(&self) -> usize {
         self.0.first().copied().unwrap_or(1)
@@ -814,7 +995,9 @@ impl ManualPageCounter {
             match item {
                 FrameItem::Group(group) => self.visit(engine, &group.frame)?,
                 FrameItem::Tag(tag) => {
-                    let Some(elem) = tag.elem.to_packed::<CounterUpdateElem>() else {
+                    let Some(elem) =
+                        tag.elem().and_then(|elem| elem.to_packed::<CounterUpdateElem>())
+                    else {
                         continue;
                     };
                     if *elem.key() == CounterKey::Page {
@@ -10,8 +10,8 @@ use smallvec::SmallVec;
 
 use crate::diag::{bail, StrResult};
 use crate::foundations::{Content, Label, Repr, Selector};
-use crate::introspection::Location;
-use crate::layout::{Frame, FrameItem, Page, Point, Position, Transform};
+use crate::introspection::{Location, Tag};
+use crate::layout::{Frame, FrameItem, Page, Point, Position, Size, Transform};
 use crate::model::Numbering;
 use crate::utils::NonZeroExt;
 
@@ -22,6 +22,11 @@ pub struct Introspector {
     pages: usize,
     /// All introspectable elements.
     elems: IndexMap<Location, (Content, Position)>,
+    /// The areas covered by the elements, one for each page they are on.
+    extents: HashMap<Location, SmallVec<[Extent; 1]>>,
+    /// Maps elements inside of multi-column layouts to the top-left corner of
+    /// their innermost column.
+    columns: HashMap<Location, Point>,
     /// Maps labels to their indices in the element list. We use a smallvec such
     /// that if the label is unique, we don't need to allocate.
     labels: HashMap<Label, SmallVec<[usize; 1]>>,
@@ -30,6 +35,8 @@ pub struct Introspector {
     keys: HashMap<u128, SmallVec<[Location; 1]>>,
     /// The page numberings, indexed by page number minus 1.
     page_numberings: Vec<Option<Numbering>>,
+    /// The page sizes, indexed by page number minus 1.
+    page_sizes: Vec<Size>,
     /// Caches queries done on the introspector. This is important because
     /// even if all top-level queries are distinct, they often have shared
     /// subqueries. Example: Individual counter queries with `before` that
@@ -43,51 +50,106 @@ impl Introspector {
     pub fn rebuild(&mut self, pages: &[Page]) {
         self.pages = pages.len();
         self.elems.clear();
+        self.extents.clear();
+        self.columns.clear();
         self.labels.clear();
         self.keys.clear();
         self.page_numberings.clear();
+        self.page_sizes.clear();
         self.queries.clear();
 
+        let mut open = vec![];
         for (i, page) in pages.iter().enumerate() {
             let page_nr = NonZeroUsize::new(1 + i).unwrap();
-            self.extract(&page.frame, page_nr, Transform::identity());
+            self.extract(&page.frame, page_nr, Transform::identity(), None, &mut open);
             self.page_numberings.push(page.numbering.clone());
+            self.page_sizes.push(page.frame.size());
         }
     }
 
     /// Extract metadata from a frame.
-    fn extract(&mut self, frame: &Frame, page: NonZeroUsize, ts: Transform) {
+    ///
+    /// The `open` elements are those whose start tag was already visited, but
+    /// whose end tag wasn't yet. Their extents grow with each visible item.
+    fn extract(
+        &mut self,
+        frame: &Frame,
+        page: NonZeroUsize,
+        ts: Transform,
+        column: Option<Point>,
+        open: &mut Vec<Location>,
+    ) {
         for (pos, item) in frame.items() {
             match item {
                 FrameItem::Group(group) => {
                     let ts = ts
                         .pre_concat(Transform::translate(pos.x, pos.y))
                         .pre_concat(group.transform);
-                    self.extract(&group.frame, page, ts);
+                    let column = if group.column {
+                        Some(Point::zero().transform(ts))
+                    } else {
+                        column
+                    };
+                    self.extract(&group.frame, page, ts, column, open);
                 }
-                FrameItem::Tag(tag)
-                    if !self.elems.contains_key(&tag.elem.location().unwrap()) =>
+                FrameItem::Tag(Tag::Start { elem, key })
+                    if !self.elems.contains_key(&elem.location().unwrap()) =>
                 {
                     let pos = pos.transform(ts);
-                    let loc = tag.elem.location().unwrap();
+                    let loc = elem.location().unwrap();
                     let ret = self
                         .elems
-                        .insert(loc, (tag.elem.clone(), Position { page, point: pos }));
+                        .insert(loc, (elem.clone(), Position { page, point: pos }));
                     assert!(ret.is_none(), "duplicate locations");
 
+                    // An element's extent starts where the element does, even
+                    // if its visible content is indented.
+                    self.extend(loc, page, pos, pos);
+                    open.push(loc);
+
+                    if let Some(origin) = column {
+                        self.columns.insert(loc, origin);
+                    }
+
                     // Build the key map.
-                    self.keys.entry(tag.key).or_default().push(loc);
+                    self.keys.entry(*key).or_default().push(loc);
 
                     // Build the label cache.
-                    if let Some(label) = tag.elem.label() {
+                    if let Some(label) = elem.label() {
                         self.labels.entry(label).or_default().push(self.elems.len() - 1);
                     }
                 }
+                FrameItem::Tag(Tag::End(loc)) => {
+                    if let Some(i) = open.iter().rposition(|open| open == loc) {
+                        open.remove(i);
+                    }
+                }
+                FrameItem::Text(_) | FrameItem::Shape(..) | FrameItem::Image(..)
+                    if !open.is_empty() =>
+                {
+                    let (min, max) = bounds(*pos, item, ts);
+                    for &loc in open.iter() {
+                        self.extend(loc, page, min, max);
+                    }
+                }
                 _ => {}
             }
         }
     }
 
+    /// Grow the extent of an element on the given page to include the
+    /// rectangle from `min` to `max`.
+    fn extend(&mut self, loc: Location, page: NonZeroUsize, min: Point, max: Point) {
+        let extents = self.extents.entry(loc).or_default();
+        match extents.last_mut() {
+            Some(extent) if extent.page == page => {
+                extent.min = extent.min.min(min);
+                extent.max = extent.max.max(max);
+            }
+            _ => extents.push(Extent { page, min, max }),
+        }
+    }
+
     /// Iterate over all locatable elements.
     pub fn all(&self) -> impl Iterator<Item = &Content> + '_ {
         self.elems.values().map(|(c, _)| c)
@@ -253,6 +315,14 @@ impl Introspector {
         Ok(&self.elems[indices[0]].0)
     }
 
+    /// Query for all matching elements that are located on the given page.
+    pub fn query_page(&self, selector: &Selector, page: NonZeroUsize) -> EcoVec<Content> {
+        self.query(selector)
+            .into_iter()
+            .filter(|elem| self.page(elem.location().unwrap()) == page)
+            .collect()
+    }
+
     /// This is an optimized version of
     /// `query(selector.before(end, true).len()` used by counters and state.
     pub fn query_count_before(&self, selector: &Selector, end: Location) -> usize {
@@ -281,17 +351,63 @@ impl Introspector {
             .and_then(|slot| slot.as_ref())
     }
 
+    /// Gets the size of the given page, if it exists.
+    pub fn page_size(&self, page: NonZeroUsize) -> Option<Size> {
+        self.page_sizes.get(page.get() - 1).copied()
+    }
+
     /// Find the page number for the given location.
     pub fn page(&self, location: Location) -> NonZeroUsize {
-        self.position(location).page
+        self.position(location).map_or(NonZeroUsize::ONE, |pos| pos.page)
     }
 
     /// Find the position for the given location.
-    pub fn position(&self, location: Location) -> Position {
+    ///
+    /// The position is measured from the top-left of the page. For elements
+    /// that were split across multiple regions, this is the position of the
+    /// first fragment. Returns `None` if the location does not exist in the
+    /// document.
+    pub fn position(&self, location: Location) -> Option<Position> {
+        self.elems.get(&location).map(|&(_, pos)| pos)
+    }
+
+    /// Find the top-left corner of the innermost column that contains the
+    /// element with the given location.
+    ///
+    /// Returns `None` if the element is not part of a multi-column layout.
+    pub fn column(&self, location: Location) -> Option<Point> {
+        self.columns.get(&location).copied()
+    }
+
+    /// Query for all elements whose extent intersects the rectangle at
+    /// `point` with the given `size` on the given page.
+    ///
+    /// Elements that span multiple pages are found on each of these pages.
+    pub fn query_region(
+        &self,
+        page: NonZeroUsize,
+        point: Point,
+        size: Size,
+    ) -> EcoVec<Content> {
         self.elems
-            .get(&location)
-            .map(|&(_, pos)| pos)
-            .unwrap_or(Position { page: NonZeroUsize::ONE, point: Point::zero() })
+            .iter()
+            .filter(|(&loc, _)| self.is_within(loc, page, point, size))
+            .map(|(_, (elem, _))| elem.clone())
+            .collect()
+    }
+
+    /// Whether the extent of the element with the given location intersects
+    /// the rectangle at `point` with the given `size` on the given page.
+    pub fn is_within(
+        &self,
+        location: Location,
+        page: NonZeroUsize,
+        point: Point,
+        size: Size,
+    ) -> bool {
+        self.extents.get(&location).is_some_and(|extents| {
+            extents.iter().any(|extent| within(extent, page, point, size))
+        })
     }
 
     /// Try to find a location for an element with the given `key` hash
@@ -310,6 +426,52 @@ impl Introspector {
     }
 }
 
+/// The area covered by an element on a page.
+#[derive(Debug, Copy, Clone)]
+struct Extent {
+    /// The page the area is on.
+    page: NonZeroUsize,
+    /// The top-left corner of the area.
+    min: Point,
+    /// The bottom-right corner of the area.
+    max: Point,
+}
+
+/// Whether an extent intersects the rectangle at `point` with the given
+/// `size` on the given page.
+fn within(extent: &Extent, page: NonZeroUsize, point: Point, size: Size) -> bool {
+    let end = point + size.to_point();
+    extent.page == page
+        && extent.min.x <= end.x
+        && point.x <= extent.max.x
+        && extent.min.y <= end.y
+        && point.y <= extent.max.y
+}
+
+/// The top-left and bottom-right corners of the bounding box of a visible
+/// frame item at `pos`, after applying the transform.
+fn bounds(pos: Point, item: &FrameItem, ts: Transform) -> (Point, Point) {
+    let (a, b) = match item {
+        FrameItem::Text(text) => {
+            let metrics = text.font.metrics();
+            let top = pos.y - metrics.ascender.at(text.size);
+            let bottom = pos.y - metrics.descender.at(text.size);
+            (Point::new(pos.x, top), Point::new(pos.x + text.width(), bottom))
+        }
+        FrameItem::Shape(shape, _) => (pos, pos + shape.geometry.bbox_size().to_point()),
+        FrameItem::Image(_, size, _) => (pos, pos + size.to_point()),
+        _ => (pos, pos),
+    };
+
+    let corners = [a, Point::new(b.x, a.y), Point::new(a.x, b.y), b]
+        .map(|corner| corner.transform(ts));
+    corners[1..]
+        .iter()
+        .fold((corners[0], corners[0]), |(min, max), &corner| {
+            (min.min(corner), max.max(corner))
+        })
+}
+
 impl Debug for Introspector {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         f.pad("Introspector(..)")
@@ -4,9 +4,10 @@ use std::num::NonZeroUsize;
 use ecow::EcoString;
 
 use crate::engine::Engine;
-use crate::foundations::{func, scope, ty, Repr};
-use crate::layout::Position;
+use crate::foundations::{dict, func, scope, ty, Dict, Repr};
+use crate::layout::{Point, Position};
 use crate::model::Numbering;
+use crate::utils::NonZeroExt;
 
 /// Identifies an element in the document.
 ///
@@ -77,7 +78,22 @@ impl Location {
     /// Typst to skip unnecessary work.
     #[func]
     pub fn position(self, engine: &mut Engine) -> Position {
-        engine.introspector.position(self)
+        engine
+            .introspector
+            .position(self)
+            .unwrap_or(Position { page: NonZeroUsize::ONE, point: Point::zero() })
+    }
+
+    /// Returns a dictionary with the `width` and `height` of the page this
+    /// location is on.
+    ///
+    /// Together with [`position`]($location.position), this can be used to
+    /// draw relative to the absolute position of a marker on its page.
+    #[func]
+    pub fn page_size(self, engine: &mut Engine) -> Dict {
+        let page = engine.introspector.page(self);
+        let size = engine.introspector.page_size(page).unwrap_or_default();
+        dict! { "width" => size.x, "height" => size.y }
     }
 
     /// Returns the page numbering pattern of the page at this location. This
@@ -1,6 +1,8 @@
 use crate::diag::SourceResult;
 use crate::engine::Engine;
-use crate::foundations::{elem, Content, Packed, Show, StyleChain, Value};
+use crate::foundations::{
+    elem, Args, Construct, Content, NativeElement, Packed, Show, Str, StyleChain, Value,
+};
 use crate::introspection::Locatable;
 
 /// Exposes a value to the query system without producing visible content.
@@ -24,13 +26,44 @@ use crate::introspection::Locatable;
 ///   query(<note>).first().value
 /// }
 /// ```
-#[elem(Show, Locatable)]
+///
+/// # Keys
+/// Instead of a label, you can also give metadata a string `key` and then
+/// query for it with `metadata.where(key: ..)`. Multiple metadata elements
+/// may share the same key, in which case the query returns all of them in
+/// document order. The embedded values retain their types.
+///
+/// ```example
+/// #metadata(key: "chapter-color", value: red)
+///
+/// #context {
+///   let color = query(metadata.where(key: "chapter-color")).last().value
+///   text(fill: color)[Colorful chapter]
+/// }
+/// ```
+#[elem(Construct, Show, Locatable)]
 pub struct MetadataElem {
+    /// A key to identify the metadata by in queries.
+    pub key: Option<Str>,
+
     /// The value to embed into the document.
+    ///
+    /// Can be passed positionally or as a named argument.
     #[required]
     pub value: Value,
 }
 
+impl Construct for MetadataElem {
+    fn construct(_: &mut Engine, args: &mut Args) -> SourceResult<Content> {
+        let key = args.named::<Option<Str>>("key")?.flatten();
+        let value = match args.named("value")? {
+            Some(value) => value,
+            None => args.expect("value")?,
+        };
+        Ok(Self::new(value).with_key(key).pack())
+    }
+}
+
 impl Show for Packed<MetadataElem> {
     fn show(&self, _: &mut Engine, _styles: StyleChain) -> SourceResult<Content> {
         Ok(Content::empty())
@@ -58,7 +58,8 @@ pub fn define(global: &mut Scope) {
     global.define_func::<locate>();
 }
 
-/// Holds a tag for a locatable element that was realized.
+/// Holds a tag that marks the start or end of a locatable element that was
+/// realized.
 ///
 /// The `TagElem` is handled by all layouters. The held element becomes
 /// available for introspection in the next compiler iteration.
@@ -94,28 +95,44 @@ impl Behave for Packed<TagElem> {
     }
 }
 
-/// Holds a locatable element that was realized.
+/// Marks the start or end of a locatable element that was realized.
 #[derive(Clone, PartialEq, Hash)]
-pub struct Tag {
-    /// The introspectible element.
-    pub elem: Content,
-    /// The element's key hash, which forms the base of its location (but is
-    /// locally disambiguated and combined with outer hashes).
-    ///
-    /// We need to retain this for introspector-assisted location assignment
-    /// during measurement.
-    pub(crate) key: u128,
+pub enum Tag {
+    /// The start of an element.
+    Start {
+        /// The introspectible element.
+        elem: Content,
+        /// The element's key hash, which forms the base of its location (but
+        /// is locally disambiguated and combined with outer hashes).
+        ///
+        /// We need to retain this for introspector-assisted location
+        /// assignment during measurement.
+        key: u128,
+    },
+    /// The end of the element with the given location.
+    End(Location),
 }
 
 impl Tag {
-    /// Create a tag from an element and its key hash.
+    /// Create a start tag from an element and its key hash.
     pub fn new(elem: Content, key: u128) -> Self {
-        Self { elem, key }
+        Self::Start { elem, key }
+    }
+
+    /// The element, if this is a start tag.
+    pub fn elem(&self) -> Option<&Content> {
+        match self {
+            Self::Start { elem, .. } => Some(elem),
+            Self::End(_) => None,
+        }
     }
 }
 
 impl Debug for Tag {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Tag({:?})", self.elem)
+        match self {
+            Self::Start { elem, .. } => write!(f, "Start({elem:?})"),
+            Self::End(loc) => write!(f, "End({loc:?})"),
+        }
     }
 }
@@ -1,9 +1,14 @@
+use std::num::NonZeroUsize;
+
 use comemo::Tracked;
 
 use crate::diag::HintedStrResult;
 use crate::engine::Engine;
-use crate::foundations::{func, Array, Context, LocatableSelector, Value};
+use crate::foundations::{
+    cast, func, Array, Context, Dict, LocatableSelector, Resolve, Value,
+};
 use crate::introspection::Location;
+use crate::layout::{Axes, Length};
 
 /// Finds elements in the document.
 ///
@@ -96,6 +101,31 @@ use crate::introspection::Location;
 /// }
 /// ```
 ///
+/// # Querying a region { #region }
+/// With the `region` argument, you can restrict a query to elements that lie
+/// within a rectangular area of a page. The region is given as a dictionary
+/// with the keys `page`, `x`, `y`, `width`, and `height`, where the
+/// coordinates are measured from the top-left of the page, just like the
+/// result of [`location.position`]($location.position). An element is part of
+/// the region if the area it covers, which spans from the point where it
+/// starts to the farthest of its content, intersects the region. Elements that
+/// span multiple pages are found on each of these pages.
+///
+/// ```example
+/// #set page(height: 160pt)
+/// #figure(rect[A], caption: [Top])
+/// #v(1fr)
+/// #figure(rect[B], caption: [Bottom])
+///
+/// #context {
+///   let figures = query(
+///     figure,
+///     region: (page: 1, x: 0pt, y: 0pt, width: 200pt, height: 60pt),
+///   )
+///   [Near the top: #figures.map(it => it.caption.body).join()]
+/// }
+/// ```
+///
 /// # Command line queries
 /// You can also perform queries from the command line with the `typst query`
 /// command. This command executes an arbitrary query on the document and
@@ -153,11 +183,49 @@ pub fn query(
     /// Typst 0.10 and lower and shouldn't be used anymore.
     #[default]
     location: Option<Location>,
+    /// A rectangular area of a page to which the query is restricted. See
+    /// [querying a region](#region) for details.
+    #[named]
+    region: Option<QueryRegion>,
 ) -> HintedStrResult<Array> {
     if location.is_none() {
         context.introspect()?;
     }
 
-    let vec = engine.introspector.query(&target.0);
+    let mut vec = engine.introspector.query(&target.0);
+    if let Some(QueryRegion { page, point, size }) = region {
+        let styles = context.styles()?;
+        let point = point.resolve(styles).to_point();
+        let size = size.resolve(styles);
+        vec.retain(|elem| {
+            elem.location()
+                .is_some_and(|loc| engine.introspector.is_within(loc, page, point, size))
+        });
+    }
+
     Ok(vec.into_iter().map(Value::Content).collect())
 }
+
+/// A rectangular area on a page.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct QueryRegion {
+    /// The page the area is on.
+    pub page: NonZeroUsize,
+    /// The top-left corner of the area.
+    pub point: Axes<Length>,
+    /// The size of the area.
+    pub size: Axes<Length>,
+}
+
+cast! {
+    QueryRegion,
+    mut dict: Dict => {
+        let page = dict.take("page")?.cast()?;
+        let x: Length = dict.take("x")?.cast()?;
+        let y: Length = dict.take("y")?.cast()?;
+        let width: Length = dict.take("width")?.cast()?;
+        let height: Length = dict.take("height")?.cast()?;
+        dict.finish(&["page", "x", "y", "width", "height"])?;
+        Self { page, point: Axes::new(x, y), size: Axes::new(width, height) }
+    },
+}
@@ -183,6 +183,20 @@ use crate::World;
 /// values or functions that compute the new value from the previous value.
 /// Sometimes, it cannot be helped, but in those cases it is up to you to ensure
 /// that the result converges.
+///
+/// # Strict states { #strict }
+/// If a state is created with `{strict: true}`, it has no initial value.
+/// Instead, reading it at a position before its first update is an error
+/// rather than silently yielding the initial value. This is useful to catch
+/// ordering problems, for instance when a state is only updated conditionally
+/// in a show rule and read elsewhere.
+///
+/// ```example
+/// #let total = state("total", strict: true)
+/// #total.update(3)
+/// #total.update(x => x + 4)
+/// Total: #context total.get()
+/// ```
 #[ty(scope)]
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub struct State {
@@ -190,18 +204,37 @@ pub struct State {
     key: Str,
     /// The initial value of the state.
     init: Value,
+    /// Whether reading the state before its first update is an error.
+    strict: bool,
 }
 
 impl State {
     /// Create a new state identified by a key.
     pub fn new(key: Str, init: Value) -> State {
-        Self { key, init }
+        Self { key, init, strict: false }
     }
 
     /// Get the value of the state at the given location.
     pub fn at_loc(&self, engine: &mut Engine, loc: Location) -> SourceResult<Value> {
+        self.at_loc_spanned(engine, loc, Span::detached())
+    }
+
+    /// Get the value of the state at the given location, reporting a read of
+    /// a strict state before its first update at the given span.
+    pub fn at_loc_spanned(
+        &self,
+        engine: &mut Engine,
+        loc: Location,
+        span: Span,
+    ) -> SourceResult<Value> {
         let sequence = self.sequence(engine)?;
         let offset = engine.introspector.query_count_before(&self.selector(), loc);
+        if self.strict && offset == 0 {
+            bail!(
+                span, "state {} was read before it was updated", self.key.repr();
+                hint: "strict states have no initial value"
+            );
+        }
         Ok(sequence[offset].clone())
     }
 
@@ -269,8 +302,14 @@ impl State {
         /// The initial value of the state.
         #[default]
         init: Value,
+        /// Whether reading the state before its first update is an error.
+        /// Strict states have no initial value, so `init` is ignored. See
+        /// [strict states]($state/#strict) for details.
+        #[named]
+        #[default(false)]
+        strict: bool,
     ) -> State {
-        Self::new(key, init)
+        Self { key, init, strict }
     }
 
     /// Retrieves the value of the state at the current location.
@@ -287,7 +326,7 @@ impl State {
         span: Span,
     ) -> SourceResult<Value> {
         let loc = context.location().at(span)?;
-        self.at_loc(engine, loc)
+        self.at_loc_spanned(engine, loc, span)
     }
 
     /// Retrieves the value of the state at the given selector's unique match.
@@ -312,7 +351,7 @@ impl State {
         selector: LocatableSelector,
     ) -> SourceResult<Value> {
         let loc = selector.resolve_unique(engine.introspector, context).at(span)?;
-        self.at_loc(engine, loc)
+        self.at_loc_spanned(engine, loc, span)
     }
 
     /// Retrieves the value of the state at the end of the document.
@@ -335,6 +374,9 @@ impl State {
         }
 
         let sequence = self.sequence(engine)?;
+        if self.strict && sequence.len() == 1 {
+            bail!(span, "state {} is never updated", self.key.repr());
+        }
         Ok(sequence.last().unwrap().clone())
     }
 
@@ -379,7 +421,11 @@ impl State {
 
 impl Repr for State {
     fn repr(&self) -> EcoString {
-        eco_format!("state({}, {})", self.key.repr(), self.init.repr())
+        if self.strict {
+            eco_format!("state({}, strict: true)", self.key.repr())
+        } else {
+            eco_format!("state({}, {})", self.key.repr(), self.init.repr())
+        }
     }
 }
 
@@ -444,7 +490,7 @@ impl Show for Packed<StateDisplayElem> {
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         let location = self.location().unwrap();
         let context = Context::new(Some(location), Some(styles));
-        let value = self.state().at_loc(engine, location)?;
+        let value = self.state().at_loc_spanned(engine, location, self.span())?;
         Ok(match self.func() {
             Some(func) => func.call(engine, context.track(), [value])?.display(),
             None => value.display(),
@@ -66,6 +66,9 @@ impl Show for Packed<AlignElem> {
 /// - `top`: Aligns at the top.
 /// - `horizon`: Aligns in the middle, vertically.
 /// - `bottom`: Align at the bottom.
+/// - `baseline`: Aligns the first baselines of neighbouring
+///   [table cells]($table.cell) with each other. Everywhere else, this aligns
+///   at the top.
 ///
 /// These values are available globally and also in the alignment type's scope,
 /// so you can write either of the following two:
@@ -138,10 +141,11 @@ impl Alignment {
     pub const TOP: Self = Alignment::V(VAlignment::Top);
     pub const HORIZON: Self = Alignment::V(VAlignment::Horizon);
     pub const BOTTOM: Self = Alignment::V(VAlignment::Bottom);
+    pub const BASELINE: Self = Alignment::V(VAlignment::Baseline);
 
     /// The axis this alignment belongs to.
     /// - `{"horizontal"}` for `start`, `left`, `center`, `right`, and `end`
-    /// - `{"vertical"}` for `top`, `horizon`, and `bottom`
+    /// - `{"vertical"}` for `top`, `horizon`, `bottom`, and `baseline`
     /// - `{none}` for 2-dimensional alignments
     ///
     /// ```example
@@ -405,6 +409,9 @@ pub enum VAlignment {
     Top,
     Horizon,
     Bottom,
+    /// Aligns the first baselines of cells in a grid row. Behaves like `Top`
+    /// everywhere else.
+    Baseline,
 }
 
 impl VAlignment {
@@ -414,6 +421,9 @@ impl VAlignment {
             Self::Top => Self::Bottom,
             Self::Horizon => Self::Horizon,
             Self::Bottom => Self::Top,
+            // Like the horizon, the baseline stays where it is when the
+            // vertical direction is flipped.
+            Self::Baseline => Self::Baseline,
         }
     }
 
@@ -421,7 +431,7 @@ impl VAlignment {
     /// extent.
     pub fn position(self, extent: Abs) -> Abs {
         match self {
-            Self::Top => Abs::zero(),
+            Self::Top | Self::Baseline => Abs::zero(),
             Self::Horizon => extent / 2.0,
             Self::Bottom => extent,
         }
@@ -432,7 +442,7 @@ impl FixAlignment for VAlignment {
     fn fix(self, _: Dir) -> FixedAlignment {
         // The vertical alignment does not depend on text direction.
         match self {
-            Self::Top => FixedAlignment::Start,
+            Self::Top | Self::Baseline => FixedAlignment::Start,
             Self::Horizon => FixedAlignment::Center,
             Self::Bottom => FixedAlignment::End,
         }
@@ -445,6 +455,7 @@ impl Repr for VAlignment {
             Self::Top => "top".into(),
             Self::Horizon => "horizon".into(),
             Self::Bottom => "bottom".into(),
+            Self::Baseline => "baseline".into(),
         }
     }
 }
@@ -477,7 +488,10 @@ impl TryFrom<Alignment> for VAlignment {
     fn try_from(value: Alignment) -> StrResult<Self> {
         match value {
             Alignment::V(v) => Ok(v),
-            v => bail!("expected `top`, `horizon`, or `bottom`, found {}", v.repr()),
+            v => bail!(
+                "expected `top`, `horizon`, `bottom`, or `baseline`, found {}",
+                v.repr()
+            ),
         }
     }
 }
@@ -1,15 +1,23 @@
 use std::num::NonZeroUsize;
 
+use comemo::Tracked;
+
 use crate::diag::SourceResult;
 use crate::engine::Engine;
-use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
-use crate::introspection::Locator;
+use crate::foundations::{
+    elem, Content, NativeElement, Packed, Resolve, SequenceElem, Show, StyleChain,
+    StyledElem, Styles,
+};
+use crate::introspection::{
+    Introspector, Locatable, Location, Locator, SplitLocator, Tag, TagElem,
+};
 use crate::layout::{
-    Abs, Axes, BlockElem, Dir, Fragment, Frame, Length, Point, Ratio, Regions, Rel, Size,
+    Abs, Axes, BlockElem, Dir, FixedAlignment, Fragment, Frame, FrameItem, GroupItem,
+    Length, PlaceElem, PlacementScope, Point, Ratio, Regions, Rel, Size,
 };
 use crate::realize::{Behave, Behaviour};
 use crate::text::TextElem;
-use crate::utils::Numeric;
+use crate::utils::{hash128, Numeric};
 
 /// Separates a region into multiple equally sized columns.
 ///
@@ -42,7 +50,7 @@ use crate::utils::Numeric;
 /// increasingly been used to solve a
 /// variety of problems.
 /// ```
-#[elem(Show)]
+#[elem(Locatable, Show)]
 pub struct ColumnsElem {
     /// The number of columns.
     #[positional]
@@ -54,6 +62,23 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to equalize the heights of the columns.
+    ///
+    /// If the content fits into the available space, it is distributed such
+    /// that all columns are about equally tall instead of filling the first
+    /// ones completely. Lines are never split across columns. Content that
+    /// doesn't fit into one region is not balanced.
+    ///
+    /// ```example
+    /// #columns(2, balance: true)[
+    ///   #lorem(20)
+    /// ]
+    ///
+    /// Right below.
+    /// ```
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
@@ -85,64 +110,310 @@ fn layout_columns(
         return body.layout(engine, locator, styles, regions);
     }
 
+    // Floats that span all columns are laid out separately at the top or
+    // bottom of the region in which they occur.
+    let mut locator = locator.split();
+    let mut floats = vec![];
+    let marked = extract_parent_floats(
+        body,
+        styles,
+        &Styles::new(),
+        &mut locator,
+        engine.introspector,
+        &mut floats,
+    );
+    let body = if floats.is_empty() { body } else { &marked };
+
+    let mut placed = vec![];
+    for (place, local, _) in &floats {
+        let styles = styles.chain(local);
+        let clearance = place.clearance(styles);
+        let alignment = place.alignment(styles);
+        let delta = Axes::new(place.dx(styles), place.dy(styles)).resolve(styles);
+        let x_align = alignment.map_or(FixedAlignment::Center, |align| {
+            align.x().unwrap_or_default().resolve(styles)
+        });
+        let y_align = alignment.map_or(FixedAlignment::Start, |align| {
+            align.y().unwrap_or_default().resolve(styles)
+        });
+        let mut frame = place
+            .layout(engine, locator.next(&place.span()), styles, regions.base())?
+            .into_frame();
+        frame.post_process(styles);
+        frame.size_mut().y += clearance;
+        let bottom = y_align == FixedAlignment::End;
+        if bottom {
+            frame.translate(Point::with_y(clearance));
+        }
+        placed.push((frame, x_align, delta, bottom));
+    }
+
     // Determine the width of the gutter and each column.
     let columns = elem.count(styles).get();
     let gutter = elem.gutter(styles).relative_to(regions.base().x);
     let width = (regions.size.x - gutter * (columns - 1) as f64) / columns as f64;
+    let body_locator = locator.next(&());
 
-    let backlog: Vec<_> = std::iter::once(&regions.size.y)
-        .chain(regions.backlog)
-        .flat_map(|&height| std::iter::repeat(height).take(columns))
-        .skip(1)
+    // Find out in which region each float occurs. This is where its marker
+    // ended up in the previous layout iteration, relative to the first region,
+    // where the columns themselves are located.
+    let first_page = elem
+        .location()
+        .and_then(|loc| engine.introspector.position(loc))
+        .map(|pos| pos.page.get());
+    let assignment: Vec<usize> = floats
+        .iter()
+        .zip(&placed)
+        .map(|((.., loc), (frame, ..))| {
+            // A float that takes up the whole region would push its own marker
+            // into the next one, so it stays in the first region.
+            if frame.height() >= regions.size.y {
+                return 0;
+            }
+            let page = engine.introspector.position(*loc).map(|pos| pos.page.get());
+            match (first_page, page) {
+                (Some(first_page), Some(page)) => page.saturating_sub(first_page),
+                _ => 0,
+            }
+        })
         .collect();
 
+    // The columns of each region make room for the region's floats.
+    let reserved = float_heights(&placed, &assignment);
+    let heights = column_heights(regions, columns, &reserved);
+    let mut backlog = &heights[1..];
+    let mut expand = regions.expand.y;
+
+    // When balancing, all columns get the same, smallest possible height.
+    let balanced;
+    let mut first = heights[0];
+    if elem.balance(styles) && columns > 1 {
+        if let Some(height) = balanced_height(
+            engine,
+            &body_locator,
+            body,
+            styles,
+            width,
+            heights[0],
+            columns,
+        )? {
+            balanced = vec![height; columns - 1];
+            backlog = &balanced;
+            first = height;
+            expand = false;
+        }
+    }
+
     // Create the pod regions.
     let pod = Regions {
-        size: Size::new(width, regions.size.y),
+        size: Size::new(width, first),
         full: regions.full,
-        backlog: &backlog,
+        backlog,
         last: regions.last,
-        expand: Axes::new(true, regions.expand.y),
+        expand: Axes::new(true, expand),
         root: regions.root,
     };
 
     // Layout the children.
-    let mut frames = body.layout(engine, locator, styles, pod)?.into_iter();
+    let mut frames = body.layout(engine, body_locator, styles, pod)?.into_iter();
     let mut finished = vec![];
 
     let dir = TextElem::dir_in(styles);
-    let total_regions = (frames.len() as f32 / columns as f32).ceil() as usize;
+    let total_regions = (frames.len() as f32 / columns as f32)
+        .ceil()
+        .max(assignment.iter().map(|&i| i + 1).max().unwrap_or(0) as f32)
+        as usize;
 
     // Stitch together the columns for each region.
-    for region in regions.iter().take(total_regions) {
-        // The height should be the parent height if we should expand.
-        // Otherwise its the maximum column height for the frame. In that
-        // case, the frame is first created with zero height and then
-        // resized.
-        let height = if regions.expand.y { region.y } else { Abs::zero() };
+    for (i, region) in regions.iter().take(total_regions).enumerate() {
+        let (top_height, bottom_height) = reserved.get(i).copied().unwrap_or_default();
+        let extra = top_height + bottom_height;
+
+        // The height should be the parent height if we should expand or if
+        // floats sit at the bottom of the region. Otherwise its the maximum
+        // column height for the frame. In that case, the frame is first
+        // created with just the floats' height and then resized.
+        let fill = !bottom_height.is_zero() && region.y.is_finite();
+        let height = if regions.expand.y || fill { region.y } else { extra };
         let mut output = Frame::hard(Size::new(regions.size.x, height));
         let mut cursor = Abs::zero();
 
         for _ in 0..columns {
             let Some(frame) = frames.next() else { break };
             if !regions.expand.y {
-                output.size_mut().y.set_max(frame.height());
+                output.size_mut().y.set_max(frame.height() + extra);
             }
 
             let width = frame.width();
             let x =
                 if dir == Dir::LTR { cursor } else { regions.size.x - cursor - width };
 
-            output.push_frame(Point::with_x(x), frame);
+            let group = GroupItem { column: true, ..GroupItem::new(frame) };
+            output.push(Point::new(x, top_height), FrameItem::Group(group));
             cursor += width + gutter;
         }
 
+        let size = output.size();
+        let mut top = Abs::zero();
+        let mut bottom = size.y - bottom_height;
+        for ((frame, x_align, delta, at_bottom), _) in
+            placed.iter().zip(&assignment).filter(|(_, region)| **region == i)
+        {
+            let y = if *at_bottom { &mut bottom } else { &mut top };
+            let pos = Point::new(x_align.position(size.x - frame.width()), *y)
+                + delta.zip_map(size, Rel::relative_to).to_point();
+            *y += frame.height();
+            output.push_frame(pos, frame.clone());
+        }
+
         finished.push(output);
     }
 
     Ok(Fragment::frames(finished))
 }
 
+/// Determines the smallest column height at which the body still fits into the
+/// given number of columns without exceeding the available height.
+///
+/// Returns `None` if the body doesn't fit at all or no fitting height was
+/// found within a bounded number of attempts.
+fn balanced_height(
+    engine: &mut Engine,
+    locator: &Locator,
+    body: &Content,
+    styles: StyleChain,
+    width: Abs,
+    available: Abs,
+    columns: usize,
+) -> SourceResult<Option<Abs>> {
+    // The number of layouts at a guessed height after which the search
+    // settles for the best height found so far.
+    const MAX_ATTEMPTS: usize = 5;
+
+    // Returns the height of the tallest column if the body fits into columns
+    // of the given height.
+    let fit = |engine: &mut Engine, height: Abs| -> SourceResult<Option<Abs>> {
+        let pod = Regions::repeat(Size::new(width, height), Axes::new(true, false));
+        let fragment = body.layout(engine, locator.relayout(), styles, pod)?;
+        Ok((fragment.len() <= columns).then(|| {
+            fragment
+                .iter()
+                .fold(Abs::zero(), |max, frame| max.max(frame.height()))
+        }))
+    };
+
+    // The columns can't be shorter than an even share of the total height and
+    // never need to be taller than the whole body.
+    let pod = Regions::one(Size::new(width, Abs::inf()), Axes::new(true, false));
+    let total = body
+        .layout(engine, locator.relayout(), styles, pod)?
+        .into_frame()
+        .height();
+    if total > available * columns as f64 {
+        return Ok(None);
+    }
+
+    let mut low = total / columns as f64;
+    let mut high = available.min(total);
+    let mut best = None;
+
+    // An even share often fits already. Otherwise, narrow down the height.
+    // Each attempt that fits shrinks the upper bound to the height the columns
+    // actually use.
+    let mut guess = low;
+    for _ in 0..MAX_ATTEMPTS {
+        match fit(engine, guess)? {
+            Some(used) => {
+                high = used;
+                best = Some(used);
+            }
+            None => low = guess,
+        }
+        if high - low <= Abs::pt(0.5) {
+            break;
+        }
+        guess = (low + high) / 2.0;
+    }
+
+    Ok(best)
+}
+
+/// Sums up the heights of the top and bottom floats in each region.
+fn float_heights(
+    placed: &[(Frame, FixedAlignment, Axes<Rel<Abs>>, bool)],
+    assignment: &[usize],
+) -> Vec<(Abs, Abs)> {
+    let mut heights = vec![];
+    for ((frame, .., bottom), &region) in placed.iter().zip(assignment) {
+        if heights.len() <= region {
+            heights.resize(region + 1, (Abs::zero(), Abs::zero()));
+        }
+        let (top_height, bottom_height) = &mut heights[region];
+        if *bottom {
+            *bottom_height += frame.height();
+        } else {
+            *top_height += frame.height();
+        }
+    }
+    heights
+}
+
+/// Determines the height of each column, leaving room for the floats of each
+/// region.
+fn column_heights(regions: Regions, columns: usize, reserved: &[(Abs, Abs)]) -> Vec<Abs> {
+    std::iter::once(&regions.size.y)
+        .chain(regions.backlog)
+        .enumerate()
+        .flat_map(|(i, &height)| {
+            let (top, bottom) = reserved.get(i).copied().unwrap_or_default();
+            std::iter::repeat((height - top - bottom).max(Abs::zero())).take(columns)
+        })
+        .collect()
+}
+
+/// Replaces floating elements with parent scope in the content with invisible
+/// markers, collecting them together with the styles they are subject to.
+///
+/// The markers are used to find out in which region each float occurs.
+fn extract_parent_floats(
+    content: &Content,
+    styles: StyleChain,
+    local: &Styles,
+    locator: &mut SplitLocator,
+    introspector: Tracked<Introspector>,
+    floats: &mut Vec<(Packed<PlaceElem>, Styles, Location)>,
+) -> Content {
+    if let Some(sequence) = content.to_packed::<SequenceElem>() {
+        Content::sequence(sequence.children().iter().map(|child| {
+            extract_parent_floats(child, styles, local, locator, introspector, floats)
+        }))
+    } else if let Some(styled) = content.to_packed::<StyledElem>() {
+        let mut inner = styled.styles().clone();
+        inner.apply(local.clone());
+        extract_parent_floats(
+            styled.child(),
+            styles,
+            &inner,
+            locator,
+            introspector,
+            floats,
+        )
+        .styled_with_map(styled.styles().clone())
+    } else if let Some(place) = content.to_packed::<PlaceElem>() {
+        let chain = styles.chain(local);
+        if place.float(chain) && place.scope(chain) == PlacementScope::Parent {
+            let key = hash128(content);
+            let loc = locator.next_location(introspector, key);
+            floats.push((place.clone(), local.clone(), loc));
+            let start = TagElem::packed(Tag::new(content.clone().located(loc), key));
+            return start + TagElem::packed(Tag::End(loc));
+        }
+        content.clone()
+    } else {
+        content.clone()
+    }
+}
+
 /// Forces a column break.
 ///
 /// The function will behave like a [page break]($pagebreak) when used in a
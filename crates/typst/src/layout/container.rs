@@ -9,11 +9,11 @@ use crate::foundations::{
 };
 use crate::introspection::Locator;
 use crate::layout::{
-    Abs, Axes, Corners, Em, Fr, Fragment, Frame, FrameKind, Length, Region, Regions, Rel,
-    Sides, Size, Spacing,
+    Abs, Alignment, Axes, Corners, Em, Fr, Fragment, Frame, FrameKind, Length, Radius,
+    Region, Regions, Rel, Sides, Size, Spacing,
 };
 use crate::utils::Numeric;
-use crate::visualize::{clip_rect, Paint, Stroke};
+use crate::visualize::{clip_rect, Paint, Shadow, Stroke};
 
 /// An inline-level container that sizes content.
 ///
@@ -72,7 +72,7 @@ pub struct BoxElem {
     /// [rectangle's documentation]($rect.radius) for more details.
     #[resolve]
     #[fold]
-    pub radius: Corners<Option<Rel<Length>>>,
+    pub radius: Corners<Option<Radius<Rel<Length>>>>,
 
     /// How much to pad the box's content.
     ///
@@ -105,6 +105,24 @@ pub struct BoxElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// A drop shadow behind the box.
+    ///
+    /// A shadow is given as a dictionary with the keys `offset` (a length or
+    /// an array of two lengths), `blur` (a length), and `color`, all of which
+    /// are optional. The shadow follows the box's `radius` and `outset` and
+    /// is not affected by `clip`.
+    ///
+    /// ```example
+    /// #box(
+    ///   fill: white,
+    ///   inset: 6pt,
+    ///   radius: 4pt,
+    ///   shadow: (offset: 2pt, blur: 4pt),
+    /// )[Floating]
+    /// ```
+    #[resolve]
+    pub shadow: Option<Shadow>,
+
     /// Whether to clip the content inside the box.
     #[default(false)]
     pub clip: bool,
@@ -186,6 +204,11 @@ impl Packed<BoxElem> {
             frame.fill_and_stroke(fill, &stroke, &outset, &radius, self.span());
         }
 
+        // Add the shadow behind the fill.
+        if let Some(shadow) = self.shadow(styles) {
+            frame.shadow(&shadow, &outset, &radius, self.span());
+        }
+
         Ok(frame)
     }
 
@@ -371,7 +394,7 @@ pub struct BlockElem {
     /// [rectangle's documentation]($rect.radius) for more details.
     #[resolve]
     #[fold]
-    pub radius: Corners<Option<Rel<Length>>>,
+    pub radius: Corners<Option<Radius<Rel<Length>>>>,
 
     /// How much to pad the block's content. See the
     /// [box's documentation]($box.inset) for more details.
@@ -385,6 +408,20 @@ pub struct BlockElem {
     #[fold]
     pub outset: Sides<Option<Rel<Length>>>,
 
+    /// A drop shadow behind the block. See the
+    /// [box's documentation]($box.shadow) for more details.
+    ///
+    /// ```example
+    /// #block(
+    ///   fill: white,
+    ///   inset: 8pt,
+    ///   radius: 4pt,
+    ///   shadow: (offset: (2pt, 3pt), blur: 6pt, color: blue.transparentize(60%)),
+    /// )[A card with a soft shadow.]
+    /// ```
+    #[resolve]
+    pub shadow: Option<Shadow>,
+
     /// The spacing around the block. When `{auto}`, inherits the paragraph
     /// [`spacing`]($par.spacing).
     ///
@@ -427,6 +464,24 @@ pub struct BlockElem {
     #[default(false)]
     pub clip: bool,
 
+    /// How to align the content inside of the block.
+    ///
+    /// This is mostly useful for blocks with a fixed height, whose content
+    /// can be centered or aligned to the bottom. When `{auto}`, the content is
+    /// aligned according to the surrounding [alignment]($align). Content that
+    /// is taller than the block overflows it (unless it is
+    /// [clipped]($block.clip)).
+    ///
+    /// ```example
+    /// #block(
+    ///   height: 2cm,
+    ///   width: 100%,
+    ///   fill: aqua,
+    ///   align-content: center + horizon,
+    /// )[Centered]
+    /// ```
+    pub align_content: Smart<Alignment>,
+
     /// Whether this block must stick to the following one.
     ///
     /// Use this to prevent page breaks between e.g. a heading and its body.
@@ -441,6 +496,12 @@ pub struct BlockElem {
     #[parse(None)]
     pub rootable: bool,
 
+    /// The element whose content this block holds in the document's logical
+    /// structure, if any.
+    #[internal]
+    #[parse(None)]
+    pub structure: Option<Content>,
+
     /// The contents of the block.
     #[positional]
     #[borrowed]
@@ -530,6 +591,15 @@ impl Packed<BlockElem> {
 
             // If we have content as our body, just layout it.
             Some(BlockChild::Content(body)) => {
+                let aligned;
+                let body = match self.align_content(styles) {
+                    Smart::Custom(align) => {
+                        aligned = body.clone().aligned(align);
+                        &aligned
+                    }
+                    Smart::Auto => body,
+                };
+
                 let mut fragment =
                     body.layout(engine, locator.relayout(), styles, pod)?;
 
@@ -592,6 +662,7 @@ impl Packed<BlockElem> {
         // Fetch/compute these outside of the loop.
         let clip = self.clip(styles);
         let has_fill_or_stroke = fill.is_some() || stroke.iter().any(Option::is_some);
+        let shadow = self.shadow(styles);
         let has_inset = !inset.is_zero();
         let is_explicit = matches!(body, None | Some(BlockChild::Content(_)));
 
@@ -599,7 +670,7 @@ impl Packed<BlockElem> {
         // one follows.
         let mut skip_first = false;
         if let [first, rest @ ..] = fragment.as_slice() {
-            skip_first = has_fill_or_stroke
+            skip_first = (has_fill_or_stroke || shadow.is_some())
                 && first.is_empty()
                 && rest.iter().any(|frame| !frame.is_empty());
         }
@@ -636,6 +707,18 @@ impl Packed<BlockElem> {
                     self.span(),
                 );
             }
+
+            // Add the shadow behind the fill.
+            if let Some(shadow) = shadow.as_ref().filter(|_| i > 0 || !skip_first) {
+                frame.shadow(shadow, &outset, &radius, self.span());
+            }
+        }
+
+        // Delimit the content of the block's structural element.
+        if let Some(elem) = self.structure(styles) {
+            for frame in fragment.iter_mut() {
+                frame.structure(elem.clone());
+            }
         }
 
         Ok(fragment)
@@ -781,9 +864,10 @@ cast! {
 }
 
 /// Defines how to size something along an axis.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Sizing {
     /// A track that fits its item's contents.
+    #[default]
     Auto,
     /// A size specified in absolute terms and relative to the parent's size.
     Rel(Rel),
@@ -804,12 +888,6 @@ impl Sizing {
     }
 }
 
-impl Default for Sizing {
-    fn default() -> Self {
-        Self::Auto
-    }
-}
-
 impl From<Smart<Rel>> for Sizing {
     fn from(smart: Smart<Rel>) -> Self {
         match smart {
@@ -876,6 +954,7 @@ fn distribute<'a>(
 /// Manual closure implementations for layout callbacks.
 ///
 /// Normal closures are not `Hash`, so we can't use them.
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
 mod callbacks {
     use super::*;
 
@@ -915,6 +994,11 @@ mod callbacks {
                     }
                 }
 
+                /// The element the callback lays out.
+                pub fn captured(&self) -> &Content {
+                    &self.captured
+                }
+
                 pub fn call(&self, $($param: $param_ty),*) -> $ret {
                     (self.f)(&self.captured, $($param),*)
                 }
@@ -5,6 +5,7 @@
 //! inline-level layoutable elements.
 
 use std::fmt::{self, Debug, Formatter};
+use std::num::NonZeroUsize;
 
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
@@ -14,10 +15,15 @@ use crate::foundations::{
 use crate::introspection::{Locator, SplitLocator, Tag, TagElem};
 use crate::layout::{
     Abs, AlignElem, Axes, BlockElem, ColbreakElem, FixedAlignment, FlushElem, Fr,
-    Fragment, Frame, FrameItem, PlaceElem, Point, Regions, Rel, Size, Spacing, VElem,
+    Fragment, Frame, FrameItem, PlaceElem, PlacementPreference, Point, Ratio, Regions,
+    Rel, Size, Spacing, VElem,
+};
+use crate::model::{
+    FootnoteElem, FootnoteEntry, FootnoteSeparator, HeadingElem, IndentScope,
+    LeadingMode, ParElem,
 };
-use crate::model::{FootnoteElem, FootnoteEntry, ParElem};
 use crate::realize::StyleVec;
+use crate::text::TextElem;
 use crate::utils::Numeric;
 
 /// Arranges spacing, paragraphs and block-level elements into a flow.
@@ -62,12 +68,21 @@ impl Packed<FlowElem> {
         // Thanks to the code below, the expansion will be passed all the way
         // through the block & pad and reach the innermost flow, so that things
         // are properly bottom-aligned.
-        let mut alone = false;
-        if let [child] = self.children().elements() {
-            alone = child.is::<BlockElem>();
-        }
+        //
+        // Tags don't count as children here, so that the block of a locatable
+        // element, like the columns of a page, can take the full space, too.
+        let mut children = self
+            .children()
+            .elements()
+            .iter()
+            .filter(|child| !child.is::<TagElem>());
+        let alone = match (children.next(), children.next()) {
+            (Some(child), None) => child.is::<BlockElem>(),
+            _ => false,
+        };
 
         let mut layouter = FlowLayouter::new(locator, styles, regions, alone);
+        layouter.reserve_footnote_separator(engine)?;
         for (child, styles) in self.children().chain(&styles) {
             if let Some(elem) = child.to_packed::<TagElem>() {
                 layouter.layout_tag(elem);
@@ -121,6 +136,11 @@ struct FlowLayouter<'a> {
     ///
     /// Used for indenting paragraphs after the first in a block.
     last_was_par: bool,
+    /// Whether the last block was a heading, for paragraphs that are indented
+    /// after headings.
+    last_was_heading: bool,
+    /// Whether a heading's tag was seen, so that the next block is its body.
+    pending_heading: bool,
     /// Spacing and layouted blocks for the current region.
     items: Vec<FlowItem>,
     /// A queue of tags that will be attached to the next frame.
@@ -137,9 +157,13 @@ struct FlowLayouter<'a> {
 
 /// Cached footnote configuration.
 struct FootnoteConfig {
-    separator: Content,
+    separator: FootnoteSeparator,
+    continuation_separator: Smart<FootnoteSeparator>,
+    continuation: Option<Content>,
     clearance: Abs,
+    clearance_always: bool,
     gap: Abs,
+    max_height: Smart<Rel<Abs>>,
 }
 
 /// A prepared item in a flow layout.
@@ -170,9 +194,16 @@ enum FlowItem {
         delta: Axes<Rel<Abs>>,
         float: bool,
         clearance: Abs,
+        prefer: PlacementPreference,
     },
     /// A footnote frame (can also be the separator).
-    Footnote(Frame),
+    Footnote {
+        /// The frame itself.
+        frame: Frame,
+        /// Whether the footnote is in a float at the top of the region. The
+        /// entries of such footnotes precede the main content's entries.
+        top: bool,
+    },
 }
 
 impl FlowItem {
@@ -218,14 +249,20 @@ impl<'a> FlowLayouter<'a> {
             expand,
             initial: regions.size,
             last_was_par: false,
+            last_was_heading: false,
+            pending_heading: false,
             items: vec![],
             pending_tags: vec![],
             pending_floats: vec![],
             has_footnotes: false,
             footnote_config: FootnoteConfig {
                 separator: FootnoteEntry::separator_in(styles),
+                continuation_separator: FootnoteEntry::continuation_separator_in(styles),
+                continuation: FootnoteEntry::continuation_in(styles),
                 clearance: FootnoteEntry::clearance_in(styles),
+                clearance_always: FootnoteEntry::separator_clearance_always_in(styles),
                 gap: FootnoteEntry::gap_in(styles),
+                max_height: FootnoteEntry::max_height_in(styles),
             },
             finished: vec![],
         }
@@ -233,6 +270,22 @@ impl<'a> FlowLayouter<'a> {
 
     /// Place explicit metadata into the flow.
     fn layout_tag(&mut self, elem: &Packed<TagElem>) {
+        // An end tag belongs behind the last frame instead of sticking to the
+        // next one, so that the element's extent ends where its content does.
+        if matches!(elem.tag, Tag::End(_)) && self.pending_tags.is_empty() {
+            let last = self.items.iter_mut().rev().find(|item| {
+                matches!(item, FlowItem::Frame { .. } | FlowItem::Placed { .. })
+            });
+            if let Some(FlowItem::Frame { frame, .. }) = last {
+                let pos = Point::with_y(frame.height());
+                frame.push(pos, FrameItem::Tag(elem.tag.clone()));
+                return;
+            }
+        }
+
+        if elem.tag.elem().is_some_and(|elem| elem.is::<HeadingElem>()) {
+            self.pending_heading = true;
+        }
         self.pending_tags.push(elem.tag.clone());
     }
 
@@ -264,7 +317,11 @@ impl<'a> FlowLayouter<'a> {
     ) -> SourceResult<()> {
         let align = AlignElem::alignment_in(styles).resolve(styles);
         let leading = ParElem::leading_in(styles);
-        let consecutive = self.last_was_par;
+        let consecutive = match ParElem::first_line_indent_in(styles).scope {
+            IndentScope::NeverFirst => self.last_was_par,
+            IndentScope::Always => true,
+            IndentScope::AfterHeading => self.last_was_par || self.last_was_heading,
+        };
         let lines = par
             .layout(
                 engine,
@@ -276,9 +333,80 @@ impl<'a> FlowLayouter<'a> {
             )?
             .into_frames();
 
-        // If the first line doesn’t fit in this region, then defer any
+        // The first and last lines that must be kept together. A paragraph
+        // that is too short to be split without violating both is kept
+        // together as a whole. Explicit line counts take precedence, while
+        // automatic ones follow the widow and orphan costs: Positive costs
+        // keep two lines together unless the line that would join the first
+        // or last one is empty, zero and negative costs disable it.
+        let len = lines.len();
+        let costs = TextElem::costs_in(styles);
+        let kept = |count: Smart<NonZeroUsize>, cost: Ratio, joining: Option<&Frame>| {
+            let joins = cost.get() > 0.0 && joining.is_some_and(|line| !line.is_empty());
+            match count {
+                Smart::Custom(count) => count.get(),
+                Smart::Auto if joins => 2,
+                Smart::Auto => 1,
+            }
+        };
+        let orphans = kept(
+            ParElem::min_lines_in(styles).or(ParElem::orphans_in(styles)),
+            costs.orphan(),
+            lines.get(1),
+        );
+        let widows = kept(
+            ParElem::widows_in(styles),
+            costs.widow(),
+            len.checked_sub(2).and_then(|i| lines.get(i)),
+        );
+        let head_len = if len < orphans + widows { len } else { orphans };
+        let tail_start = len.saturating_sub(widows).max(head_len);
+
+        // In grid mode, the distance between the baselines of consecutive
+        // lines is rounded up to a multiple of the grid step.
+        let grid = match ParElem::leading_mode_in(styles) {
+            LeadingMode::Normal => None,
+            LeadingMode::Grid => Some(
+                ParElem::grid_step_in(styles)
+                    .unwrap_or_else(|| TextElem::size_in(styles) + leading),
+            ),
+        };
+
+        // Merge the lines that must be kept together into one frame each, so
+        // that they move between regions as a unit.
+        let mut frames: Vec<(Abs, Frame)> = vec![];
+        let mut prev_descent = Abs::zero();
+        for (i, frame) in lines.into_iter().enumerate() {
+            let mut gap = Abs::zero();
+            if i > 0 {
+                gap = leading;
+                if let Some(step) = grid.filter(|step| *step > Abs::zero()) {
+                    // An advance that is already a multiple of the step
+                    // stays on its row, even with a bit of float noise.
+                    let advance = prev_descent + leading + frame.baseline();
+                    let mut snapped = step * (advance / step).ceil();
+                    if (snapped - step).approx_eq(advance) {
+                        snapped -= step;
+                    }
+                    gap += snapped - advance;
+                }
+            }
+
+            prev_descent = frame.height() - frame.baseline();
+
+            match frames.last_mut() {
+                Some((_, first)) if i < head_len || i > tail_start => {
+                    let offset = first.height() + gap;
+                    first.size_mut().y = offset + frame.height();
+                    first.push_frame(Point::with_y(offset), frame);
+                }
+                _ => frames.push((gap, frame)),
+            }
+        }
+
+        // If the first lines don’t fit in this region, then defer any
         // previous sticky frame to the next region (if available)
-        if let Some(first) = lines.first() {
+        if let Some((_, first)) = frames.first() {
             while !self.regions.size.y.fits(first.height()) && !self.regions.in_last() {
                 let mut sticky = self.items.len();
                 for (i, item) in self.items.iter().enumerate().rev() {
@@ -303,9 +431,9 @@ impl<'a> FlowLayouter<'a> {
             }
         }
 
-        for (i, mut frame) in lines.into_iter().enumerate() {
+        for (i, (gap, mut frame)) in frames.into_iter().enumerate() {
             if i > 0 {
-                self.layout_item(engine, FlowItem::Absolute(leading, true))?;
+                self.layout_item(engine, FlowItem::Absolute(gap, true))?;
             }
 
             self.drain_tag(&mut frame);
@@ -316,6 +444,7 @@ impl<'a> FlowLayouter<'a> {
         }
 
         self.last_was_par = true;
+        self.last_was_heading = false;
         Ok(())
     }
 
@@ -373,7 +502,14 @@ impl<'a> FlowLayouter<'a> {
 
         self.root = is_root;
         self.regions.root = false;
+        // The tag of a heading directly after a paragraph ends up in that
+        // paragraph, so we also check the block's structure.
+        let heading = block
+            .structure(styles)
+            .as_ref()
+            .is_some_and(|elem| elem.is::<HeadingElem>());
         self.last_was_par = false;
+        self.last_was_heading = std::mem::take(&mut self.pending_heading) || heading;
 
         Ok(())
     }
@@ -387,6 +523,7 @@ impl<'a> FlowLayouter<'a> {
     ) -> SourceResult<()> {
         let float = placed.float(styles);
         let clearance = placed.clearance(styles);
+        let prefer = placed.prefer(styles);
         let alignment = placed.alignment(styles);
         let delta = Axes::new(placed.dx(styles), placed.dy(styles)).resolve(styles);
         let x_align = alignment.map_or(FixedAlignment::Center, |align| {
@@ -402,7 +539,15 @@ impl<'a> FlowLayouter<'a> {
             )?
             .into_frame();
         frame.post_process(styles);
-        let item = FlowItem::Placed { frame, x_align, y_align, delta, float, clearance };
+        let item = FlowItem::Placed {
+            frame,
+            x_align,
+            y_align,
+            delta,
+            float,
+            clearance,
+            prefer,
+        };
         self.layout_item(engine, item)
     }
 
@@ -467,8 +612,26 @@ impl<'a> FlowLayouter<'a> {
                 ref mut y_align,
                 float: true,
                 clearance,
+                prefer,
+                x_align,
                 ..
             } => {
+                // If the float prefers to stay in-flow and there is enough
+                // space left, lay it out like a normal block, keeping the
+                // clearance around it.
+                if prefer == PlacementPreference::Here
+                    && self.pending_floats.is_empty()
+                    && self.regions.size.y.fits(frame.height() + clearance)
+                {
+                    let mut frame = std::mem::take(frame);
+                    frame.size_mut().y += clearance;
+                    frame.translate(Point::with_y(clearance / 2.0));
+                    let align = Axes::new(x_align, FixedAlignment::Start);
+                    let item =
+                        FlowItem::Frame { frame, align, sticky: false, movable: true };
+                    return self.layout_item(engine, item);
+                }
+
                 // If there is a queued float in front or if the float doesn't
                 // fit, queue it for the next region.
                 if !self.pending_floats.is_empty()
@@ -503,12 +666,22 @@ impl<'a> FlowLayouter<'a> {
 
                 // Find footnotes in the frame.
                 if self.root {
+                    let top = *y_align == Smart::Custom(Some(FixedAlignment::Start));
                     let mut notes = vec![];
                     find_footnotes(&mut notes, frame);
+                    let start = self.items.len();
+                    let regions = self.finished.len();
                     self.try_handle_footnotes(engine, notes)?;
+                    if top && self.finished.len() == regions {
+                        for item in &mut self.items[start..] {
+                            if let FlowItem::Footnote { top, .. } = item {
+                                *top = true;
+                            }
+                        }
+                    }
                 }
             }
-            FlowItem::Footnote(_) => {}
+            FlowItem::Footnote { .. } => {}
         }
 
         self.items.push(item);
@@ -565,7 +738,7 @@ impl<'a> FlowLayouter<'a> {
                     }
                     _ => {}
                 },
-                FlowItem::Footnote(frame) => {
+                FlowItem::Footnote { frame, .. } => {
                     footnote_height += frame.height();
                     if !first_footnote {
                         footnote_height += self.footnote_config.gap;
@@ -585,12 +758,34 @@ impl<'a> FlowLayouter<'a> {
             size.y = self.initial.y;
         }
 
+        // Determine the order of the footnote entries. The separator always
+        // comes first, followed by the entries of floats at the top.
+        let mut footnotes: Vec<_> = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                FlowItem::Footnote { frame, top } => Some((frame.height(), *top)),
+                _ => None,
+            })
+            .enumerate()
+            .collect();
+        if let Some(entries) = footnotes.get_mut(1..) {
+            entries.sort_by_key(|&(_, (_, top))| !top);
+        }
+        let mut footnote_offsets = vec![Abs::zero(); footnotes.len()];
+        let mut footnote_offset = Abs::zero();
+        for (i, (height, _)) in footnotes {
+            footnote_offsets[i] = footnote_offset;
+            footnote_offset += height + self.footnote_config.gap;
+        }
+
         let mut output = Frame::soft(size);
         let mut ruler = FixedAlignment::Start;
         let mut float_top_offset = Abs::zero();
         let mut offset = float_top_height;
         let mut float_bottom_offset = Abs::zero();
-        let mut footnote_offset = Abs::zero();
+        let mut footnote_offsets = footnote_offsets.into_iter();
+        let mut top_footnote_tags = vec![];
 
         // Place all frames.
         for item in self.items.drain(..) {
@@ -611,7 +806,9 @@ impl<'a> FlowLayouter<'a> {
                     offset += frame.height();
                     output.push_frame(pos, frame);
                 }
-                FlowItem::Placed { frame, x_align, y_align, delta, float, .. } => {
+                FlowItem::Placed {
+                    mut frame, x_align, y_align, delta, float, ..
+                } => {
                     let x = x_align.position(size.x - frame.width());
                     let y = if float {
                         match y_align {
@@ -640,16 +837,31 @@ impl<'a> FlowLayouter<'a> {
                     let pos = Point::new(x, y)
                         + delta.zip_map(size, Rel::relative_to).to_point();
 
+                    // Footnotes in floats at the top come first in the frame,
+                    // so that they are numbered before the ones in the main
+                    // content.
+                    if float && y_align == Smart::Custom(Some(FixedAlignment::Start)) {
+                        let tags = frame.take_tags(|tag| {
+                            tag.elem().is_some_and(|elem| elem.is::<FootnoteElem>())
+                        });
+                        top_footnote_tags.extend(
+                            tags.into_iter()
+                                .map(|(p, tag)| (pos + p, FrameItem::Tag(tag))),
+                        );
+                    }
+
                     output.push_frame(pos, frame);
                 }
-                FlowItem::Footnote(frame) => {
-                    let y = size.y - footnote_height + footnote_offset;
-                    footnote_offset += frame.height() + self.footnote_config.gap;
+                FlowItem::Footnote { frame, .. } => {
+                    let offset = footnote_offsets.next().unwrap_or_default();
+                    let y = size.y - footnote_height + offset;
                     output.push_frame(Point::with_y(y), frame);
                 }
             }
         }
 
+        output.prepend_multiple(top_footnote_tags);
+
         if force && !self.pending_tags.is_empty() {
             let pos = Point::with_y(offset);
             output.push_multiple(
@@ -662,6 +874,7 @@ impl<'a> FlowLayouter<'a> {
         self.regions.next();
         self.initial = self.regions.size;
         self.has_footnotes = false;
+        self.reserve_footnote_separator(engine)?;
 
         // Try to place floats into the next region.
         for item in std::mem::take(&mut self.pending_floats) {
@@ -691,8 +904,14 @@ impl<'a> FlowLayouter<'a> {
             }
         }
 
+        // A region that only holds the footnote separator reserved at its
+        // start doesn't need to be finished.
         self.finish_region(engine, true)?;
-        while !self.items.is_empty() {
+        while self
+            .items
+            .iter()
+            .any(|item| !matches!(item, FlowItem::Footnote { .. }))
+        {
             self.finish_region(engine, true)?;
         }
 
@@ -744,26 +963,65 @@ impl FlowLayouter<'_> {
         // Process footnotes one at a time.
         let mut k = 0;
         while k < notes.len() {
-            if notes[k].is_ref() {
+            if notes[k].is_ref() || notes[k].is_endnote() || notes[k].is_sidenote() {
                 k += 1;
                 continue;
             }
 
             if !self.has_footnotes {
-                self.layout_footnote_separator(engine)?;
+                self.layout_footnote_separator(engine, false)?;
             }
 
             self.regions.size.y -= self.footnote_config.gap;
-            let frames = FootnoteEntry::new(notes[k].clone())
-                .pack()
-                .layout(
-                    engine,
-                    Locator::synthesize(notes[k].location().unwrap()),
-                    self.styles,
-                    self.regions.with_root(false),
-                )?
+
+            // Restrict the entry to the space left in the footnote area, if its
+            // height is limited. Entries that exceed it break into the next
+            // region.
+            let mut regions = self.regions.with_root(false);
+            if let Smart::Custom(max) = self.footnote_config.max_height {
+                let max = max.relative_to(self.initial.y);
+                regions
+                    .size
+                    .y
+                    .set_min((max - self.footnote_height()).max(Abs::zero()));
+            }
+
+            let entry = FootnoteEntry::new(notes[k].clone()).pack();
+            let locator = Locator::synthesize(notes[k].location().unwrap());
+            let mut frames = entry
+                .layout(engine, locator.relayout(), self.styles, regions)?
                 .into_frames();
 
+            // If the entry breaks across regions, mark each broken fragment with
+            // the continuation indicator. To make room for it, the entry has to
+            // be laid out again with a bit less space.
+            if frames.len() > 1 {
+                if let Some(continuation) = &self.footnote_config.continuation {
+                    let pod = Regions::one(self.regions.base(), Axes::splat(false));
+                    let marker_locator = self.locator.next(&notes[k].location());
+                    let marker = continuation
+                        .layout(engine, marker_locator, self.styles, pod)?
+                        .into_frame();
+                    let height = marker.height();
+                    let mut backlog = vec![];
+                    let regions =
+                        regions.map(&mut backlog, |size| size - Size::with_y(height));
+                    frames = entry
+                        .layout(engine, locator, self.styles, regions)?
+                        .into_frames();
+                    let last = frames.len() - 1;
+                    for frame in &mut frames[..last] {
+                        if frame.is_empty() {
+                            continue;
+                        }
+                        let pos =
+                            Point::new(frame.width() - marker.width(), frame.height());
+                        frame.size_mut().y += height;
+                        frame.push_frame(pos, marker.clone());
+                    }
+                }
+            }
+
             // If the entries didn't fit, abort (to keep footnote and entry
             // together).
             if !force
@@ -783,11 +1041,11 @@ impl FlowLayouter<'_> {
                 find_footnotes(notes, &frame);
                 if i > 0 {
                     self.finish_region(engine, false)?;
-                    self.layout_footnote_separator(engine)?;
+                    self.layout_footnote_separator(engine, true)?;
                     self.regions.size.y -= self.footnote_config.gap;
                 }
                 self.regions.size.y -= frame.height();
-                self.items.push(FlowItem::Footnote(frame));
+                self.items.push(FlowItem::Footnote { frame, top: false });
             }
 
             k += 1;
@@ -803,14 +1061,65 @@ impl FlowLayouter<'_> {
         Ok(true)
     }
 
+    /// The height of the footnote area in the current region, including the
+    /// separator.
+    fn footnote_height(&self) -> Abs {
+        let mut height = Abs::zero();
+        let mut first = true;
+        for item in &self.items {
+            if let FlowItem::Footnote { frame, .. } = item {
+                height += frame.height();
+                if !first {
+                    height += self.footnote_config.gap;
+                }
+                first = false;
+            }
+        }
+        height
+    }
+
+    /// Lays out the footnote separator at the start of a region if the
+    /// footnote entries are configured to always reserve space for it.
+    fn reserve_footnote_separator(&mut self, engine: &mut Engine) -> SourceResult<()> {
+        if self.root && self.footnote_config.clearance_always && !self.has_footnotes {
+            self.layout_footnote_separator(engine, false)?;
+        }
+        Ok(())
+    }
+
     /// Layout and save the footnote separator, typically a line.
-    fn layout_footnote_separator(&mut self, engine: &mut Engine) -> SourceResult<()> {
+    ///
+    /// If `continued` is true, the separator sits above the continuation of an
+    /// entry from the previous region.
+    fn layout_footnote_separator(
+        &mut self,
+        engine: &mut Engine,
+        continued: bool,
+    ) -> SourceResult<()> {
         let expand = Axes::new(self.regions.expand.x, false);
         let pod = Regions::one(self.regions.base(), expand);
-        let separator = &self.footnote_config.separator;
+        let separator = match &self.footnote_config.continuation_separator {
+            Smart::Custom(separator) if continued => separator,
+            _ => &self.footnote_config.separator,
+        };
+
+        // A separator reserved at the start of the region gives way to the
+        // one above the continued entry.
+        if continued && self.has_footnotes {
+            let reserved = self
+                .items
+                .iter()
+                .position(|item| matches!(item, FlowItem::Footnote { .. }));
+            if let Some(FlowItem::Footnote { frame, .. }) =
+                reserved.map(|i| self.items.remove(i))
+            {
+                self.regions.size.y += frame.height();
+            }
+        }
 
         // FIXME: Shouldn't use `root()` here.
         let mut frame = separator
+            .resolve(engine, self.styles, self.regions.size.x)?
             .layout(engine, Locator::root(), self.styles, pod)?
             .into_frame();
         frame.size_mut().y += self.footnote_config.clearance;
@@ -818,7 +1127,7 @@ impl FlowLayouter<'_> {
 
         self.has_footnotes = true;
         self.regions.size.y -= frame.height();
-        self.items.push(FlowItem::Footnote(frame));
+        self.items.push(FlowItem::Footnote { frame, top: false });
 
         Ok(())
     }
@@ -829,13 +1138,15 @@ fn find_footnotes(notes: &mut Vec<Packed<FootnoteElem>>, frame: &Frame) {
     for (_, item) in frame.items() {
         match item {
             FrameItem::Group(group) => find_footnotes(notes, &group.frame),
-            FrameItem::Tag(tag)
-                if !notes.iter().any(|note| note.location() == tag.elem.location()) =>
-            {
-                let Some(footnote) = tag.elem.to_packed::<FootnoteElem>() else {
+            FrameItem::Tag(tag) => {
+                let Some(footnote) =
+                    tag.elem().and_then(|elem| elem.to_packed::<FootnoteElem>())
+                else {
                     continue;
                 };
-                notes.push(footnote.clone());
+                if !notes.iter().any(|note| note.location() == footnote.location()) {
+                    notes.push(footnote.clone());
+                }
             }
             _ => {}
         }
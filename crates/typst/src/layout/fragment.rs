@@ -47,12 +47,12 @@ impl Fragment {
     }
 
     /// Iterate over the contained frames.
-    pub fn iter(&self) -> std::slice::Iter<Frame> {
+    pub fn iter(&self) -> std::slice::Iter<'_, Frame> {
         self.0.iter()
     }
 
     /// Iterate over the contained frames.
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<Frame> {
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Frame> {
         self.0.iter_mut()
     }
 }
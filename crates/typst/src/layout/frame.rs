@@ -6,18 +6,18 @@ use std::sync::Arc;
 
 use smallvec::SmallVec;
 
-use crate::foundations::{cast, dict, Dict, StyleChain, Value};
+use crate::foundations::{cast, dict, Content, Dict, StyleChain, Value};
 use crate::introspection::Tag;
 use crate::layout::{
-    Abs, Axes, Corners, FixedAlignment, HideElem, Length, Point, Rel, Sides, Size,
-    Transform,
+    Abs, Axes, Corners, FixedAlignment, HideElem, Length, Point, Radius, Rel, Sides,
+    Size, Transform,
 };
 use crate::model::{Destination, LinkElem};
 use crate::syntax::Span;
 use crate::text::TextItem;
 use crate::utils::{LazyHash, Numeric};
 use crate::visualize::{
-    ellipse, styled_rect, Color, FixedStroke, Geometry, Image, Paint, Path, Shape,
+    ellipse, styled_rect, Color, FixedStroke, Geometry, Image, Paint, Path, Shadow, Shape,
 };
 
 /// A finished layout with items at fixed positions.
@@ -307,6 +307,42 @@ impl Frame {
         }
     }
 
+    /// Remove the tags matching the predicate from the frame and its groups.
+    ///
+    /// Returns the removed tags along with their positions relative to the
+    /// top-left of the frame.
+    pub fn take_tags<F>(&mut self, mut f: F) -> Vec<(Point, Tag)>
+    where
+        F: FnMut(&Tag) -> bool,
+    {
+        let mut tags = vec![];
+        self.take_tags_impl(&mut f, Transform::identity(), &mut tags);
+        tags
+    }
+
+    /// Remove the tags matching the predicate, recursively.
+    fn take_tags_impl(
+        &mut self,
+        f: &mut dyn FnMut(&Tag) -> bool,
+        ts: Transform,
+        tags: &mut Vec<(Point, Tag)>,
+    ) {
+        Arc::make_mut(&mut self.items).retain_mut(|(pos, item)| match item {
+            FrameItem::Group(group) => {
+                let ts = ts
+                    .pre_concat(Transform::translate(pos.x, pos.y))
+                    .pre_concat(group.transform);
+                group.frame.take_tags_impl(f, ts, tags);
+                true
+            }
+            FrameItem::Tag(tag) if f(tag) => {
+                tags.push((pos.transform(ts), tag.clone()));
+                false
+            }
+            _ => true,
+        });
+    }
+
     /// Apply late-stage properties from the style chain to this frame. This
     /// includes:
     /// - `HideElem::hidden`
@@ -370,7 +406,7 @@ impl Frame {
         fill: Option<Paint>,
         stroke: &Sides<Option<FixedStroke>>,
         outset: &Sides<Rel<Abs>>,
-        radius: &Corners<Rel<Abs>>,
+        radius: &Corners<Radius<Rel<Abs>>>,
         span: Span,
     ) {
         let outset = outset.relative_to(self.size());
@@ -383,6 +419,26 @@ impl Frame {
         )
     }
 
+    /// Add a drop shadow with optional radius and outset behind the frame's
+    /// contents.
+    pub fn shadow(
+        &mut self,
+        shadow: &Shadow<Abs>,
+        outset: &Sides<Rel<Abs>>,
+        radius: &Corners<Radius<Rel<Abs>>>,
+        span: Span,
+    ) {
+        let outset = outset.relative_to(self.size());
+        let size = self.size() + outset.sum_by_axis();
+        let pos = Point::new(-outset.left, -outset.top);
+        self.prepend_multiple(
+            shadow
+                .shapes(size, radius)
+                .into_iter()
+                .map(|(p, shape)| (pos + p, FrameItem::Shape(shape, span))),
+        )
+    }
+
     /// Arbitrarily transform the contents of the frame.
     pub fn transform(&mut self, transform: Transform) {
         if !self.is_empty() {
@@ -401,6 +457,14 @@ impl Frame {
         }
     }
 
+    /// Wrap the frame's contents in a group that holds the content of the
+    /// given element in the document's logical structure.
+    pub fn structure(&mut self, elem: Content) {
+        if !self.is_empty() {
+            self.group(|g| g.structure = Some(elem));
+        }
+    }
+
     /// Wrap the frame's contents in a group and modify that group with `f`.
     fn group<F>(&mut self, f: F)
     where
@@ -549,6 +613,14 @@ pub struct GroupItem {
     pub transform: Transform,
     /// Whether the frame should be a clipping boundary.
     pub clip_path: Option<Path>,
+    /// Whether the group holds page furniture like headers and footers rather
+    /// than part of the document's logical content.
+    pub artifact: bool,
+    /// Whether the group is a single column of a multi-column layout.
+    pub column: bool,
+    /// The element whose content the group holds in the document's logical
+    /// structure, like a heading or a list item.
+    pub structure: Option<Content>,
 }
 
 impl GroupItem {
@@ -558,6 +630,9 @@ impl GroupItem {
             frame,
             transform: Transform::identity(),
             clip_path: None,
+            artifact: false,
+            column: false,
+            structure: None,
         }
     }
 }
@@ -186,6 +186,12 @@ pub struct Cell<'a> {
     /// By default, a cell spanning only fixed-size rows is unbreakable, while
     /// a cell spanning at least one `auto`-sized row is breakable.
     pub breakable: bool,
+    /// Whether the cell is aligned to the first baseline of the other
+    /// baseline-aligned cells in its row.
+    pub baseline: bool,
+    /// The element whose content the cell holds in the document's logical
+    /// structure, if any.
+    pub structure: Option<Content>,
 }
 
 impl<'a> Cell<'a> {
@@ -200,6 +206,8 @@ impl<'a> Cell<'a> {
             stroke: Sides::splat(None),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            baseline: false,
+            structure: None,
         }
     }
 
@@ -220,7 +228,13 @@ impl<'a> Cell<'a> {
         if disambiguator > 0 {
             locator = locator.split().next_inner(disambiguator as u128);
         }
-        self.body.layout(engine, locator, styles, regions)
+        let mut fragment = self.body.layout(engine, locator, styles, regions)?;
+        if let Some(elem) = &self.structure {
+            for frame in fragment.iter_mut() {
+                frame.structure(elem.clone());
+            }
+        }
+        Ok(fragment)
     }
 }
 
@@ -515,10 +529,7 @@ impl<'a> CellGrid<'a> {
                 ResolvableGridChild::Item(item) => (None, Some(item)),
             };
 
-            let items = header_footer_items
-                .into_iter()
-                .flatten()
-                .chain(simple_item.into_iter());
+            let items = header_footer_items.into_iter().flatten().chain(simple_item);
             for item in items {
                 let cell = match item {
                     ResolvableGridItem::HLine {
@@ -1092,7 +1103,7 @@ impl<'a> CellGrid<'a> {
                     // Include the gutter right before the footer, unless there is
                     // none, or the gutter is already included in the header (no
                     // rows between the header and the footer).
-                    if header_end.map_or(true, |header_end| header_end != footer.start) {
+                    if header_end != Some(footer.start) {
                         footer.start = footer.start.saturating_sub(1);
                     }
                 }
@@ -1223,7 +1234,7 @@ impl<'a> CellGrid<'a> {
     /// position. It is guaranteed to have a non-gutter, non-merged cell at
     /// the returned position, due to how the grid is built.
     /// - If the entry at the given position is a cell, returns the given
-    /// position.
+    ///   position.
     /// - If it is a merged cell, returns the parent cell's position.
     /// - If it is a gutter cell, returns None.
     #[track_caller]
@@ -11,7 +11,7 @@ use crate::engine::Engine;
 use crate::foundations::{Resolve, StyleChain};
 use crate::layout::{
     Abs, Axes, Cell, CellGrid, Dir, Fr, Fragment, Frame, FrameItem, Length, Point,
-    Regions, Rel, Size, Sizing,
+    Regions, Rel, Size, Sizing, Transform,
 };
 use crate::syntax::Span;
 use crate::text::TextElem;
@@ -57,6 +57,12 @@ pub struct GridLayouter<'a> {
     /// The simulated footer height for this region.
     /// The simulation occurs before any rows are laid out for a region.
     pub(super) footer_height: Abs,
+    /// Whether the heights of rowspans are distributed across all of their
+    /// spanned auto rows instead of only the last one.
+    pub(super) balance_spans: bool,
+    /// How many more times a repeatable header may be repeated in new
+    /// regions, or `None` if it repeats indefinitely.
+    pub(super) header_repeats_left: Option<usize>,
     /// The span of the grid element.
     pub(super) span: Span,
 }
@@ -84,7 +90,7 @@ pub(super) enum Row {
 
 impl Row {
     /// Returns the `y` index of this row.
-    fn index(&self) -> usize {
+    pub(super) fn index(&self) -> usize {
         match self {
             Self::Frame(_, y, _) => *y,
             Self::Fr(_, y, _) => *y,
@@ -122,10 +128,25 @@ impl<'a> GridLayouter<'a> {
             is_rtl: TextElem::dir_in(styles) == Dir::RTL,
             header_height: Abs::zero(),
             footer_height: Abs::zero(),
+            balance_spans: false,
+            header_repeats_left: None,
             span,
         }
     }
 
+    /// Distribute the heights of rowspans across all of their spanned auto
+    /// rows.
+    pub fn with_balanced_spans(mut self, balance: bool) -> Self {
+        self.balance_spans = balance;
+        self
+    }
+
+    /// Limit how many times a repeatable header is repeated in new regions.
+    pub fn with_header_repeat_limit(mut self, limit: Option<usize>) -> Self {
+        self.header_repeats_left = limit;
+        self
+    }
+
     /// Determines the columns sizes and then layouts the grid row-by-row.
     pub fn layout(mut self, engine: &mut Engine) -> SourceResult<Fragment> {
         self.measure_columns(engine)?;
@@ -943,6 +964,8 @@ impl<'a> GridLayouter<'a> {
         // Layout into a single region.
         if let &[first] = resolved.as_slice() {
             let frame = self.layout_single_row(engine, disambiguator, first, y)?;
+            // Baseline-aligned cells may have grown the row.
+            let first = frame.height();
             self.push_row(frame, y, true);
 
             if self
@@ -1014,6 +1037,7 @@ impl<'a> GridLayouter<'a> {
         let breakable = unbreakable_rows_left == 0;
         let mut resolved: Vec<Abs> = vec![];
         let mut pending_rowspans: Vec<(usize, usize, Vec<Abs>)> = vec![];
+        let mut balanced_share = Abs::zero();
 
         for x in 0..self.rcols.len() {
             // Get the parent cell in case this is a merged position.
@@ -1043,7 +1067,19 @@ impl<'a> GridLayouter<'a> {
 
                 if last_spanned_auto_row != Some(y) {
                     // A rowspan should only affect the height of its last
-                    // spanned auto row.
+                    // spanned auto row, unless its height is balanced across
+                    // all of its spanned auto rows.
+                    if self.balance_spans {
+                        let share = self.measure_balanced_rowspan_share(
+                            engine,
+                            disambiguator,
+                            cell,
+                            parent,
+                            rowspan,
+                            y,
+                        )?;
+                        balanced_share.set_max(share);
+                    }
                     continue;
                 }
             }
@@ -1168,6 +1204,15 @@ impl<'a> GridLayouter<'a> {
             )?;
         }
 
+        // Grow this row by its share of the height of balanced rowspans
+        // which don't end here.
+        if balanced_share > Abs::zero() {
+            match resolved.first_mut() {
+                Some(first) => first.set_max(balanced_share),
+                None => resolved.push(balanced_share),
+            }
+        }
+
         debug_assert!(breakable || resolved.len() <= 1);
 
         Ok(Some(resolved))
@@ -1234,7 +1279,7 @@ impl<'a> GridLayouter<'a> {
             bail!(self.span, "cannot create grid with infinite height");
         }
 
-        let mut output = Frame::soft(Size::new(self.width, height));
+        let mut frames = vec![];
         let mut pos = Point::zero();
 
         // Reverse the column order when using RTL.
@@ -1244,7 +1289,9 @@ impl<'a> GridLayouter<'a> {
                 if cell.rowspan.get() == 1 {
                     let width = self.cell_spanned_width(cell, x);
                     let size = Size::new(width, height);
-                    let mut pod = Regions::one(size, Axes::splat(true));
+                    // Baseline-aligned cells keep their natural height, so
+                    // that we know how far they may be shifted down.
+                    let mut pod = Regions::one(size, Axes::new(true, !cell.baseline));
                     if self.grid.rows[y] == Sizing::Auto
                         && self.unbreakable_rows_left == 0
                     {
@@ -1270,13 +1317,37 @@ impl<'a> GridLayouter<'a> {
                         let offset = -width + rcol;
                         pos.x += offset;
                     }
-                    output.push_frame(pos, frame);
+                    // Cells without any text fall back to top alignment.
+                    let baseline =
+                        if cell.baseline { first_baseline(&frame) } else { None };
+                    frames.push((pos, frame, baseline));
                 }
             }
 
             pos.x += rcol;
         }
 
+        // Shift baseline-aligned cells down so that their first baselines
+        // line up with the lowest one among them.
+        let max_baseline = frames.iter().filter_map(|(_, _, baseline)| *baseline).max();
+        let mut height = height;
+        if let Some(max_baseline) = max_baseline {
+            for (pos, frame, baseline) in &mut frames {
+                if let Some(baseline) = *baseline {
+                    pos.y += max_baseline - baseline;
+                    if self.grid.rows[y] == Sizing::Auto {
+                        // Grow auto rows to fit the shifted cells.
+                        height.set_max(pos.y + frame.height());
+                    }
+                }
+            }
+        }
+
+        let mut output = Frame::soft(Size::new(self.width, height));
+        for (pos, frame, _) in frames {
+            output.push_frame(pos, frame);
+        }
+
         Ok(output)
     }
 
@@ -1302,6 +1373,7 @@ impl<'a> GridLayouter<'a> {
 
         // Layout the row.
         let mut pos = Point::zero();
+        let mut laid_out = vec![];
         for (x, &rcol) in self.rcols.iter().enumerate().rev_if(self.is_rtl) {
             if let Some(cell) = self.grid.cell(x, y) {
                 // Rowspans have a separate layout step
@@ -1309,23 +1381,48 @@ impl<'a> GridLayouter<'a> {
                     let width = self.cell_spanned_width(cell, x);
                     pod.size.x = width;
 
-                    // Push the layouted frames into the individual output frames.
                     let fragment =
                         cell.layout(engine, disambiguator, self.styles, pod)?;
-                    for (output, frame) in outputs.iter_mut().zip(fragment) {
-                        let mut pos = pos;
-                        if self.is_rtl {
-                            let offset = -width + rcol;
-                            pos.x += offset;
-                        }
-                        output.push_frame(pos, frame);
+                    let baseline = if cell.baseline {
+                        fragment.as_slice().first().and_then(first_baseline)
+                    } else {
+                        None
+                    };
+
+                    let mut pos = pos;
+                    if self.is_rtl {
+                        let offset = -width + rcol;
+                        pos.x += offset;
                     }
+                    laid_out.push((cell, width, pos, fragment, baseline));
                 }
             }
 
             pos.x += rcol;
         }
 
+        // Shift baseline-aligned cells down like in a single region. To keep
+        // them within the region, they are laid out again with a first region
+        // that is shorter by the shift.
+        let max_baseline = laid_out.iter().filter_map(|&(.., baseline)| baseline).max();
+        for (cell, width, mut pos, mut fragment, baseline) in laid_out {
+            if let (Some(max_baseline), Some(baseline)) = (max_baseline, baseline) {
+                let shift = max_baseline - baseline;
+                if shift > Abs::zero() {
+                    let mut pod = pod;
+                    pod.size = Size::new(width, (heights[0] - shift).max(Abs::zero()));
+                    fragment = cell.layout(engine, disambiguator, self.styles, pod)?;
+                    pos.y += shift;
+                }
+            }
+
+            // Push the layouted frames into the individual output frames.
+            for (i, (output, frame)) in outputs.iter_mut().zip(fragment).enumerate() {
+                let pos = if i == 0 { pos } else { Point::with_x(pos.x) };
+                output.push_frame(pos, frame);
+            }
+        }
+
         Ok(Fragment::frames(outputs))
     }
 
@@ -1535,8 +1632,15 @@ impl<'a> GridLayouter<'a> {
             }
 
             if let Some(Repeatable::Repeated(header)) = &self.grid.header {
-                // Add a header to the new region.
-                self.layout_header(header, engine, disambiguator)?;
+                if self.header_repeats_left == Some(0) {
+                    // The header was repeated often enough already.
+                    self.header_height = Abs::zero();
+                } else {
+                    // Add a header to the new region.
+                    self.header_repeats_left =
+                        self.header_repeats_left.map(|left| left - 1);
+                    self.layout_header(header, engine, disambiguator)?;
+                }
             }
 
             // Ensure rows don't try to overrun the footer.
@@ -1581,3 +1685,29 @@ pub(super) fn in_last_with_offset(regions: Regions<'_>, offset: Abs) -> bool {
     regions.backlog.is_empty()
         && regions.last.map_or(true, |height| regions.size.y + offset == height)
 }
+
+/// Determines the position of the first baseline in a frame, that is, the
+/// topmost position of any text within it.
+///
+/// Returns `None` if the frame contains no text.
+fn first_baseline(frame: &Frame) -> Option<Abs> {
+    first_baseline_impl(frame, Transform::identity())
+}
+
+/// Determines the first baseline of a frame whose items are subject to the
+/// given transform.
+fn first_baseline_impl(frame: &Frame, ts: Transform) -> Option<Abs> {
+    frame
+        .items()
+        .filter_map(|(pos, item)| match item {
+            FrameItem::Text(_) => Some(pos.transform(ts).y),
+            FrameItem::Group(group) => {
+                let ts = ts
+                    .pre_concat(Transform::translate(pos.x, pos.y))
+                    .pre_concat(group.transform);
+                first_baseline_impl(&group.frame, ts)
+            }
+            _ => None,
+        })
+        .min()
+}
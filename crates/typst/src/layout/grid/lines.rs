@@ -616,6 +616,8 @@ mod test {
             stroke: Sides::splat(Some(Arc::new(Stroke::default()))),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            baseline: false,
+            structure: None,
         }
     }
 
@@ -629,6 +631,8 @@ mod test {
             stroke: Sides::splat(Some(Arc::new(Stroke::default()))),
             stroke_overridden: Sides::splat(false),
             breakable: true,
+            baseline: false,
+            structure: None,
         }
     }
 
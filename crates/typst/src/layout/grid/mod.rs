@@ -25,7 +25,7 @@ use crate::foundations::{
 use crate::introspection::Locator;
 use crate::layout::{
     Abs, Alignment, Axes, BlockElem, Dir, Fragment, Length, OuterHAlignment,
-    OuterVAlignment, Regions, Rel, Sides, Sizing,
+    OuterVAlignment, Regions, Rel, Sides, Sizing, VAlignment,
 };
 use crate::model::{TableCell, TableFooter, TableHLine, TableHeader, TableVLine};
 use crate::syntax::Span;
@@ -310,6 +310,14 @@ pub struct GridElem {
     #[fold]
     pub inset: Celled<Sides<Option<Rel<Length>>>>,
 
+    /// Whether to distribute the height of cells spanning multiple rows
+    /// evenly across their spanned automatically sized rows.
+    ///
+    /// You can find an example for this argument at the
+    /// [`table.balance-spans`]($table.balance-spans) parameter.
+    #[default(false)]
+    pub balance_spans: bool,
+
     /// The contents of the grid cells, plus any extra grid lines specified
     /// with the [`grid.hline`]($grid.hline) and [`grid.vline`]($grid.vline)
     /// elements.
@@ -396,7 +404,8 @@ fn layout_grid(
     )
     .trace(engine.world, tracepoint, elem.span())?;
 
-    let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
+    let layouter = GridLayouter::new(&grid, regions, styles, elem.span())
+        .with_balanced_spans(elem.balance_spans(styles));
 
     // Measure the columns and layout the grid row-by-row.
     layouter.layout(engine)
@@ -920,6 +929,10 @@ impl ResolvableCell for Packed<GridCell> {
             }),
         );
         cell.push_breakable(Smart::Custom(breakable));
+        let baseline = matches!(
+            cell.align(styles),
+            Smart::Custom(align) if align.y() == Some(VAlignment::Baseline)
+        );
         Cell {
             body: self.pack(),
             locator,
@@ -929,6 +942,8 @@ impl ResolvableCell for Packed<GridCell> {
             stroke,
             stroke_overridden,
             breakable,
+            baseline,
+            structure: None,
         }
     }
 
@@ -144,7 +144,7 @@ impl<'a> GridLayouter<'a> {
         for ((i, finished), frame) in self
             .finished
             .iter_mut()
-            .chain(current_region.into_iter())
+            .chain(current_region)
             .skip(first_region)
             .enumerate()
             .zip(fragment)
@@ -570,6 +570,61 @@ impl<'a> GridLayouter<'a> {
         }
     }
 
+    /// Used in `measure_auto_row` to determine how far an auto row spanned by
+    /// a balanced rowspan, but which isn't its last spanned auto row, should
+    /// grow.
+    ///
+    /// The height still needed by the rowspan, after subtracting the heights
+    /// of its rows laid out so far and of its upcoming fixed-size rows, is
+    /// split evenly between this row and its remaining spanned auto rows. The
+    /// last spanned auto row then covers whatever is still missing, as usual.
+    /// Returns zero if the rowspan started in a previous region.
+    pub(super) fn measure_balanced_rowspan_share(
+        &self,
+        engine: &mut Engine,
+        disambiguator: usize,
+        cell: &Cell,
+        parent: Axes<usize>,
+        rowspan: usize,
+        y: usize,
+    ) -> SourceResult<Abs> {
+        let mut covered = Abs::zero();
+        for spanned_y in parent.y..y {
+            let Some(row) = self.lrows.iter().find(|row| row.index() == spanned_y) else {
+                // The rowspan started in a previous region.
+                return Ok(Abs::zero());
+            };
+            if let Row::Frame(frame, _, _) = row {
+                covered += frame.height();
+            }
+        }
+
+        // Upcoming fixed-size rows aren't balanced, but they cover part of the
+        // rowspan's height. Fractional rows are ignored.
+        let mut auto_rows = 0;
+        for (spanned_y, row) in
+            self.grid.rows.iter().enumerate().take(parent.y + rowspan).skip(y)
+        {
+            match row {
+                Sizing::Auto if !self.grid.is_gutter_track(spanned_y) => auto_rows += 1,
+                Sizing::Rel(v) => {
+                    covered += v.resolve(self.styles).relative_to(self.regions.base().y)
+                }
+                _ => {}
+            }
+        }
+
+        let width = self.cell_spanned_width(cell, parent.x);
+        let pod = Regions::one(Size::new(width, Abs::inf()), self.regions.expand);
+        let height = cell
+            .layout(engine, disambiguator, self.styles, pod)?
+            .into_frame()
+            .height();
+
+        let share = (height - covered) / auto_rows.max(1) as f64;
+        Ok(share.max(Abs::zero()).min(self.regions.size.y))
+    }
+
     /// Used in `measure_auto_row` to prepare a rowspan's `sizes` vector.
     /// Returns `true` if we'll need to run a simulation to more accurately
     /// expand the auto row based on the rowspan's demanded size, or `false`
@@ -128,7 +128,7 @@ pub fn collect<'a>(
     let mut iter = children.chain(styles).peekable();
     let mut locator = locator.split();
 
-    let first_line_indent = ParElem::first_line_indent_in(*styles);
+    let first_line_indent = ParElem::first_line_indent_in(*styles).amount;
     if !first_line_indent.is_zero()
         && consecutive
         && AlignElem::alignment_in(*styles).resolve(*styles).x
@@ -164,7 +164,9 @@ pub fn collect<'a>(
                 }
 
                 if let Some(case) = TextElem::case_in(styles) {
-                    full.push_str(&case.apply(elem.text()));
+                    let lang = TextElem::lang_in(styles);
+                    let region = TextElem::region_in(styles);
+                    full.push_str(&case.apply(elem.text(), Some(lang), region));
                 } else {
                     full.push_str(elem.text());
                 }
@@ -1,5 +1,4 @@
 use super::*;
-use crate::layout::{Abs, Frame, Point};
 use crate::utils::Numeric;
 
 /// Turns the selected lines into frames.
@@ -24,40 +23,11 @@ pub fn finalize(
         region.x
     };
 
-    // Stack the lines into one frame per region.
+    // Turn each line into a frame. Widows and orphans are handled by the flow.
     let shrink = ParElem::shrink_in(styles);
-    let mut frames: Vec<Frame> = lines
+    lines
         .iter()
         .map(|line| commit(engine, p, line, width, region.y, shrink))
-        .collect::<SourceResult<_>>()?;
-
-    // Positive ratios enable prevention, while zero and negative ratios disable
-    // it.
-    if p.costs.orphan().get() > 0.0 {
-        // Prevent orphans.
-        if frames.len() >= 2 && !frames[1].is_empty() {
-            let second = frames.remove(1);
-            let first = &mut frames[0];
-            merge(first, second, p.leading);
-        }
-    }
-    if p.costs.widow().get() > 0.0 {
-        // Prevent widows.
-        let len = frames.len();
-        if len >= 2 && !frames[len - 2].is_empty() {
-            let second = frames.pop().unwrap();
-            let first = frames.last_mut().unwrap();
-            merge(first, second, p.leading);
-        }
-    }
-
-    Ok(Fragment::frames(frames))
-}
-
-/// Merge two line frames
-fn merge(first: &mut Frame, second: Frame, leading: Abs) {
-    let offset = first.height() + leading;
-    let total = offset + second.height();
-    first.push_frame(Point::with_y(offset), second);
-    first.size_mut().y = total;
+        .collect::<SourceResult<_>>()
+        .map(Fragment::frames)
 }
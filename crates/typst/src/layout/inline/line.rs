@@ -1,3 +1,4 @@
+use smallvec::SmallVec;
 use unicode_bidi::BidiInfo;
 
 use super::*;
@@ -363,11 +364,10 @@ pub fn commit(
     // Handle hanging punctuation to the left.
     if let Some(Item::Text(text)) = reordered.first() {
         if let Some(glyph) = text.glyphs.first() {
-            if !text.dir.is_positive()
-                && TextElem::overhang_in(text.styles)
-                && (reordered.len() > 1 || text.glyphs.len() > 1)
-            {
-                let amount = overhang(glyph.c) * glyph.x_advance.at(text.size);
+            if reordered.len() > 1 || text.glyphs.len() > 1 {
+                let start = text.dir.is_positive();
+                let amount =
+                    hang(p, text, glyph.c, start) * glyph.x_advance.at(text.size);
                 offset -= amount;
                 remaining += amount;
             }
@@ -377,11 +377,10 @@ pub fn commit(
     // Handle hanging punctuation to the right.
     if let Some(Item::Text(text)) = reordered.last() {
         if let Some(glyph) = text.glyphs.last() {
-            if text.dir.is_positive()
-                && TextElem::overhang_in(text.styles)
-                && (reordered.len() > 1 || text.glyphs.len() > 1)
-            {
-                let amount = overhang(glyph.c) * glyph.x_advance.at(text.size);
+            if reordered.len() > 1 || text.glyphs.len() > 1 {
+                let start = !text.dir.is_positive();
+                let amount =
+                    hang(p, text, glyph.c, start) * glyph.x_advance.at(text.size);
                 remaining += amount;
             }
         }
@@ -449,8 +448,13 @@ pub fn commit(
                 }
             }
             Item::Text(shaped) => {
-                let mut frame =
-                    shaped.build(engine, justification_ratio, extra_justification);
+                let joins = decoration_joins(p, shaped);
+                let mut frame = shaped.build(
+                    engine,
+                    &joins,
+                    justification_ratio,
+                    extra_justification,
+                );
                 frame.post_process(shaped.styles);
                 push(&mut offset, frame);
             }
@@ -488,8 +492,25 @@ pub fn commit(
     Ok(output)
 }
 
+/// Determine for each decoration of a text run whether it continues into the
+/// logically preceding and following text of the paragraph, possibly across
+/// a line break.
+fn decoration_joins(p: &Preparation, shaped: &ShapedText) -> Vec<(bool, bool)> {
+    let decos_at = |offset: Option<usize>| match offset.and_then(|o| p.find(o)) {
+        Some(Item::Text(other)) => TextElem::deco_in(other.styles),
+        _ => SmallVec::new(),
+    };
+
+    let before = decos_at(shaped.base.checked_sub(1));
+    let after = decos_at(Some(shaped.base + shaped.text.len()));
+    TextElem::deco_in(shaped.styles)
+        .iter()
+        .map(|deco| (before.contains(deco), after.contains(deco)))
+        .collect()
+}
+
 /// Return a line's items in visual order.
-fn reorder<'a>(line: &'a Line<'a>) -> (Vec<&Item<'a>>, bool) {
+fn reorder<'a>(line: &'a Line<'a>) -> (Vec<&'a Item<'a>>, bool) {
     let mut reordered = vec![];
 
     // The bidi crate doesn't like empty lines.
@@ -528,6 +549,26 @@ fn reorder<'a>(line: &'a Line<'a>) -> (Vec<&Item<'a>>, bool) {
     (reordered, starts_rtl)
 }
 
+/// How much a character at the logical start or end of a line should hang
+/// into the margin.
+///
+/// Without [hanging punctuation]($par.hanging-punctuation), only the
+/// [overhang]($text.overhang) at the end of lines applies.
+fn hang(p: &Preparation, text: &ShapedText, c: char, start: bool) -> f64 {
+    if p.hanging_punctuation {
+        match c {
+            '"' | '\'' | '“' | '‘' | '„' | '‚' | '«' | '‹' if start => 1.0,
+            '"' | '\'' | '”' | '’' | '»' | '›' if !start => 1.0,
+            _ if start => 0.0,
+            _ => overhang(c),
+        }
+    } else if !start && TextElem::overhang_in(text.styles) {
+        overhang(c)
+    } else {
+        0.0
+    }
+}
+
 /// How much a character should hang into the end margin.
 ///
 /// For more discussion, see:
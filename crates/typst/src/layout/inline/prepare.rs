@@ -31,12 +31,13 @@ pub struct Preparation<'a> {
     pub justify: bool,
     /// The paragraph's hanging indent.
     pub hang: Abs,
+    /// Whether quotes and punctuation at the edges of lines hang into the
+    /// margin.
+    pub hanging_punctuation: bool,
     /// Whether to add spacing between CJK and Latin characters.
     pub cjk_latin_spacing: bool,
     /// Whether font fallback is enabled for this paragraph.
     pub fallback: bool,
-    /// The leading of the paragraph.
-    pub leading: Abs,
     /// How to determine line breaks.
     pub linebreaks: Smart<Linebreaks>,
     /// The text size.
@@ -139,9 +140,9 @@ pub fn prepare<'a>(
         align: AlignElem::alignment_in(styles).resolve(styles).x,
         justify: ParElem::justify_in(styles),
         hang: ParElem::hanging_indent_in(styles),
+        hanging_punctuation: ParElem::hanging_punctuation_in(styles),
         cjk_latin_spacing,
         fallback: TextElem::fallback_in(styles),
-        leading: ParElem::leading_in(styles),
         linebreaks: ParElem::linebreaks_in(styles),
         size: TextElem::size_in(styles),
     })
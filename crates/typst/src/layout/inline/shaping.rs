@@ -13,13 +13,13 @@ use unicode_script::{Script, UnicodeScript};
 use super::{Item, Range, SpanMapper};
 use crate::engine::Engine;
 use crate::foundations::{Smart, StyleChain};
-use crate::layout::{Abs, Dir, Em, Frame, FrameItem, Point, Size};
+use crate::layout::{Abs, Dir, Em, Frame, FrameItem, Point, Ratio, Size};
 use crate::syntax::Span;
 use crate::text::{
-    decorate, families, features, variant, Font, FontVariant, Glyph, Lang, Region,
-    TextElem, TextItem,
+    decorate, families, families_for, features, variant, Font, FontVariant, Glyph, Lang,
+    Region, TextElem, TextItem,
 };
-use crate::utils::SliceExt;
+use crate::utils::{Scalar, SliceExt};
 use crate::World;
 
 /// The result of shaping text.
@@ -86,6 +86,10 @@ pub struct ShapedGlyph {
     pub is_justifiable: bool,
     /// The script of the glyph.
     pub script: Script,
+    /// How much the glyph is scaled down relative to the text's size. This is
+    /// less than one for synthesized small capitals. The advance and offsets
+    /// are already scaled.
+    pub scale: Ratio,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -209,11 +213,14 @@ enum Side {
 impl<'a> ShapedText<'a> {
     /// Build the shaped text's frame.
     ///
-    /// The `justification` defines how much extra advance width each
+    /// The `joins` define for each decoration whether it continues into the
+    /// logically preceding and following text. The `justification` defines
+    /// how much extra advance width each
     /// [justifiable glyph](ShapedGlyph::is_justifiable) will get.
     pub fn build(
         &self,
         engine: &Engine,
+        joins: &[(bool, bool)],
         justification_ratio: f64,
         extra_justification: Abs,
     ) -> Frame {
@@ -230,9 +237,14 @@ impl<'a> ShapedText<'a> {
         let stroke = TextElem::stroke_in(self.styles);
         let span_offset = TextElem::span_offset_in(self.styles);
 
-        for ((font, y_offset), group) in
-            self.glyphs.as_ref().group_by_key(|g| (g.font.clone(), g.y_offset))
-        {
+        let groups: Vec<_> = self
+            .glyphs
+            .as_ref()
+            .group_by_key(|g| (g.font.clone(), g.y_offset, g.scale))
+            .collect();
+        let count = groups.len();
+
+        for (i, ((font, y_offset, scale), group)) in groups.into_iter().enumerate() {
             let mut range = group[0].range.clone();
             for glyph in group {
                 range.start = range.start.min(glyph.range.start);
@@ -289,12 +301,16 @@ impl<'a> ShapedText<'a> {
                     // D: justification_right
                     // A+B: Glyph's x_offset
                     // A+B+C+D: Glyph's x_advance
+                    //
+                    // The glyph's metrics are relative to the size of its text
+                    // item, which is scaled along with the glyph.
                     Glyph {
                         id: shaped.glyph_id,
-                        x_advance: shaped.x_advance
+                        x_advance: (shaped.x_advance
                             + justification_left
-                            + justification_right,
-                        x_offset: shaped.x_offset + justification_left,
+                            + justification_right)
+                            / scale.get(),
+                        x_offset: (shaped.x_offset + justification_left) / scale.get(),
                         range: (shaped.range.start - range.start).saturating_as()
                             ..(shaped.range.end - range.start).saturating_as(),
                         span,
@@ -304,7 +320,7 @@ impl<'a> ShapedText<'a> {
 
             let item = TextItem {
                 font,
-                size: self.size,
+                size: scale.of(self.size),
                 lang: self.lang,
                 region: self.region,
                 fill: fill.clone(),
@@ -319,8 +335,14 @@ impl<'a> ShapedText<'a> {
             } else {
                 // Apply line decorations.
                 frame.push(pos, FrameItem::Text(item.clone()));
-                for deco in &decos {
-                    decorate(&mut frame, deco, &item, width, shift, pos);
+                for (deco, &(before, after)) in decos.iter().zip(joins) {
+                    let (left, right) = if self.dir.is_positive() {
+                        (before, after)
+                    } else {
+                        (after, before)
+                    };
+                    let joins = (left || i > 0, right || i + 1 < count);
+                    decorate(&mut frame, deco, &item, width, shift, pos, joins);
                 }
             }
 
@@ -481,7 +503,7 @@ impl<'a> ShapedText<'a> {
             // ensure that the glyph range still falls after self.base so
             // that subtracting either of the endpoints by self.base doesn't
             // underflow. See <https://github.com/typst/typst/issues/2283>.
-            .unwrap_or_else(|| self.base..self.base);
+            .unwrap_or(self.base..self.base);
             self.width += x_advance.at(self.size);
             let glyph = ShapedGlyph {
                 font,
@@ -496,6 +518,7 @@ impl<'a> ShapedText<'a> {
                 span: (Span::detached(), 0),
                 is_justifiable: false,
                 script: Script::Common,
+                scale: Ratio::one(),
             };
             match side {
                 Side::Left => self.glyphs.to_mut().insert(0, glyph),
@@ -685,11 +708,17 @@ fn shape<'a>(
         variant: variant(styles),
         features: features(styles),
         fallback: TextElem::fallback_in(styles),
+        optical_size: TextElem::optical_size_in(styles)
+            .map(|opsz| opsz.map_or(size.to_pt(), Scalar::get) as f32),
+        synthesize_smallcaps: TextElem::synthesize_smallcaps_in(styles),
         dir,
     };
 
     if !text.is_empty() {
-        shape_segment(&mut ctx, base, text, families(styles));
+        // Runs are split by script, so the first specific script determines
+        // the script-specific font families.
+        let script = text.chars().map(|c| c.script()).find(|&s| !is_generic_script(s));
+        shape_segment(&mut ctx, base, text, families_for(styles, script));
     }
 
     track_and_space(&mut ctx);
@@ -725,6 +754,8 @@ struct ShapingContext<'a, 'v> {
     variant: FontVariant,
     features: Vec<rustybuzz::Feature>,
     fallback: bool,
+    optical_size: Option<f32>,
+    synthesize_smallcaps: bool,
     dir: Dir,
 }
 
@@ -743,9 +774,14 @@ fn shape_segment<'a>(
     // Find the next available family.
     let world = ctx.engine.world;
     let book = world.book();
+    let instance = |font: Font| match ctx.optical_size {
+        Some(opsz) => font.with_optical_size(opsz),
+        None => font,
+    };
     let mut selection = families.find_map(|family| {
         book.select(family, ctx.variant)
             .and_then(|id| world.font(id))
+            .map(instance)
             .filter(|font| !ctx.used.contains(font))
     });
 
@@ -755,6 +791,7 @@ fn shape_segment<'a>(
         selection = book
             .select_fallback(first, ctx.variant, text)
             .and_then(|id| world.font(id))
+            .map(instance)
             .filter(|font| !ctx.used.contains(font));
     }
 
@@ -768,9 +805,20 @@ fn shape_segment<'a>(
 
     ctx.used.push(font.clone());
 
+    // If the font lacks true small capitals, they are synthesized by shaping
+    // capitals in place of lowercase letters and scaling them down.
+    let smallcaps = (ctx.synthesize_smallcaps && !has_smallcaps(&font)).then(|| {
+        let metrics = font.metrics();
+        Ratio::new(metrics.x_height / metrics.cap_height)
+    });
+
     // Fill the buffer with our text.
     let mut buffer = UnicodeBuffer::new();
-    buffer.push_str(text);
+    if smallcaps.is_some() {
+        buffer.push_str(&text.chars().map(to_capital).collect::<String>());
+    } else {
+        buffer.push_str(text);
+    }
     buffer.set_language(language(ctx.styles));
     if let Some(script) = TextElem::script_in(ctx.styles).custom().and_then(|script| {
         rustybuzz::Script::from_iso15924_tag(Tag::from_bytes(script.as_bytes()))
@@ -818,14 +866,16 @@ fn shape_segment<'a>(
 
             let c = text[cluster..].chars().next().unwrap();
             let script = c.script();
-            let x_advance = font.to_em(pos[i].x_advance);
+            let scale =
+                smallcaps.filter(|_| to_capital(c) != c).unwrap_or_else(Ratio::one);
+            let x_advance = scale.of(font.to_em(pos[i].x_advance));
             ctx.glyphs.push(ShapedGlyph {
                 font: font.clone(),
                 glyph_id: info.glyph_id as u16,
                 // TODO: Don't ignore y_advance.
                 x_advance,
-                x_offset: font.to_em(pos[i].x_offset),
-                y_offset: font.to_em(pos[i].y_offset),
+                x_offset: scale.of(font.to_em(pos[i].x_offset)),
+                y_offset: scale.of(font.to_em(pos[i].y_offset)),
                 adjustability: Adjustability::default(),
                 range: start..end,
                 safe_to_break: !info.unsafe_to_break(),
@@ -838,6 +888,7 @@ fn shape_segment<'a>(
                     Adjustability::default().stretchability,
                 ),
                 script,
+                scale,
             });
         } else {
             // First, search for the end of the tofu sequence.
@@ -886,6 +937,31 @@ fn shape_segment<'a>(
     ctx.used.pop();
 }
 
+/// Whether the font supports true small capitals. ("smcp")
+fn has_smallcaps(font: &Font) -> bool {
+    font.ttf()
+        .tables()
+        .gsub
+        .is_some_and(|gsub| gsub.features.find(Tag::from_bytes(b"smcp")).is_some())
+}
+
+/// The capital to shape in place of a lowercase letter when synthesizing small
+/// capitals.
+///
+/// Letters whose capital isn't a single character of the same length keep
+/// their lowercase form, so that the glyph clusters still map onto the text.
+fn to_capital(c: char) -> char {
+    if !c.is_lowercase() {
+        return c;
+    }
+
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(capital), None) if capital.len_utf8() == c.len_utf8() => capital,
+        _ => c,
+    }
+}
+
 /// Create a shape plan.
 #[comemo::memoize]
 fn create_shape_plan(
@@ -929,6 +1005,7 @@ fn shape_tofus(ctx: &mut ShapingContext, base: usize, text: &str, font: Font) {
                 Adjustability::default().stretchability,
             ),
             script,
+            scale: Ratio::one(),
         });
     };
     if ctx.dir.is_positive() {
@@ -940,7 +1017,9 @@ fn shape_tofus(ctx: &mut ShapingContext, base: usize, text: &str, font: Font) {
 
 /// Apply tracking and spacing to the shaped glyphs.
 fn track_and_space(ctx: &mut ShapingContext) {
-    let tracking = Em::from_length(TextElem::tracking_in(ctx.styles), ctx.size);
+    let tracking = TextElem::tracking_in(ctx.styles)
+        .map(|abs| Em::from_length(abs, ctx.size))
+        .relative_to(Em::one());
     let spacing =
         TextElem::spacing_in(ctx.styles).map(|abs| Em::from_length(abs, ctx.size));
 
@@ -25,6 +25,7 @@ mod pad;
 mod page;
 mod place;
 mod point;
+mod radius;
 mod ratio;
 mod regions;
 mod rel;
@@ -57,6 +58,7 @@ pub use self::pad::*;
 pub use self::page::*;
 pub use self::place::*;
 pub use self::point::*;
+pub use self::radius::*;
 pub use self::ratio::*;
 pub use self::regions::*;
 pub use self::rel::*;
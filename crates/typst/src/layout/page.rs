@@ -6,7 +6,7 @@ use std::str::FromStr;
 
 use comemo::Track;
 
-use crate::diag::{bail, SourceResult};
+use crate::diag::{bail, warning, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, AutoValue, Cast, Content, Context, Dict, Fold, Func, NativeElement,
@@ -16,12 +16,12 @@ use crate::introspection::{
     Counter, CounterDisplayElem, CounterKey, Locator, ManualPageCounter, SplitLocator,
 };
 use crate::layout::{
-    Abs, AlignElem, Alignment, Axes, ColumnsElem, Dir, Frame, HAlignment, Length,
-    OuterVAlignment, Point, Ratio, Regions, Rel, Sides, Size, SpecificAlignment,
-    VAlignment,
+    Abs, AlignElem, Alignment, Axes, ColumnsElem, Dir, Frame, FrameItem, GroupItem,
+    HAlignment, Length, OuterVAlignment, Point, Ratio, Regions, Rel, Sides, Size,
+    SpecificAlignment, Transform, VAlignment,
 };
 
-use crate::model::Numbering;
+use crate::model::{FootnoteElem, FootnoteEntry, Numbering};
 use crate::text::TextElem;
 use crate::utils::{NonZeroExt, Numeric, Scalar};
 use crate::visualize::Paint;
@@ -309,12 +309,29 @@ pub struct PageElem {
     /// In the year 2023, we plan to take
     /// over the world (of typesetting).
     /// ```
+    ///
+    /// Instead of content, you can also pass a function that receives the
+    /// physical page number, starting at one, and returns the background of
+    /// that page. To query the document from within the background, return
+    /// [context]($context) content, which knows its location on the page.
+    ///
+    /// ```example
+    /// #set page(height: 80pt, background: n => {
+    ///   if calc.odd(n) { text(24pt, fill: silver)[*DRAFT*] }
+    /// })
+    ///
+    /// Odd pages are drafts.
+    /// #pagebreak()
+    /// Even pages are not.
+    /// ```
     #[borrowed]
-    pub background: Option<Content>,
+    pub background: Option<Marginal>,
 
     /// Content in the page's foreground.
     ///
-    /// This content will overlay the page's body.
+    /// This content will overlay the page's body. Like the
+    /// [`background`]($page.background), it can also be a function of the
+    /// page number.
     ///
     /// ```example
     /// #set page(foreground: text(24pt)[🥸])
@@ -324,7 +341,7 @@ pub struct PageElem {
     /// not understand our approach...
     /// ```
     #[borrowed]
-    pub foreground: Option<Content>,
+    pub foreground: Option<Marginal>,
 
     /// The contents of the page(s).
     ///
@@ -509,7 +526,8 @@ impl PageLayout<'_> {
             // Thus, for left-bound pages, we want to swap on even pages and
             // for right-bound pages, we want to swap on odd pages.
             let mut margin = self.margin;
-            if self.two_sided && binding.swap(page_counter.physical()) {
+            let swapped = self.two_sided && binding.swap(page_counter.physical());
+            if swapped {
                 std::mem::swap(&mut margin.left, &mut margin.right);
             }
 
@@ -518,10 +536,98 @@ impl PageLayout<'_> {
             frame.translate(Point::new(margin.left, margin.top));
 
             // The page size with margins.
-            let size = frame.size();
+            let mut size = frame.size();
+
+            // Realize margin notes in the outside margin.
+            let mut notes = vec![];
+            find_margin_notes(&mut notes, &frame, Transform::identity());
+            let margin_gap = FootnoteEntry::margin_gap_in(styles);
+            let gap = FootnoteEntry::gap_in(styles);
+            let (outside, x) = if swapped {
+                (margin.left, margin_gap)
+            } else {
+                (margin.right, size.x - margin.right + margin_gap)
+            };
+            let width = outside - 2.0 * margin_gap;
+            let mut placed = vec![];
+            let mut bottom = Abs::zero();
+            for (note, y) in &notes {
+                if width <= Abs::zero() {
+                    engine.sink.warn(warning!(
+                        note.span(),
+                        "margin is too narrow to hold the margin note";
+                        hint: "increase the page margin or decrease the `margin-gap` \
+                               of `footnote.entry`"
+                    ));
+                    continue;
+                }
+
+                // Shift the note down if it would overlap with the previous one.
+                let y = (*y).max(bottom);
+                let pod =
+                    Regions::one(Size::new(width, Abs::inf()), Axes::new(true, false));
+                let sub = FootnoteEntry::new(note.clone())
+                    .pack()
+                    .layout(
+                        engine,
+                        Locator::synthesize(note.location().unwrap()),
+                        styles,
+                        pod,
+                    )?
+                    .into_frame();
+                bottom = y + sub.height() + gap;
+                placed.push((note, y, sub));
+            }
+
+            if !self.area.y.is_finite() {
+                // A page with automatic height grows to hold the notes.
+                size.y.set_max(bottom - gap + margin.bottom);
+                frame.set_size(size);
+            } else {
+                // Pull the notes back up if the last ones would run past the
+                // bottom margin. If they don't fit even then, they stay where
+                // they are.
+                let mut ys: Vec<Abs> = placed.iter().map(|(_, y, _)| *y).collect();
+                let mut limit = size.y - margin.bottom;
+                for (y, (_, _, sub)) in ys.iter_mut().zip(&placed).rev() {
+                    y.set_min(limit - sub.height());
+                    limit = *y - gap;
+                }
+
+                if ys.first().map_or(true, |y| y.fits(margin.top)) {
+                    for ((_, y, _), pulled) in placed.iter_mut().zip(ys) {
+                        *y = pulled;
+                    }
+                } else if let Some((note, _, _)) = placed.last() {
+                    engine.sink.warn(warning!(
+                        note.span(),
+                        "margin notes do not fit on the page";
+                        hint: "try shortening the notes or spreading them out"
+                    ));
+                }
+            }
+
+            for (_, y, sub) in placed {
+                frame.push_frame(Point::new(x, y), sub);
+            }
+
+            // Resolve the background and foreground of this page.
+            let number = page_counter.physical().get();
+            let background = match background {
+                Some(marginal) => {
+                    Some(marginal.resolve(engine, styles, number)?.into_owned())
+                }
+                None => None,
+            };
+            let foreground = match foreground {
+                Some(marginal) => {
+                    Some(marginal.resolve(engine, styles, number)?.into_owned())
+                }
+                None => None,
+            };
 
             // Realize overlays.
-            for marginal in [header, footer, background, foreground] {
+            for marginal in [header, footer, &background, &foreground] {
                 let Some(content) = marginal.as_ref() else { continue };
 
                 let (pos, area, align);
@@ -548,10 +654,14 @@ impl PageLayout<'_> {
                     .layout(engine, self.locator.next(&content.span()), styles, pod)?
                     .into_frame();
 
-                if ptr::eq(marginal, header) || ptr::eq(marginal, background) {
-                    frame.prepend_frame(pos, sub);
+                // Marginals are never inlined so that exporters can tell them
+                // apart from the page's body.
+                let group =
+                    FrameItem::Group(GroupItem { artifact: true, ..GroupItem::new(sub) });
+                if ptr::eq(marginal, header) || ptr::eq(marginal, &background) {
+                    frame.prepend(pos, group);
                 } else {
-                    frame.push_frame(pos, sub);
+                    frame.push(pos, group);
                 }
             }
 
@@ -573,6 +683,35 @@ impl PageLayout<'_> {
     }
 }
 
+/// Finds all margin notes in the frame along with their vertical position.
+fn find_margin_notes(
+    notes: &mut Vec<(Packed<FootnoteElem>, Abs)>,
+    frame: &Frame,
+    ts: Transform,
+) {
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => {
+                let ts = ts
+                    .pre_concat(Transform::translate(pos.x, pos.y))
+                    .pre_concat(group.transform);
+                find_margin_notes(notes, &group.frame, ts);
+            }
+            FrameItem::Tag(tag) => {
+                let Some(note) =
+                    tag.elem().and_then(|elem| elem.to_packed::<FootnoteElem>())
+                else {
+                    continue;
+                };
+                if !note.is_ref() && note.is_sidenote() {
+                    notes.push((note.clone(), pos.transform(ts).y));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 /// A finished page.
 #[derive(Debug, Clone)]
 pub struct Page {
@@ -732,7 +871,7 @@ cast! {
 }
 
 /// A header, footer, foreground or background definition.
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, PartialEq, Hash)]
 pub enum Marginal {
     /// Bare content.
     Content(Content),
@@ -799,6 +938,20 @@ impl PageRanges {
             (None, None) => true,
         })
     }
+
+    /// Ensure that all page numbers that bound the ranges exist in a document
+    /// with `count` pages.
+    pub fn check(&self, count: usize) -> StrResult<()> {
+        for range in &self.0 {
+            for page in [range.start(), range.end()].into_iter().flatten() {
+                if page.get() > count {
+                    let noun = if count == 1 { "page" } else { "pages" };
+                    bail!("page {page} is out of range, the document has {count} {noun}");
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// A manual page break.
@@ -1,6 +1,8 @@
 use crate::diag::{bail, At, Hint, SourceResult};
 use crate::engine::Engine;
-use crate::foundations::{elem, scope, Content, Packed, Smart, StyleChain, Unlabellable};
+use crate::foundations::{
+    elem, scope, Cast, Content, Packed, Smart, StyleChain, Unlabellable,
+};
 use crate::introspection::Locator;
 use crate::layout::{
     Alignment, Axes, Em, Fragment, Length, Regions, Rel, Size, VAlignment,
@@ -66,6 +68,52 @@ pub struct PlaceElem {
     /// ```
     pub float: bool,
 
+    /// Relative to which containing scope the element is placed.
+    ///
+    /// Floating elements with `{"parent"}` scope inside of [`columns`] span
+    /// the full width of the columns instead of being confined to a single
+    /// column. The content of all columns is then pushed away from them. Such a
+    /// float is placed into the region (e.g. the page) in which it occurs.
+    ///
+    /// ```example
+    /// #set page(height: 150pt, columns: 2)
+    /// #place(
+    ///   top + center,
+    ///   float: true,
+    ///   scope: "parent",
+    ///   rect(width: 80%)[Spanning],
+    /// )
+    /// #lorem(40)
+    /// ```
+    pub scope: PlacementScope,
+
+    /// Where a floating element prefers to be placed.
+    ///
+    /// With `{"here"}`, the element stays in-flow where it was specified if
+    /// it fits into the remaining space of the current region. Only if it
+    /// doesn't fit, it floats to the top or bottom as usual, which typically
+    /// means that it moves to the top of the next region.
+    ///
+    /// ```example
+    /// #set page(height: 150pt)
+    /// #lorem(10)
+    /// #place(
+    ///   auto,
+    ///   float: true,
+    ///   prefer: "here",
+    ///   rect[Fits here],
+    /// )
+    /// #lorem(10)
+    /// #place(
+    ///   auto,
+    ///   float: true,
+    ///   prefer: "here",
+    ///   rect(height: 80pt)[Too large],
+    /// )
+    /// #lorem(10)
+    /// ```
+    pub prefer: PlacementPreference,
+
     /// The amount of clearance the placed element has in a floating layout.
     #[default(Em::new(1.5).into())]
     #[resolve]
@@ -141,6 +189,28 @@ impl Packed<PlaceElem> {
     }
 }
 
+/// Relative to which containing scope something is placed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum PlacementScope {
+    /// Place into the current column.
+    #[default]
+    Column,
+    /// Place relative to the parent, letting the content span over all
+    /// columns.
+    Parent,
+}
+
+/// Where a floating element prefers to be placed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum PlacementPreference {
+    /// Float to the top or bottom of the region.
+    #[default]
+    Edge,
+    /// Stay in-flow if there is enough space left in the current region and
+    /// float otherwise.
+    Here,
+}
+
 impl Behave for Packed<PlaceElem> {
     fn behaviour(&self) -> Behaviour {
         Behaviour::Ignorant
@@ -0,0 +1,118 @@
+use std::fmt::{self, Debug, Formatter};
+
+use crate::diag::HintedStrResult;
+use crate::foundations::{
+    dict, CastInfo, Dict, Fold, FromValue, IntoValue, Reflect, Resolve, StyleChain, Value,
+};
+
+/// The radius of a rounded corner.
+///
+/// A corner is rounded with an elliptical arc that spans `x` along the
+/// horizontal and `y` along the vertical side. It is circular if both are
+/// equal.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Radius<T> {
+    /// The radius along the horizontal side.
+    pub x: T,
+    /// The radius along the vertical side.
+    pub y: T,
+}
+
+impl<T> Radius<T> {
+    /// Create a new instance from the two components.
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Create a circular radius.
+    pub fn splat(v: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { x: v.clone(), y: v }
+    }
+
+    /// Map the individual fields with `f`.
+    pub fn map<F, U>(self, mut f: F) -> Radius<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Radius { x: f(self.x), y: f(self.y) }
+    }
+}
+
+impl<T: Debug + PartialEq> Debug for Radius<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.x == self.y {
+            write!(f, "Radius::splat({:?})", self.x)
+        } else {
+            write!(f, "Radius({:?}, {:?})", self.x, self.y)
+        }
+    }
+}
+
+impl<T: Reflect> Reflect for Radius<T> {
+    fn input() -> CastInfo {
+        T::input() + Dict::input()
+    }
+
+    fn output() -> CastInfo {
+        T::output() + Dict::output()
+    }
+
+    fn castable(value: &Value) -> bool {
+        // Only claim dictionaries with our keys, so that other dictionaries
+        // remain available to containers like `Corners`.
+        match value {
+            Value::Dict(dict) => dict.contains("x") || dict.contains("y"),
+            _ => T::castable(value),
+        }
+    }
+}
+
+impl<T> IntoValue for Radius<T>
+where
+    T: PartialEq + IntoValue,
+{
+    fn into_value(self) -> Value {
+        if self.x == self.y {
+            self.x.into_value()
+        } else {
+            Value::Dict(dict! { "x" => self.x, "y" => self.y })
+        }
+    }
+}
+
+impl<T> FromValue for Radius<T>
+where
+    T: FromValue + Clone,
+{
+    fn from_value(mut value: Value) -> HintedStrResult<Self> {
+        if let Value::Dict(dict) = &mut value {
+            let x = T::from_value(dict.take("x")?)?;
+            let y = T::from_value(dict.take("y")?)?;
+            dict.finish(&["x", "y"])?;
+            return Ok(Self { x, y });
+        }
+
+        if T::castable(&value) {
+            Ok(Self::splat(T::from_value(value)?))
+        } else {
+            Err(Self::error(&value))
+        }
+    }
+}
+
+impl<T: Resolve> Resolve for Radius<T> {
+    type Output = Radius<T::Output>;
+
+    fn resolve(self, styles: StyleChain) -> Self::Output {
+        self.map(|v| v.resolve(styles))
+    }
+}
+
+impl<T: Fold> Fold for Radius<T> {
+    fn fold(self, outer: Self) -> Self {
+        Self { x: self.x.fold(outer.x), y: self.y.fold(outer.y) }
+    }
+}
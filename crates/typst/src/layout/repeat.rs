@@ -5,7 +5,7 @@ use crate::foundations::{
 };
 use crate::introspection::Locator;
 use crate::layout::{
-    Abs, AlignElem, Axes, BlockElem, Fragment, Frame, Point, Regions, Size,
+    Abs, AlignElem, Axes, BlockElem, Fragment, Frame, Length, Point, Regions, Size,
 };
 use crate::utils::Numeric;
 
@@ -35,6 +35,29 @@ pub struct RepeatElem {
     /// The content to repeat.
     #[required]
     pub body: Content,
+
+    /// The gap between each instance of the body.
+    ///
+    /// ```example
+    /// #box(width: 1fr, repeat(gap: 0.5em)[.])
+    /// ```
+    #[resolve]
+    #[default]
+    pub gap: Length,
+
+    /// Whether to increase the gap between instances to completely fill the
+    /// available space.
+    ///
+    /// When `{false}`, the instances are placed exactly [`gap`]($repeat.gap)
+    /// apart and the remaining space is distributed according to the current
+    /// [alignment]($align.alignment).
+    ///
+    /// ```example
+    /// #set repeat(gap: 0.5em, justify: false)
+    /// #box(width: 1fr, align(end, repeat[.]))
+    /// ```
+    #[default(true)]
+    pub justify: bool,
 }
 
 impl Show for Packed<RepeatElem> {
@@ -61,9 +84,10 @@ fn layout_repeat(
 
     let fill = regions.size.x;
     let width = piece.width();
-    let count = (fill / width).floor();
-    let remaining = fill % width;
-    let apart = remaining / (count - 1.0);
+    let gap = elem.gap(styles);
+    let count = ((fill + gap) / (width + gap)).floor();
+    let remaining = fill - width * count - gap * (count - 1.0).max(0.0);
+    let apart = if elem.justify(styles) { gap + remaining / (count - 1.0) } else { gap };
 
     let size = Size::new(regions.size.x, piece.height());
 
@@ -77,7 +101,7 @@ fn layout_repeat(
     }
 
     let mut offset = Abs::zero();
-    if count == 1.0 {
+    if count == 1.0 || !elem.justify(styles) {
         offset += align.x.position(remaining);
     }
 
@@ -69,7 +69,7 @@ use crate::foundations::{
     Array, Bytes, Datetime, Dict, Module, Scope, StyleChain, Styles, Value,
 };
 use crate::introspection::Introspector;
-use crate::layout::{Alignment, Dir};
+use crate::layout::{Alignment, Dir, Page};
 use crate::model::Document;
 use crate::syntax::package::PackageSpec;
 use crate::syntax::{FileId, Source, Span};
@@ -89,6 +89,35 @@ pub fn compile(world: &dyn World) -> Warned<SourceResult<Document>> {
     Warned { output, warnings: sink.warnings() }
 }
 
+/// Compile sources and pass the finished pages to `emit`, one by one.
+///
+/// Layout reruns until introspection converges, just like in [`compile`].
+/// The pages of the final iteration are then handed over in order and by
+/// value, without another layout pass. This doesn't lower peak memory usage:
+/// All pages are fully laid out before the first one is emitted.
+///
+/// - Returns `Ok(count)` with the number of emitted pages if there were no
+///   fatal errors.
+/// - Returns `Err(errors)` if there were fatal errors. In this case, no page
+///   was emitted.
+#[typst_macros::time]
+pub fn compile_pages(
+    world: &dyn World,
+    mut emit: impl FnMut(Page),
+) -> Warned<SourceResult<usize>> {
+    let mut sink = Sink::new();
+    let output = compile_inner(world.track(), Traced::default().track(), &mut sink)
+        .map(|mut document| {
+            let pages = std::mem::take(&mut document.pages);
+            drop(document);
+            let count = pages.len();
+            pages.into_iter().for_each(&mut emit);
+            count
+        })
+        .map_err(deduplicate);
+    Warned { output, warnings: sink.warnings() }
+}
+
 /// Compiles sources and returns all values and styles observed at the given
 /// `span` during compilation.
 #[typst_macros::time]
@@ -401,4 +430,5 @@ fn prelude(global: &mut Scope) {
     global.define("top", Alignment::TOP);
     global.define("horizon", Alignment::HORIZON);
     global.define("bottom", Alignment::BOTTOM);
+    global.define("baseline", Alignment::BASELINE);
 }
@@ -2,7 +2,7 @@ use crate::diag::{bail, SourceResult};
 use crate::foundations::{
     cast, elem, func, Content, NativeElement, Packed, Resolve, Smart, StyleChain, Value,
 };
-use crate::layout::{Em, Frame, Length, Point, Rel, Size};
+use crate::layout::{Abs, Em, Frame, Length, Point, Ratio, Rel, Size, Transform};
 use crate::math::{
     style_cramped, FrameFragment, GlyphFragment, LayoutMath, MathContext, MathFragment,
     Scaled,
@@ -48,11 +48,17 @@ macro_rules! accents {
                 /// The size of the accent, relative to the width of the base.
                 #[named]
                 size: Option<Smart<Rel<Length>>>,
+                /// Whether to stretch the accent to the full width of the base.
+                #[named]
+                stretch: Option<bool>,
             ) -> Content {
                 let mut accent = AccentElem::new(base, Accent::new($primary));
                 if let Some(size) = size {
                     accent = accent.with_size(size);
                 }
+                if let Some(stretch) = stretch {
+                    accent = accent.with_stretch(stretch);
+                }
                 accent.pack()
             }
         )+
@@ -108,6 +114,20 @@ pub struct AccentElem {
 
     /// The size of the accent, relative to the width of the base.
     pub size: Smart<Rel<Length>>,
+
+    /// Whether to stretch the accent to the full width of the base.
+    ///
+    /// By default, an accent may fall somewhat short of a wide base. When
+    /// stretching, the font's wider variants and glyph assemblies are used to
+    /// cover the whole base. If the font has no variant that is wide enough,
+    /// the widest one is scaled horizontally instead.
+    ///
+    /// ```example
+    /// $hat(x + y), hat(x + y, stretch: #true)$ \
+    /// $tilde(a b c), tilde(a b c, stretch: #true)$
+    /// ```
+    #[default(false)]
+    pub stretch: bool,
 }
 
 impl LayoutMath for Packed<AccentElem> {
@@ -127,11 +147,25 @@ impl LayoutMath for Packed<AccentElem> {
             .relative_to(base.width());
 
         // Forcing the accent to be at least as large as the base makes it too
-        // wide in many case.
+        // wide in many case, so this only happens when stretching is requested.
+        let stretch = self.stretch(styles);
         let Accent(c) = self.accent();
         let glyph = GlyphFragment::new(ctx, styles, *c, self.span());
-        let short_fall = ACCENT_SHORT_FALL.at(glyph.font_size);
-        let variant = glyph.stretch_horizontal(ctx, width, short_fall);
+        let short_fall =
+            if stretch { Abs::zero() } else { ACCENT_SHORT_FALL.at(glyph.font_size) };
+        let mut variant = glyph.stretch_horizontal(ctx, width, short_fall);
+
+        // Scale the accent if the font doesn't have a wide enough variant.
+        let natural = variant.frame.width();
+        if stretch && natural > Abs::zero() && natural < width {
+            let ratio = width / natural;
+            variant
+                .frame
+                .transform(Transform::scale(Ratio::new(ratio), Ratio::one()));
+            variant.frame.size_mut().x = width;
+            variant.accent_attach *= ratio;
+        }
+
         let accent = variant.frame;
         let accent_attach = variant.accent_attach;
 
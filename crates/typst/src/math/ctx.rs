@@ -148,7 +148,7 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
         elem: &dyn LayoutMath,
         styles: StyleChain,
     ) -> SourceResult<MathRun> {
-        Ok(MathRun::new(self.layout_into_fragments(elem, styles)?))
+        Ok(MathRun::new(self.layout_into_fragments(elem, styles)?, styles))
     }
 
     /// Layout the given element and return the result as a
@@ -252,7 +252,7 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
                 let c = styled_char(styles, c, false);
                 fragments.push(GlyphFragment::new(self, styles, c, span).into());
             }
-            let frame = MathRun::new(fragments).into_frame(self, styles);
+            let frame = MathRun::new(fragments, styles).into_frame(self, styles);
             FrameFragment::new(self, styles, frame).with_text_like(true).into()
         } else {
             let local = [
@@ -277,7 +277,7 @@ impl<'a, 'b, 'v> MathContext<'a, 'b, 'v> {
                             .push(self.layout_complex_text(piece, span, styles)?.into());
                     }
                 }
-                let mut frame = MathRun::new(fragments).into_frame(self, styles);
+                let mut frame = MathRun::new(fragments, styles).into_frame(self, styles);
                 let axis = scaled!(self, styles, axis_height);
                 frame.set_baseline(frame.height() / 2.0 + axis);
                 FrameFragment::new(self, styles, frame).into()
@@ -1,28 +1,32 @@
 use std::num::NonZeroUsize;
 
+use comemo::Track;
 use unicode_math_class::MathClass;
 
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Content, NativeElement, Packed, Resolve, Show, ShowSet, Smart, StyleChain,
-    Styles, Synthesize,
+    elem, scope, Content, Context, NativeElement, Packed, Resolve, SequenceElem, Show,
+    ShowSet, Smart, StyleChain, StyledElem, Styles, Synthesize,
 };
 use crate::introspection::{Count, Counter, CounterUpdate, Locatable, Locator};
 use crate::layout::{
-    Abs, AlignElem, Alignment, Axes, BlockElem, Em, FixedAlignment, Fragment, Frame,
-    InlineElem, InlineItem, OuterHAlignment, Point, Regions, Size, SpecificAlignment,
-    VAlignment,
+    Abs, AlignElem, Alignment, Axes, BlockElem, Dir, Em, FixedAlignment, Fragment, Frame,
+    FrameItem, InlineElem, InlineItem, OuterHAlignment, Point, Regions, Size,
+    SpecificAlignment, VAlignment,
 };
 use crate::math::{
-    scaled_font_size, LayoutMath, MathContext, MathRunFrameBuilder, MathSize, MathVariant,
+    scaled_font_size, LayoutMath, MathContext, MathRunFrameBuilder, MathSize,
+    MathSpacing, MathVariant,
 };
-use crate::model::{Numbering, Outlinable, ParElem, Refable, Supplement};
+use crate::model::{HeadingElem, Numbering, Outlinable, ParElem, Refable, Supplement};
 use crate::syntax::Span;
 use crate::text::{
-    families, variant, Font, FontFamily, FontList, FontWeight, LocalName, TextElem,
+    families, variant, Font, FontFamily, FontList, FontWeight, LinebreakElem, LocalName,
+    TextElem,
 };
 use crate::utils::{NonZeroExt, Numeric};
+use crate::visualize::{Geometry, PathItem};
 use crate::World;
 
 /// A mathematical equation.
@@ -49,7 +53,7 @@ use crate::World;
 /// horizontally. For more details about math syntax, see the
 /// [main math page]($category/math).
 #[elem(
-    Locatable, Synthesize, Show, ShowSet, LayoutMath, Count, LocalName, Refable,
+    scope, Locatable, Synthesize, Show, ShowSet, LayoutMath, Count, LocalName, Refable,
     Outlinable
 )]
 pub struct EquationElem {
@@ -88,6 +92,87 @@ pub struct EquationElem {
     #[default(SpecificAlignment::Both(OuterHAlignment::End, VAlignment::Horizon))]
     pub number_align: SpecificAlignment<OuterHAlignment, VAlignment>,
 
+    /// The heading level within which equations are numbered.
+    ///
+    /// If set, the equation counter restarts at each numbered heading of this
+    /// level or above and its numbers are prefixed with the heading's numbers
+    /// up to that level. The numbering pattern should thus have one counting
+    /// symbol more than the level. Equations before the first such heading
+    /// are numbered as if in a section zero.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #set math.equation(numbering: "(1.1)", number-within: 1)
+    ///
+    /// = Kinematics
+    /// $ v = s / t $
+    /// $ a = v / t $
+    ///
+    /// = Dynamics
+    /// $ F = m a $
+    /// ```
+    pub number_within: Option<NonZeroUsize>,
+
+    /// Whether to number each line of a block-level equation on its own.
+    ///
+    /// The numbering then receives the equation's numbers followed by the
+    /// line's number, so that a pattern like `{"(1a)"}` numbers the lines of
+    /// the third equation as (3a), (3b), and so on. References to the
+    /// equation itself show its number without a line. To reference a single
+    /// line, place a labelled [line marker]($math.equation.line) in it.
+    ///
+    /// ```example
+    /// #set math.equation(numbering: "(1a)", sub-numbering: true)
+    ///
+    /// $ (a + b)^2 &= (a + b)(a + b) \
+    ///   &= a^2 + 2 a b + b^2 #[#math.equation.line()<expanded>] $
+    ///
+    /// The square is expanded in @expanded.
+    /// ```
+    #[default(false)]
+    pub sub_numbering: bool,
+
+    /// The automatic spacing around operators.
+    ///
+    /// Currently, the following spacings can be customized:
+    /// - `relation`: around relations like `=` and `<`, a thick space by
+    ///   default
+    /// - `binary`: around binary operators like `+`, a medium space by default
+    /// - `large`: around large operators like `sum`, a thin space by default
+    /// - `punctuation`: after punctuation like `,`, a thin space by default
+    ///
+    /// Spacings are best given in `em`, so that they shrink along with the
+    /// font size in scripts and fractions. As usual, no spacing is inserted
+    /// around relations and binary operators in scripts.
+    ///
+    /// ```example
+    /// $ a = b + c $
+    ///
+    /// #set math.equation(spacing: (relation: 0.5em))
+    /// $ a = b + c $
+    /// ```
+    #[fold]
+    pub spacing: MathSpacing,
+
+    /// Whether to mirror the equation in right-to-left text.
+    ///
+    /// Equations are laid out from left to right regardless of the text
+    /// direction by default. If this is enabled and the text direction is
+    /// right-to-left, the layout is mirrored instead, as is common in Arabic
+    /// mathematical notation. Characters with a mirrored counterpart, like
+    /// parentheses, relations, and arrows, are replaced by it. Other glyphs
+    /// and any text stay readable.
+    ///
+    /// ```example
+    /// #set text(lang: "ar")
+    /// #set math.equation(mirror: true)
+    ///
+    /// $ 0 -> 1 -> 2 $
+    /// $ س(ص) <= 1 / 2 $
+    /// ```
+    #[default(false)]
+    pub mirror: bool,
+
     /// A supplement for the equation.
     ///
     /// For references to equations, this is added before the referenced number.
@@ -144,6 +229,12 @@ pub struct EquationElem {
     pub class: Option<MathClass>,
 }
 
+#[scope]
+impl EquationElem {
+    #[elem]
+    type EquationLineElem;
+}
+
 impl Synthesize for Packed<EquationElem> {
     fn synthesize(
         &mut self,
@@ -159,10 +250,50 @@ impl Synthesize for Packed<EquationElem> {
         };
 
         self.push_supplement(Smart::Custom(Some(Supplement::Content(supplement))));
+
+        // Tell each line marker in which row of the equation it is placed.
+        if let Some(body) = mark_rows(&self.body, &mut 0) {
+            self.body = body;
+        }
+
         Ok(())
     }
 }
 
+/// Tells each line marker in the content in which row of the equation it is
+/// placed. Like math layout, this looks through sequences and styled content
+/// for linebreaks.
+///
+/// Returns the marked content or `None` if it contains no line markers.
+fn mark_rows(content: &Content, row: &mut usize) -> Option<Content> {
+    if content.is::<LinebreakElem>() {
+        *row += 1;
+        None
+    } else if let Some(line) = content.to_packed::<EquationLineElem>() {
+        let mut line = line.clone();
+        line.push_row(*row);
+        Some(line.pack())
+    } else if let Some(sequence) = content.to_packed::<SequenceElem>() {
+        let mut marked = false;
+        let mut children = vec![];
+        for child in sequence.children() {
+            match mark_rows(child, row) {
+                Some(child) => {
+                    marked = true;
+                    children.push(child);
+                }
+                None => children.push(child.clone()),
+            }
+        }
+        marked.then(|| Content::sequence(children).spanned(content.span()))
+    } else if let Some(styled) = content.to_packed::<StyledElem>() {
+        mark_rows(styled.child(), row)
+            .map(|child| child.styled_with_map(styled.styles().clone()))
+    } else {
+        None
+    }
+}
+
 impl Show for Packed<EquationElem> {
     fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         if self.block(styles) {
@@ -187,6 +318,15 @@ impl ShowSet for Packed<EquationElem> {
         } else {
             out.set(EquationElem::set_size(MathSize::Text));
         }
+        if self.block(styles) && self.sub_numbering(styles) {
+            out.set(EquationLineElem::set_equation_numbering(
+                (**self).numbering(styles).clone(),
+            ));
+        }
+        out.set(EquationLineElem::set_equation_supplement(Some(Refable::supplement(
+            self,
+        ))));
+        out.set(EquationElem::set_mirror(self.mirror(styles)));
         out.set(TextElem::set_weight(FontWeight::from_number(450)));
         out.set(TextElem::set_font(FontList(vec![FontFamily::new(
             "New Computer Modern Math",
@@ -197,8 +337,17 @@ impl ShowSet for Packed<EquationElem> {
 
 impl Count for Packed<EquationElem> {
     fn update(&self) -> Option<CounterUpdate> {
-        (self.block(StyleChain::default()) && self.numbering().is_some())
-            .then(|| CounterUpdate::Step(NonZeroUsize::ONE))
+        let default = StyleChain::default();
+        (self.block(default) && self.numbering().is_some()).then(|| {
+            match self.number_within(default) {
+                // Equations numbered within sections take the section's numbers
+                // and restart when they change.
+                Some(level) => {
+                    CounterUpdate::Within(Counter::of(HeadingElem::elem()), level)
+                }
+                None => CounterUpdate::Step(NonZeroUsize::ONE),
+            }
+        })
     }
 }
 
@@ -281,7 +430,10 @@ fn layout_equation_inline(
     let mut ctx = MathContext::new(engine, locator, styles, region, &font);
     let run = ctx.layout_into_run(elem, styles)?;
 
-    let mut items = if run.row_count() == 1 {
+    // A mirrored equation can't be broken across lines, as the paragraph
+    // would arrange its pieces from left to right.
+    let mirrored = is_mirrored(styles);
+    let mut items = if run.row_count() == 1 && !mirrored {
         run.into_par_items()
     } else {
         vec![InlineItem::Frame(run.into_fragment(&ctx, styles).into_frame())]
@@ -295,6 +447,9 @@ fn layout_equation_inline(
 
     for item in &mut items {
         let InlineItem::Frame(frame) = item else { continue };
+        if mirrored {
+            *frame = mirror_frame(frame);
+        }
 
         let font_size = scaled_font_size(&ctx, styles);
         let slack = ParElem::leading_in(styles) * 0.7;
@@ -328,11 +483,18 @@ fn layout_equation_block(
     let mut locator = locator.split();
     let mut ctx =
         MathContext::new(engine, locator.next(&()), styles, regions.base(), &font);
-    let full_equation_builder = ctx
+    let mut full_equation_builder = ctx
         .layout_into_run(elem, styles)?
         .multiline_frame_builder(&ctx, styles);
     let width = full_equation_builder.size.x;
 
+    if is_mirrored(styles) {
+        for (row, pos) in &mut full_equation_builder.frames {
+            *row = mirror_frame(row);
+            pos.x = width - pos.x - row.width();
+        }
+    }
+
     let equation_builders = if BlockElem::breakable_in(styles) {
         let mut rows = full_equation_builder.frames.into_iter().peekable();
         let mut equation_builders = vec![];
@@ -399,15 +561,40 @@ fn layout_equation_block(
         return Ok(Fragment::frames(frames));
     };
 
+    // With sub-numbering, each row gets the equation's numbers followed by
+    // its own.
+    let loc = elem.location().unwrap();
+    let counter = Counter::of(EquationElem::elem());
+    let sub_numbering = elem.sub_numbering(styles);
+    let numbers = if sub_numbering {
+        let rows = equation_builders.iter().map(|builder| builder.frames.len()).sum();
+        let state = counter.at_loc(engine, loc)?;
+        let context = Context::new(Some(loc), Some(styles));
+        (1..=rows)
+            .map(|row| {
+                let mut state = state.clone();
+                state.0.push(row);
+                Ok(state.display(engine, context.track(), numbering)?.display())
+            })
+            .collect::<SourceResult<Vec<_>>>()?
+    } else {
+        vec![counter.display_at_loc(engine, loc, styles, numbering)?]
+    };
+
     let pod = Regions::one(regions.base(), Axes::splat(false));
-    let number = Counter::of(EquationElem::elem())
-        .display_at_loc(engine, elem.location().unwrap(), styles, numbering)?
-        .spanned(span)
-        .layout(engine, locator.next(&()), styles, pod)?
-        .into_frame();
+    let numbers = numbers
+        .into_iter()
+        .map(|number| {
+            Ok(number
+                .spanned(span)
+                .layout(engine, locator.next(&()), styles, pod)?
+                .into_frame())
+        })
+        .collect::<SourceResult<Vec<_>>>()?;
 
     static NUMBER_GUTTER: Em = Em::new(0.5);
-    let full_number_width = number.width() + NUMBER_GUTTER.resolve(styles);
+    let full_number_width = numbers.iter().map(Frame::width).max().unwrap_or_default()
+        + NUMBER_GUTTER.resolve(styles);
 
     let number_align = match elem.number_align(styles) {
         SpecificAlignment::H(h) => SpecificAlignment::Both(h, VAlignment::Horizon),
@@ -416,23 +603,102 @@ fn layout_equation_block(
     };
 
     // Add equation numbers to each equation region.
+    let number_align = number_align.resolve(styles);
+    let equation_align = AlignElem::alignment_in(styles).resolve(styles).x;
+    let mut sub_numbers = numbers.iter().cloned();
     let frames = equation_builders
         .into_iter()
         .map(|builder| {
-            add_equation_number(
-                builder,
-                number.clone(),
-                number_align.resolve(styles),
-                AlignElem::alignment_in(styles).resolve(styles).x,
-                regions.size.x,
-                full_number_width,
-            )
+            if sub_numbering {
+                add_sub_equation_numbers(
+                    builder,
+                    &mut sub_numbers,
+                    number_align,
+                    equation_align,
+                    regions.size.x,
+                    full_number_width,
+                )
+            } else {
+                add_equation_number(
+                    builder,
+                    numbers[0].clone(),
+                    number_align,
+                    equation_align,
+                    regions.size.x,
+                    full_number_width,
+                )
+            }
         })
         .collect();
 
     Ok(Fragment::frames(frames))
 }
 
+/// Whether math is mirrored in the given styles.
+pub(super) fn is_mirrored(styles: StyleChain) -> bool {
+    EquationElem::mirror_in(styles) && TextElem::dir_in(styles) == Dir::RTL
+}
+
+/// Mirror a frame horizontally, keeping its text and images readable.
+///
+/// Transformed or clipped groups are moved as a whole.
+fn mirror_frame(frame: &Frame) -> Frame {
+    let width = frame.width();
+    let mut mirrored = Frame::new(frame.size(), frame.kind());
+    if frame.has_baseline() {
+        mirrored.set_baseline(frame.baseline());
+    }
+
+    for (pos, item) in frame.items() {
+        let (x, item) = match item {
+            FrameItem::Group(group)
+                if group.transform.is_identity() && group.clip_path.is_none() =>
+            {
+                let mut group = group.clone();
+                group.frame = mirror_frame(&group.frame);
+                (width - pos.x - group.frame.width(), FrameItem::Group(group))
+            }
+            FrameItem::Group(group) => {
+                (width - pos.x - group.frame.width(), item.clone())
+            }
+            FrameItem::Text(text) => (width - pos.x - text.width(), item.clone()),
+            FrameItem::Shape(shape, span) => {
+                let mut shape = shape.clone();
+                let x = match &mut shape.geometry {
+                    Geometry::Line(delta) => {
+                        delta.x = -delta.x;
+                        width - pos.x
+                    }
+                    Geometry::Rect(size) => width - pos.x - size.x,
+                    Geometry::Path(path) => {
+                        let flip = |p: &mut Point| p.x = -p.x;
+                        for item in &mut path.0 {
+                            match item {
+                                PathItem::MoveTo(p) | PathItem::LineTo(p) => flip(p),
+                                PathItem::CubicTo(a, b, c) => {
+                                    flip(a);
+                                    flip(b);
+                                    flip(c);
+                                }
+                                PathItem::ClosePath => {}
+                            }
+                        }
+                        width - pos.x
+                    }
+                };
+                (x, FrameItem::Shape(shape, *span))
+            }
+            FrameItem::Image(_, size, _) | FrameItem::Link(_, size) => {
+                (width - pos.x - size.x, item.clone())
+            }
+            FrameItem::Tag(_) => (width - pos.x, item.clone()),
+        };
+        mirrored.push(Point::new(x, pos.y), item);
+    }
+
+    mirrored
+}
+
 fn find_math_font(
     engine: &mut Engine<'_>,
     styles: StyleChain,
@@ -517,6 +783,50 @@ fn add_equation_number(
     equation
 }
 
+/// Add a number to each row of an equation, treating every row like an
+/// equation of its own and keeping the original gaps between them.
+fn add_sub_equation_numbers(
+    equation_builder: MathRunFrameBuilder,
+    numbers: &mut impl Iterator<Item = Frame>,
+    number_align: Axes<FixedAlignment>,
+    equation_align: FixedAlignment,
+    region_size_x: Abs,
+    full_number_width: Abs,
+) -> Frame {
+    let width = equation_builder.size.x;
+    let mut rows = vec![];
+    let mut y = Abs::zero();
+    let mut prev_bottom = Abs::zero();
+    for ((sub, pos), number) in equation_builder.frames.into_iter().zip(numbers) {
+        y += pos.y - prev_bottom;
+        prev_bottom = pos.y + sub.height();
+
+        let row = MathRunFrameBuilder {
+            size: Size::new(width, sub.height()),
+            frames: vec![(sub, Point::with_x(pos.x))],
+        };
+        let row = add_equation_number(
+            row,
+            number,
+            number_align,
+            equation_align,
+            region_size_x,
+            full_number_width,
+        );
+
+        let height = row.height();
+        rows.push((Point::with_y(y), row));
+        y += height;
+    }
+
+    let width = rows.first().map_or(width, |(_, row)| row.width());
+    let mut equation = Frame::soft(Size::new(width, y));
+    for (pos, row) in rows {
+        equation.push_frame(pos, row);
+    }
+    equation
+}
+
 /// Resize the equation's frame accordingly so that it emcompasses the number.
 fn resize_equation(
     equation: &mut Frame,
@@ -562,3 +872,93 @@ fn resize_equation(
     equation.translate(Point::with_y(excess_above));
     resizing_offset + Point::with_y(excess_above)
 }
+
+/// A referenceable line of a sub-numbered equation.
+///
+/// Place a labelled line marker anywhere in a line of an equation with
+/// [sub-numbering]($math.equation.sub-numbering) to reference that line's
+/// number. The marker itself is invisible.
+///
+/// ```example
+/// #set math.equation(numbering: "(1a)", sub-numbering: true)
+///
+/// $ a &= b #[#math.equation.line()<first>] \
+///     &= c $
+///
+/// See @first.
+/// ```
+#[elem(
+    name = "line",
+    title = "Equation Line",
+    Locatable,
+    Synthesize,
+    Show,
+    Count,
+    Refable
+)]
+pub struct EquationLineElem {
+    /// The numbering of the equation this line is placed in, if the equation
+    /// is sub-numbered.
+    #[internal]
+    #[ghost]
+    pub equation_numbering: Option<Numbering>,
+
+    /// The supplement of the equation this line is placed in.
+    #[internal]
+    #[ghost]
+    pub equation_supplement: Option<Content>,
+
+    /// The index of the row of the equation this line is placed in.
+    #[internal]
+    #[synthesized]
+    pub row: usize,
+
+    /// The numbering used when referencing the line.
+    #[internal]
+    #[synthesized]
+    pub numbering: Option<Numbering>,
+
+    /// The supplement used when referencing the line.
+    #[internal]
+    #[synthesized]
+    pub supplement: Content,
+}
+
+impl Synthesize for Packed<EquationLineElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        let elem = self.as_mut();
+        elem.push_numbering(EquationLineElem::equation_numbering_in(styles));
+        elem.push_supplement(
+            EquationLineElem::equation_supplement_in(styles).unwrap_or_default(),
+        );
+        Ok(())
+    }
+}
+
+impl Show for Packed<EquationLineElem> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(Content::empty())
+    }
+}
+
+impl Count for Packed<EquationLineElem> {
+    fn update(&self) -> Option<CounterUpdate> {
+        // Lines extend their equation's numbers by the number of their row.
+        let row = self.row().copied().unwrap_or(0);
+        Some(CounterUpdate::Extend(Counter::of(EquationElem::elem()), row + 1))
+    }
+}
+
+impl Refable for Packed<EquationLineElem> {
+    fn supplement(&self) -> Content {
+        (**self).supplement().cloned().unwrap_or_default()
+    }
+
+    fn counter(&self) -> Counter {
+        Counter::of(EquationLineElem::elem())
+    }
+
+    fn numbering(&self) -> Option<&Numbering> {
+        (**self).numbering().and_then(Option::as_ref)
+    }
+}
@@ -10,7 +10,7 @@ use crate::layout::{
     Abs, Corner, Em, Frame, FrameItem, HideElem, Point, Size, VAlignment,
 };
 use crate::math::{
-    scaled_font_size, EquationElem, Limits, MathContext, MathSize, Scaled,
+    is_mirrored, scaled_font_size, EquationElem, Limits, MathContext, MathSize, Scaled,
 };
 use crate::model::{Destination, LinkElem};
 use crate::syntax::Span;
@@ -227,6 +227,7 @@ pub struct GlyphFragment {
 
 impl GlyphFragment {
     pub fn new(ctx: &MathContext, styles: StyleChain, c: char, span: Span) -> Self {
+        let c = mirror_char(styles, c);
         let id = ctx.ttf.glyph_index(c).unwrap_or_default();
         let id = Self::adjust_glyph_index(ctx, id);
         Self::with_id(ctx, styles, c, id, span)
@@ -238,6 +239,7 @@ impl GlyphFragment {
         c: char,
         span: Span,
     ) -> Option<Self> {
+        let c = mirror_char(styles, c);
         let id = ctx.ttf.glyph_index(c)?;
         let id = Self::adjust_glyph_index(ctx, id);
         Some(Self::with_id(ctx, styles, c, id, span))
@@ -564,3 +566,47 @@ fn kern_at_height(
 
     Some(kern.kern(i)?.scaled(ctx, font_size))
 }
+
+/// Replace a character by its mirrored counterpart in mirrored math.
+fn mirror_char(styles: StyleChain, c: char) -> char {
+    if !is_mirrored(styles) {
+        return c;
+    }
+
+    const PAIRS: &[(char, char)] = &[
+        ('(', ')'),
+        ('[', ']'),
+        ('{', '}'),
+        ('<', '>'),
+        ('⟨', '⟩'),
+        ('⌈', '⌉'),
+        ('⌊', '⌋'),
+        ('≤', '≥'),
+        ('≪', '≫'),
+        ('≺', '≻'),
+        ('⊂', '⊃'),
+        ('⊆', '⊇'),
+        ('∈', '∋'),
+        ('∉', '∌'),
+        ('⊲', '⊳'),
+        ('←', '→'),
+        ('⇐', '⇒'),
+        ('⟵', '⟶'),
+        ('⟸', '⟹'),
+        ('↤', '↦'),
+        ('↼', '⇀'),
+    ];
+
+    PAIRS
+        .iter()
+        .find_map(|&(a, b)| {
+            if c == a {
+                Some(b)
+            } else if c == b {
+                Some(a)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(c)
+}
@@ -7,7 +7,8 @@ use crate::foundations::{
     Smart, StyleChain, Value,
 };
 use crate::layout::{
-    Abs, Axes, Em, FixedAlignment, Frame, FrameItem, Length, Point, Ratio, Rel, Size,
+    Abs, Axes, Em, FixedAlignment, Frame, FrameItem, HAlignment, Length, Point, Ratio,
+    Rel, Size,
 };
 use crate::math::{
     alignments, scaled_font_size, stack, style_for_denominator, AlignmentResult,
@@ -177,6 +178,19 @@ pub struct MatElem {
     #[default(DEFAULT_COL_GAP.into())]
     pub column_gap: Rel<Length>,
 
+    /// The horizontal alignment of the columns.
+    ///
+    /// Either a single alignment for all columns or an array with one
+    /// alignment per column. If there are more columns than alignments, the
+    /// alignments are cycled through. Cells that contain alignment points
+    /// (`&`) are aligned by them instead.
+    ///
+    /// ```example
+    /// $ mat(1, 10, 100; 100, 10, 1; align: #(right, left)) $
+    /// ```
+    #[default(ColumnAlignments(smallvec![HAlignment::Center]))]
+    pub align: ColumnAlignments,
+
     /// An array of arrays with the rows of the matrix.
     ///
     /// ```example
@@ -251,6 +265,7 @@ impl LayoutMath for Packed<MatElem> {
             rows,
             augment,
             Axes::new(self.column_gap(styles), self.row_gap(styles)),
+            &self.align(styles),
             self.span(),
         )?;
 
@@ -301,6 +316,19 @@ pub struct CasesElem {
     #[default(DEFAULT_ROW_GAP.into())]
     pub gap: Rel<Length>,
 
+    /// The horizontal alignment of the branches.
+    ///
+    /// Like the [matrix's alignment]($math.mat.align), this can be a single
+    /// alignment or an array. As a case distinction has a single column, only
+    /// the first alignment of an array applies.
+    ///
+    /// ```example
+    /// #set math.cases(align: right)
+    /// $ x = cases(1, 100) $
+    /// ```
+    #[default(ColumnAlignments(smallvec![HAlignment::Start]))]
+    pub align: ColumnAlignments,
+
     /// The branches of the case distinction.
     #[variadic]
     pub children: Vec<Content>,
@@ -314,7 +342,7 @@ impl LayoutMath for Packed<CasesElem> {
             ctx,
             styles,
             self.children(),
-            FixedAlignment::Start,
+            self.align(styles).get(0).resolve(styles),
             self.gap(styles),
             LeftRightAlternator::None,
         )?;
@@ -456,6 +484,7 @@ fn layout_mat_body(
     rows: &[Vec<Content>],
     augment: Option<Augment<Abs>>,
     gap: Axes<Rel<Abs>>,
+    align: &ColumnAlignments,
     span: Span,
 ) -> SourceResult<Frame> {
     let gap = gap.zip_map(ctx.regions.base(), Rel::relative_to);
@@ -532,13 +561,18 @@ fn layout_mat_body(
 
     for (index, col) in cols.into_iter().enumerate() {
         let AlignmentResult { points, width: rcol } = alignments(&col);
+        let align = align.get(index).resolve(styles);
 
         let mut y = Abs::zero();
 
         for (cell, &(ascent, descent)) in col.into_iter().zip(&heights) {
             let cell = cell.into_line_frame(&points, LeftRightAlternator::Right);
             let pos = Point::new(
-                if points.is_empty() { x + (rcol - cell.width()) / 2.0 } else { x },
+                if points.is_empty() {
+                    x + align.position(rcol - cell.width())
+                } else {
+                    x
+                },
                 y + ascent - cell.ascent(),
             );
 
@@ -718,6 +752,35 @@ cast! {
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct AugmentOffsets(SmallVec<[isize; 1]>);
 
+/// The horizontal alignments of a matrix's columns.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ColumnAlignments(SmallVec<[HAlignment; 1]>);
+
+impl ColumnAlignments {
+    /// The alignment of the column with the given index, cycling through the
+    /// alignments if there are fewer than columns.
+    fn get(&self, index: usize) -> HAlignment {
+        self.0[index % self.0.len()]
+    }
+}
+
+cast! {
+    ColumnAlignments,
+    self => match self.0.as_slice() {
+        &[align] => align.into_value(),
+        _ => self.0.into_value(),
+    },
+    v: HAlignment => Self(smallvec![v]),
+    v: Array => {
+        let aligns: SmallVec<_> =
+            v.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?;
+        if aligns.is_empty() {
+            bail!("alignment array must not be empty");
+        }
+        Self(aligns)
+    },
+}
+
 cast! {
     AugmentOffsets,
     self => self.0.into_value(),
@@ -34,6 +34,7 @@ pub use self::lr::*;
 pub use self::matrix::*;
 pub use self::op::*;
 pub use self::root::*;
+pub use self::spacing::MathSpacing;
 pub use self::style::*;
 pub use self::underover::*;
 
@@ -20,7 +20,8 @@ pub struct MathRun(Vec<MathFragment>);
 
 impl MathRun {
     /// Takes the given [`MathFragment`]s and do some basic processing.
-    pub fn new(fragments: Vec<MathFragment>) -> Self {
+    pub fn new(fragments: Vec<MathFragment>, styles: StyleChain) -> Self {
+        let amounts = EquationElem::spacing_in(styles);
         let iter = fragments.into_iter().peekable();
         let mut last: Option<usize> = None;
         let mut space: Option<MathFragment> = None;
@@ -79,7 +80,8 @@ impl MathRun {
 
             // Insert spacing between the last and this item.
             if let Some(i) = last {
-                if let Some(s) = spacing(&resolved[i], space.take(), &fragment) {
+                if let Some(s) = spacing(&resolved[i], space.take(), &fragment, &amounts)
+                {
                     resolved.insert(i + 1, s);
                 }
             }
@@ -1,7 +1,7 @@
 use unicode_math_class::MathClass;
 
-use crate::foundations::{NativeElement, Scope};
-use crate::layout::{Abs, Em, HElem};
+use crate::foundations::{cast, dict, Dict, Fold, NativeElement, Scope};
+use crate::layout::{Abs, Em, HElem, Length};
 use crate::math::{MathFragment, MathSize, SpacingFragment};
 
 pub(super) const THIN: Em = Em::new(1.0 / 6.0);
@@ -19,15 +19,82 @@ pub(super) fn define(math: &mut Scope) {
     math.define("wide", HElem::new(WIDE.into()).pack());
 }
 
+/// The automatic spacing around operators of different classes.
+///
+/// Spacings are updated (prioritizing the later value) when folded.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct MathSpacing {
+    relation: Option<Length>,
+    binary: Option<Length>,
+    large: Option<Length>,
+    punctuation: Option<Length>,
+}
+
+impl MathSpacing {
+    #[must_use]
+    pub fn relation(&self) -> Length {
+        self.relation.unwrap_or(THICK.into())
+    }
+
+    #[must_use]
+    pub fn binary(&self) -> Length {
+        self.binary.unwrap_or(MEDIUM.into())
+    }
+
+    #[must_use]
+    pub fn large(&self) -> Length {
+        self.large.unwrap_or(THIN.into())
+    }
+
+    #[must_use]
+    pub fn punctuation(&self) -> Length {
+        self.punctuation.unwrap_or(THIN.into())
+    }
+}
+
+impl Fold for MathSpacing {
+    #[inline]
+    fn fold(self, outer: Self) -> Self {
+        Self {
+            relation: self.relation.or(outer.relation),
+            binary: self.binary.or(outer.binary),
+            large: self.large.or(outer.large),
+            punctuation: self.punctuation.or(outer.punctuation),
+        }
+    }
+}
+
+cast! {
+    MathSpacing,
+    self => dict![
+        "relation" => self.relation(),
+        "binary" => self.binary(),
+        "large" => self.large(),
+        "punctuation" => self.punctuation(),
+    ].into_value(),
+    mut v: Dict => {
+        let ret = Self {
+            relation: v.take("relation").ok().map(|v| v.cast()).transpose()?,
+            binary: v.take("binary").ok().map(|v| v.cast()).transpose()?,
+            large: v.take("large").ok().map(|v| v.cast()).transpose()?,
+            punctuation: v.take("punctuation").ok().map(|v| v.cast()).transpose()?,
+        };
+        v.finish(&["relation", "binary", "large", "punctuation"])?;
+        ret
+    },
+}
+
 /// Create the spacing between two fragments in a given style.
 pub(super) fn spacing(
     l: &MathFragment,
     space: Option<MathFragment>,
     r: &MathFragment,
+    amounts: &MathSpacing,
 ) -> Option<MathFragment> {
     use MathClass::*;
 
-    let resolve = |v: Em, size_ref: &MathFragment| -> Option<MathFragment> {
+    let resolve = |v: Length, size_ref: &MathFragment| -> Option<MathFragment> {
         let width = size_ref.font_size().map_or(Abs::zero(), |size| v.at(size));
         Some(SpacingFragment { width, weak: false }.into())
     };
@@ -37,7 +104,7 @@ pub(super) fn spacing(
         // No spacing before punctuation; thin spacing after punctuation, unless
         // in script size.
         (_, Punctuation) => None,
-        (Punctuation, _) if !script(l) => resolve(THIN, l),
+        (Punctuation, _) if !script(l) => resolve(amounts.punctuation(), l),
 
         // No spacing after opening delimiters and before closing delimiters.
         (Opening, _) | (_, Closing) => None,
@@ -45,18 +112,18 @@ pub(super) fn spacing(
         // Thick spacing around relations, unless followed by a another relation
         // or in script size.
         (Relation, Relation) => None,
-        (Relation, _) if !script(l) => resolve(THICK, l),
-        (_, Relation) if !script(r) => resolve(THICK, r),
+        (Relation, _) if !script(l) => resolve(amounts.relation(), l),
+        (_, Relation) if !script(r) => resolve(amounts.relation(), r),
 
         // Medium spacing around binary operators, unless in script size.
-        (Binary, _) if !script(l) => resolve(MEDIUM, l),
-        (_, Binary) if !script(r) => resolve(MEDIUM, r),
+        (Binary, _) if !script(l) => resolve(amounts.binary(), l),
+        (_, Binary) if !script(r) => resolve(amounts.binary(), r),
 
         // Thin spacing around large operators, unless to the left of
         // an opening delimiter. TeXBook, p170
         (Large, Opening | Fence) => None,
-        (Large, _) => resolve(THIN, l),
-        (_, Large) => resolve(THIN, r),
+        (Large, _) => resolve(amounts.large(), l),
+        (_, Large) => resolve(amounts.large(), r),
 
         // Spacing around spaced frames.
         _ if (l.is_spaced() || r.is_spaced()) => space,
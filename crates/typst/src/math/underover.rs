@@ -267,7 +267,7 @@ fn layout_underoverspreader(
     let glyph = GlyphFragment::new(ctx, styles, c, span);
     let stretched = glyph.stretch_horizontal(ctx, body.width(), Abs::zero());
 
-    let mut rows = vec![MathRun::new(vec![body]), stretched.into()];
+    let mut rows = vec![MathRun::new(vec![body], styles), stretched.into()];
 
     let (sup_style, sub_style);
     let row_styles = if reverse {
@@ -338,7 +338,7 @@ pub(super) fn stack(
     for (i, row) in rows.into_iter().enumerate() {
         let x = align.position(width - row.width());
         let ascent_padded_part = minimum_ascent_descent
-            .map_or(Abs::zero(), |(a, _)| (a - row.ascent()))
+            .map_or(Abs::zero(), |(a, _)| a - row.ascent())
             .max(Abs::zero());
         let pos = Point::new(x, y + ascent_padded_part);
         if i == baseline {
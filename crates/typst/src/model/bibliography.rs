@@ -6,10 +6,11 @@ use std::num::NonZeroUsize;
 use std::path::Path;
 use std::sync::Arc;
 
-use comemo::Tracked;
+use comemo::{Track, Tracked};
 use ecow::{eco_format, EcoString, EcoVec};
 use hayagriva::archive::ArchivedStyle;
 use hayagriva::io::BibLaTeXError;
+use hayagriva::types::EntryType;
 use hayagriva::{
     citationberg, BibliographyDriver, BibliographyRequest, CitationItem, CitationRequest,
     SpecificLocator,
@@ -23,9 +24,9 @@ use crate::diag::{bail, error, At, FileError, HintedStrResult, SourceResult, Str
 use crate::engine::Engine;
 use crate::eval::{eval_string, EvalMode};
 use crate::foundations::{
-    cast, elem, ty, Args, Array, Bytes, CastInfo, Content, FromValue, IntoValue, Label,
-    NativeElement, Packed, Reflect, Repr, Scope, Show, ShowSet, Smart, Str, StyleChain,
-    Styles, Synthesize, Type, Value,
+    cast, dict, elem, ty, Args, Array, Bytes, CastInfo, Content, Context, Dict,
+    FromValue, Func, IntoValue, Label, NativeElement, Packed, Reflect, Repr, Scope, Show,
+    ShowSet, Smart, Str, StyleChain, Styles, Synthesize, Type, Value,
 };
 use crate::introspection::{Introspector, Locatable, Location};
 use crate::layout::{
@@ -125,6 +126,40 @@ pub struct BibliographyElem {
     #[default(CslStyle::from_name("ieee").unwrap())]
     pub style: CslStyle,
 
+    /// A function that formats each reference in the bibliography, replacing
+    /// the output of the [style]($bibliography.style).
+    ///
+    /// The function receives a dictionary with the entry's fields and must
+    /// return content. The dictionary contains the keys `key`, `type`,
+    /// `title`, `authors`, `year`, `parent`, `url`, and `prefix`. Authors are
+    /// given as an array of dictionaries with the keys `given` and `family`.
+    /// The `prefix` is the label the style puts in front of the reference,
+    /// like `[1]` for numeric styles, or `{none}` if it has none. Fields
+    /// that the entry doesn't specify are `{none}`. The references are still
+    /// ordered according to the style.
+    ///
+    /// The `type` is one of `{"article"}`, `{"chapter"}`, `{"entry"}`,
+    /// `{"anthos"}`, `{"report"}`, `{"thesis"}`, `{"web"}`, `{"scene"}`,
+    /// `{"artwork"}`, `{"patent"}`, `{"case"}`, `{"newspaper"}`,
+    /// `{"legislation"}`, `{"manuscript"}`, `{"original"}`, `{"post"}`,
+    /// `{"misc"}`, `{"performance"}`, `{"periodical"}`, `{"proceedings"}`,
+    /// `{"book"}`, `{"blog"}`, `{"reference"}`, `{"conference"}`,
+    /// `{"anthology"}`, `{"thread"}`, `{"video"}`, `{"audio"}`, or
+    /// `{"exhibition"}`.
+    ///
+    /// ```example
+    /// #bibliography(
+    ///   "works.bib",
+    ///   format: it => [
+    ///     #it.authors.map(a => a.family).join(", "):
+    ///     _#it.title;_ #it.year
+    ///   ],
+    /// )
+    ///
+    /// @netwok, @arrgh
+    /// ```
+    pub format: Option<Func>,
+
     /// The loaded bibliography.
     #[internal]
     #[required]
@@ -231,16 +266,39 @@ impl Show for Packed<BibliographyElem> {
             .ok_or("CSL style is not suitable for bibliographies")
             .at(span)?;
 
+        // Each reference is assigned a manually created well-known location
+        // that is derived from the bibliography's location. This way,
+        // citations can link to them.
+        let location = self.location().unwrap();
+        let backlink = |k: usize| location.variant(k + 1);
+
         let row_gutter = ParElem::spacing_in(styles).into();
-        if references.iter().any(|(prefix, _)| prefix.is_some()) {
+        if let Some(format) = self.format(styles) {
+            let database = self.bibliography();
+            for (k, (key, prefix, _)) in references.iter().enumerate() {
+                let Some(entry) = database.map.get(key) else { continue };
+                let context = Context::new(Some(location), Some(styles));
+                let fields = entry_fields(entry, prefix.clone());
+                let mut reference = format
+                    .call(engine, context.track(), [fields])?
+                    .display()
+                    .spanned(span);
+                reference.set_location(backlink(k));
+
+                seq.push(VElem::new(row_gutter).with_weakness(3).pack());
+                seq.push(reference);
+            }
+        } else if references.iter().any(|(_, prefix, _)| prefix.is_some()) {
             let mut cells = vec![];
-            for (prefix, reference) in references {
+            for (k, (_, prefix, reference)) in references.iter().enumerate() {
+                // Attach the backlink to either the prefix or the reference.
+                let (mut prefix, mut reference) = (prefix.clone(), reference.clone());
+                prefix.as_mut().unwrap_or(&mut reference).set_location(backlink(k));
                 cells.push(GridChild::Item(GridItem::Cell(
-                    Packed::new(GridCell::new(prefix.clone().unwrap_or_default()))
-                        .spanned(span),
+                    Packed::new(GridCell::new(prefix.unwrap_or_default())).spanned(span),
                 )));
                 cells.push(GridChild::Item(GridItem::Cell(
-                    Packed::new(GridCell::new(reference.clone())).spanned(span),
+                    Packed::new(GridCell::new(reference)).spanned(span),
                 )));
             }
 
@@ -254,9 +312,11 @@ impl Show for Packed<BibliographyElem> {
                     .spanned(self.span()),
             );
         } else {
-            for (_, reference) in references {
+            for (k, (_, _, reference)) in references.iter().enumerate() {
+                let mut reference = reference.clone();
+                reference.set_location(backlink(k));
                 seq.push(VElem::new(row_gutter).with_weakness(3).pack());
-                seq.push(reference.clone());
+                seq.push(reference);
             }
         }
 
@@ -541,9 +601,10 @@ impl Repr for CslStyle {
 pub(super) struct Works {
     /// Maps from the location of a citation group to its rendered content.
     pub citations: HashMap<Location, SourceResult<Content>>,
-    /// Lists all references in the bibliography, with optional prefix, or
-    /// `None` if the citation style can't be used for bibliographies.
-    pub references: Option<Vec<(Option<Content>, Content)>>,
+    /// Lists all references in the bibliography, with their key and optional
+    /// prefix, or `None` if the citation style can't be used for
+    /// bibliographies.
+    pub references: Option<Vec<(PicoStr, Option<Content>, Content)>>,
     /// Whether the bibliography should have hanging indent.
     pub hanging_indent: bool,
 }
@@ -686,11 +747,17 @@ impl<'a> Generator<'a> {
                 continue;
             }
 
-            let style = match first.style(StyleChain::default()) {
+            let mut style = match first.style(StyleChain::default()) {
                 Smart::Auto => &bibliography_style.style,
                 Smart::Custom(style) => styles.alloc(style.style),
             };
 
+            if let Smart::Custom(collapse) = first.collapse(StyleChain::default()) {
+                if let Some(adjusted) = with_collapse(style, collapse) {
+                    style = styles.alloc(adjusted);
+                }
+            }
+
             self.infos.push(GroupInfo {
                 location,
                 subinfos,
@@ -796,7 +863,7 @@ impl<'a> Generator<'a> {
     fn display_references(
         &self,
         rendered: &hayagriva::Rendered,
-    ) -> Option<Vec<(Option<Content>, Content)>> {
+    ) -> Option<Vec<(PicoStr, Option<Content>, Content)>> {
         let rendered = rendered.bibliography.as_ref()?;
 
         // Determine for each citation key where it first occurred, so that we
@@ -809,11 +876,8 @@ impl<'a> Generator<'a> {
             }
         }
 
-        // The location of the bibliography.
-        let location = self.bibliography.location().unwrap();
-
         let mut output = vec![];
-        for (k, item) in rendered.items.iter().enumerate() {
+        for item in &rendered.items {
             let renderer = ElemRenderer {
                 world: self.world,
                 span: self.bibliography.span(),
@@ -821,11 +885,6 @@ impl<'a> Generator<'a> {
                 link: &|_| None,
             };
 
-            // Each reference is assigned a manually created well-known location
-            // that is derived from the bibliography's location. This way,
-            // citations can link to them.
-            let backlink = location.variant(k + 1);
-
             // Render the first field.
             let mut prefix = item.first_field.as_ref().map(|elem| {
                 let mut content = renderer.display_elem_child(elem, &mut None);
@@ -837,20 +896,124 @@ impl<'a> Generator<'a> {
             });
 
             // Render the main reference content.
-            let mut reference =
-                renderer.display_elem_children(&item.content, &mut prefix);
-
-            // Attach a backlink to either the prefix or the reference so that
-            // we can link to the bibliography entry.
-            prefix.as_mut().unwrap_or(&mut reference).set_location(backlink);
+            let reference = renderer.display_elem_children(&item.content, &mut prefix);
 
-            output.push((prefix, reference));
+            output.push((PicoStr::new(&item.key), prefix, reference));
         }
 
         Some(output)
     }
 }
 
+/// Collects the fields of a bibliography entry and the prefix the style
+/// rendered for it into a dictionary for custom formatting. Missing fields are
+/// `none`.
+fn entry_fields(entry: &hayagriva::Entry, prefix: Option<Content>) -> Dict {
+    let authors = entry.authors().map(|authors| {
+        authors
+            .iter()
+            .map(|person| {
+                dict! {
+                    "given" => person.given_name.as_deref().map(EcoString::from),
+                    "family" => EcoString::from(person.name.as_str()),
+                }
+                .into_value()
+            })
+            .collect::<Array>()
+    });
+
+    let title = |entry: &hayagriva::Entry| -> Option<EcoString> {
+        entry.title().map(|title| title.value.to_str().into())
+    };
+
+    dict! {
+        "key" => EcoString::from(entry.key()),
+        "type" => entry_type_name(entry.entry_type()),
+        "title" => title(entry),
+        "authors" => authors,
+        "year" => entry.date().map(|date| date.year),
+        "parent" => entry.parents().first().and_then(title),
+        "url" => entry.url().map(|url| EcoString::from(url.value.as_str())),
+        "prefix" => prefix,
+    }
+}
+
+/// The name of an entry type that is exposed to custom formatting.
+fn entry_type_name(kind: &EntryType) -> &'static str {
+    const NAMES: &[(EntryType, &str)] = &[
+        (EntryType::Article, "article"),
+        (EntryType::Chapter, "chapter"),
+        (EntryType::Entry, "entry"),
+        (EntryType::Anthos, "anthos"),
+        (EntryType::Report, "report"),
+        (EntryType::Thesis, "thesis"),
+        (EntryType::Web, "web"),
+        (EntryType::Scene, "scene"),
+        (EntryType::Artwork, "artwork"),
+        (EntryType::Patent, "patent"),
+        (EntryType::Case, "case"),
+        (EntryType::Newspaper, "newspaper"),
+        (EntryType::Legislation, "legislation"),
+        (EntryType::Manuscript, "manuscript"),
+        (EntryType::Original, "original"),
+        (EntryType::Post, "post"),
+        (EntryType::Misc, "misc"),
+        (EntryType::Performance, "performance"),
+        (EntryType::Periodical, "periodical"),
+        (EntryType::Proceedings, "proceedings"),
+        (EntryType::Book, "book"),
+        (EntryType::Blog, "blog"),
+        (EntryType::Reference, "reference"),
+        (EntryType::Conference, "conference"),
+        (EntryType::Anthology, "anthology"),
+        (EntryType::Thread, "thread"),
+        (EntryType::Video, "video"),
+        (EntryType::Audio, "audio"),
+        (EntryType::Exhibition, "exhibition"),
+    ];
+
+    // Types that hayagriva may add in the future are treated like
+    // miscellaneous entries until they get a name of their own.
+    NAMES
+        .iter()
+        .find(|(other, _)| other == kind)
+        .map_or("misc", |&(_, name)| name)
+}
+
+/// Returns a copy of the style with citation number collapsing turned on or
+/// off, or `None` if the style already behaves as requested.
+///
+/// Collapsing is only turned on for numeric styles. This is memoized so that
+/// the style is only copied once and not for each citation group.
+#[comemo::memoize]
+fn with_collapse(
+    style: &Arc<LazyHash<citationberg::IndependentStyle>>,
+    collapse: bool,
+) -> Option<Arc<LazyHash<citationberg::IndependentStyle>>> {
+    let numeric = style.info.category.iter().any(|category| {
+        matches!(
+            category,
+            citationberg::StyleCategory::CitationFormat {
+                format: citationberg::CitationFormat::Numeric,
+                ..
+            }
+        )
+    });
+
+    let target = match style.citation.collapse {
+        _ if !collapse => None,
+        Some(current) => Some(current),
+        None if numeric => Some(citationberg::Collapse::CitationNumber),
+        None => None,
+    };
+
+    (target != style.citation.collapse).then(|| {
+        let mut style = (***style).clone();
+        style.citation.collapse = target;
+        Arc::new(LazyHash::new(style))
+    })
+}
+
 /// Renders hayagriva elements into content.
 struct ElemRenderer<'a> {
     /// The world that is used to evaluate mathematical material.
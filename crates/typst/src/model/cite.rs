@@ -97,6 +97,24 @@ pub struct CiteElem {
     #[parse(CslStyle::parse_smart(engine, args)?)]
     pub style: Smart<CslStyle>,
 
+    /// Whether to collapse consecutive numbers in a group of adjacent
+    /// citations into a range.
+    ///
+    /// This only has an effect for numeric citation styles. Non-consecutive
+    /// numbers are still joined with commas. When `{false}`, each citation in
+    /// the group is listed separately. When `{auto}`, the citation style
+    /// decides whether to collapse.
+    ///
+    /// ```example
+    /// #cite(<netwok>)#cite(<arrgh>)#cite(<quark>) \
+    /// #set cite(collapse: false)
+    /// #cite(<netwok>)#cite(<arrgh>)#cite(<quark>)
+    ///
+    /// >>> #set text(0pt)
+    /// >>> #bibliography("works.bib")
+    /// ```
+    pub collapse: Smart<bool>,
+
     /// The text language setting where the citation is.
     #[internal]
     #[synthesized]
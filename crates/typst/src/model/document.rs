@@ -58,6 +58,14 @@ pub struct DocumentElem {
     #[ghost]
     pub date: Smart<Option<Datetime>>,
 
+    /// The seed for pseudo-random numbers generated with
+    /// [`calc.random`]($calc.random).
+    ///
+    /// The same seed always produces the same numbers, so documents stay
+    /// reproducible. Change it to get a different set of numbers.
+    #[ghost]
+    pub seed: i64,
+
     /// The page runs.
     #[internal]
     #[variadic]
@@ -114,6 +122,7 @@ impl Packed<DocumentElem> {
             keywords: DocumentElem::keywords_in(styles).0,
             date: DocumentElem::date_in(styles),
             introspector: Introspector::default(),
+            content: self.clone().pack().styled_with_map(styles.to_map()),
         })
     }
 }
@@ -155,6 +164,9 @@ pub struct Document {
     pub date: Smart<Option<Datetime>>,
     /// Provides the ability to execute queries on the document.
     pub introspector: Introspector,
+    /// The realized content the pages were laid out from, together with its
+    /// styles. Exporters that don't work with pages start from this.
+    pub content: Content,
 }
 
 #[cfg(test)]
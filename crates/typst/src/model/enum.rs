@@ -291,10 +291,13 @@ fn layout_enum(
         cells.push(Cell::new(Content::empty(), locator.next(&())));
         cells.push(Cell::new(resolved, locator.next(&())));
         cells.push(Cell::new(Content::empty(), locator.next(&())));
-        cells.push(Cell::new(
-            item.body.clone().styled(EnumElem::set_parents(smallvec![number])),
-            locator.next(&item.body.span()),
-        ));
+        cells.push(Cell {
+            structure: Some(item.clone().pack()),
+            ..Cell::new(
+                item.body.clone().styled(EnumElem::set_parents(smallvec![number])),
+                locator.next(&item.body.span()),
+            )
+        });
         number = number.saturating_add(1);
     }
 
@@ -309,8 +312,12 @@ fn layout_enum(
         cells,
     );
     let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
+    let mut fragment = layouter.layout(engine)?;
+    for frame in fragment.iter_mut() {
+        frame.structure(elem.clone().pack());
+    }
 
-    layouter.layout(engine)
+    Ok(fragment)
 }
 
 /// An enumeration item.
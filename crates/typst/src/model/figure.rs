@@ -2,23 +2,24 @@ use std::borrow::Cow;
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 
+use comemo::Track;
 use ecow::EcoString;
 
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, select_where, Content, Element, NativeElement, Packed, Selector,
-    Show, ShowSet, Smart, StyleChain, Styles, Synthesize,
+    cast, elem, scope, select_where, Content, Context, Element, NativeElement, Packed,
+    Selector, Show, ShowSet, Smart, StyleChain, Styles, Synthesize,
 };
 use crate::introspection::{
     Count, Counter, CounterKey, CounterUpdate, Locatable, Location,
 };
 use crate::layout::{
     AlignElem, Alignment, BlockChild, BlockElem, Em, HAlignment, Length, OuterVAlignment,
-    PlaceElem, VAlignment, VElem,
+    PlaceElem, PlacementPreference, VAlignment, VElem,
 };
 use crate::model::{Numbering, NumberingPattern, Outlinable, Refable, Supplement};
-use crate::text::{Lang, Region, TextElem};
+use crate::text::{Lang, Region, SpaceElem, TextElem};
 use crate::utils::NonZeroExt;
 use crate::visualize::ImageElem;
 
@@ -133,9 +134,36 @@ pub struct FigureElem {
     /// ```
     pub placement: Option<Smart<VAlignment>>,
 
+    /// Where a floating figure prefers to be placed.
+    ///
+    /// Has no effect if the figure's `placement` is `{none}`. See the
+    /// [`prefer`]($place.prefer) argument on the `place` function for details.
+    ///
+    /// ```example
+    /// #set page(height: 200pt)
+    ///
+    /// = Introduction
+    /// #lorem(10)
+    /// #figure(
+    ///   placement: auto,
+    ///   prefer: "here",
+    ///   caption: [A glacier],
+    ///   image("glacier.jpg", width: 40%),
+    /// )
+    /// #lorem(20)
+    /// ```
+    pub prefer: PlacementPreference,
+
     /// The figure's caption.
     pub caption: Option<Packed<FigureCaption>>,
 
+    /// A text describing the figure's content for readers who cannot see it.
+    ///
+    /// Exporters that support it attach this text to the figure. If it is
+    /// not given, the [`alt`]($image.alt) text of an image body is used
+    /// instead.
+    pub alt: Option<EcoString>,
+
     /// The kind of figure this is.
     ///
     /// All figures of the same kind share a common counter.
@@ -220,6 +248,9 @@ pub struct FigureElem {
 impl FigureElem {
     #[elem]
     type FigureCaption;
+
+    #[elem]
+    type SubFigureElem;
 }
 
 impl Synthesize for Packed<FigureElem> {
@@ -305,7 +336,14 @@ impl Synthesize for Packed<FigureElem> {
 impl Show for Packed<FigureElem> {
     #[typst_macros::time(name = "figure", span = self.span())]
     fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        let mut realized = self.body().clone();
+        // Let subfigures know which figure they belong to.
+        let mut realized = self
+            .body()
+            .clone()
+            .styled(SubFigureElem::set_figure_kind(self.kind(styles).custom()))
+            .styled(SubFigureElem::set_figure_supplement(Some(Refable::supplement(
+                self,
+            ))));
 
         // Build the caption, if any.
         if let Some(caption) = self.caption(styles) {
@@ -319,6 +357,7 @@ impl Show for Packed<FigureElem> {
         // Wrap the contents in a block.
         realized = BlockElem::new()
             .with_body(Some(BlockChild::Content(realized)))
+            .with_structure(Some(self.clone().pack()))
             .pack()
             .spanned(self.span());
 
@@ -326,6 +365,7 @@ impl Show for Packed<FigureElem> {
         if let Some(align) = self.placement(styles) {
             realized = PlaceElem::new(realized)
                 .with_float(true)
+                .with_prefer(self.prefer(styles))
                 .with_alignment(align.map(|align| HAlignment::Center + align))
                 .pack()
                 .spanned(self.span());
@@ -588,6 +628,168 @@ cast! {
     v: Content => v.unpack::<Self>().unwrap_or_else(Self::new),
 }
 
+/// A part of a composite figure.
+///
+/// Subfigures are placed within the body of a [figure]($figure) and share its
+/// number. Each subfigure is additionally numbered within its figure and can be
+/// referenced on its own.
+///
+/// ```example
+/// #figure(
+///   grid(
+///     columns: 2,
+///     gutter: 1em,
+///     [#figure.subfigure(
+///       rect(fill: aqua),
+///       caption: [Water],
+///     ) <water>],
+///     figure.subfigure(
+///       rect(fill: green),
+///       caption: [Grass],
+///     ),
+///   ),
+///   caption: [Two rectangles],
+/// )
+///
+/// @water shows the water.
+/// ```
+#[elem(name = "subfigure", Locatable, Synthesize, Count, Show, Refable)]
+pub struct SubFigureElem {
+    /// The content of the subfigure.
+    #[required]
+    pub body: Content,
+
+    /// The subfigure's caption.
+    pub caption: Option<Content>,
+
+    /// How to number the subfigure within its figure. Accepts a
+    /// [numbering pattern or function]($numbering), which receives only the
+    /// subfigure's own number.
+    #[default(Some(NumberingPattern::from_str("(a)").unwrap().into()))]
+    #[borrowed]
+    pub numbering: Option<Numbering>,
+
+    /// How to number references to the subfigure. Accepts a
+    /// [numbering pattern or function]($numbering), which receives both the
+    /// figure's and the subfigure's number.
+    ///
+    /// ```example
+    /// #set figure.subfigure(ref-numbering: "1.i")
+    /// #figure(
+    ///   [#figure.subfigure(rect()) <sub>],
+    ///   caption: [A figure],
+    /// )
+    ///
+    /// See @sub.
+    /// ```
+    #[default(NumberingPattern::from_str("1a").unwrap().into())]
+    #[borrowed]
+    pub ref_numbering: Numbering,
+
+    /// The vertical gap between the body and the caption.
+    #[default(Em::new(0.65).into())]
+    pub gap: Length,
+
+    /// The kind of the figure this subfigure is placed in.
+    #[internal]
+    #[ghost]
+    pub figure_kind: Option<FigureKind>,
+
+    /// The supplement of the figure this subfigure is placed in.
+    #[internal]
+    #[ghost]
+    pub figure_supplement: Option<Content>,
+
+    /// The supplement used when referencing the subfigure.
+    #[internal]
+    #[synthesized]
+    pub supplement: Content,
+
+    /// The counter of the subfigure.
+    #[internal]
+    #[synthesized]
+    pub counter: Option<Counter>,
+}
+
+impl Synthesize for Packed<SubFigureElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        let span = self.span();
+        let Some(kind) = SubFigureElem::figure_kind_in(styles) else {
+            bail!(span, "subfigures must be placed within a figure");
+        };
+
+        // Steps at the figures of the parent's kind reset the subfigure
+        // numbers, so that the second level counts within the figure.
+        let counter = Counter::new(CounterKey::Selector(
+            select_where!(FigureElem, Kind => kind)
+                .or(vec![SubFigureElem::elem().select()]),
+        ));
+
+        let elem = self.as_mut();
+        elem.push_supplement(
+            SubFigureElem::figure_supplement_in(styles).unwrap_or_default(),
+        );
+        elem.push_counter(Some(counter));
+        Ok(())
+    }
+}
+
+impl Show for Packed<SubFigureElem> {
+    #[typst_macros::time(name = "figure.subfigure", span = self.span())]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let mut realized = self.body().clone();
+
+        // Build the caption from the subfigure's own number and its caption.
+        let mut caption = self.caption(styles);
+        if let (Some(numbering), Some(location)) =
+            ((**self).numbering(styles).as_ref(), self.location())
+        {
+            let state = Refable::counter(self).at_loc(engine, location)?;
+            let number = state.0.last().copied().unwrap_or(1);
+            let context = Context::new(Some(location), Some(styles));
+            let label = numbering.apply(engine, context.track(), &[number])?.display();
+            caption = Some(match caption {
+                Some(caption) => label + SpaceElem::new().pack() + caption,
+                None => label,
+            });
+        }
+
+        if let Some(caption) = caption {
+            let v = VElem::weak(self.gap(styles).into()).pack();
+            realized = realized + v + caption;
+        }
+
+        Ok(BlockElem::new()
+            .with_body(Some(BlockChild::Content(realized)))
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+impl Count for Packed<SubFigureElem> {
+    fn update(&self) -> Option<CounterUpdate> {
+        Some(CounterUpdate::Step(NonZeroUsize::new(2).unwrap()))
+    }
+}
+
+impl Refable for Packed<SubFigureElem> {
+    fn supplement(&self) -> Content {
+        (**self).supplement().cloned().unwrap_or_default()
+    }
+
+    fn counter(&self) -> Counter {
+        (**self)
+            .counter()
+            .cloned()
+            .flatten()
+            .unwrap_or_else(|| Counter::of(SubFigureElem::elem()))
+    }
+
+    fn numbering(&self) -> Option<&Numbering> {
+        Some((**self).ref_numbering(StyleChain::default()))
+    }
+}
+
 /// The `kind` parameter of a [`FigureElem`].
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum FigureKind {
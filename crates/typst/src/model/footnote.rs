@@ -1,17 +1,25 @@
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 
-use crate::diag::{bail, At, SourceResult, StrResult};
+use comemo::{Track, Tracked};
+
+use crate::diag::{bail, At, HintedStrResult, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Content, Label, NativeElement, Packed, Show, ShowSet, Smart,
-    StyleChain, Styles,
+    cast, elem, func, scope, Array, Cast, Content, Context, Func, Label,
+    LocatableSelector, NativeElement, Packed, Selector, Show, ShowSet, Smart, StyleChain,
+    Styles, Value,
+};
+use crate::introspection::{
+    Count, Counter, CounterRegion, CounterUpdate, Locatable, Location,
 };
-use crate::introspection::{Count, Counter, CounterUpdate, Locatable, Location};
-use crate::layout::{Abs, Em, HElem, Length, Ratio};
-use crate::model::{Destination, Numbering, NumberingPattern, ParElem};
-use crate::text::{SuperElem, TextElem, TextSize};
-use crate::utils::NonZeroExt;
+use crate::layout::{Abs, Em, HElem, Length, Ratio, Rel};
+use crate::model::{
+    Destination, HeadingElem, Numbering, NumberingPattern, ParElem, ParbreakElem,
+};
+use crate::syntax::Span;
+use crate::text::{SubElem, SuperElem, TextElem, TextSize};
+use crate::utils::{NonZeroExt, Numeric};
 use crate::visualize::{LineElem, Stroke};
 
 /// A footnote.
@@ -23,9 +31,11 @@ use crate::visualize::{LineElem, Stroke};
 ///
 /// To customize the appearance of the entry in the footnote listing, see
 /// [`footnote.entry`]($footnote.entry). The footnote itself is realized as a
-/// normal superscript, so you can use a set rule on the [`super`] function to
-/// customize it. You can also apply a show rule to customize only the footnote
-/// marker (superscript number) in the running text.
+/// normal superscript by default, so you can use a set rule on the [`super`]
+/// function to customize it. Alternatively, the
+/// [`marker-style`]($footnote.marker-style) can be changed. You can also apply
+/// a show rule to customize only the footnote marker (superscript number) in
+/// the running text.
 ///
 /// # Example
 /// ```example
@@ -55,9 +65,8 @@ pub struct FootnoteElem {
     /// How to number footnotes.
     ///
     /// By default, the footnote numbering continues throughout your document.
-    /// If you prefer per-page footnote numbering, you can reset the footnote
-    /// [counter] in the page [header]($page.header). In the future, there might
-    /// be a simpler way to achieve this.
+    /// If you prefer per-page footnote numbering, you can set the
+    /// [`scope`]($footnote.scope) to `{"page"}`.
     ///
     /// ```example
     /// #set footnote(numbering: "*")
@@ -70,8 +79,126 @@ pub struct FootnoteElem {
     #[default(Numbering::Pattern(NumberingPattern::from_str("1").unwrap()))]
     pub numbering: Numbering,
 
+    /// Where the footnote numbering restarts.
+    ///
+    /// With `{"document"}`, footnotes are numbered sequentially throughout the
+    /// whole document. With `{"page"}`, the numbering restarts at one on each
+    /// page. A footnote that breaks across pages keeps the number it received
+    /// on the page where it was declared. With `{"column"}`, the numbering
+    /// restarts in each column of a multi-column layout and the column is
+    /// determined by where the footnote's marker ends up.
+    ///
+    /// ```example
+    /// >>> #set page(height: 100pt)
+    /// #set footnote(scope: "page")
+    ///
+    /// First #footnote[One]
+    /// #pagebreak()
+    /// Second #footnote[Again one]
+    /// ```
+    #[default(FootnoteScope::Document)]
+    pub scope: FootnoteScope,
+
+    /// Where to place the footnote's entry.
+    ///
+    /// With `{"page"}`, the entry is placed at the bottom of the page the
+    /// footnote appears on. With `{"end"}`, the marker is still inserted into
+    /// the running text, but the entry is deferred to the next
+    /// [`endnotes`]($endnotes) call. Footnotes and endnotes share the same
+    /// counter.
+    ///
+    /// With `{"margin"}`, the entry is placed in the outside page margin next
+    /// to the marker. Consecutive margin notes are shifted down so that they
+    /// don't overlap and pulled back up if they would run past the bottom
+    /// margin. Pages with automatic height grow to hold them instead. The [`sidenote`]($sidenote) function is a shorthand for
+    /// such a footnote.
+    ///
+    /// ```example
+    /// #set footnote(placement: "end")
+    ///
+    /// Typst #footnote[A typesetting system.]
+    /// is fast #footnote[Really fast.].
+    ///
+    /// #endnotes()
+    /// ```
+    #[default(FootnotePlacement::Page)]
+    pub placement: FootnotePlacement,
+
+    /// The delimiter between the markers of a footnote that points to multiple
+    /// other footnotes or between the markers of [grouped]($footnote.group)
+    /// footnotes.
+    ///
+    /// ```example
+    /// #set footnote(delimiter: [--])
+    ///
+    /// A #footnote[One] <one>
+    /// B #footnote[Two] <two>
+    /// C #footnote((<one>, <two>))
+    /// ```
+    #[default(TextElem::packed(","))]
+    pub delimiter: Content,
+
+    /// Whether to join the markers of consecutive footnotes with the
+    /// [`delimiter`]($footnote.delimiter).
+    ///
+    /// Footnotes are consecutive if they directly follow each other, only
+    /// separated by whitespace.
+    ///
+    /// ```example
+    /// #set footnote(group: true)
+    /// Word #footnote[One] #footnote[Two]
+    /// ```
+    #[default(false)]
+    pub group: bool,
+
+    /// A custom marker for this footnote.
+    ///
+    /// When set, the marker is displayed instead of the footnote's number and
+    /// the footnote does not step the footnote counter. References to the
+    /// footnote display the same marker.
+    ///
+    /// ```example
+    /// = A Title #footnote(mark: [\*])[Title note]
+    /// Numbered #footnote[Regular note]
+    /// ```
+    pub mark: Smart<Content>,
+
+    /// The gap between the preceding word and the footnote marker.
+    ///
+    /// The gap never permits a line break between the word and the marker.
+    ///
+    /// ```example
+    /// #set footnote(marker-gap: 0.1em)
+    /// Spaced out#footnote[A little.]
+    /// ```
+    #[resolve]
+    #[default(Length::zero())]
+    pub marker_gap: Length,
+
+    /// Whether this footnote directly follows another footnote, only separated
+    /// by whitespace. Determined during realization.
+    #[internal]
+    #[synthesized]
+    pub grouped: bool,
+
+    /// How to set the footnote's marker in the running text.
+    ///
+    /// ```example
+    /// #set footnote(marker-style: "bracket")
+    /// In brackets #footnote[Full size.]
+    /// ```
+    #[default(FootnoteMarkerStyle::Super)]
+    pub marker_style: FootnoteMarkerStyle,
+
     /// The content to put into the footnote. Can also be the label of another
-    /// footnote this one should point to.
+    /// footnote this one should point to or an array of labels to point to
+    /// multiple footnotes at once.
+    ///
+    /// ```example
+    /// Typst #footnote[Fast] <a>
+    /// is fun #footnote[Easy] <b>
+    /// and free. #footnote((<a>, <b>))
+    /// ```
     #[required]
     pub body: FootnoteBody,
 }
@@ -80,6 +207,34 @@ pub struct FootnoteElem {
 impl FootnoteElem {
     #[elem]
     type FootnoteEntry;
+
+    /// Finds all footnotes whose markers appear on the given physical page.
+    ///
+    /// Returns an array of footnote elements in the order they appear in the
+    /// document. This is useful to build a listing of notes by page.
+    ///
+    /// ```example
+    /// A #footnote[One]
+    /// B #footnote[Two]
+    ///
+    /// #context {
+    ///   let notes = footnote.on-page(here().page())
+    ///   [This page has #notes.len() notes.]
+    /// }
+    /// ```
+    #[func(contextual)]
+    pub fn on_page(
+        /// The engine.
+        engine: &mut Engine,
+        /// The callsite context.
+        context: Tracked<Context>,
+        /// The physical page number, starting at one.
+        page: NonZeroUsize,
+    ) -> HintedStrResult<Array> {
+        context.introspect()?;
+        let notes = engine.introspector.query_page(&Self::elem().select(), page);
+        Ok(notes.into_iter().map(Value::Content).collect())
+    }
 }
 
 impl FootnoteElem {
@@ -95,7 +250,17 @@ impl FootnoteElem {
 
     /// Tests if this footnote is a reference to another footnote.
     pub fn is_ref(&self) -> bool {
-        matches!(self.body(), FootnoteBody::Reference(_))
+        matches!(self.body(), FootnoteBody::Reference(_) | FootnoteBody::References(_))
+    }
+
+    /// Tests if the entry of this footnote is deferred to an endnotes listing.
+    pub fn is_endnote(&self) -> bool {
+        self.placement(StyleChain::default()) == FootnotePlacement::End
+    }
+
+    /// Tests if the entry of this footnote is placed in the page margin.
+    pub fn is_sidenote(&self) -> bool {
+        self.placement(StyleChain::default()) == FootnotePlacement::Margin
     }
 
     /// Returns the content of the body of this footnote if it is not a ref.
@@ -112,14 +277,73 @@ impl Packed<FootnoteElem> {
     pub fn declaration_location(&self, engine: &Engine) -> StrResult<Location> {
         match self.body() {
             FootnoteBody::Reference(label) => {
-                let element = engine.introspector.query_label(*label)?;
-                let footnote = element
-                    .to_packed::<FootnoteElem>()
-                    .ok_or("referenced element should be a footnote")?;
-                footnote.declaration_location(engine)
+                self.resolve_reference(engine, *label)?.declaration_location(engine)
+            }
+            FootnoteBody::References(_) => {
+                bail!("footnote references multiple footnotes")
+            }
+            FootnoteBody::Content(_) => {
+                self.location().ok_or_else(|| "footnote must have a location".into())
+            }
+        }
+    }
+
+    /// Returns the number of this footnote, that is, the value of the footnote
+    /// counter at its declaration. For references, this is the number of the
+    /// referenced footnote.
+    ///
+    /// Fails for footnotes with a custom mark as those aren't counted.
+    pub fn number(&self, engine: &mut Engine) -> SourceResult<NonZeroUsize> {
+        let span = self.span();
+        let decls = self.declarations(engine).at(span)?;
+        let [decl] = decls.as_slice() else {
+            bail!(span, "footnote references multiple footnotes");
+        };
+        if decl.mark(StyleChain::default()).is_custom() {
+            bail!(span, "footnote with a custom mark has no number");
+        }
+        let loc = decl.declaration_location(engine).at(span)?;
+        let state = Counter::of(FootnoteElem::elem()).at_loc(engine, loc)?;
+        NonZeroUsize::new(state.first())
+            .ok_or("footnote counter is zero")
+            .at(span)
+    }
+
+    /// Returns the definitions of all footnotes this footnote points to, in
+    /// the order they were referenced. For a footnote that isn't a reference,
+    /// this is just the footnote itself.
+    pub fn declarations(&self, engine: &Engine) -> StrResult<Vec<Packed<FootnoteElem>>> {
+        match self.body() {
+            FootnoteBody::Reference(label) => {
+                self.resolve_reference(engine, *label)?.declarations(engine)
             }
-            _ => Ok(self.location().unwrap()),
+            FootnoteBody::References(labels) => {
+                let mut decls = vec![];
+                for &label in labels {
+                    decls.extend(
+                        self.resolve_reference(engine, label)?.declarations(engine)?,
+                    );
+                }
+                Ok(decls)
+            }
+            FootnoteBody::Content(_) => Ok(vec![self.clone()]),
+        }
+    }
+
+    /// Finds the footnote the given label refers to.
+    fn resolve_reference(
+        &self,
+        engine: &Engine,
+        label: Label,
+    ) -> StrResult<Packed<FootnoteElem>> {
+        let element = engine.introspector.query_label(label)?;
+        let footnote = element
+            .to_packed::<FootnoteElem>()
+            .ok_or("referenced element should be a footnote")?;
+        if footnote.location() == self.location() {
+            bail!("footnote cannot reference itself");
         }
+        Ok(footnote.clone())
     }
 }
 
@@ -127,29 +351,148 @@ impl Show for Packed<FootnoteElem> {
     #[typst_macros::time(name = "footnote", span = self.span())]
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         let span = self.span();
-        let loc = self.declaration_location(engine).at(span)?;
+        let decls = self.declarations(engine).at(span)?;
         let numbering = self.numbering(styles);
         let counter = Counter::of(FootnoteElem::elem());
-        let num = counter.display_at_loc(engine, loc, styles, numbering)?;
-        let sup = SuperElem::new(num).pack().spanned(span);
-        let loc = loc.variant(1);
+        let grouped = self.group(styles) && self.grouped().copied().unwrap_or(false);
+        let mut parts = vec![];
+        for (i, decl) in decls.into_iter().enumerate() {
+            if i > 0 || grouped {
+                parts.push((self.delimiter(styles), None));
+            }
+            let loc = decl.declaration_location(engine).at(span)?;
+            let num = match decl.mark(styles) {
+                Smart::Custom(mark) => mark,
+                Smart::Auto => counter.display_at_loc(engine, loc, styles, numbering)?,
+            };
+            parts.push((num, Some(loc.variant(1))));
+        }
+
+        // A lone number is linked as a whole, so that it can still be set with
+        // the font's superscript or subscript glyphs.
+        let lone = match parts.as_slice() {
+            [(_, Some(loc))] => Some(*loc),
+            _ => None,
+        };
+        let markers = Content::sequence(parts.into_iter().map(|(part, loc)| match loc {
+            Some(loc) if lone.is_none() => part.linked(Destination::Location(loc)),
+            _ => part,
+        }));
+        let mut marker = match self.marker_style(styles) {
+            FootnoteMarkerStyle::Super => SuperElem::new(markers).pack(),
+            FootnoteMarkerStyle::Sub => SubElem::new(markers).pack(),
+            FootnoteMarkerStyle::Bracket => {
+                TextElem::packed('[') + markers + TextElem::packed(']')
+            }
+        }
+        .spanned(span);
+        if let Some(loc) = lone {
+            marker = marker.linked(Destination::Location(loc));
+        }
+
         // Add zero-width weak spacing to make the footnote "sticky".
-        Ok(HElem::hole().pack() + sup.linked(Destination::Location(loc)))
+        let mut seq = vec![HElem::hole().pack()];
+
+        // Surround the gap with word joiners so that it doesn't become a
+        // line break opportunity.
+        let gap = self.marker_gap(styles);
+        if !gap.is_zero() && !grouped {
+            seq.push(TextElem::packed('\u{2060}'));
+            seq.push(HElem::new(gap.into()).pack());
+            seq.push(TextElem::packed('\u{2060}'));
+        }
+
+        seq.push(marker);
+        Ok(Content::sequence(seq))
     }
 }
 
 impl Count for Packed<FootnoteElem> {
     fn update(&self) -> Option<CounterUpdate> {
-        (!self.is_ref()).then(|| CounterUpdate::Step(NonZeroUsize::ONE))
+        let default = StyleChain::default();
+        (!self.is_ref() && self.mark(default).is_auto()).then(|| {
+            match self.scope(default) {
+                FootnoteScope::Document => CounterUpdate::Step(NonZeroUsize::ONE),
+                FootnoteScope::Page => {
+                    CounterUpdate::StepIn(CounterRegion::Page, NonZeroUsize::ONE)
+                }
+                FootnoteScope::Column => {
+                    CounterUpdate::StepIn(CounterRegion::Column, NonZeroUsize::ONE)
+                }
+            }
+        })
     }
 }
 
-/// The body of a footnote can be either some content or a label referencing
-/// another footnote.
+/// Where the numbering of footnotes restarts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum FootnoteScope {
+    /// Footnotes are numbered continuously throughout the document.
+    Document,
+    /// The footnote numbering restarts on each page.
+    Page,
+    /// The footnote numbering restarts in each column.
+    Column,
+}
+
+/// A margin note.
+///
+/// Creates a [footnote] whose entry is placed in the outside page margin
+/// instead of at the bottom of the page. Sidenotes share their counter and
+/// numbering with footnotes. Make sure to configure a margin that is wide
+/// enough to hold the notes.
+///
+/// # Example
+/// ```example
+/// #set page(margin: (right: 50%))
+///
+/// Tufte-style notes #sidenote[A margin remark.]
+/// sit next to the text.
+/// ```
+#[func]
+pub fn sidenote(
+    /// The callsite span.
+    span: Span,
+    /// The content to put into the note. Can also be the label of another
+    /// footnote this one should point to.
+    body: FootnoteBody,
+) -> Content {
+    FootnoteElem::new(body)
+        .with_placement(FootnotePlacement::Margin)
+        .pack()
+        .spanned(span)
+}
+
+/// How the marker of a footnote is set in the running text.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum FootnoteMarkerStyle {
+    /// As a [superscript]($super).
+    Super,
+    /// As a [subscript]($sub).
+    Sub,
+    /// In full size, enclosed in square brackets.
+    Bracket,
+}
+
+/// Where the entry of a footnote is placed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum FootnotePlacement {
+    /// At the bottom of the page the footnote appears on.
+    Page,
+    /// In the next [`endnotes`]($endnotes) listing.
+    End,
+    /// In the outside page margin, next to the line with the footnote's
+    /// marker.
+    Margin,
+}
+
+/// The body of a footnote can be either some content or one or multiple
+/// labels referencing other footnotes.
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum FootnoteBody {
     Content(Content),
     Reference(Label),
+    References(Vec<Label>),
 }
 
 cast! {
@@ -157,9 +500,11 @@ cast! {
     self => match self {
         Self::Content(v) => v.into_value(),
         Self::Reference(v) => v.into_value(),
+        Self::References(v) => v.into_value(),
     },
     v: Content => Self::Content(v),
     v: Label => Self::Reference(v),
+    v: Vec<Label> => Self::References(v),
 }
 
 /// An entry in a footnote list.
@@ -201,6 +546,19 @@ pub struct FootnoteEntry {
     #[required]
     pub note: Packed<FootnoteElem>,
 
+    /// How to number the entry.
+    ///
+    /// When set to `{auto}`, the entry uses the
+    /// [numbering of its footnote]($footnote.numbering).
+    ///
+    /// ```example
+    /// #set footnote.entry(entry-numbering: "1.")
+    ///
+    /// Numbered #footnote[With a dot]
+    /// ```
+    #[borrowed]
+    pub entry_numbering: Smart<Numbering>,
+
     /// The separator between the document body and the footnote listing.
     ///
     /// ```example
@@ -214,7 +572,21 @@ pub struct FootnoteEntry {
     ///   not that bad?
     /// ]
     /// ```
-    #[default(
+    ///
+    /// The separator can also be a function that receives the available width
+    /// and returns content. This is useful to draw shapes that span the text
+    /// block.
+    ///
+    /// ```example
+    /// #set footnote.entry(separator: width => path(
+    ///   stroke: 0.5pt,
+    ///   ..range(9).map(i => (i * width / 8, calc.rem(i, 2) * 3pt)),
+    /// ))
+    ///
+    /// A wavy separator.
+    /// #footnote[Drawn with a path.]
+    /// ```
+    #[default(FootnoteSeparator::Content(
         LineElem::new()
             .with_length(Ratio::new(0.3).into())
             .with_stroke(Stroke {
@@ -222,8 +594,24 @@ pub struct FootnoteEntry {
                 ..Default::default()
             })
             .pack()
-    )]
-    pub separator: Content,
+    ))]
+    pub separator: FootnoteSeparator,
+
+    /// The separator above entries that continue from the previous page.
+    ///
+    /// When set to `{auto}`, the regular [`separator`]($footnote.entry.separator)
+    /// is used.
+    ///
+    /// ```example
+    /// >>> #set page(height: 100pt)
+    /// #set footnote.entry(
+    ///   continuation-separator: line(length: 100%, stroke: 0.5pt),
+    /// )
+    ///
+    /// #v(40pt)
+    /// Long #footnote[#lorem(30)]
+    /// ```
+    pub continuation_separator: Smart<FootnoteSeparator>,
 
     /// The amount of clearance between the document body and the separator.
     ///
@@ -239,6 +627,34 @@ pub struct FootnoteEntry {
     #[resolve]
     pub clearance: Length,
 
+    /// The horizontal distance between [margin notes]($sidenote) and the
+    /// edges of the page margin they are placed in, on both sides.
+    ///
+    /// ```example
+    /// #set page(margin: (right: 50%))
+    /// #set footnote.entry(margin-gap: 2em)
+    ///
+    /// Spacious #sidenote[A margin remark.]
+    /// ```
+    #[default(Em::new(1.0).into())]
+    #[resolve]
+    pub margin_gap: Length,
+
+    /// Whether to reserve space for the separator and its clearance on every
+    /// page, even if the page has no footnotes.
+    ///
+    /// This keeps the main content area the same height across pages, which
+    /// helps with aligning the content of facing pages.
+    ///
+    /// ```example
+    /// >>> #set page(height: 100pt)
+    /// #set footnote.entry(separator-clearance-always: true)
+    ///
+    /// #lorem(20)
+    /// ```
+    #[default(false)]
+    pub separator_clearance_always: bool,
+
     /// The gap between footnote entries.
     ///
     /// ```example
@@ -252,6 +668,22 @@ pub struct FootnoteEntry {
     #[resolve]
     pub gap: Length,
 
+    /// The maximum height of the footnote area on a page, including the
+    /// separator. Relative to the height of the page's text area.
+    ///
+    /// Entries that don't fit into the remaining space of the area break onto
+    /// the next page. When set to `{auto}`, the footnote area can take up as
+    /// much space as it needs.
+    ///
+    /// ```example
+    /// >>> #set page(height: 120pt)
+    /// #set footnote.entry(max-height: 30%)
+    ///
+    /// Limited #footnote[#lorem(20)]
+    /// ```
+    #[resolve]
+    pub max_height: Smart<Rel<Length>>,
+
     /// The indent of each footnote entry.
     ///
     /// ```example
@@ -263,6 +695,46 @@ pub struct FootnoteEntry {
     /// ```
     #[default(Em::new(1.0).into())]
     pub indent: Length,
+
+    /// The minimum number of lines of an entry that must be kept together on
+    /// the page where the entry starts.
+    ///
+    /// If fewer lines would fit, the whole entry moves to the next page
+    /// instead of breaking. If the lines don't fit even there, the entry is
+    /// split normally. When `{auto}`, the entry's lines are kept together
+    /// like those of any other paragraph.
+    ///
+    /// ```example
+    /// >>> #set page(height: 100pt)
+    /// #set footnote.entry(min-lines: 2)
+    ///
+    /// #v(40pt)
+    /// Stay together #footnote[#lorem(20)]
+    /// ```
+    pub min_lines: Smart<NonZeroUsize>,
+
+    /// Whether the number of the entry links back to the footnote's marker in
+    /// the running text.
+    ///
+    /// ```example
+    /// #set footnote.entry(backlink: false)
+    ///
+    /// No way #footnote[back]
+    /// ```
+    #[default(true)]
+    pub backlink: bool,
+
+    /// An indicator placed at the end of each part of an entry that breaks
+    /// across pages.
+    ///
+    /// ```example
+    /// >>> #set page(height: 100pt)
+    /// #set footnote.entry(continuation: [(cont.)])
+    ///
+    /// #v(40pt)
+    /// Long #footnote[#lorem(30)]
+    /// ```
+    pub continuation: Option<Content>,
 }
 
 impl Show for Packed<FootnoteEntry> {
@@ -272,8 +744,10 @@ impl Show for Packed<FootnoteEntry> {
         let note = self.note();
         let number_gap = Em::new(0.05);
         let default = StyleChain::default();
-        let numbering = note.numbering(default);
-        let counter = Counter::of(FootnoteElem::elem());
+        let numbering = self
+            .entry_numbering(styles)
+            .as_ref()
+            .unwrap_or_else(|| note.numbering(default));
         let Some(loc) = note.location() else {
             bail!(
                 span, "footnote entry must have a location";
@@ -281,12 +755,19 @@ impl Show for Packed<FootnoteEntry> {
             );
         };
 
-        let num = counter.display_at_loc(engine, loc, styles, numbering)?;
-        let sup = SuperElem::new(num)
-            .pack()
-            .spanned(span)
-            .linked(Destination::Location(loc))
-            .located(loc.variant(1));
+        let num = match note.mark(default) {
+            Smart::Custom(mark) => mark,
+            Smart::Auto => {
+                let number = note.number(engine)?;
+                let context = Context::new(Some(loc), Some(styles));
+                numbering.apply(engine, context.track(), &[number.get()])?.display()
+            }
+        };
+        let mut sup = SuperElem::new(num).pack().spanned(span);
+        if self.backlink(styles) {
+            sup = sup.linked(Destination::Location(loc));
+        }
+        let sup = sup.located(loc.variant(1));
 
         Ok(Content::sequence([
             HElem::new(self.indent(styles).into()).pack(),
@@ -298,17 +779,141 @@ impl Show for Packed<FootnoteEntry> {
 }
 
 impl ShowSet for Packed<FootnoteEntry> {
-    fn show_set(&self, _: StyleChain) -> Styles {
+    fn show_set(&self, styles: StyleChain) -> Styles {
         let text_size = Em::new(0.85);
         let leading = Em::new(0.5);
         let mut out = Styles::new();
         out.set(ParElem::set_leading(leading.into()));
+        out.set(ParElem::set_min_lines(self.min_lines(styles)));
         out.set(TextElem::set_size(TextSize(text_size.into())));
         out
     }
 }
 
+/// A listing of endnotes.
+///
+/// Collects the entries of all footnotes with `{placement: "end"}` that appear
+/// before this listing and after the previous one. The entries are styled just
+/// like regular footnote entries, so [`footnote.entry`]($footnote.entry) set
+/// and show rules apply to them, too.
+///
+/// # Example
+/// ```example
+/// #set footnote(placement: "end")
+///
+/// = Introduction
+/// Endnotes #footnote[Collected below.]
+/// are gathered in one place.
+///
+/// = Notes
+/// #endnotes()
+/// ```
+#[elem(Locatable, Show)]
+pub struct EndnotesElem {
+    /// The heading level at which the collection of endnotes restarts.
+    ///
+    /// If set, only endnotes that appear after the last heading of at most
+    /// this level are listed. This way, endnotes can be emitted per chapter by
+    /// placing an `endnotes` call at the end of each chapter.
+    ///
+    /// ```example
+    /// #set footnote(placement: "end")
+    ///
+    /// = Chapter
+    /// First #footnote[In chapter one.]
+    /// #endnotes(level: 1)
+    ///
+    /// = Chapter
+    /// Second #footnote[In chapter two.]
+    /// #endnotes(level: 1)
+    /// ```
+    pub level: Option<NonZeroUsize>,
+}
+
+impl Show for Packed<EndnotesElem> {
+    #[typst_macros::time(name = "endnotes", span = self.span())]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let loc = self.location().unwrap();
+        let before =
+            |selector: Selector| selector.before(LocatableSelector(loc.into()), false);
+        let after = |selector: Selector, start: &Content| {
+            selector.after(LocatableSelector(start.location().unwrap().into()), false)
+        };
+
+        // Only collect endnotes after the previous listing and, if configured,
+        // after the last heading of at most the given level.
+        let mut selector = before(FootnoteElem::elem().select());
+        let prev = engine.introspector.query(&before(EndnotesElem::elem().select()));
+        if let Some(prev) = prev.last() {
+            selector = after(selector, prev);
+        }
+
+        if let Some(level) = self.level(styles) {
+            let headings =
+                engine.introspector.query(&before(HeadingElem::elem().select()));
+            if let Some(heading) = headings.iter().rev().find(|elem| {
+                elem.to_packed::<HeadingElem>().is_some_and(|heading| {
+                    heading.resolve_level(StyleChain::default()) <= level
+                })
+            }) {
+                selector = after(selector, heading);
+            }
+        }
+
+        let mut seq = vec![];
+        for elem in engine.introspector.query(&selector) {
+            let Some(note) = elem.to_packed::<FootnoteElem>() else { continue };
+            if note.is_ref() || !note.is_endnote() {
+                continue;
+            }
+            seq.push(FootnoteEntry::new(note.clone()).pack().spanned(self.span()));
+            seq.push(ParbreakElem::new().pack());
+        }
+
+        let gap = FootnoteEntry::gap_in(styles);
+        Ok(Content::sequence(seq).styled(ParElem::set_spacing(gap.into())))
+    }
+}
+
 cast! {
     FootnoteElem,
     v: Content => v.unpack::<Self>().unwrap_or_else(Self::with_content)
 }
+
+/// The separator between the document body and the footnote listing.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum FootnoteSeparator {
+    Content(Content),
+    Func(Func),
+}
+
+impl FootnoteSeparator {
+    /// Resolves the separator into its content, given the available width.
+    pub fn resolve(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        width: Abs,
+    ) -> SourceResult<Content> {
+        Ok(match self {
+            Self::Content(content) => content.clone(),
+            Self::Func(func) => func
+                .call(
+                    engine,
+                    Context::new(None, Some(styles)).track(),
+                    [Length::from(width)],
+                )?
+                .display(),
+        })
+    }
+}
+
+cast! {
+    FootnoteSeparator,
+    self => match self {
+        Self::Content(v) => v.into_value(),
+        Self::Func(v) => v.into_value(),
+    },
+    v: Content => Self::Content(v),
+    v: Func => Self::Func(v),
+}
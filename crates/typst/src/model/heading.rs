@@ -177,6 +177,30 @@ pub struct HeadingElem {
     #[default(Smart::Auto)]
     pub hanging_indent: Smart<Length>,
 
+    /// Whether the heading should run into the following paragraph instead of
+    /// standing on its own.
+    ///
+    /// A run-in heading shares its line with the start of the paragraph that
+    /// follows it. It remains a proper heading, so it still appears in the
+    /// [outline] and can be referenced and located.
+    ///
+    /// ```example
+    /// #show heading.where(level: 4): set heading(
+    ///   run-in: true,
+    ///   run-in-separator: [.],
+    /// )
+    ///
+    /// ==== Remark
+    /// This paragraph starts on the
+    /// same line as its heading.
+    /// ```
+    #[default(false)]
+    pub run_in: bool,
+
+    /// The content to insert between a [run-in]($heading.run-in) heading and
+    /// the text that follows it.
+    pub run_in_separator: Option<Content>,
+
     /// The heading's title.
     #[required]
     pub body: Content,
@@ -219,6 +243,7 @@ impl Show for Packed<HeadingElem> {
 
         let span = self.span();
         let mut realized = self.body().clone();
+        let run_in = self.run_in(styles);
 
         let hanging_indent = self.hanging_indent(styles);
         let mut indent = match hanging_indent {
@@ -232,7 +257,7 @@ impl Show for Packed<HeadingElem> {
                 .display_at_loc(engine, location, styles, numbering)?
                 .spanned(span);
 
-            if hanging_indent.is_auto() {
+            if hanging_indent.is_auto() && !run_in {
                 let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
 
                 // We don't have a locator for the numbering here, so we just
@@ -251,12 +276,20 @@ impl Show for Packed<HeadingElem> {
                 + realized;
         }
 
+        if run_in {
+            if let Some(separator) = self.run_in_separator(styles) {
+                realized += separator;
+            }
+            return Ok(realized + SpaceElem::new().pack());
+        }
+
         if indent != Abs::zero() {
             realized = realized.styled(ParElem::set_hanging_indent(indent.into()));
         }
 
         Ok(BlockElem::new()
             .with_body(Some(BlockChild::Content(realized)))
+            .with_structure(Some(self.clone().pack()))
             .pack()
             .spanned(span))
     }
@@ -189,10 +189,13 @@ fn layout_list(
         cells.push(Cell::new(Content::empty(), locator.next(&())));
         cells.push(Cell::new(marker.clone(), locator.next(&marker.span())));
         cells.push(Cell::new(Content::empty(), locator.next(&())));
-        cells.push(Cell::new(
-            item.body.clone().styled(ListElem::set_depth(Depth(1))),
-            locator.next(&item.body.span()),
-        ));
+        cells.push(Cell {
+            structure: Some(item.clone().pack()),
+            ..Cell::new(
+                item.body.clone().styled(ListElem::set_depth(Depth(1))),
+                locator.next(&item.body.span()),
+            )
+        });
     }
 
     let grid = CellGrid::new(
@@ -206,8 +209,12 @@ fn layout_list(
         cells,
     );
     let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
+    let mut fragment = layouter.layout(engine)?;
+    for frame in fragment.iter_mut() {
+        frame.structure(elem.clone().pack());
+    }
 
-    layouter.layout(engine)
+    Ok(fragment)
 }
 
 /// A bullet list item.
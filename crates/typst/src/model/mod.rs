@@ -60,6 +60,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<HeadingElem>();
     global.define_elem::<FigureElem>();
     global.define_elem::<FootnoteElem>();
+    global.define_elem::<EndnotesElem>();
     global.define_elem::<QuoteElem>();
     global.define_elem::<CiteElem>();
     global.define_elem::<BibliographyElem>();
@@ -72,4 +73,5 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<EmphElem>();
     global.define_elem::<StrongElem>();
     global.define_func::<numbering>();
+    global.define_func::<sidenote>();
 }
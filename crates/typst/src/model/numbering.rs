@@ -42,13 +42,26 @@ pub fn numbering(
     /// Defines how the numbering works.
     ///
     /// **Counting symbols** are `1`, `a`, `A`, `i`, `I`, `一`, `壹`, `あ`, `い`, `ア`, `イ`, `א`, `가`,
-    /// `ㄱ`, and `*`. They are replaced by the number in the sequence, in the
-    /// given case.
+    /// `ㄱ`, `*`, and `†`. They are replaced by the number in the sequence, in
+    /// the given case.
     ///
     /// The `*` character means that symbols should be used to count, in the
     /// order of `*`, `†`, `‡`, `§`, `¶`, and `‖`. If there are more than six
     /// items, the number is represented using multiple symbols.
     ///
+    /// The `†` character works the same, but only cycles through `*`, `†`, and
+    /// `‡` before doubling and tripling them, as is common for footnotes. It
+    /// only counts if it is the sole counting symbol of the pattern. Otherwise,
+    /// it is kept as-is, so that e.g. `{"1†"}` still yields `{"1†"}`.
+    ///
+    /// **Counting words** are `one` and `1st`. The word `one` spells out the
+    /// number in English words ("twenty-one"), the word `One` does the same,
+    /// but capitalizes the first letter. The word `1st` displays ordinal
+    /// numbers ("21st") and can be combined with other counting symbols, as
+    /// in `{"1st.a)"}`. For other languages, use a function instead. Like `†`,
+    /// the word `one` only counts if it is the sole counting symbol of the
+    /// pattern and is kept as-is otherwise.
+    ///
     /// **Suffixes** are all characters after the last counting symbol. They are
     /// repeated as-is at the end of any rendered number.
     ///
@@ -56,6 +69,11 @@ pub fn numbering(
     /// suffixes. They are repeated as-is at in front of their rendered
     /// equivalent of their counting symbol.
     ///
+    /// A pattern can end with `|arabic>` followed by a threshold, e.g.
+    /// `{"i|arabic>20"}`. Numbers above the threshold are then displayed with
+    /// Arabic numerals instead, which is useful for Roman numerals that get
+    /// unwieldy for large numbers.
+    ///
     /// This parameter can also be an arbitrary function that gets each number
     /// as an individual argument. When given a function, the `numbering`
     /// function just forwards the arguments to that function. While this is not
@@ -124,17 +142,24 @@ cast! {
 /// How to turn a number into text.
 ///
 /// A pattern consists of a prefix, followed by one of
-/// `1`, `a`, `A`, `i`, `I`, `一`, `壹`, `あ`, `い`, `ア`, `イ`, `א`, `가`, `ㄱ`, `*`, `①`, or `⓵`,
-/// and then a suffix.
+/// `1`, `a`, `A`, `i`, `I`, `一`, `壹`, `あ`, `い`, `ア`, `イ`, `א`, `가`, `ㄱ`, `*`, `†`, `①`, `⓵`,
+/// `one`, `One`, or `1st`, and then a suffix.
+///
+/// The pattern can end with `|arabic>` and a threshold, above which numbers
+/// are displayed with Arabic numerals.
 ///
 /// Examples of valid patterns:
 /// - `1)`
 /// - `a.`
 /// - `(I)`
+/// - `i|arabic>3999`
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct NumberingPattern {
     pub pieces: EcoVec<(EcoString, NumberingKind, Case)>,
     pub suffix: EcoString,
+    /// The largest number displayed with the pattern's counting symbols.
+    /// Larger numbers fall back to Arabic numerals.
+    pub cap: Option<usize>,
     trimmed: bool,
 }
 
@@ -150,7 +175,7 @@ impl NumberingPattern {
             if i > 0 || !self.trimmed {
                 fmt.push_str(prefix);
             }
-            fmt.push_str(&kind.apply(n, *case));
+            fmt.push_str(&self.format(*kind, n, *case));
         }
 
         for ((prefix, kind, case), &n) in
@@ -161,7 +186,7 @@ impl NumberingPattern {
             } else {
                 fmt.push_str(prefix);
             }
-            fmt.push_str(&kind.apply(n, *case));
+            fmt.push_str(&self.format(*kind, n, *case));
         }
 
         if !self.trimmed {
@@ -183,12 +208,20 @@ impl NumberingPattern {
             .chain(self.pieces.last().into_iter().cycle())
             .nth(k)
         {
-            fmt.push_str(&kind.apply(number, *case));
+            fmt.push_str(&self.format(*kind, number, *case));
         }
         fmt.push_str(&self.suffix);
         fmt
     }
 
+    /// Format a single number, falling back to Arabic numerals above the cap.
+    fn format(&self, kind: NumberingKind, n: usize, case: Case) -> EcoString {
+        match self.cap {
+            Some(cap) if n > cap => NumberingKind::Arabic.apply(n, case),
+            _ => kind.apply(n, case),
+        }
+    }
+
     /// How many counting symbols this pattern has.
     pub fn pieces(&self) -> usize {
         self.pieces.len()
@@ -199,28 +232,71 @@ impl FromStr for NumberingPattern {
     type Err = &'static str;
 
     fn from_str(pattern: &str) -> Result<Self, Self::Err> {
-        let mut pieces = EcoVec::new();
-        let mut handled = 0;
-
-        for (i, c) in pattern.char_indices() {
-            let Some(kind) = NumberingKind::from_char(c.to_ascii_lowercase()) else {
-                continue;
-            };
-
-            let prefix = pattern[handled..i].into();
-            let case =
-                if c.is_uppercase() || c == '壹' { Case::Upper } else { Case::Lower };
-            pieces.push((prefix, kind, case));
-            handled = c.len_utf8() + i;
+        let mut cap = None;
+        let mut pattern = pattern;
+        if let Some((head, tail)) = pattern.rsplit_once("|arabic>") {
+            cap = Some(tail.parse().map_err(|_| "invalid numbering threshold")?);
+            pattern = head;
+        }
+
+        let (mut pieces, mut suffix) = parse_pieces(pattern, true);
+
+        // Exclusive counting symbols only count if they are the sole counting
+        // symbol of the pattern, such that patterns which use them as a
+        // literal mark, like `"1†"`, keep working.
+        if pieces.len() > 1 && pieces.iter().any(|(_, kind, _)| kind.is_exclusive()) {
+            (pieces, suffix) = parse_pieces(pattern, false);
         }
 
-        let suffix = pattern[handled..].into();
         if pieces.is_empty() {
             return Err("invalid numbering pattern");
         }
 
-        Ok(Self { pieces, suffix, trimmed: false })
+        Ok(Self { pieces, suffix, cap, trimmed: false })
+    }
+}
+
+/// Splits a numbering pattern into its pieces and suffix.
+///
+/// If `exclusive` is false, exclusive counting symbols are treated as literal
+/// text.
+fn parse_pieces(
+    pattern: &str,
+    exclusive: bool,
+) -> (EcoVec<(EcoString, NumberingKind, Case)>, EcoString) {
+    let mut pieces = EcoVec::new();
+    let mut handled = 0;
+    let mut i = 0;
+
+    while let Some(c) = pattern[i..].chars().next() {
+        let len = match NumberingKind::from_word(pattern, i)
+            .filter(|(kind, _)| exclusive || !kind.is_exclusive())
+        {
+            Some((kind, len)) => {
+                let case = if c.is_uppercase() { Case::Upper } else { Case::Lower };
+                pieces.push((pattern[handled..i].into(), kind, case));
+                handled = i + len;
+                len
+            }
+            None => {
+                if let Some(kind) = NumberingKind::from_char(c.to_ascii_lowercase())
+                    .filter(|kind| exclusive || !kind.is_exclusive())
+                {
+                    let case = if c.is_uppercase() || c == '壹' {
+                        Case::Upper
+                    } else {
+                        Case::Lower
+                    };
+                    pieces.push((pattern[handled..i].into(), kind, case));
+                    handled = i + c.len_utf8();
+                }
+                c.len_utf8()
+            }
+        };
+        i += len;
     }
+
+    (pieces, pattern[handled..].into())
 }
 
 cast! {
@@ -229,13 +305,20 @@ cast! {
         let mut pat = EcoString::new();
         for (prefix, kind, case) in &self.pieces {
             pat.push_str(prefix);
-            let mut c = kind.to_char();
-            if *case == Case::Upper {
-                c = c.to_ascii_uppercase();
+            let symbol = kind.to_str();
+            let mut chars = symbol.chars();
+            match chars.next() {
+                Some(c) if *case == Case::Upper => {
+                    pat.push(c.to_ascii_uppercase());
+                    pat.push_str(chars.as_str());
+                }
+                _ => pat.push_str(symbol),
             }
-            pat.push(c);
         }
         pat.push_str(&self.suffix);
+        if let Some(cap) = self.cap {
+            pat.push_str(&eco_format!("|arabic>{cap}"));
+        }
         pat.into_value()
     },
     v: Str => v.parse()?,
@@ -252,6 +335,8 @@ pub enum NumberingKind {
     Roman,
     /// The symbols *, †, ‡, §, ¶, and ‖. Further items use multiple symbols.
     Symbol,
+    /// The symbols *, †, and ‡. Further items use multiple symbols.
+    Dagger,
     /// Hebrew numerals.
     Hebrew,
     /// Simplified Chinese numerals. Uses standard numerals for lowercase and “banknote” numerals for uppercase.
@@ -280,6 +365,11 @@ pub enum NumberingKind {
     EasternArabic,
     /// The variant of Eastern Arabic numerals used in Persian and Urdu.
     EasternArabicPersian,
+    /// English words (one, two, three, etc.). Uppercase capitalizes the
+    /// first letter.
+    Word,
+    /// English ordinal numbers (1st, 2nd, 3rd, etc.).
+    Ordinal,
     /// Circled numbers (①, ②, ③, etc.), up to 50.
     CircledNumber,
     /// Double-circled numbers (⓵, ⓶, ⓷, etc.), up to 10.
@@ -294,6 +384,7 @@ impl NumberingKind {
             'a' => NumberingKind::Letter,
             'i' => NumberingKind::Roman,
             '*' => NumberingKind::Symbol,
+            '†' => NumberingKind::Dagger,
             'א' => NumberingKind::Hebrew,
             '一' | '壹' => NumberingKind::SimplifiedChinese,
             'あ' => NumberingKind::HiraganaAiueo,
@@ -310,26 +401,55 @@ impl NumberingKind {
         })
     }
 
-    /// The lowercase character for this numbering kind.
-    pub fn to_char(self) -> char {
+    /// Create a numbering kind from a counting word starting at the given
+    /// index of the pattern. Also returns the word's length.
+    ///
+    /// Words are only recognized in the spellings `one`, `One`, and `1st` and
+    /// if they are not part of a longer word.
+    pub fn from_word(pattern: &str, i: usize) -> Option<(Self, usize)> {
+        let is_alpha = |c: Option<char>| c.is_some_and(char::is_alphabetic);
+        if is_alpha(pattern[..i].chars().next_back()) {
+            return None;
+        }
+
+        let rest = &pattern[i..];
+        [("one", Self::Word), ("One", Self::Word), ("1st", Self::Ordinal)]
+            .into_iter()
+            .find_map(|(word, kind)| {
+                (rest.starts_with(word) && !is_alpha(rest[word.len()..].chars().next()))
+                    .then_some((kind, word.len()))
+            })
+    }
+
+    /// Whether this kind only counts if it is the sole counting symbol of a
+    /// pattern.
+    pub fn is_exclusive(self) -> bool {
+        matches!(self, Self::Dagger | Self::Word)
+    }
+
+    /// The lowercase counting symbol for this numbering kind.
+    pub fn to_str(self) -> &'static str {
         match self {
-            Self::Arabic => '1',
-            Self::Letter => 'a',
-            Self::Roman => 'i',
-            Self::Symbol => '*',
-            Self::Hebrew => 'א',
-            Self::SimplifiedChinese => '一',
-            Self::TraditionalChinese => '一',
-            Self::HiraganaAiueo => 'あ',
-            Self::HiraganaIroha => 'い',
-            Self::KatakanaAiueo => 'ア',
-            Self::KatakanaIroha => 'イ',
-            Self::KoreanJamo => 'ㄱ',
-            Self::KoreanSyllable => '가',
-            Self::EasternArabic => '\u{0661}',
-            Self::EasternArabicPersian => '\u{06F1}',
-            Self::CircledNumber => '①',
-            Self::DoubleCircledNumber => '⓵',
+            Self::Arabic => "1",
+            Self::Letter => "a",
+            Self::Roman => "i",
+            Self::Symbol => "*",
+            Self::Dagger => "†",
+            Self::Hebrew => "א",
+            Self::SimplifiedChinese => "一",
+            Self::TraditionalChinese => "一",
+            Self::HiraganaAiueo => "あ",
+            Self::HiraganaIroha => "い",
+            Self::KatakanaAiueo => "ア",
+            Self::KatakanaIroha => "イ",
+            Self::KoreanJamo => "ㄱ",
+            Self::KoreanSyllable => "가",
+            Self::EasternArabic => "\u{0661}",
+            Self::EasternArabicPersian => "\u{06F1}",
+            Self::Word => "one",
+            Self::Ordinal => "1st",
+            Self::CircledNumber => "①",
+            Self::DoubleCircledNumber => "⓵",
         }
     }
 
@@ -437,16 +557,8 @@ impl NumberingKind {
 
                 fmt
             }
-            Self::Symbol => {
-                if n == 0 {
-                    return '-'.into();
-                }
-
-                const SYMBOLS: &[char] = &['*', '†', '‡', '§', '¶', '‖'];
-                let symbol = SYMBOLS[(n - 1) % SYMBOLS.len()];
-                let amount = ((n - 1) / SYMBOLS.len()) + 1;
-                std::iter::repeat(symbol).take(amount).collect()
-            }
+            Self::Symbol => repeating(&['*', '†', '‡', '§', '¶', '‖'], n),
+            Self::Dagger => repeating(&['*', '†', '‡'], n),
             Self::Hebrew => {
                 if n == 0 {
                     return '-'.into();
@@ -537,6 +649,29 @@ impl NumberingKind {
                 },
                 n,
             ),
+            Self::Word => {
+                let mut words = english_words(n);
+                if case == Case::Upper {
+                    if let Some(first) = words.chars().next() {
+                        words = eco_format!(
+                            "{}{}",
+                            first.to_ascii_uppercase(),
+                            &words[first.len_utf8()..]
+                        );
+                    }
+                }
+                words
+            }
+            Self::Ordinal => {
+                let suffix = match (n % 10, n % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                eco_format!("{n}{suffix}")
+            }
             Self::EasternArabic => decimal('\u{0660}', n),
             Self::EasternArabicPersian => decimal('\u{06F0}', n),
             Self::CircledNumber => zeroless::<50>(
@@ -599,6 +734,111 @@ fn zeroless<const N_DIGITS: usize>(
     cs.into_iter().rev().collect()
 }
 
+/// Stringify a number by cycling through the symbols, repeating each symbol
+/// once more on every pass.
+///
+/// With the symbols '*' and '†', we would get the following:
+///
+/// ```text
+/// 1 =>   "*"
+/// 2 =>   "†"
+/// 3 =>  "**"
+/// 4 =>  "††"
+/// 5 => "***"
+///    etc.
+/// ```
+fn repeating(symbols: &[char], n: usize) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+    let symbol = symbols[(n - 1) % symbols.len()];
+    let amount = ((n - 1) / symbols.len()) + 1;
+    std::iter::repeat(symbol).take(amount).collect()
+}
+
+/// Spell out a number in English words.
+///
+/// ```text
+///    21 => "twenty-one"
+///   100 => "one hundred"
+///  1234 => "one thousand two hundred thirty-four"
+/// ```
+fn english_words(n: usize) -> EcoString {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty",
+        "ninety",
+    ];
+    const SCALES: [&str; 7] =
+        ["", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion"];
+
+    /// Spell out a number below one thousand.
+    fn below_thousand(n: usize, fmt: &mut Vec<EcoString>) {
+        let (hundreds, rest) = (n / 100, n % 100);
+        if hundreds > 0 {
+            fmt.push(eco_format!("{} hundred", ONES[hundreds]));
+        }
+        match rest {
+            0 => {}
+            1..=19 => fmt.push(ONES[rest].into()),
+            _ if rest % 10 == 0 => fmt.push(TENS[rest / 10].into()),
+            _ => fmt.push(eco_format!("{}-{}", TENS[rest / 10], ONES[rest % 10])),
+        }
+    }
+
+    if n == 0 {
+        return ONES[0].into();
+    }
+
+    let mut groups = vec![];
+    let mut rest = n;
+    while rest > 0 {
+        groups.push(rest % 1000);
+        rest /= 1000;
+    }
+
+    let mut fmt = vec![];
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        below_thousand(group, &mut fmt);
+        if scale > 0 {
+            fmt.push(SCALES[scale].into());
+        }
+    }
+
+    let mut joined = EcoString::new();
+    for (i, word) in fmt.iter().enumerate() {
+        if i > 0 {
+            joined.push(' ');
+        }
+        joined.push_str(word);
+    }
+    joined
+}
+
 /// Stringify a number using a base-10 counting system with a zero digit.
 ///
 /// This function assumes that the digits occupy contiguous codepoints.
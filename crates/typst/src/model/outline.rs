@@ -6,13 +6,18 @@ use comemo::Track;
 use crate::diag::{bail, At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, select_where, Content, Context, Func, LocatableSelector,
-    NativeElement, Packed, Show, ShowSet, Smart, StyleChain, Styles,
+    cast, dict, elem, scope, select_where, Content, Context, Dict, Func,
+    LocatableSelector, NativeElement, Packed, Show, ShowSet, Smart, StyleChain, Styles,
+    Value,
 };
 use crate::introspection::{Counter, CounterKey, Locatable};
-use crate::layout::{BoxElem, Em, Fr, HElem, HideElem, Length, Rel, RepeatElem, Spacing};
+use crate::layout::{
+    BoxElem, ColumnsElem, Em, Fr, HAlignment, HElem, HideElem, Length, Rel, RepeatElem,
+    Sizing, Spacing,
+};
 use crate::model::{
-    Destination, HeadingElem, NumberingPattern, ParElem, ParbreakElem, Refable,
+    Destination, FirstLineIndent, HeadingElem, NumberingPattern, ParElem, ParbreakElem,
+    Refable,
 };
 use crate::syntax::Span;
 use crate::text::{LinebreakElem, LocalName, SpaceElem, TextElem};
@@ -117,6 +122,23 @@ pub struct OutlineElem {
     /// ```
     pub depth: Option<NonZeroUsize>,
 
+    /// A function deciding which of the targeted elements are included in the
+    /// outline. When `{none}`, all of them are included.
+    ///
+    /// The function receives each element matched by the
+    /// [`target`]($outline.target) and should return a boolean. Filtered out
+    /// elements do not count as parents when indenting nested entries.
+    ///
+    /// ```example
+    /// #outline(filter: it => it.has("label"))
+    ///
+    /// = Introduction <intro>
+    /// = Details
+    /// = Conclusion <end>
+    /// ```
+    #[borrowed]
+    pub filter: Option<Func>,
+
     /// How to indent the outline's entries.
     ///
     /// - `{none}`: No indent
@@ -179,6 +201,23 @@ pub struct OutlineElem {
     /// ```
     #[default(Some(RepeatElem::new(TextElem::packed(".")).pack()))]
     pub fill: Option<Content>,
+
+    /// The number of columns the entries are arranged in.
+    ///
+    /// The entries flow into [balanced columns]($columns.balance) below the
+    /// title. An entry's line is never split across columns.
+    ///
+    /// ```example
+    /// #outline(columns: 2)
+    ///
+    /// = Introduction
+    /// = Background
+    /// = Methods
+    /// = Results
+    /// = Discussion
+    /// ```
+    #[default(NonZeroUsize::ONE)]
+    pub columns: NonZeroUsize,
 }
 
 #[scope]
@@ -206,6 +245,7 @@ impl Show for Packed<OutlineElem> {
         let indent = self.indent(styles);
         let depth = self.depth(styles).unwrap_or(NonZeroUsize::new(usize::MAX).unwrap());
 
+        let mut entries = vec![];
         let mut ancestors: Vec<&Content> = vec![];
         let elems = engine.introspector.query(&self.target(styles).0);
 
@@ -226,6 +266,17 @@ impl Show for Packed<OutlineElem> {
                 continue;
             }
 
+            if let Some(filter) = self.filter(styles) {
+                let context = Context::new(elem.location(), Some(styles));
+                if !filter
+                    .call(engine, context.track(), [elem.clone()])?
+                    .cast::<bool>()
+                    .at(self.span())?
+                {
+                    continue;
+                }
+            }
+
             // Deals with the ancestors of the current element.
             // This is only applicable for elements with a hierarchy/level.
             while ancestors
@@ -240,18 +291,31 @@ impl Show for Packed<OutlineElem> {
                 indent,
                 engine,
                 &ancestors,
-                &mut seq,
+                &mut entries,
                 styles,
                 self.span(),
             )?;
 
             // Add the overridable outline entry, followed by a line break.
-            seq.push(entry.pack());
-            seq.push(LinebreakElem::new().pack());
+            entries.push(entry.pack());
+            entries.push(LinebreakElem::new().pack());
 
             ancestors.push(elem);
         }
 
+        let columns = self.columns(styles);
+        if columns.get() > 1 {
+            seq.push(
+                ColumnsElem::new(Content::sequence(entries))
+                    .with_count(columns)
+                    .with_balance(true)
+                    .pack()
+                    .spanned(self.span()),
+            );
+        } else {
+            seq.extend(entries);
+        }
+
         seq.push(ParbreakElem::new().pack());
 
         Ok(Content::sequence(seq))
@@ -263,7 +327,7 @@ impl ShowSet for Packed<OutlineElem> {
         let mut out = Styles::new();
         out.set(HeadingElem::set_outlined(false));
         out.set(HeadingElem::set_numbering(None));
-        out.set(ParElem::set_first_line_indent(Em::new(0.0).into()));
+        out.set(ParElem::set_first_line_indent(FirstLineIndent::default()));
         out
     }
 }
@@ -441,6 +505,36 @@ pub struct OutlineEntry {
     /// numbering set for the referenced page.
     #[required]
     pub page: Content,
+
+    /// How the [fill]($outline.entry.fill) between the element's outline and
+    /// its page number is arranged.
+    ///
+    /// This can be a dictionary with the following keys:
+    /// - `glyph`: The content to repeat instead of the outline's fill. When
+    ///   `{none}` (the default), the fill is used as is.
+    /// - `gap`: The [gap]($repeat.gap) between the repeated instances. When
+    ///   `{none}` (the default), the gap is left unchanged.
+    /// - `aligned`: Whether the leaders of all entries line up vertically,
+    ///   regardless of their indent. This places the instances exactly `gap`
+    ///   apart, starting from the page number, and sets the page numbers in a
+    ///   box of fixed width. Defaults to `{false}`.
+    /// - `page-width`: The width of the page number box of aligned leaders.
+    ///   Defaults to `{2em}`.
+    ///
+    /// ```example
+    /// #set outline.entry(leader: (
+    ///   glyph: [·],
+    ///   gap: 0.4em,
+    ///   aligned: true,
+    /// ))
+    ///
+    /// #outline(indent: auto)
+    ///
+    /// = Introduction
+    /// == Motivation
+    /// = Conclusion
+    /// ```
+    pub leader: OutlineLeader,
 }
 
 impl OutlineEntry {
@@ -483,7 +577,7 @@ impl OutlineEntry {
 
 impl Show for Packed<OutlineEntry> {
     #[typst_macros::time(name = "outline.entry", span = self.span())]
-    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         let mut seq = vec![];
         let elem = self.element();
 
@@ -503,11 +597,28 @@ impl Show for Packed<OutlineEntry> {
         seq.push(self.body().clone().linked(Destination::Location(location)));
 
         // Add filler symbols between the section name and page number.
+        let leader = self.leader(styles);
+        let aligned = leader.aligned && self.fill().is_some();
         if let Some(filler) = self.fill() {
+            let mut filler = match &leader.glyph {
+                Some(glyph) => RepeatElem::new(glyph.clone()).pack(),
+                None => filler.clone(),
+            };
+            if let Some(gap) = leader.gap {
+                filler = filler.styled(RepeatElem::set_gap(gap));
+            }
+            if aligned {
+                // Anchoring the instances at the page number lines them up
+                // across entries with different indents.
+                filler = filler
+                    .styled(RepeatElem::set_justify(false))
+                    .aligned(HAlignment::End.into());
+            }
+
             seq.push(SpaceElem::new().pack());
             seq.push(
                 BoxElem::new()
-                    .with_body(Some(filler.clone()))
+                    .with_body(Some(filler))
                     .with_width(Fr::one().into())
                     .pack()
                     .spanned(self.span()),
@@ -518,9 +629,64 @@ impl Show for Packed<OutlineEntry> {
         }
 
         // Add the page number.
-        let page = self.page().clone().linked(Destination::Location(location));
+        let mut page = self.page().clone().linked(Destination::Location(location));
+        if aligned {
+            page = BoxElem::new()
+                .with_body(Some(page.aligned(HAlignment::End.into())))
+                .with_width(Sizing::Rel(leader.page_width.into()))
+                .pack()
+                .spanned(self.span());
+        }
         seq.push(page);
 
         Ok(Content::sequence(seq))
     }
 }
+
+/// Configures the leader between an outline entry's body and its page number.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct OutlineLeader {
+    /// The content to repeat instead of the outline's fill.
+    pub glyph: Option<Content>,
+    /// The gap between the repeated instances.
+    pub gap: Option<Length>,
+    /// Whether the leaders of all entries line up vertically.
+    pub aligned: bool,
+    /// The width of the page number box of aligned leaders.
+    pub page_width: Length,
+}
+
+impl Default for OutlineLeader {
+    fn default() -> Self {
+        Self {
+            glyph: None,
+            gap: None,
+            aligned: false,
+            page_width: Em::new(2.0).into(),
+        }
+    }
+}
+
+cast! {
+    OutlineLeader,
+    self => dict! {
+        "glyph" => self.glyph,
+        "gap" => self.gap,
+        "aligned" => self.aligned,
+        "page-width" => self.page_width,
+    }.into_value(),
+    mut dict: Dict => {
+        let glyph = dict.take("glyph").ok().map(Value::cast).transpose()?;
+        let gap = dict.take("gap").ok().map(Value::cast).transpose()?;
+        let aligned = dict.take("aligned").ok().map(Value::cast).transpose()?;
+        let page_width = dict.take("page-width").ok().map(Value::cast).transpose()?;
+        dict.finish(&["glyph", "gap", "aligned", "page-width"])?;
+        let default = Self::default();
+        Self {
+            glyph: glyph.unwrap_or(default.glyph),
+            gap: gap.unwrap_or(default.gap),
+            aligned: aligned.unwrap_or(default.aligned),
+            page_width: page_width.unwrap_or(default.page_width),
+        }
+    },
+}
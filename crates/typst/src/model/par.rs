@@ -1,10 +1,11 @@
 use std::fmt::{self, Debug, Formatter};
+use std::num::NonZeroUsize;
 
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Args, Cast, Construct, Content, NativeElement, Packed, Set, Smart, StyleChain,
-    Unlabellable,
+    cast, dict, elem, Args, Cast, Construct, Content, Dict, NativeElement, Packed, Set,
+    Smart, StyleChain, Unlabellable, Value,
 };
 use crate::introspection::Locator;
 use crate::layout::{Em, Fragment, Length, Size};
@@ -55,6 +56,30 @@ pub struct ParElem {
     #[default(Em::new(0.65).into())]
     pub leading: Length,
 
+    /// How the distance between the lines of a paragraph is determined.
+    ///
+    /// With the `{"grid"}` mode, the baselines of consecutive lines snap to
+    /// a grid. This keeps them aligned even when a line contains taller
+    /// content, like inline math. Such lines take up multiple grid rows.
+    ///
+    /// ```example
+    /// #set par(leading-mode: "grid")
+    /// Lines with $x^2$, with
+    /// $sum_(i=0)^n i$ and with
+    /// plain text stay on the grid.
+    /// ```
+    #[ghost]
+    pub leading_mode: LeadingMode,
+
+    /// The distance between the baselines of the grid lines in the `{"grid"}`
+    /// [leading mode]($par.leading-mode).
+    ///
+    /// When `{auto}`, the step is the sum of the font size and the
+    /// [leading]($par.leading).
+    #[ghost]
+    #[resolve]
+    pub grid_step: Smart<Length>,
+
     /// The spacing between paragraphs.
     ///
     /// Just like leading, this defines the spacing between the bottom edge of a
@@ -109,22 +134,62 @@ pub struct ParElem {
 
     /// The indent the first line of a paragraph should have.
     ///
-    /// Only the first line of a consecutive paragraph will be indented (not
-    /// the first one in a block or on the page).
+    /// By default, only the first line of a consecutive paragraph will be
+    /// indented (not the first one in a block or on the page). To control
+    /// which paragraphs are indented, pass a dictionary with an `amount` and
+    /// a `scope`:
+    ///
+    /// - `{"never-first"}` only indents consecutive paragraphs (the default),
+    /// - `{"always"}` indents all paragraphs,
+    /// - `{"after-heading"}` additionally indents the first paragraph directly
+    ///   following a heading. If other blocks, like lists or quotes, are
+    ///   between the heading and the paragraph, it is not indented.
     ///
     /// By typographic convention, paragraph breaks are indicated either by some
     /// space between paragraphs or by indented first lines. Consider reducing
     /// the [paragraph spacing]($block.spacing) to the [`leading`]($par.leading)
     /// when using this property (e.g. using
     /// `[#show par: set block(spacing: 0.65em)]`).
+    ///
+    /// ```example
+    /// #set par(first-line-indent: (
+    ///   amount: 1em,
+    ///   scope: "after-heading",
+    /// ))
+    ///
+    /// Not indented.
+    /// = Heading
+    /// Indented after the heading.
+    ///
+    /// Indented as usual.
+    /// ```
     #[ghost]
-    pub first_line_indent: Length,
+    pub first_line_indent: FirstLineIndent,
 
     /// The indent all but the first line of a paragraph should have.
     #[ghost]
     #[resolve]
     pub hanging_indent: Length,
 
+    /// Whether quotation marks and punctuation at the edges of lines hang
+    /// into the margin.
+    ///
+    /// Opening quotation marks at the start of a line and closing quotation
+    /// marks at its end protrude completely, while other trailing punctuation
+    /// protrudes by the same amount as with [`overhang`]($text.overhang). This
+    /// optical margin alignment makes the edges of justified text look
+    /// straighter. The start and end of lines follow the text direction.
+    ///
+    /// ```example
+    /// #set par(justify: true, hanging-punctuation: true)
+    /// "Hanging quotes", they said, "make
+    /// the left edge of the paragraph look
+    /// much cleaner."
+    /// ```
+    #[ghost]
+    #[default(false)]
+    pub hanging_punctuation: bool,
+
     /// Indicates wheter an overflowing line should be shrunk.
     ///
     /// This property is set to `false` on raw blocks, because shrinking a line
@@ -134,6 +199,55 @@ pub struct ParElem {
     #[default(true)]
     pub shrink: bool,
 
+    /// The minimum number of lines of a paragraph that must be placed in a
+    /// region before it may break into the next one. When `{auto}`, the
+    /// [`orphans`]($par.orphans) property applies.
+    ///
+    /// This property is set by footnote entries to keep their first lines
+    /// together.
+    #[ghost]
+    #[internal]
+    pub min_lines: Smart<NonZeroUsize>,
+
+    /// The minimum number of lines of a paragraph that must be placed on a
+    /// page (or in a column) before the paragraph may break onto the next
+    /// one.
+    ///
+    /// If fewer lines would fit, the paragraph starts on the next page
+    /// instead. A paragraph with fewer lines than the sum of `orphans` and
+    /// [`widows`]($par.widows) is never broken.
+    ///
+    /// When `{auto}`, two lines are kept together unless the `orphan` weight
+    /// of the text's [costs]($text.costs) is zero. A number of lines always
+    /// applies, regardless of the costs.
+    ///
+    /// ```example
+    /// #set page(height: 60pt)
+    /// #set par(orphans: 3)
+    /// #v(20pt)
+    /// #lorem(12)
+    /// ```
+    #[ghost]
+    pub orphans: Smart<NonZeroUsize>,
+
+    /// The minimum number of lines of a paragraph that must be placed on a
+    /// page (or in a column) after the paragraph broke from the previous one.
+    ///
+    /// If fewer lines would remain for the next page, more lines are moved
+    /// there, as long as this leaves enough [`orphans`]($par.orphans) behind.
+    ///
+    /// When `{auto}`, two lines are kept together unless the `widow` weight of
+    /// the text's [costs]($text.costs) is zero. A number of lines always
+    /// applies, regardless of the costs.
+    ///
+    /// ```example
+    /// #set page(height: 60pt)
+    /// #set par(widows: 3)
+    /// #lorem(18)
+    /// ```
+    #[ghost]
+    pub widows: Smart<NonZeroUsize>,
+
     /// The contents of the paragraph.
     #[external]
     #[required]
@@ -203,6 +317,50 @@ pub enum Linebreaks {
     Optimized,
 }
 
+/// The indent of the first line of a paragraph.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+pub struct FirstLineIndent {
+    /// The size of the indent.
+    pub amount: Length,
+    /// Which paragraphs are indented.
+    pub scope: IndentScope,
+}
+
+cast! {
+    FirstLineIndent,
+    self => dict!["amount" => self.amount, "scope" => self.scope].into_value(),
+    amount: Length => Self { amount, scope: IndentScope::default() },
+    mut dict: Dict => {
+        let amount = dict.take("amount")?.cast()?;
+        let scope = dict.take("scope").ok().map(Value::cast).transpose()?;
+        dict.finish(&["amount", "scope"])?;
+        Self { amount, scope: scope.unwrap_or_default() }
+    },
+}
+
+/// Which paragraphs have an indented first line.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum IndentScope {
+    /// Only paragraphs directly following another paragraph.
+    #[default]
+    NeverFirst,
+    /// All paragraphs.
+    Always,
+    /// Paragraphs directly following another paragraph or a heading.
+    AfterHeading,
+}
+
+/// How the distance between the lines of a paragraph is determined.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum LeadingMode {
+    /// Lines are separated by the leading.
+    #[default]
+    Normal,
+    /// Lines are separated by at least the leading, with their baselines
+    /// snapped to a grid.
+    Grid,
+}
+
 /// A paragraph break.
 ///
 /// This starts a new paragraph. Especially useful when used within code like
@@ -1,7 +1,7 @@
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Content, Depth, Label, NativeElement, Packed, Show, ShowSet, Smart,
+    cast, elem, Cast, Content, Depth, Label, NativeElement, Packed, Show, ShowSet, Smart,
     StyleChain, Styles,
 };
 use crate::layout::{
@@ -124,6 +124,30 @@ pub struct QuoteElem {
     #[borrowed]
     attribution: Option<Attribution>,
 
+    /// Where the attribution of a block quote is placed.
+    ///
+    /// ```example
+    /// #set quote(block: true)
+    ///
+    /// #quote(
+    ///   attribution: [Plato],
+    ///   attribution-position: "above",
+    /// )[
+    ///   The beginning is the most
+    ///   important part of the work.
+    /// ]
+    ///
+    /// #quote(
+    ///   attribution: [Heraclitus],
+    ///   attribution-position: "inline",
+    /// )[
+    ///   No man ever steps in the same
+    ///   river twice.
+    /// ]
+    /// ```
+    #[default(AttributionPosition::Below)]
+    attribution_position: AttributionPosition,
+
     /// The quote.
     #[required]
     body: Content,
@@ -152,6 +176,18 @@ cast! {
     label: Label => Self::Label(label),
 }
 
+/// Where the attribution of a block quote is placed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum AttributionPosition {
+    /// In its own line above the quote.
+    Above,
+    /// In its own line below the quote.
+    #[default]
+    Below,
+    /// Flowing with the last line of the quote.
+    Inline,
+}
+
 impl Show for Packed<QuoteElem> {
     #[typst_macros::time(name = "quote", span = self.span())]
     fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
@@ -183,12 +219,8 @@ impl Show for Packed<QuoteElem> {
         }
 
         if block {
-            realized = BlockElem::new()
-                .with_body(Some(BlockChild::Content(realized)))
-                .pack()
-                .spanned(self.span());
-
-            if let Some(attribution) = self.attribution(styles).as_ref() {
+            let position = self.attribution_position(styles);
+            let attribution = self.attribution(styles).as_ref().map(|attribution| {
                 let mut seq = vec![TextElem::packed('—'), SpaceElem::new().pack()];
 
                 match attribution {
@@ -205,10 +237,34 @@ impl Show for Packed<QuoteElem> {
                     }
                 }
 
+                Content::sequence(seq)
+            });
+
+            if let (Some(attribution), AttributionPosition::Inline) =
+                (&attribution, position)
+            {
+                realized += SpaceElem::new().pack() + attribution.clone();
+            }
+
+            realized = BlockElem::new()
+                .with_body(Some(BlockChild::Content(realized)))
+                .pack()
+                .spanned(self.span());
+
+            if let Some(attribution) = attribution {
                 // Use v(0.9em, weak: true) bring the attribution closer to the
                 // quote.
                 let weak_v = VElem::weak(Spacing::Rel(Em::new(0.9).into())).pack();
-                realized += weak_v + Content::sequence(seq).aligned(Alignment::END);
+                let attribution = attribution.aligned(Alignment::END);
+                match position {
+                    AttributionPosition::Above => {
+                        realized = attribution + weak_v + realized;
+                    }
+                    AttributionPosition::Below => {
+                        realized += weak_v + attribution;
+                    }
+                    AttributionPosition::Inline => {}
+                }
             }
 
             realized = PadElem::new(realized).pack();
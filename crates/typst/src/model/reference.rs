@@ -8,11 +8,12 @@ use crate::foundations::{
     Smart, StyleChain, Synthesize,
 };
 use crate::introspection::{Counter, Locatable};
-use crate::math::EquationElem;
+use crate::math::{EquationElem, EquationLineElem};
 use crate::model::{
-    BibliographyElem, CiteElem, Destination, Figurable, FootnoteElem, Numbering,
+    BibliographyElem, CiteElem, Destination, Figurable, FigureElem, FigureKind,
+    FootnoteElem, Numbering,
 };
-use crate::text::TextElem;
+use crate::text::{localized_str, TextElem};
 
 /// A reference to a label or bibliography.
 ///
@@ -62,6 +63,20 @@ use crate::text::TextElem;
 /// To customize the supplement, add content in square brackets after the
 /// reference: `[@intro[Chapter]]`.
 ///
+/// # Grouped references
+/// To reference several elements at once, list their labels in parentheses:
+/// `[@(intro, outro)]`. If they are all numbered elements of the same kind,
+/// the supplement is pluralized and the numbers are listed after it.
+/// Otherwise, the references are simply listed one after another. For
+/// languages without a translated plural, the singular supplement is used.
+///
+/// ```example
+/// #figure(rect[A], caption: [First]) <a>
+/// #figure(rect[B], caption: [Second]) <b>
+///
+/// See @(a, b).
+/// ```
+///
 /// # Customization
 /// If you write a show rule for references, you can access the referenced
 /// element through the `element` field of the reference. The `element` may
@@ -100,6 +115,12 @@ pub struct RefElem {
     #[required]
     pub target: Label,
 
+    /// Further labels that should be referenced together with the target.
+    #[internal]
+    #[default]
+    #[parse(None)]
+    pub others: Vec<Label>,
+
     /// A supplement for the reference.
     ///
     /// For references to headings or figures, this is added before the
@@ -163,6 +184,10 @@ impl Synthesize for Packed<RefElem> {
 impl Show for Packed<RefElem> {
     #[typst_macros::time(name = "ref", span = self.span())]
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        if !self.others(styles).is_empty() {
+            return show_group(self, engine, styles);
+        }
+
         let target = *self.target();
         let elem = engine.introspector.query_label(target);
         let span = self.span();
@@ -201,15 +226,22 @@ impl Show for Packed<RefElem> {
             .ok_or_else(|| {
                 eco_format!("cannot reference {} without numbering", elem.func().name())
             })
-            .hint(eco_format!(
-                "you can enable {} numbering with `#set {}(numbering: \"1.\")`",
-                elem.func().name(),
-                if elem.func() == EquationElem::elem() {
-                    "math.equation"
-                } else {
-                    elem.func().name()
-                }
-            ))
+            .hint(if elem.func() == EquationLineElem::elem() {
+                eco_format!(
+                    "you can number the lines of equations with \
+                     `#set math.equation(numbering: \"(1a)\", sub-numbering: true)`"
+                )
+            } else {
+                eco_format!(
+                    "you can enable {} numbering with `#set {}(numbering: \"1.\")`",
+                    elem.func().name(),
+                    if elem.func() == EquationElem::elem() {
+                        "math.equation"
+                    } else {
+                        elem.func().name()
+                    }
+                )
+            })
             .at(span)?;
 
         let loc = elem.location().unwrap();
@@ -237,6 +269,127 @@ impl Show for Packed<RefElem> {
     }
 }
 
+/// Show a reference to multiple targets.
+///
+/// If all targets are numbered elements of the same kind, they share one
+/// pluralized supplement. Otherwise, each target is referenced on its own.
+fn show_group(
+    reference: &Packed<RefElem>,
+    engine: &mut Engine,
+    styles: StyleChain,
+) -> SourceResult<Content> {
+    let span = reference.span();
+    let targets: Vec<Label> = std::iter::once(*reference.target())
+        .chain(reference.others(styles))
+        .collect();
+
+    // Find the referenced elements, giving up on grouping as soon as one of
+    // them can't be grouped.
+    let mut elems = vec![];
+    for &target in &targets {
+        if BibliographyElem::has(engine, target) {
+            break;
+        }
+        let Ok(elem) = engine.introspector.query_label(target) else { break };
+        let Some(refable) = elem.with::<dyn Refable>() else { break };
+        if refable.numbering().is_none() {
+            break;
+        }
+        if let Some(first) =
+            elems.first().and_then(|first: &Content| first.with::<dyn Refable>())
+        {
+            if elem.func() != elems[0].func()
+                || refable.supplement() != first.supplement()
+            {
+                break;
+            }
+        }
+        elems.push(elem.clone());
+    }
+
+    // Mixed or unnumbered targets are referenced one by one.
+    if elems.len() < targets.len() {
+        let parts = targets.into_iter().map(|target| {
+            let mut elem = RefElem::new(target);
+            if let Smart::Custom(supplement) = reference.supplement(styles) {
+                elem.push_supplement(Smart::Custom(supplement.clone()));
+            }
+            elem.pack().spanned(span)
+        });
+        return Ok(join(parts, styles));
+    }
+
+    let mut parts = vec![];
+    for elem in &elems {
+        let refable = elem.with::<dyn Refable>().unwrap();
+        let loc = elem.location().unwrap();
+        let numbering = refable.numbering().unwrap();
+        let numbers = refable.counter().display_at_loc(
+            engine,
+            loc,
+            styles,
+            &numbering.clone().trimmed(),
+        )?;
+        parts.push(numbers.linked(Destination::Location(loc)));
+    }
+
+    let first = &elems[0];
+    let supplement = match reference.supplement(styles).as_ref() {
+        Smart::Auto => {
+            let singular = first.with::<dyn Refable>().unwrap().supplement();
+            let lang = TextElem::lang_in(styles);
+            let region = TextElem::region_in(styles);
+
+            // A figure is named after its kind rather than itself.
+            let kind = first
+                .to_packed::<FigureElem>()
+                .map(|figure| figure.kind(StyleChain::default()));
+            let func = match kind {
+                Some(Smart::Custom(FigureKind::Elem(func))) => func,
+                _ => first.func(),
+            };
+
+            // Only pluralize the default supplement, not a custom one.
+            let is_default = func
+                .local_name(lang, region)
+                .is_some_and(|name| singular.plain_text() == name);
+            match func.local_name_plural(lang, region) {
+                Some(plural) if is_default => TextElem::packed(plural),
+                _ => singular,
+            }
+        }
+        Smart::Custom(None) => Content::empty(),
+        Smart::Custom(Some(supplement)) => {
+            supplement.resolve(engine, styles, [first.clone()])?
+        }
+    };
+
+    let mut content = join(parts, styles);
+    if !supplement.is_empty() {
+        content = supplement + TextElem::packed("\u{a0}") + content;
+    }
+
+    Ok(content)
+}
+
+/// Join references with commas and a localized "and" before the last one.
+fn join(parts: impl IntoIterator<Item = Content>, styles: StyleChain) -> Content {
+    let parts: Vec<_> = parts.into_iter().collect();
+    let and =
+        localized_str(TextElem::lang_in(styles), TextElem::region_in(styles), "and");
+    let mut seq = vec![];
+    let len = parts.len();
+    for (i, part) in parts.into_iter().enumerate() {
+        if i + 1 == len && i > 0 {
+            seq.push(TextElem::packed(eco_format!(" {and} ")));
+        } else if i > 0 {
+            seq.push(TextElem::packed(", "));
+        }
+        seq.push(part);
+    }
+    Content::sequence(seq)
+}
+
 /// Turn a reference into a citation.
 fn to_citation(
     reference: &Packed<RefElem>,
@@ -13,7 +13,7 @@ use crate::layout::{
     show_grid_cell, Abs, Alignment, Axes, BlockElem, Cell, CellGrid, Celled, Dir,
     Fragment, GridCell, GridFooter, GridHLine, GridHeader, GridLayouter, GridVLine,
     Length, LinePosition, OuterHAlignment, OuterVAlignment, Regions, Rel, ResolvableCell,
-    ResolvableGridChild, ResolvableGridItem, Sides, TrackSizings,
+    ResolvableGridChild, ResolvableGridItem, Sides, TrackSizings, VAlignment,
 };
 use crate::model::Figurable;
 use crate::syntax::Span;
@@ -236,6 +236,50 @@ pub struct TableElem {
     #[default(Celled::Value(Sides::splat(Some(Abs::pt(5.0).into()))))]
     pub inset: Celled<Sides<Option<Rel<Length>>>>,
 
+    /// Whether to distribute the height of cells spanning multiple rows
+    /// evenly across their spanned automatically sized rows.
+    ///
+    /// By default, a tall cell spanning multiple rows only expands the last
+    /// automatically sized row it spans. With this option enabled, each of
+    /// the spanned `{auto}` rows grows by a share of the needed height instead.
+    /// Rows with a fixed height are not affected.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   balance-spans: true,
+    ///   table.cell(rowspan: 3, lorem(20)),
+    ///   [A], [B], [C],
+    /// )
+    /// ```
+    #[default(false)]
+    pub balance_spans: bool,
+
+    /// How often the [header]($table.header) is repeated when the table
+    /// breaks across pages.
+    ///
+    /// If `{true}`, the header is repeated on every page. If `{false}`, it is
+    /// never repeated. If an integer, it is repeated on at most that many
+    /// pages following the first one. This only has an effect if the header
+    /// itself is set to [repeat]($table.header.repeat).
+    ///
+    /// ```example
+    /// #set page(height: 8em)
+    /// #table(
+    ///   repeat-header: 1,
+    ///   table.header[*Number*],
+    ///   ..range(1, 10).map(str),
+    /// )
+    /// ```
+    #[default(HeaderRepeat(None))]
+    pub repeat_header: HeaderRepeat,
+
+    /// Whether the [footer]($table.footer) is repeated when the table breaks
+    /// across pages. This only has an effect if the footer itself is set to
+    /// [repeat]($table.footer.repeat).
+    #[default(true)]
+    pub repeat_footer: bool,
+
     /// The contents of the table cells, plus any extra table lines specified
     /// with the [`table.hline`]($table.hline) and
     /// [`table.vline`]($table.vline) elements.
@@ -292,14 +336,16 @@ fn layout_table(
     // Use trace to link back to the table when a specific cell errors
     let tracepoint = || Tracepoint::Call(Some(eco_format!("table")));
     let resolve_item = |item: &TableItem| item.to_resolvable(styles);
+    let repeat_header = elem.repeat_header(styles);
+    let repeat_footer = elem.repeat_footer(styles);
     let children = elem.children().iter().map(|child| match child {
         TableChild::Header(header) => ResolvableGridChild::Header {
-            repeat: header.repeat(styles),
+            repeat: header.repeat(styles) && repeat_header.0 != Some(0),
             span: header.span(),
             items: header.children().iter().map(resolve_item),
         },
         TableChild::Footer(footer) => ResolvableGridChild::Footer {
-            repeat: footer.repeat(styles),
+            repeat: footer.repeat(styles) && repeat_footer,
             span: footer.span(),
             items: footer.children().iter().map(resolve_item),
         },
@@ -320,8 +366,31 @@ fn layout_table(
     )
     .trace(engine.world, tracepoint, elem.span())?;
 
-    let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
-    layouter.layout(engine)
+    let layouter = GridLayouter::new(&grid, regions, styles, elem.span())
+        .with_balanced_spans(elem.balance_spans(styles))
+        .with_header_repeat_limit(repeat_header.0);
+    let mut fragment = layouter.layout(engine)?;
+    for frame in fragment.iter_mut() {
+        frame.structure(elem.clone().pack());
+    }
+
+    Ok(fragment)
+}
+
+/// How often a table header is repeated on subsequent pages.
+///
+/// `None` means that there is no limit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct HeaderRepeat(pub Option<usize>);
+
+cast! {
+    HeaderRepeat,
+    self => match self.0 {
+        None => true.into_value(),
+        Some(limit) => limit.into_value(),
+    },
+    v: bool => Self(if v { None } else { Some(0) }),
+    v: usize => Self(Some(v)),
 }
 
 impl LocalName for Packed<TableElem> {
@@ -866,6 +935,10 @@ impl ResolvableCell for Packed<TableCell> {
             }),
         );
         cell.push_breakable(Smart::Custom(breakable));
+        let baseline = matches!(
+            cell.align(styles),
+            Smart::Custom(align) if align.y() == Some(VAlignment::Baseline)
+        );
         Cell {
             body: self.pack(),
             locator,
@@ -875,6 +948,8 @@ impl ResolvableCell for Packed<TableCell> {
             stroke,
             stroke_overridden,
             breakable,
+            baseline,
+            structure: None,
         }
     }
 
@@ -30,8 +30,8 @@ use crate::layout::{
 };
 use crate::math::{EquationElem, LayoutMath};
 use crate::model::{
-    CiteElem, CiteGroup, DocumentElem, EnumElem, EnumItem, ListElem, ListItem, ParElem,
-    ParbreakElem, TermItem, TermsElem,
+    CiteElem, CiteGroup, DocumentElem, EnumElem, EnumItem, FootnoteElem, HeadingElem,
+    ListElem, ListItem, ParElem, ParbreakElem, TermItem, TermsElem,
 };
 use crate::syntax::Span;
 use crate::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
@@ -81,6 +81,10 @@ struct Builder<'a, 'v, 't> {
     flow: FlowBuilder<'a>,
     /// The current paragraph building state.
     par: ParBuilder<'a>,
+    /// Whether the paragraph under construction ends with a run-in heading.
+    run_in: bool,
+    /// Whether the last inline content was a footnote, ignoring spaces.
+    after_footnote: bool,
     /// The current list building state.
     list: ListBuilder<'a>,
     /// The current citation grouping state.
@@ -101,6 +105,8 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
             doc: top.then(DocBuilder::default),
             flow: FlowBuilder::default(),
             par: ParBuilder::default(),
+            run_in: false,
+            after_footnote: false,
             list: ListBuilder::default(),
             cites: CiteGroupBuilder::default(),
         }
@@ -119,6 +125,16 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
                 .store(EquationElem::new(content.clone()).pack().spanned(content.span()));
         }
 
+        // Mark footnotes that directly follow another footnote, only separated
+        // by spaces, such that their markers can be grouped.
+        if let Some(note) = content.to_packed::<FootnoteElem>() {
+            if self.after_footnote && note.grouped().is_none() {
+                let mut note = note.clone();
+                note.push_grouped(true);
+                content = self.arenas.store(note.pack());
+            }
+        }
+
         // Styled elements and sequences can (at least currently) also have
         // labels, so this needs to happen before they are handled.
         if let Some(realized) = process(self.engine, &mut self.locator, content, styles)?
@@ -130,9 +146,28 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
                     hint: "check whether the show rule matches its own output"
                 );
             }
-            let result = self.accept(self.arenas.store(realized), styles);
+            let realized = self.arenas.store(realized);
+            let result = self.accept(realized, styles);
             self.engine.route.decrease();
-            return result;
+            result?;
+
+            if let Some(heading) = content.to_packed::<HeadingElem>() {
+                // Show-set rules are only applied to the realized content, so
+                // we need its styles to know whether the heading runs in.
+                let styles = match realized.to_packed::<StyledElem>() {
+                    Some(styled) => styles.chain(&styled.styles),
+                    None => styles,
+                };
+                if heading.run_in(styles) {
+                    self.run_in = !self.par.0.is_empty();
+                }
+            }
+
+            if content.is::<FootnoteElem>() {
+                self.after_footnote = true;
+            }
+
+            return Ok(());
         }
 
         if let Some(styled) = content.to_packed::<StyledElem>() {
@@ -146,6 +181,10 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
             return Ok(());
         }
 
+        if !content.is::<SpaceElem>() && !content.is::<TagElem>() {
+            self.after_footnote = false;
+        }
+
         // Try to merge `content` with an element under construction
 
         if self.cites.accept(content, styles) {
@@ -164,7 +203,16 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
             return Ok(());
         }
 
+        // A paragraph break directly after a run-in heading is ignored so that
+        // the heading merges into the following paragraph.
+        if self.run_in && content.is::<ParbreakElem>() {
+            return Ok(());
+        }
+
         if self.par.accept(content, styles) {
+            if !content.is::<SpaceElem>() && !content.is::<TagElem>() {
+                self.run_in = false;
+            }
             return Ok(());
         }
 
@@ -275,6 +323,7 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
     /// Interrupts paragraph building and adds the resulting paragraph element to the builder.
     fn interrupt_par(&mut self) -> SourceResult<()> {
         self.interrupt_list()?;
+        self.run_in = false;
         if !self.par.0.is_empty() {
             let (par, styles) = mem::take(&mut self.par).finish();
             self.accept(self.arenas.store(par.pack()), styles)?;
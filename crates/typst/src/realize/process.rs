@@ -48,9 +48,9 @@ pub fn process(
 
     // If the element isn't yet prepared (we're seeing it for the first time),
     // prepare it.
-    let mut tag = None;
+    let mut tags = None;
     if !prepared {
-        tag = prepare(engine, locator, &mut target, &mut map, styles)?;
+        tags = prepare(engine, locator, &mut target, &mut map, styles)?;
     }
 
     // Apply a step, if there is one.
@@ -67,9 +67,10 @@ pub fn process(
         None => target,
     };
 
-    // If necessary, add the tag generated in the preparation.
-    if let Some(tag) = tag {
-        output = tag + output;
+    // If necessary, surround the output with the tags generated in the
+    // preparation.
+    if let Some((start, end)) = tags {
+        output = start + output + end;
     }
 
     Ok(Some(output.styled_with_map(map)))
@@ -187,7 +188,7 @@ fn prepare(
     target: &mut Content,
     map: &mut Styles,
     styles: StyleChain,
-) -> SourceResult<Option<Content>> {
+) -> SourceResult<Option<(Content, Content)>> {
     // Generate a location for the element, which uniquely identifies it in
     // the document. This has some overhead, so we only do it for elements
     // that are explicitly marked as locatable and labelled elements.
@@ -221,18 +222,21 @@ fn prepare(
     // available in rules.
     target.materialize(styles.chain(map));
 
-    // If the element is locatable, create a tag element to be able to find the
-    // element in the frames after layout. Do this after synthesis and
-    // materialization, so that it includes the synthesized fields. Do it before
-    // marking as prepared so that show-set rules will apply to this element
-    // when queried.
-    let tag = key.map(|key| TagElem::packed(Tag::new(target.clone(), key)));
+    // If the element is locatable, create tag elements to be able to find the
+    // element and its extent in the frames after layout. Do this after
+    // synthesis and materialization, so that it includes the synthesized
+    // fields. Do it before marking as prepared so that show-set rules will
+    // apply to this element when queried.
+    let tags = key.map(|key| {
+        let end = Tag::End(target.location().unwrap());
+        (TagElem::packed(Tag::new(target.clone(), key)), TagElem::packed(end))
+    });
 
     // Ensure that this preparation only runs once by marking the element as
     // prepared.
     target.mark_prepared();
 
-    Ok(tag)
+    Ok(tags)
 }
 
 /// Apply a step.
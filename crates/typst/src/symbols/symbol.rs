@@ -48,6 +48,7 @@ pub struct Symbol(Repr);
 
 /// The character of a symbol, possibly with a function.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
 pub struct SymChar(char, Option<fn() -> Func>);
 
 /// The internal representation.
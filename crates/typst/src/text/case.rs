@@ -1,5 +1,5 @@
 use crate::foundations::{cast, func, Cast, Content, Str};
-use crate::text::TextElem;
+use crate::text::{Lang, Region, TextElem};
 
 /// Converts a string or content to lowercase.
 ///
@@ -7,14 +7,31 @@ use crate::text::TextElem;
 /// ```example
 /// #lower("ABC") \
 /// #lower[*My Text*] \
-/// #lower[already low]
+/// #lower[already low] \
+/// #lower("DİYARBAKIR", lang: "tr")
 /// ```
 #[func(title = "Lowercase")]
 pub fn lower(
     /// The text to convert to lowercase.
     text: Caseable,
+    /// The language whose casing rules to follow.
+    ///
+    /// For example, Turkish and Azerbaijani lowercase `I` to the dotless `ı`.
+    /// When converting content, this defaults to the
+    /// [text language]($text.lang) in effect where the content ends up. When
+    /// converting a string, language-independent rules are used by default.
+    #[named]
+    lang: Option<Lang>,
+    /// The region whose casing conventions to follow, in addition to those of
+    /// the language.
+    ///
+    /// Like the language, this defaults to the [text region]($text.region)
+    /// when converting content. No casing rule currently depends on the
+    /// region alone.
+    #[named]
+    region: Option<Region>,
 ) -> Caseable {
-    case(text, Case::Lower)
+    case(text, Casing { case: Case::Lower, lang, region, eszett: false })
 }
 
 /// Converts a string or content to uppercase.
@@ -23,22 +40,44 @@ pub fn lower(
 /// ```example
 /// #upper("abc") \
 /// #upper[*my text*] \
-/// #upper[ALREADY HIGH]
+/// #upper[ALREADY HIGH] \
+/// #upper("istanbul", lang: "tr") \
+/// #upper("straße", eszett: true)
 /// ```
 #[func(title = "Uppercase")]
 pub fn upper(
     /// The text to convert to uppercase.
     text: Caseable,
+    /// The language whose casing rules to follow.
+    ///
+    /// For example, Turkish and Azerbaijani uppercase `i` to the dotted `İ`.
+    /// When converting content, this defaults to the
+    /// [text language]($text.lang) in effect where the content ends up. When
+    /// converting a string, language-independent rules are used by default.
+    #[named]
+    lang: Option<Lang>,
+    /// The region whose casing conventions to follow, in addition to those of
+    /// the language.
+    ///
+    /// Like the language, this defaults to the [text region]($text.region)
+    /// when converting content. No casing rule currently depends on the
+    /// region alone.
+    #[named]
+    region: Option<Region>,
+    /// Whether to uppercase `ß` to the capital sharp s `ẞ` instead of `SS`.
+    #[named]
+    #[default(false)]
+    eszett: bool,
 ) -> Caseable {
-    case(text, Case::Upper)
+    case(text, Casing { case: Case::Upper, lang, region, eszett })
 }
 
 /// Change the case of text.
-fn case(text: Caseable, case: Case) -> Caseable {
+fn case(text: Caseable, casing: Casing) -> Caseable {
     match text {
-        Caseable::Str(v) => Caseable::Str(case.apply(&v).into()),
+        Caseable::Str(v) => Caseable::Str(casing.apply(&v, None, None).into()),
         Caseable::Content(v) => {
-            Caseable::Content(v.styled(TextElem::set_case(Some(case))))
+            Caseable::Content(v.styled(TextElem::set_case(Some(casing))))
         }
     }
 }
@@ -77,3 +116,71 @@ impl Case {
         }
     }
 }
+
+/// A case transformation together with the locale-specific rules it should
+/// respect.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Casing {
+    /// The case to convert to.
+    pub case: Case,
+    /// The language whose rules to follow. If `None`, the language passed to
+    /// [`apply`](Self::apply) is used instead.
+    pub lang: Option<Lang>,
+    /// The region whose rules to follow. If `None`, the region passed to
+    /// [`apply`](Self::apply) is used instead.
+    pub region: Option<Region>,
+    /// Whether `ß` should be uppercased to `ẞ` instead of `SS`.
+    pub eszett: bool,
+}
+
+impl Casing {
+    /// Apply the casing to a string, falling back to the given language and
+    /// region if none were configured explicitly.
+    pub fn apply(self, text: &str, lang: Option<Lang>, region: Option<Region>) -> String {
+        let lang = self.lang.or(lang);
+        let region = self.region.or(region);
+        if self.tailored(lang, region) {
+            self.case.apply(&self.prepare(text, lang))
+        } else {
+            self.case.apply(text)
+        }
+    }
+
+    /// Whether the locale or the options call for rules beyond the default
+    /// Unicode case mapping.
+    fn tailored(self, lang: Option<Lang>, _: Option<Region>) -> bool {
+        is_turkic(lang) || self.eszett && self.case == Case::Upper
+    }
+
+    /// Replaces the characters whose case mapping is tailored, leaving the
+    /// rest to the default, context-sensitive mapping of the whole string
+    /// (which, for instance, picks the final form of the Greek sigma).
+    fn prepare(self, text: &str, lang: Option<Lang>) -> String {
+        let turkic = is_turkic(lang);
+        let mut out = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (self.case, c) {
+                (Case::Upper, 'i') if turkic => out.push('İ'),
+                (Case::Upper, 'ß') if self.eszett => out.push('ẞ'),
+                (Case::Lower, 'İ') if turkic => out.push('i'),
+                (Case::Lower, 'I') if turkic => {
+                    // A combining dot above turns a dotless capital into the
+                    // dotted lowercase letter.
+                    if chars.next_if_eq(&'\u{307}').is_some() {
+                        out.push('i');
+                    } else {
+                        out.push('ı');
+                    }
+                }
+                _ => out.push(c),
+            }
+        }
+        out
+    }
+}
+
+/// Whether the language uses the Turkic dotted and dotless `i`.
+fn is_turkic(lang: Option<Lang>) -> bool {
+    matches!(lang, Some(Lang::TURKISH | Lang::AZERBAIJANI))
+}
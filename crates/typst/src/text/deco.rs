@@ -4,15 +4,15 @@ use ttf_parser::{GlyphId, OutlineBuilder};
 
 use crate::diag::SourceResult;
 use crate::engine::Engine;
-use crate::foundations::{elem, Content, Packed, Show, Smart, StyleChain};
+use crate::foundations::{elem, Cast, Content, Packed, Show, Smart, StyleChain};
 use crate::layout::{
-    Abs, Corners, Em, Frame, FrameItem, Length, Point, Rel, Sides, Size,
+    Abs, Corners, Em, Frame, FrameItem, Length, Point, Radius, Rel, Sides, Size,
 };
 use crate::syntax::Span;
 use crate::text::{
     BottomEdge, BottomEdgeMetric, TextElem, TextItem, TopEdge, TopEdgeMetric,
 };
-use crate::visualize::{styled_rect, Color, FixedStroke, Geometry, Paint, Stroke};
+use crate::visualize::{styled_rect, Color, FixedStroke, Geometry, Paint, Path, Stroke};
 
 /// Underlines text.
 ///
@@ -63,6 +63,10 @@ pub struct UnderlineElem {
     /// Whether the line skips sections in which it would collide with the
     /// glyphs.
     ///
+    /// Evasion can look out of place for connected scripts like Arabic, where
+    /// the line runs through the connections between glyphs. Disable it in
+    /// such cases.
+    ///
     /// ```example
     /// This #underline(evade: true)[is great].
     /// This #underline(evade: false)[is less great].
@@ -70,6 +74,15 @@ pub struct UnderlineElem {
     #[default(true)]
     pub evade: bool,
 
+    /// The space to leave between the line and the glyphs it evades.
+    ///
+    /// ```example
+    /// #underline(gap: 0.2em)[gyp] \
+    /// #underline(gap: 0pt)[gyp]
+    /// ```
+    #[default(Em::new(0.08).into())]
+    pub gap: Length,
+
     /// Whether the line is placed behind the content it underlines.
     ///
     /// ```example
@@ -93,6 +106,7 @@ impl Show for Packed<UnderlineElem> {
                 stroke: self.stroke(styles).unwrap_or_default(),
                 offset: self.offset(styles),
                 evade: self.evade(styles),
+                gap: self.gap(styles),
                 background: self.background(styles),
             },
             extent: self.extent(styles),
@@ -162,6 +176,14 @@ pub struct OverlineElem {
     #[default(true)]
     pub evade: bool,
 
+    /// The space to leave between the line and the glyphs it evades.
+    ///
+    /// ```example
+    /// #overline(gap: 0.2em)[Hold]
+    /// ```
+    #[default(Em::new(0.08).into())]
+    pub gap: Length,
+
     /// Whether the line is placed behind the content it overlines.
     ///
     /// ```example
@@ -185,6 +207,7 @@ impl Show for Packed<OverlineElem> {
                 stroke: self.stroke(styles).unwrap_or_default(),
                 offset: self.offset(styles),
                 evade: self.evade(styles),
+                gap: self.gap(styles),
                 background: self.background(styles),
             },
             extent: self.extent(styles),
@@ -239,6 +262,19 @@ pub struct StrikeElem {
     #[resolve]
     pub extent: Length,
 
+    /// The style of the line. The thickness of the line is set through the
+    /// `stroke`.
+    ///
+    /// A wavy line is adjusted to end on a full wave. Hence, the waves of
+    /// adjacent pieces, for instance across a line break, continue each other.
+    ///
+    /// ```example
+    /// This is #strike(style: "double")[double]. \
+    /// This is #strike(style: "wavy", stroke: 0.5pt + red)[wavy].
+    /// ```
+    #[default(StrikeStyle::Single)]
+    pub style: StrikeStyle,
+
     /// Whether the line is placed behind the content.
     ///
     /// ```example
@@ -262,6 +298,7 @@ impl Show for Packed<StrikeElem> {
             line: DecoLine::Strikethrough {
                 stroke: self.stroke(styles).unwrap_or_default(),
                 offset: self.offset(styles),
+                style: self.style(styles),
                 background: self.background(styles),
             },
             extent: self.extent(styles),
@@ -269,6 +306,17 @@ impl Show for Packed<StrikeElem> {
     }
 }
 
+/// The style of a strikethrough line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum StrikeStyle {
+    /// A single straight line.
+    Single,
+    /// Two parallel straight lines.
+    Double,
+    /// A wavy line.
+    Wavy,
+}
+
 /// Highlights text with a background color.
 ///
 /// # Example
@@ -342,7 +390,7 @@ pub struct HighlightElem {
     /// ```
     #[resolve]
     #[fold]
-    pub radius: Corners<Option<Rel<Length>>>,
+    pub radius: Corners<Option<Radius<Rel<Length>>>>,
 
     /// The content that should be highlighted.
     #[required]
@@ -380,22 +428,26 @@ pub struct Decoration {
 
 /// A kind of decorative line.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[allow(clippy::large_enum_variant)]
 enum DecoLine {
     Underline {
         stroke: Stroke<Abs>,
         offset: Smart<Abs>,
         evade: bool,
+        gap: Length,
         background: bool,
     },
     Strikethrough {
         stroke: Stroke<Abs>,
         offset: Smart<Abs>,
+        style: StrikeStyle,
         background: bool,
     },
     Overline {
         stroke: Stroke<Abs>,
         offset: Smart<Abs>,
         evade: bool,
+        gap: Length,
         background: bool,
     },
     Highlight {
@@ -403,11 +455,16 @@ enum DecoLine {
         stroke: Sides<Option<FixedStroke>>,
         top_edge: TopEdge,
         bottom_edge: BottomEdge,
-        radius: Corners<Rel<Abs>>,
+        radius: Corners<Radius<Rel<Abs>>>,
     },
 }
 
 /// Add line decorations to a single run of shaped text.
+///
+/// The `joins` define whether the decoration continues into the text to the
+/// left and right of the run. A highlight is not rounded, stroked, or extended
+/// on such sides, so that the pieces of a highlight that spans multiple runs or
+/// lines fit together.
 pub(crate) fn decorate(
     frame: &mut Frame,
     deco: &Decoration,
@@ -415,16 +472,35 @@ pub(crate) fn decorate(
     width: Abs,
     shift: Abs,
     pos: Point,
+    joins: (bool, bool),
 ) {
     let font_metrics = text.font.metrics();
 
     if let DecoLine::Highlight { fill, stroke, top_edge, bottom_edge, radius } =
         &deco.line
     {
+        let (join_left, join_right) = joins;
+        let mut radius = *radius;
+        let mut stroke = stroke.clone();
+        let mut left = deco.extent;
+        let mut right = deco.extent;
+        if join_left {
+            radius.top_left = Radius::default();
+            radius.bottom_left = Radius::default();
+            stroke.left = None;
+            left = Abs::zero();
+        }
+        if join_right {
+            radius.top_right = Radius::default();
+            radius.bottom_right = Radius::default();
+            stroke.right = None;
+            right = Abs::zero();
+        }
+
         let (top, bottom) = determine_edges(text, *top_edge, *bottom_edge);
-        let size = Size::new(width + 2.0 * deco.extent, top - bottom);
-        let rects = styled_rect(size, radius, fill.clone(), stroke);
-        let origin = Point::new(pos.x - deco.extent, pos.y - top - shift);
+        let size = Size::new(left + width + right, top - bottom);
+        let rects = styled_rect(size, &radius, fill.clone(), &stroke);
+        let origin = Point::new(pos.x - left, pos.y - top - shift);
         frame.prepend_multiple(
             rects
                 .into_iter()
@@ -433,15 +509,20 @@ pub(crate) fn decorate(
         return;
     }
 
-    let (stroke, metrics, offset, evade, background) = match &deco.line {
-        DecoLine::Strikethrough { stroke, offset, background } => {
-            (stroke, font_metrics.strikethrough, offset, false, *background)
-        }
-        DecoLine::Overline { stroke, offset, evade, background } => {
-            (stroke, font_metrics.overline, offset, *evade, *background)
+    let (stroke, metrics, offset, evade, gap, background) = match &deco.line {
+        DecoLine::Strikethrough { stroke, offset, background, .. } => (
+            stroke,
+            font_metrics.strikethrough,
+            offset,
+            false,
+            Length::zero(),
+            *background,
+        ),
+        DecoLine::Overline { stroke, offset, evade, gap, background } => {
+            (stroke, font_metrics.overline, offset, *evade, *gap, *background)
         }
-        DecoLine::Underline { stroke, offset, evade, background } => {
-            (stroke, font_metrics.underline, offset, *evade, *background)
+        DecoLine::Underline { stroke, offset, evade, gap, background } => {
+            (stroke, font_metrics.underline, offset, *evade, *gap, *background)
         }
         _ => return,
     };
@@ -452,23 +533,43 @@ pub(crate) fn decorate(
         metrics.thickness.at(text.size),
     ));
 
-    let gap_padding = 0.08 * text.size;
+    let gap_padding = gap.at(text.size);
     let min_width = 0.162 * text.size;
 
     let start = pos.x - deco.extent;
     let end = pos.x + width + deco.extent;
 
+    let style = match &deco.line {
+        DecoLine::Strikethrough { style, .. } => *style,
+        _ => StrikeStyle::Single,
+    };
+
     let mut push_segment = |from: Abs, to: Abs, prepend: bool| {
         let origin = Point::new(from, pos.y + offset);
         let target = Point::new(to - from, Abs::zero());
 
         if target.x >= min_width || !evade {
-            let shape = Geometry::Line(target).stroked(stroke.clone());
-
-            if prepend {
-                frame.prepend(origin, FrameItem::Shape(shape, Span::detached()));
-            } else {
-                frame.push(origin, FrameItem::Shape(shape, Span::detached()));
+            let thickness = stroke.thickness;
+            let lines = match style {
+                StrikeStyle::Double => vec![
+                    (origin - Point::with_y(thickness), Geometry::Line(target)),
+                    (origin + Point::with_y(thickness), Geometry::Line(target)),
+                ],
+                StrikeStyle::Wavy
+                    if thickness > Abs::zero() && target.x > Abs::zero() =>
+                {
+                    vec![(origin, Geometry::Path(wavy_line(target.x, thickness)))]
+                }
+                _ => vec![(origin, Geometry::Line(target))],
+            };
+
+            for (origin, geometry) in lines {
+                let shape = geometry.stroked(stroke.clone());
+                if prepend {
+                    frame.prepend(origin, FrameItem::Shape(shape, Span::detached()));
+                } else {
+                    frame.push(origin, FrameItem::Shape(shape, Span::detached()));
+                }
             }
         }
     };
@@ -559,6 +660,35 @@ fn determine_edges(
     (top, bottom)
 }
 
+/// Builds a wavy line of the given width around the vertical origin.
+///
+/// The line consists of full waves only, so that it starts and ends on its
+/// center line heading upwards. This way, adjacent wavy lines continue each
+/// other seamlessly.
+fn wavy_line(width: Abs, thickness: Abs) -> Path {
+    let wavelength = 6.0 * thickness;
+    let count = (width / wavelength).round().max(1.0);
+    let half = width / (2.0 * count);
+
+    // The control points of a cubic bezier curve with both at the same
+    // height reach three quarters of that height.
+    let peak = 4.0 / 3.0 * thickness;
+
+    let mut path = Path::new();
+    path.move_to(Point::zero());
+    let mut x = Abs::zero();
+    for i in 0..2 * count as usize {
+        let y = if i % 2 == 0 { -peak } else { peak };
+        path.cubic_to(
+            Point::new(x + half / 3.0, y),
+            Point::new(x + half * 2.0 / 3.0, y),
+            Point::new(x + half, Abs::zero()),
+        );
+        x += half;
+    }
+    path
+}
+
 /// Builds a kurbo [`BezPath`] for a glyph.
 struct BezPathBuilder {
     path: BezPath,
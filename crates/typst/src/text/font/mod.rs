@@ -13,7 +13,7 @@ use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use ttf_parser::GlyphId;
+use ttf_parser::{GlyphId, Tag};
 
 use self::book::find_name;
 use crate::foundations::{Bytes, Cast};
@@ -41,6 +41,8 @@ struct Repr {
     ttf: ttf_parser::Face<'static>,
     /// The underlying rustybuzz face.
     rusty: rustybuzz::Face<'static>,
+    /// The value of the optical size axis, if it was set explicitly.
+    optical_size: Option<f32>,
 }
 
 impl Font {
@@ -60,7 +62,15 @@ impl Font {
         let metrics = FontMetrics::from_ttf(&ttf);
         let info = FontInfo::from_ttf(&ttf)?;
 
-        Some(Self(Arc::new(Repr { data, index, info, metrics, ttf, rusty })))
+        Some(Self(Arc::new(Repr {
+            data,
+            index,
+            info,
+            metrics,
+            ttf,
+            rusty,
+            optical_size: None,
+        })))
     }
 
     /// Parse all fonts in the given data.
@@ -125,12 +135,84 @@ impl Font {
         // internal 'static lifetime.
         &self.0.rusty
     }
+
+    /// The value of the font's optical size axis, if it was set explicitly.
+    pub fn optical_size(&self) -> Option<f32> {
+        self.0.optical_size
+    }
+
+    /// Create an instance of this font with the given optical size, clamped to
+    /// the range of the font's `opsz` axis.
+    ///
+    /// Returns the font unchanged if it doesn't have such an axis or if the
+    /// size is the axis' default, in which case the font data already is the
+    /// right instance.
+    ///
+    /// The font data itself isn't instanced, only the faces used for shaping
+    /// and outlines are. Exporters that embed the font data must thus draw the
+    /// glyphs of fonts with an [optical size](Self::optical_size) themselves.
+    pub fn with_optical_size(&self, size: f32) -> Self {
+        const OPSZ: Tag = Tag::from_bytes(b"opsz");
+
+        let Some(axis) = self.ttf().variation_axes().into_iter().find(|a| a.tag == OPSZ)
+        else {
+            return self.clone();
+        };
+
+        let value = size.clamp(axis.min_value, axis.max_value);
+        let base = self.default_instance();
+        if value == axis.def_value {
+            return base;
+        }
+
+        instance(base, value.to_bits())
+    }
+
+    /// This font without any explicitly set variations.
+    fn default_instance(&self) -> Self {
+        if self.0.optical_size.is_none() {
+            return self.clone();
+        }
+
+        parse(self.0.data.clone(), self.0.index)
+    }
+}
+
+/// Parse a font that was parsed successfully before.
+#[comemo::memoize]
+fn parse(data: Bytes, index: u32) -> Font {
+    Font::new(data, index).expect("font was parsed successfully before")
+}
+
+/// Create an instance of a font at an optical size, given by its bits.
+///
+/// This is memoized so that text set in the same size shares the instance.
+#[comemo::memoize]
+fn instance(font: Font, bits: u32) -> Font {
+    const OPSZ: Tag = Tag::from_bytes(b"opsz");
+
+    let value = f32::from_bits(bits);
+    let mut ttf = font.0.ttf.clone();
+    let mut rusty = font.0.rusty.clone();
+    ttf.set_variation(OPSZ, value);
+    rusty.set_variations(&[rustybuzz::Variation { tag: OPSZ, value }]);
+
+    Font(Arc::new(Repr {
+        data: font.0.data.clone(),
+        index: font.0.index,
+        info: font.0.info.clone(),
+        metrics: font.0.metrics,
+        ttf,
+        rusty,
+        optical_size: Some(value),
+    }))
 }
 
 impl Hash for Font {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.0.data.hash(state);
         self.0.index.hash(state);
+        self.0.optical_size.map(f32::to_bits).hash(state);
     }
 }
 
@@ -144,7 +226,10 @@ impl Eq for Font {}
 
 impl PartialEq for Font {
     fn eq(&self, other: &Self) -> bool {
-        self.0.data == other.0.data && self.0.index == other.0.index
+        self.0.data == other.0.data
+            && self.0.index == other.0.index
+            && self.0.optical_size.map(f32::to_bits)
+                == other.0.optical_size.map(f32::to_bits)
     }
 }
 
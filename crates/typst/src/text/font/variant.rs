@@ -32,11 +32,12 @@ impl Debug for FontVariant {
 }
 
 /// The style of a font.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[derive(Serialize, Deserialize, Cast)]
 #[serde(rename_all = "kebab-case")]
 pub enum FontStyle {
     /// The default, typically upright style.
+    #[default]
     Normal,
     /// A cursive style with custom letterform.
     Italic,
@@ -57,12 +58,6 @@ impl FontStyle {
     }
 }
 
-impl Default for FontStyle {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 impl From<usvg::FontStyle> for FontStyle {
     fn from(style: usvg::FontStyle) -> Self {
         match style {
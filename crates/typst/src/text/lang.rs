@@ -57,6 +57,7 @@ pub struct Lang([u8; 3], u8);
 impl Lang {
     pub const ALBANIAN: Self = Self(*b"sq ", 2);
     pub const ARABIC: Self = Self(*b"ar ", 2);
+    pub const AZERBAIJANI: Self = Self(*b"az ", 2);
     pub const BOKMÅL: Self = Self(*b"nb ", 2);
     pub const CATALAN: Self = Self(*b"ca ", 2);
     pub const CHINESE: Self = Self(*b"zh ", 2);
@@ -73,6 +74,7 @@ impl Lang {
     pub const GERMAN: Self = Self(*b"de ", 2);
     pub const GREEK: Self = Self(*b"gr ", 2);
     pub const HUNGARIAN: Self = Self(*b"hu ", 2);
+    pub const INDONESIAN: Self = Self(*b"id ", 2);
     pub const ITALIAN: Self = Self(*b"it ", 2);
     pub const JAPANESE: Self = Self(*b"ja ", 2);
     pub const LOWER_SORBIAN: Self = Self(*b"dsb", 3);
@@ -230,6 +232,13 @@ pub trait LocalName {
         localized_str(lang, region, Self::KEY)
     }
 
+    /// Get the plural name in the given language and (optionally) region.
+    ///
+    /// Falls back to the singular name if there is no plural for the language.
+    fn local_name_plural(lang: Lang, region: Option<Region>) -> &'static str {
+        localized_plural(lang, region, Self::KEY)
+    }
+
     /// Gets the local name from the style chain.
     fn local_name_in(styles: StyleChain) -> &'static str
     where
@@ -290,6 +299,27 @@ fn parse_language_bundle(
     Ok(bundle)
 }
 
+/// Retrieves the plural of the localized string with the given key for a
+/// given language and region.
+///
+/// Not all translations have plurals yet. Unlike [`localized_str`], this
+/// therefore does not fall back to the English plural, but to the singular in
+/// the given language, so that a reference like "Figures 1 and 2" never shows
+/// up in otherwise non-English text.
+#[comemo::memoize]
+pub fn localized_plural(lang: Lang, region: Option<Region>, key: &str) -> &'static str {
+    let plural = eco_format!("{key}-plural");
+    [(lang, region), (lang, None)]
+        .into_iter()
+        .find_map(|(lang, region)| {
+            parse_language_bundle(lang, region)
+                .unwrap()
+                .get(plural.as_str())
+                .copied()
+        })
+        .unwrap_or_else(|| localized_str(lang, region, key))
+}
+
 /// Convert language + region to a string to be able to get a file name.
 fn lang_str(lang: Lang, region: Option<Region>) -> EcoString {
     EcoString::from(lang.as_str())
@@ -307,4 +337,22 @@ mod tests {
         assert!(option_eq(region, "US"));
         assert!(!option_eq(region, "AB"));
     }
+
+    #[test]
+    fn test_translations_join_references() {
+        for (name, file) in TRANSLATIONS {
+            let defined = file
+                .lines()
+                .filter_map(|line| line.split_once('='))
+                .any(|(key, _)| key.trim() == "and");
+            assert!(defined, "{name} translation is missing \"and\"");
+        }
+    }
+
+    #[test]
+    fn test_localized_plural_falls_back_to_singular() {
+        assert_eq!(localized_plural(Lang::ENGLISH, None, "figure"), "Figures");
+        assert_eq!(localized_plural(Lang::GERMAN, None, "table"), "Tabellen");
+        assert_eq!(localized_plural(Lang::SPANISH, None, "figure"), "Figura");
+    }
 }
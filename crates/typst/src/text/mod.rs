@@ -34,17 +34,19 @@ use ecow::{eco_format, EcoString};
 use rustybuzz::Feature;
 use smallvec::SmallVec;
 use ttf_parser::{Rect, Tag};
+use unicode_script::Script;
 
-use crate::diag::{bail, warning, HintedStrResult, SourceResult};
+use crate::diag::{bail, warning, HintedStrResult, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, category, dict, elem, Args, Array, Cast, Category, Construct, Content, Dict,
     Fold, NativeElement, Never, Packed, PlainText, Repr, Resolve, Scope, Set, Smart,
-    StyleChain,
+    StyleChain, Value,
 };
 use crate::layout::{Abs, Axis, Dir, Em, Length, Ratio, Rel};
 use crate::model::ParElem;
 use crate::syntax::Spanned;
+use crate::utils::Scalar;
 use crate::visualize::{Color, Paint, RelativeTo, Stroke};
 use crate::World;
 
@@ -126,6 +128,26 @@ pub struct TextElem {
     /// This is Latin. \
     /// هذا عربي.
     /// ```
+    ///
+    /// Instead of a plain list, you can also provide a [dictionary] that maps
+    /// [Unicode scripts](https://www.unicode.org/standard/supported.html) to
+    /// font families. Runs of text in one of the given scripts then try the
+    /// mapped families first, before falling back to all the other families
+    /// in the order they were given. Punctuation and other characters shared
+    /// by multiple scripts follow the surrounding run. Scripts are named by
+    /// their lowercase Unicode name (like `latin`, `han`, or `old-italic`) or
+    /// their ISO 15924 code (like `latn` or `hani`). Families under the `rest`
+    /// key aren't preferred for any script.
+    ///
+    /// ```example
+    /// #set text(font: (
+    ///   latin: "Inria Serif",
+    ///   arabic: "Noto Sans Arabic",
+    /// ))
+    ///
+    /// This is Latin. \
+    /// هذا عربي.
+    /// ```
     #[parse({
         let font_list: Option<Spanned<FontList>> = args.named("font")?;
         if let Some(font_list) = &font_list {
@@ -242,6 +264,29 @@ pub struct TextElem {
     #[ghost]
     pub size: TextSize,
 
+    /// The optical size to use for variable fonts with an `opsz` axis.
+    ///
+    /// Such fonts adapt the shapes of their glyphs to the size they are set
+    /// in, typically making small text sturdier and large text more delicate.
+    /// Optical sizing is opt-in: When set to `{none}`, such fonts are used at
+    /// their default instance. When set to `{auto}`, the optical size follows
+    /// the font size in points. A number sets it explicitly. Fonts without an
+    /// optical size axis ignore this property.
+    ///
+    /// In PDF and SVG export, the glyphs of fonts at an optical size other
+    /// than the axis' default are drawn as shapes instead of being embedded
+    /// as part of the font. In PDFs, such text can still be searched and
+    /// copied.
+    ///
+    /// ```example
+    /// #set text(size: 6pt)
+    /// Default instance. \
+    /// #text(optical-size: auto)[Automatic optical size.] \
+    /// #text(optical-size: 24)[Optical size for display text.]
+    /// ```
+    #[ghost]
+    pub optical_size: Option<Smart<Scalar>>,
+
     /// The glyph fill paint.
     ///
     /// ```example
@@ -276,13 +321,21 @@ pub struct TextElem {
 
     /// The amount of space that should be added between characters.
     ///
+    /// Can be given as an absolute length, but also relative to the font size.
+    /// A relative tracking scales along with the text. Negative values bring
+    /// the characters closer together.
+    ///
     /// ```example
     /// #set text(tracking: 1.5pt)
     /// Distant text.
+    ///
+    /// #set text(tracking: 5%)
+    /// #text(10pt)[Scaled] \
+    /// #text(20pt)[Scaled]
     /// ```
     #[resolve]
     #[ghost]
-    pub tracking: Length,
+    pub tracking: Rel<Length>,
 
     /// The amount of space between words.
     ///
@@ -509,8 +562,9 @@ pub struct TextElem {
     /// next page, can result in an uneven number of lines between pages.
     /// The `widow` and `orphan` costs allow disabling these modifications.
     /// (Currently, 0% allows widows/orphans; anything else, including the
-    /// default of `auto`, prevents them. More nuanced cost specification for
-    /// these modifications is planned for the future.)
+    /// default of `auto`, prevents them. This only applies to paragraphs whose
+    /// [`widows`]($par.widows) and [`orphans`]($par.orphans) are `{auto}`.
+    /// Explicit line counts take precedence over the costs.)
     ///
     /// The default costs are an acceptable balance, but some may find that it
     /// hyphenates or avoids runs too eagerly, breaking the flow of dense prose.
@@ -579,23 +633,29 @@ pub struct TextElem {
     #[ghost]
     pub stylistic_set: Option<StylisticSet>,
 
-    /// Whether standard ligatures are active.
+    /// Which ligatures are active.
     ///
     /// Certain letter combinations like "fi" are often displayed as a single
-    /// merged glyph called a _ligature._ Setting this to `{false}` disables
-    /// these ligatures by turning off the OpenType `liga` and `clig` font
-    /// features.
+    /// merged glyph called a _ligature._ This can be:
+    ///
+    /// - `{"common"}` or `{true}`: Standard ligatures are active.
+    /// - `{"none"}` or `{false}`: Turns off the OpenType `liga` and `clig`
+    ///   font features.
+    /// - `{"all"}`: Additionally enables the discretionary and historical
+    ///   ligatures (the OpenType `dlig` and `hlig` font features).
+    ///
+    /// Fonts that lack a feature simply ignore it.
     ///
     /// ```example
     /// #set text(size: 20pt)
     /// A fine ligature.
     ///
-    /// #set text(ligatures: false)
+    /// #set text(ligatures: "none")
     /// A fine ligature.
     /// ```
-    #[default(true)]
+    #[default(Ligatures::Common)]
     #[ghost]
-    pub ligatures: bool,
+    pub ligatures: Ligatures,
 
     /// Whether ligatures that should be used sparingly are active. Setting this
     /// to `{true}` enables the OpenType `dlig` font feature.
@@ -717,13 +777,20 @@ pub struct TextElem {
     /// A case transformation that should be applied to the text.
     #[internal]
     #[ghost]
-    pub case: Option<Case>,
+    pub case: Option<Casing>,
 
     /// Whether small capital glyphs should be used. ("smcp")
     #[internal]
     #[default(false)]
     #[ghost]
     pub smallcaps: bool,
+
+    /// Whether small capitals should be synthesized from scaled down capitals
+    /// for fonts that lack true small capitals.
+    #[internal]
+    #[default(false)]
+    #[ghost]
+    pub synthesize_smallcaps: bool,
 }
 
 impl TextElem {
@@ -764,32 +831,66 @@ impl PlainText for Packed<TextElem> {
 
 /// A lowercased font family like "arial".
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct FontFamily(EcoString);
+pub struct FontFamily {
+    /// The lowercased family name.
+    name: EcoString,
+    /// The script the family is preferred for, if any.
+    script: Option<Script>,
+}
 
 impl FontFamily {
     /// Create a named font family variant.
     pub fn new(string: &str) -> Self {
-        Self(string.to_lowercase().into())
+        Self { name: string.to_lowercase().into(), script: None }
     }
 
     /// The lowercased family name.
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.name
+    }
+
+    /// The script this family is preferred for, if any.
+    pub fn script(&self) -> Option<Script> {
+        self.script
     }
 }
 
 impl Debug for FontFamily {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        self.0.fmt(f)
+        self.name.fmt(f)
     }
 }
 
 cast! {
     FontFamily,
-    self => self.0.into_value(),
+    self => self.name.into_value(),
     string: EcoString => Self::new(&string),
 }
 
+/// Parse a script from its lowercase Unicode name or ISO 15924 code.
+fn parse_script(name: &str) -> StrResult<Script> {
+    let capitalized = name
+        .split(['-', '_'])
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    Script::from_full_name(&capitalized.join("_"))
+        .or_else(|| Script::from_short_name(&capitalized.concat()))
+        .filter(|script| !matches!(script, Script::Common | Script::Inherited))
+        .ok_or_else(|| eco_format!("unknown script: {name}"))
+}
+
+/// The name of a script as used in a font dictionary.
+fn script_name(script: Script) -> EcoString {
+    script.full_name().to_lowercase().replace('_', "-").into()
+}
+
 /// Font family fallback list.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct FontList(pub Vec<FontFamily>);
@@ -805,17 +906,45 @@ impl<'a> IntoIterator for &'a FontList {
 
 cast! {
     FontList,
-    self => if self.0.len() == 1 {
-        self.0.into_iter().next().unwrap().0.into_value()
+    self => if self.0.iter().any(|family| family.script.is_some()) {
+        let mut dict = Dict::new();
+        for family in self.0 {
+            let key = family.script.map_or_else(|| "rest".into(), script_name);
+            let name = family.name.into_value();
+            let value = match dict.take(&key).ok() {
+                Some(Value::Array(mut array)) => {
+                    array.push(name);
+                    array.into_value()
+                }
+                Some(prev) => vec![prev, name].into_value(),
+                None => name,
+            };
+            dict.insert(key.into(), value);
+        }
+        dict.into_value()
+    } else if self.0.len() == 1 {
+        self.0.into_iter().next().unwrap().name.into_value()
     } else {
         self.0.into_value()
     },
     family: FontFamily => Self(vec![family]),
     values: Array => Self(values.into_iter().map(|v| v.cast()).collect::<HintedStrResult<_>>()?),
+    dict: Dict => {
+        let mut families = vec![];
+        for (key, value) in dict {
+            let script = match key.as_str() {
+                "rest" => None,
+                name => Some(parse_script(name)?),
+            };
+            let list: FontList = value.cast()?;
+            families.extend(list.0.into_iter().map(|family| FontFamily { script, ..family }));
+        }
+        Self(families)
+    },
 }
 
 /// Resolve a prioritized iterator over the font families.
-pub(crate) fn families(styles: StyleChain) -> impl Iterator<Item = &str> + Clone {
+pub(crate) fn families(styles: StyleChain<'_>) -> impl Iterator<Item = &str> + Clone {
     const FALLBACKS: &[&str] = &[
         "linux libertine",
         "twitter color emoji",
@@ -831,6 +960,20 @@ pub(crate) fn families(styles: StyleChain) -> impl Iterator<Item = &str> + Clone
         .chain(tail.iter().copied())
 }
 
+/// Resolve a prioritized iterator over the font families for a run of text in
+/// the given script. Families mapped to the script come first, followed by
+/// the regular fallback chain.
+pub(crate) fn families_for(
+    styles: StyleChain<'_>,
+    script: Option<Script>,
+) -> impl Iterator<Item = &str> + Clone {
+    TextElem::font_in(styles)
+        .into_iter()
+        .filter(move |family| script.is_some() && family.script == script)
+        .map(|family| family.as_str())
+        .chain(families(styles))
+}
+
 /// Resolve the font variant.
 pub(crate) fn variant(styles: StyleChain) -> FontVariant {
     let mut variant = FontVariant::new(
@@ -1064,6 +1207,33 @@ impl Resolve for Hyphenate {
     }
 }
 
+/// Which ligatures are active.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Ligatures {
+    /// No ligatures at all.
+    None,
+    /// Standard ligatures (the OpenType `liga` and `clig` font features).
+    Common,
+    /// Standard, discretionary and historical ligatures.
+    All,
+}
+
+cast! {
+    Ligatures,
+    self => match self {
+        Self::None => "none",
+        Self::Common => "common",
+        Self::All => "all",
+    }.into_value(),
+    v: bool => if v { Self::Common } else { Self::None },
+    /// No ligatures at all.
+    "none" => Self::None,
+    /// Standard ligatures.
+    "common" => Self::Common,
+    /// Standard, discretionary and historical ligatures.
+    "all" => Self::All,
+}
+
 /// A stylistic set in a font.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct StylisticSet(u8);
@@ -1174,17 +1344,18 @@ pub(crate) fn features(styles: StyleChain) -> Vec<Feature> {
         feat(&storage, 1);
     }
 
-    if !TextElem::ligatures_in(styles) {
+    let ligatures = TextElem::ligatures_in(styles);
+    if ligatures == Ligatures::None {
         feat(b"liga", 0);
         feat(b"clig", 0);
     }
 
-    if TextElem::discretionary_ligatures_in(styles) {
+    if ligatures == Ligatures::All || TextElem::discretionary_ligatures_in(styles) {
         feat(b"dlig", 1);
     }
 
-    if TextElem::historical_ligatures_in(styles) {
-        feat(b"hilg", 1);
+    if ligatures == Ligatures::All || TextElem::historical_ligatures_in(styles) {
+        feat(b"hlig", 1);
     }
 
     match TextElem::number_type_in(styles) {
@@ -1,10 +1,12 @@
 use std::hash::Hash;
+use std::num::NonZeroUsize;
 use std::ops::Range;
 use std::sync::Arc;
 
 use ecow::{eco_format, EcoString, EcoVec};
 use once_cell::sync::Lazy;
 use once_cell::unsync::Lazy as UnsyncLazy;
+use smallvec::smallvec;
 use syntect::highlighting::{self as synt, Theme};
 use syntect::parsing::{SyntaxDefinition, SyntaxSet, SyntaxSetBuilder};
 use unicode_segmentation::UnicodeSegmentation;
@@ -13,16 +15,20 @@ use super::Lang;
 use crate::diag::{At, FileError, HintedStrResult, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Args, Array, Bytes, Content, Fold, NativeElement, Packed,
+    cast, elem, scope, Args, Array, Bytes, Content, Dict, Fold, NativeElement, Packed,
     PlainText, Show, ShowSet, Smart, StyleChain, Styles, Synthesize, Value,
 };
-use crate::layout::{BlockChild, BlockElem, Em, HAlignment};
+use crate::layout::{
+    BlockChild, BlockElem, Em, GridCell, GridChild, GridElem, GridItem, HAlignment,
+    Sizing, TrackSizings,
+};
 use crate::model::{Figurable, ParElem};
 use crate::syntax::{split_newlines, LinkedNode, Span, Spanned};
 use crate::text::{
     FontFamily, FontList, Hyphenate, LinebreakElem, LocalName, SmartQuoteElem, TextElem,
     TextSize,
 };
+use crate::utils::NonZeroExt;
 use crate::visualize::Color;
 use crate::{syntax, World};
 
@@ -30,7 +36,7 @@ use crate::{syntax, World};
 type StyleFn<'a> =
     &'a mut dyn FnMut(usize, &LinkedNode, Range<usize>, synt::Style) -> Content;
 type LineFn<'a> = &'a mut dyn FnMut(usize, Range<usize>, &mut Vec<Content>);
-type ThemeArgType = Smart<Option<EcoString>>;
+type ThemeArgType = Smart<Option<RawTheme>>;
 
 /// Raw text with optional syntax highlighting.
 ///
@@ -220,6 +226,24 @@ pub struct RawElem {
     ///
     /// Additionally, you can set the theme to `none` to disable highlighting.
     ///
+    /// Instead of a file path, you can also pass a dictionary that maps scope
+    /// selectors to styles. A style is either a color or a dictionary with
+    /// the optional keys `fill` (a color) and `bold`, `italic`, and
+    /// `underline` (booleans). Scopes that the dictionary does not mention
+    /// keep the default highlighting.
+    ///
+    /// ````example
+    /// #set raw(theme: (
+    ///   "keyword": red,
+    ///   "string": (fill: olive, italic: true),
+    /// ))
+    ///
+    /// ```py
+    /// def hello():
+    ///     return "Hello World"
+    /// ```
+    /// ````
+    ///
     /// ````example
     /// #set raw(theme: "halcyon.tmTheme")
     /// #show raw: it => block(
@@ -262,6 +286,50 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// Whether to display line numbers in a gutter next to the code.
+    ///
+    /// Only applies to raw blocks. A line that is too long and wraps is still
+    /// counted as a single line.
+    ///
+    /// ````example
+    /// #set raw(line-numbers: true)
+    /// ```rust
+    /// fn main() {
+    ///     println!("Hello World!");
+    /// }
+    /// ```
+    /// ````
+    #[default(false)]
+    pub line_numbers: bool,
+
+    /// The number of the first line in the gutter.
+    ///
+    /// ````example
+    /// #set raw(line-numbers: true, line-number-start: 10)
+    /// ```py
+    /// x = 1
+    /// y = 2
+    /// ```
+    /// ````
+    #[default(1)]
+    pub line_number_start: i64,
+
+    /// The interval at which lines are numbered in the gutter.
+    ///
+    /// Lines whose number is not a multiple of the step away from the
+    /// [start]($raw.line-number-start) leave the gutter empty.
+    ///
+    /// ````example
+    /// #set raw(line-numbers: true, line-number-step: 2)
+    /// ```
+    /// a
+    /// b
+    /// c
+    /// ```
+    /// ````
+    #[default(NonZeroUsize::ONE)]
+    pub line_number_step: NonZeroUsize,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the [`raw.line` element]($raw.line).
@@ -330,10 +398,13 @@ impl Packed<RawElem> {
             })
         };
 
-        let theme = elem.theme(styles).as_ref().as_ref().map(|theme_path| {
-            theme_path.as_ref().map(|path| {
-                load_theme(path, elem.theme_data(styles).as_ref().as_ref().unwrap())
-                    .unwrap()
+        let theme = elem.theme(styles).as_ref().as_ref().map(|theme| {
+            theme.as_ref().map(|theme| match theme {
+                RawTheme::Path(path) => {
+                    load_theme(path, elem.theme_data(styles).as_ref().as_ref().unwrap())
+                        .unwrap()
+                }
+                RawTheme::Dict(dict) => build_theme(dict).unwrap(),
             })
         });
         let theme: &Theme = match theme {
@@ -431,6 +502,10 @@ impl Show for Packed<RawElem> {
     fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         let lines = self.lines().map(|v| v.as_slice()).unwrap_or_default();
 
+        if self.block(styles) && self.line_numbers(styles) {
+            return Ok(self.numbered(lines, styles));
+        }
+
         let mut seq = EcoVec::with_capacity((2 * lines.len()).saturating_sub(1));
         for (i, line) in lines.iter().enumerate() {
             if i != 0 {
@@ -454,6 +529,48 @@ impl Show for Packed<RawElem> {
     }
 }
 
+impl Packed<RawElem> {
+    /// Lay out the lines of a raw block in a grid with a line number gutter.
+    ///
+    /// Each line gets its own row so that wrapped lines occupy a single
+    /// number.
+    fn numbered(&self, lines: &[Packed<RawLine>], styles: StyleChain) -> Content {
+        let span = self.span();
+        let start = self.line_number_start(styles);
+        let step = self.line_number_step(styles).get();
+        let align = self.align(styles);
+
+        let mut cells = Vec::with_capacity(2 * lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            let number = if i % step == 0 {
+                TextElem::packed(eco_format!("{}", start + i as i64))
+                    .aligned(HAlignment::End.into())
+            } else {
+                Content::empty()
+            };
+
+            for body in [number, line.clone().pack().aligned(align.into())] {
+                cells.push(GridChild::Item(GridItem::Cell(
+                    Packed::new(GridCell::new(body)).spanned(span),
+                )));
+            }
+        }
+
+        let leading = ParElem::leading_in(styles);
+        BlockElem::new()
+            .with_body(Some(BlockChild::Content(
+                GridElem::new(cells)
+                    .with_columns(TrackSizings(smallvec![Sizing::Auto; 2]))
+                    .with_column_gutter(TrackSizings(smallvec![Em::new(1.0).into()]))
+                    .with_row_gutter(TrackSizings(smallvec![leading.into()]))
+                    .pack()
+                    .spanned(span),
+            )))
+            .pack()
+            .spanned(span)
+    }
+}
+
 impl ShowSet for Packed<RawElem> {
     fn show_set(&self, styles: StyleChain) -> Styles {
         let mut out = Styles::new();
@@ -493,7 +610,7 @@ pub enum RawContent {
 
 impl RawContent {
     /// Returns or synthesizes the text content of the raw text.
-    fn get(&self) -> EcoString {
+    pub fn get(&self) -> EcoString {
         match self.clone() {
             RawContent::Text(text) => text,
             RawContent::Lines(lines) => {
@@ -514,6 +631,56 @@ cast! {
     v: EcoString => Self::Text(v),
 }
 
+/// A syntax highlighting theme.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum RawTheme {
+    /// A path to a theme file in the `tmTheme` format.
+    Path(EcoString),
+    /// A theme given inline as a mapping from scope selectors to styles.
+    Dict(Dict),
+}
+
+cast! {
+    RawTheme,
+    self => match self {
+        Self::Path(v) => v.into_value(),
+        Self::Dict(v) => v.into_value(),
+    },
+    v: EcoString => Self::Path(v),
+    v: Dict => Self::Dict(v),
+}
+
+/// The style of a scope in an inline theme.
+struct RawThemeStyle(synt::StyleModifier);
+
+cast! {
+    RawThemeStyle,
+    v: Color => Self(synt::StyleModifier {
+        foreground: Some(to_syn(v)),
+        background: None,
+        font_style: None,
+    }),
+    mut dict: Dict => {
+        let fill = dict.take("fill").ok().map(Value::cast::<Color>).transpose()?;
+        let mut font_style = synt::FontStyle::empty();
+        for (key, flag) in [
+            ("bold", synt::FontStyle::BOLD),
+            ("italic", synt::FontStyle::ITALIC),
+            ("underline", synt::FontStyle::UNDERLINE),
+        ] {
+            if dict.take(key).ok().map(Value::cast::<bool>).transpose()? == Some(true) {
+                font_style |= flag;
+            }
+        }
+        dict.finish(&["fill", "bold", "italic", "underline"])?;
+        Self(synt::StyleModifier {
+            foreground: fill.map(to_syn),
+            background: None,
+            font_style: Some(font_style),
+        })
+    },
+}
+
 /// A highlighted line of raw text.
 ///
 /// This is a helper element that is synthesized by [`raw`] elements.
@@ -798,20 +965,27 @@ fn parse_theme(
     engine: &mut Engine,
     args: &mut Args,
 ) -> SourceResult<(Option<ThemeArgType>, Option<Bytes>)> {
-    let Some(Spanned { v: path, span }) = args.named::<Spanned<ThemeArgType>>("theme")?
+    let Some(Spanned { v: theme, span }) =
+        args.named::<Spanned<ThemeArgType>>("theme")?
     else {
         // Argument `theme` not found.
         return Ok((None, None));
     };
 
-    let Smart::Custom(path) = path else {
+    let Smart::Custom(theme) = theme else {
         // Argument `theme` is `auto`.
         return Ok((Some(Smart::Auto), None));
     };
 
-    let Some(path) = path else {
+    let path = match theme {
+        Some(RawTheme::Path(path)) => path,
+        Some(RawTheme::Dict(dict)) => {
+            // Check that building the inline theme works.
+            let _ = build_theme(&dict).at(span)?;
+            return Ok((Some(Smart::Custom(Some(RawTheme::Dict(dict)))), None));
+        }
         // Argument `theme` is `none`.
-        return Ok((Some(Smart::Custom(None)), None));
+        None => return Ok((Some(Smart::Custom(None)), None)),
     };
 
     // Load theme file.
@@ -821,7 +995,25 @@ fn parse_theme(
     // Check that parsing works.
     let _ = load_theme(&path, &data).at(span)?;
 
-    Ok((Some(Smart::Custom(Some(path))), Some(data)))
+    Ok((Some(Smart::Custom(Some(RawTheme::Path(path)))), Some(data)))
+}
+
+/// Build a theme from an inline dictionary on top of the default theme.
+#[comemo::memoize]
+fn build_theme(dict: &Dict) -> HintedStrResult<Arc<synt::Theme>> {
+    let mut theme = RAW_THEME.clone();
+    for (scope, value) in dict.iter() {
+        let selector: synt::ScopeSelectors = scope
+            .parse()
+            .map_err(|_| eco_format!("invalid scope selector `{scope}`"))?;
+        let RawThemeStyle(style) = value.clone().cast()?;
+
+        // A scope given explicitly replaces the default style of that scope.
+        // Scopes that aren't mentioned keep their default style.
+        theme.scopes.retain(|item| item.scope != selector);
+        theme.scopes.push(synt::ThemeItem { scope: selector, style });
+    }
+    Ok(Arc::new(theme))
 }
 
 /// The syntect syntax definitions.
@@ -31,10 +31,26 @@ use crate::text::TextElem;
 /// #show smallcaps: set text(font: "Latin Modern Roman Caps")
 /// ```
 ///
-/// In the future, this function will support synthesizing smallcaps from normal
-/// letters, but this is not yet implemented.
+/// If the font has no small capitals at all, you can instead let Typst
+/// synthesize them from normal letters with the `synthesize` parameter.
 #[elem(title = "Small Capitals", Show)]
 pub struct SmallcapsElem {
+    /// Whether to synthesize small capitals if the font lacks them.
+    ///
+    /// If this is enabled and the font does not support the OpenType `smcp`
+    /// feature, Typst instead renders lowercase letters as capitals that are
+    /// scaled down to the font's x-height. Letters that are already uppercase
+    /// keep their full size.
+    ///
+    /// ```example
+    /// #set text(font: "PT Sans")
+    /// #smallcaps(synthesize: true)[
+    ///   Synthesized Smallcaps
+    /// ]
+    /// ```
+    #[default(false)]
+    pub synthesize: bool,
+
     /// The content to display in small capitals.
     #[required]
     pub body: Content,
@@ -42,7 +58,12 @@ pub struct SmallcapsElem {
 
 impl Show for Packed<SmallcapsElem> {
     #[typst_macros::time(name = "smallcaps", span = self.span())]
-    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
-        Ok(self.body().clone().styled(TextElem::set_smallcaps(true)))
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let body = self.body().clone().styled(TextElem::set_smallcaps(true));
+        Ok(if self.synthesize(styles) {
+            body.styled(TextElem::set_synthesize_smallcaps(true))
+        } else {
+            body
+        })
     }
 }
@@ -0,0 +1,365 @@
+use std::collections::{HashMap, HashSet};
+
+use kurbo::{BezPath, PathEl, Vec2};
+
+use crate::layout::{Abs, Point};
+use crate::visualize::{Path, PathItem};
+
+/// The maximum distance, in points, between a curve and the line segments
+/// that approximate it.
+const TOLERANCE: f64 = 0.01;
+
+/// The distance, in points, below which two points are considered the same.
+const EPSILON: f64 = 1e-6;
+
+/// How far, in points, to the sides of an edge the result's inside is
+/// probed.
+const PROBE: f64 = 1e-4;
+
+/// A boolean operation that combines the areas of two paths.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BooleanOp {
+    /// The area covered by either path.
+    Union,
+    /// The area covered by both paths.
+    Intersection,
+    /// The area covered by the first but not the second path.
+    Difference,
+}
+
+impl BooleanOp {
+    /// Whether a point is inside of the result, given whether it is inside of
+    /// the first and the second path.
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::Union => a || b,
+            Self::Intersection => a && b,
+            Self::Difference => a && !b,
+        }
+    }
+}
+
+impl Path {
+    /// Combine the area of this path with that of another one.
+    ///
+    /// Both paths are treated as closed and filled with the non-zero winding
+    /// rule, so self-intersecting paths are combined the way they are drawn.
+    /// Curves are approximated by line segments. The result consists of
+    /// closed polygons that produce the combined area when filled with the
+    /// non-zero winding rule.
+    pub fn boolean(&self, other: &Self, op: BooleanOp) -> Self {
+        let a = flatten(self);
+        let b = flatten(other);
+
+        // Split all edges where they cross or touch another edge, so that
+        // each piece lies entirely inside or outside of each path.
+        let edges: Vec<_> = edges(&a).chain(edges(&b)).collect();
+        let pieces = split(&edges);
+
+        // Keep the pieces that separate the result's inside from its outside,
+        // oriented such that the inside is on the left. Pieces shared by both
+        // paths are kept only once.
+        let inside = |p| op.apply(winding(&a, p) != 0, winding(&b, p) != 0);
+        let mut seen = HashSet::new();
+        let mut kept = vec![];
+        for (p, q) in pieces {
+            let d = q - p;
+            let normal = Vec2::new(-d.y, d.x) * (PROBE / d.hypot());
+            let mid = p.midpoint(q);
+            let left = inside(mid + normal);
+            if left == inside(mid - normal) {
+                continue;
+            }
+
+            let (p, q) = if left { (p, q) } else { (q, p) };
+            if seen.insert((key(p), key(q))) {
+                kept.push((p, q));
+            }
+        }
+
+        trace(&kept)
+    }
+}
+
+/// Approximate each subpath of a path by a polygon.
+fn flatten(path: &Path) -> Vec<Vec<kurbo::Point>> {
+    let convert = |p: Point| kurbo::Point::new(p.x.to_pt(), p.y.to_pt());
+
+    let mut bez = BezPath::new();
+    for item in &path.0 {
+        match *item {
+            PathItem::MoveTo(p) => bez.move_to(convert(p)),
+            PathItem::LineTo(p) => bez.line_to(convert(p)),
+            PathItem::CubicTo(p1, p2, p3) => {
+                bez.curve_to(convert(p1), convert(p2), convert(p3))
+            }
+            PathItem::ClosePath => bez.close_path(),
+        }
+    }
+
+    let mut polygons: Vec<Vec<kurbo::Point>> = vec![];
+    kurbo::flatten(&bez, TOLERANCE, |el| match el {
+        PathEl::MoveTo(p) => polygons.push(vec![p]),
+        PathEl::LineTo(p) => {
+            if let Some(polygon) = polygons.last_mut() {
+                polygon.push(p);
+            }
+        }
+        _ => {}
+    });
+
+    polygons
+}
+
+/// The edges of polygons, including the ones that close them. Edges of zero
+/// length are skipped.
+fn edges(
+    polygons: &[Vec<kurbo::Point>],
+) -> impl Iterator<Item = (kurbo::Point, kurbo::Point)> + '_ {
+    polygons
+        .iter()
+        .flat_map(|polygon| {
+            polygon
+                .iter()
+                .zip(polygon.iter().cycle().skip(1))
+                .map(|(&p, &q)| (p, q))
+        })
+        .filter(|&(p, q)| (q - p).hypot() >= EPSILON)
+}
+
+/// The winding number of polygons around a point.
+fn winding(polygons: &[Vec<kurbo::Point>], p: kurbo::Point) -> i32 {
+    let mut winding = 0;
+    for (a, b) in edges(polygons) {
+        let side = (b - a).cross(p - a);
+        if a.y <= p.y {
+            if b.y > p.y && side > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= p.y && side < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Split edges at all points where they cross or touch another edge.
+fn split(edges: &[(kurbo::Point, kurbo::Point)]) -> Vec<(kurbo::Point, kurbo::Point)> {
+    let min_x = |i: usize| edges[i].0.x.min(edges[i].1.x);
+    let y_range = |i: usize| {
+        let (p, q) = edges[i];
+        (p.y.min(q.y) - EPSILON, p.y.max(q.y) + EPSILON)
+    };
+
+    // Sweep over the edges from left to right, so that only edges whose
+    // bounding boxes overlap are compared.
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|&i, &j| min_x(i).total_cmp(&min_x(j)));
+
+    let mut cuts: Vec<Vec<(f64, kurbo::Point)>> = vec![vec![]; edges.len()];
+    for (k, &i) in order.iter().enumerate() {
+        let (a, b) = edges[i];
+        let max_x = a.x.max(b.x) + EPSILON;
+        let (top, bottom) = y_range(i);
+        for &j in &order[k + 1..] {
+            if min_x(j) > max_x {
+                break;
+            }
+
+            let (other_top, other_bottom) = y_range(j);
+            if other_top > bottom || other_bottom < top {
+                continue;
+            }
+
+            let (c, d) = edges[j];
+            let (r, s) = (b - a, d - c);
+            let (te, ue) = (EPSILON / r.hypot(), EPSILON / s.hypot());
+            let interior = |t: f64, e: f64| t > e && t < 1.0 - e;
+
+            let denom = r.cross(s);
+            if denom.abs() > EPSILON * r.hypot() * s.hypot() {
+                let t = (c - a).cross(s) / denom;
+                let u = (c - a).cross(r) / denom;
+                if t < -te || t > 1.0 + te || u < -ue || u > 1.0 + ue {
+                    continue;
+                }
+
+                // Reuse an existing endpoint if possible, so that the pieces
+                // connect exactly.
+                let x = if t.abs() <= te {
+                    a
+                } else if (1.0 - t).abs() <= te {
+                    b
+                } else if u.abs() <= ue {
+                    c
+                } else if (1.0 - u).abs() <= ue {
+                    d
+                } else {
+                    a + r * t
+                };
+
+                if interior(t, te) {
+                    cuts[i].push((t, x));
+                }
+                if interior(u, ue) {
+                    cuts[j].push((u, x));
+                }
+            } else if (c - a).cross(r).abs() <= EPSILON * r.hypot() {
+                // The edges are collinear, so each one is cut where the other
+                // one's endpoints lie on it.
+                for x in [c, d] {
+                    let t = (x - a).dot(r) / r.hypot2();
+                    if interior(t, te) {
+                        cuts[i].push((t, x));
+                    }
+                }
+                for x in [a, b] {
+                    let u = (x - c).dot(s) / s.hypot2();
+                    if interior(u, ue) {
+                        cuts[j].push((u, x));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut pieces = vec![];
+    for (&(a, b), mut cuts) in edges.iter().zip(cuts) {
+        cuts.sort_by(|x, y| x.0.total_cmp(&y.0));
+        let mut prev = a;
+        for x in cuts.into_iter().map(|(_, x)| x).chain([b]) {
+            if (x - prev).hypot() >= EPSILON {
+                pieces.push((prev, x));
+                prev = x;
+            }
+        }
+    }
+
+    pieces
+}
+
+/// Connect directed pieces into closed polygons.
+fn trace(pieces: &[(kurbo::Point, kurbo::Point)]) -> Path {
+    let convert = |p: kurbo::Point| Point::new(Abs::pt(p.x), Abs::pt(p.y));
+
+    let mut outgoing: HashMap<_, Vec<usize>> = HashMap::new();
+    for (i, &(p, _)) in pieces.iter().enumerate() {
+        outgoing.entry(key(p)).or_default().push(i);
+    }
+
+    let mut used = vec![false; pieces.len()];
+    let mut path = Path::new();
+    for start in 0..pieces.len() {
+        if used[start] {
+            continue;
+        }
+
+        used[start] = true;
+        let (first, mut end) = pieces[start];
+        path.move_to(convert(first));
+        while key(end) != key(first) {
+            let Some(next) = outgoing
+                .get(&key(end))
+                .and_then(|list| list.iter().copied().find(|&i| !used[i]))
+            else {
+                break;
+            };
+
+            used[next] = true;
+            path.line_to(convert(end));
+            end = pieces[next].1;
+        }
+
+        if key(end) != key(first) {
+            path.line_to(convert(end));
+        }
+        path.close_path();
+    }
+
+    path
+}
+
+/// A hashable key for a point, which is the same for points that are
+/// closer than [`EPSILON`] in most cases.
+fn key(p: kurbo::Point) -> (i64, i64) {
+    ((p.x / EPSILON).round() as i64, (p.y / EPSILON).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Path {
+        let mut path = Path::new();
+        path.move_to(Point::new(Abs::pt(x), Abs::pt(y)));
+        path.line_to(Point::new(Abs::pt(x + size), Abs::pt(y)));
+        path.line_to(Point::new(Abs::pt(x + size), Abs::pt(y + size)));
+        path.line_to(Point::new(Abs::pt(x), Abs::pt(y + size)));
+        path.close_path();
+        path
+    }
+
+    /// The area enclosed by the polygons of a path.
+    fn area(path: &Path) -> f64 {
+        let signed: f64 = flatten(path)
+            .iter()
+            .map(|polygon| {
+                let pairs = polygon.iter().zip(polygon.iter().cycle().skip(1));
+                pairs.map(|(p, q)| p.to_vec2().cross(q.to_vec2())).sum::<f64>() / 2.0
+            })
+            .sum();
+        signed.abs()
+    }
+
+    fn contains(path: &Path, x: f64, y: f64) -> bool {
+        winding(&flatten(path), kurbo::Point::new(x, y)) != 0
+    }
+
+    #[track_caller]
+    fn test(a: Path, b: Path, op: BooleanOp, expected: f64) {
+        let result = a.boolean(&b, op);
+        let found = area(&result);
+        assert!((found - expected).abs() < 1e-6, "{op:?}: {found} != {expected}");
+    }
+
+    #[test]
+    fn test_boolean_overlapping() {
+        let (a, b) = (square(0.0, 0.0, 10.0), square(5.0, 5.0, 10.0));
+        test(a.clone(), b.clone(), BooleanOp::Union, 175.0);
+        test(a.clone(), b.clone(), BooleanOp::Intersection, 25.0);
+        test(a.clone(), b.clone(), BooleanOp::Difference, 75.0);
+
+        let difference = a.boolean(&b, BooleanOp::Difference);
+        assert!(contains(&difference, 2.0, 2.0));
+        assert!(!contains(&difference, 7.0, 7.0));
+        assert!(!contains(&difference, 12.0, 12.0));
+    }
+
+    #[test]
+    fn test_boolean_disjoint() {
+        let (a, b) = (square(0.0, 0.0, 10.0), square(20.0, 0.0, 10.0));
+        test(a.clone(), b.clone(), BooleanOp::Union, 200.0);
+        test(a.clone(), b.clone(), BooleanOp::Difference, 100.0);
+        assert!(a.boolean(&b, BooleanOp::Intersection).0.is_empty());
+    }
+
+    #[test]
+    fn test_boolean_nested() {
+        let (a, b) = (square(0.0, 0.0, 10.0), square(2.0, 2.0, 4.0));
+        test(a.clone(), b.clone(), BooleanOp::Union, 100.0);
+        test(a.clone(), b.clone(), BooleanOp::Intersection, 16.0);
+        test(a.clone(), b.clone(), BooleanOp::Difference, 84.0);
+        test(b.clone(), a.clone(), BooleanOp::Difference, 0.0);
+
+        let difference = a.boolean(&b, BooleanOp::Difference);
+        assert!(contains(&difference, 1.0, 1.0));
+        assert!(!contains(&difference, 4.0, 4.0));
+    }
+
+    #[test]
+    fn test_boolean_shared_edge() {
+        let (a, b) = (square(0.0, 0.0, 10.0), square(10.0, 0.0, 10.0));
+        test(a.clone(), b.clone(), BooleanOp::Union, 200.0);
+        test(a, b, BooleanOp::Intersection, 0.0);
+    }
+}
@@ -705,6 +705,52 @@ impl Gradient {
         }
     }
 
+    /// Returns the center of this gradient's end circle, or of its rotation
+    /// for a conic gradient.
+    ///
+    /// Returns `{none}` for linear gradients.
+    #[func]
+    pub fn center(&self) -> Option<Axes<Ratio>> {
+        match self {
+            Self::Linear(_) => None,
+            Self::Radial(radial) => Some(radial.center),
+            Self::Conic(conic) => Some(conic.center),
+        }
+    }
+
+    /// Returns the radius of this gradient's end circle.
+    ///
+    /// Returns `{none}` for linear and conic gradients.
+    #[func]
+    pub fn radius(&self) -> Option<Ratio> {
+        match self {
+            Self::Radial(radial) => Some(radial.radius),
+            Self::Linear(_) | Self::Conic(_) => None,
+        }
+    }
+
+    /// Returns the center of this gradient's focal circle.
+    ///
+    /// Returns `{none}` for linear and conic gradients.
+    #[func]
+    pub fn focal_center(&self) -> Option<Axes<Ratio>> {
+        match self {
+            Self::Radial(radial) => Some(radial.focal_center),
+            Self::Linear(_) | Self::Conic(_) => None,
+        }
+    }
+
+    /// Returns the radius of this gradient's focal circle.
+    ///
+    /// Returns `{none}` for linear and conic gradients.
+    #[func]
+    pub fn focal_radius(&self) -> Option<Ratio> {
+        match self {
+            Self::Radial(radial) => Some(radial.focal_radius),
+            Self::Linear(_) | Self::Conic(_) => None,
+        }
+    }
+
     /// Sample the gradient at a given position.
     ///
     /// The position is either a position along the gradient (a [ratio] between
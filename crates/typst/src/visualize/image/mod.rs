@@ -16,8 +16,8 @@ use ecow::EcoString;
 use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, func, scope, Bytes, Cast, Content, NativeElement, Packed, Show, Smart,
-    StyleChain,
+    array, cast, elem, func, scope, Array, Bytes, Cast, Content, NativeElement, Packed,
+    Show, Smart, StyleChain,
 };
 use crate::introspection::Locator;
 use crate::layout::{
@@ -28,7 +28,7 @@ use crate::loading::Readable;
 use crate::model::Figurable;
 use crate::syntax::{Span, Spanned};
 use crate::text::{families, LocalName};
-use crate::utils::LazyHash;
+use crate::utils::{LazyHash, Scalar};
 use crate::visualize::Path;
 use crate::World;
 
@@ -82,6 +82,10 @@ pub struct ImageElem {
     pub height: Smart<Rel<Length>>,
 
     /// A text describing the image.
+    ///
+    /// An empty string marks the image as purely decorative. Exporters that
+    /// support it then exclude the image from the document's logical
+    /// structure.
     pub alt: Option<EcoString>,
 
     /// How the image should adjust itself to a given area (the area is defined
@@ -97,6 +101,27 @@ pub struct ImageElem {
     /// ```
     #[default(ImageFit::Cover)]
     pub fit: ImageFit,
+
+    /// A rectangular part of the image to show instead of the whole image.
+    ///
+    /// The rectangle is given as an array `(x, y, width, height)` in the
+    /// image's pixels, measured from its top-left corner. Parts of the
+    /// rectangle that lie outside of the image are cut off. The remaining part
+    /// is then sized and fitted as if it were the whole image.
+    ///
+    /// ```example
+    /// #image("tiger.jpg", width: 50%, crop: (0, 0, 200, 200))
+    /// ```
+    pub crop: Option<ImageCrop>,
+
+    /// Whether to apply the orientation stored in an image's EXIF metadata.
+    ///
+    /// Cameras often store photos as they were captured and record in the
+    /// metadata how to turn them upright. By default, images are rotated and
+    /// flipped accordingly. Set this to `{false}` to show the pixels as they
+    /// are stored.
+    #[default(true)]
+    pub exif_orientation: bool,
 }
 
 #[scope]
@@ -114,6 +139,7 @@ impl ImageElem {
     /// #image.decode(changed)
     /// ```
     #[func(title = "Decode Image")]
+    #[allow(clippy::too_many_arguments)]
     pub fn decode(
         /// The call span of this function.
         span: Span,
@@ -134,6 +160,12 @@ impl ImageElem {
         /// How the image should adjust itself to a given area.
         #[named]
         fit: Option<ImageFit>,
+        /// A rectangular part of the image to show instead of the whole image.
+        #[named]
+        crop: Option<Option<ImageCrop>>,
+        /// Whether to apply the orientation stored in an image's EXIF metadata.
+        #[named]
+        exif_orientation: Option<bool>,
     ) -> StrResult<Content> {
         let mut elem = ImageElem::new(EcoString::new(), data);
         if let Some(format) = format {
@@ -151,6 +183,12 @@ impl ImageElem {
         if let Some(fit) = fit {
             elem.push_fit(fit);
         }
+        if let Some(crop) = crop {
+            elem.push_crop(crop);
+        }
+        if let Some(exif_orientation) = exif_orientation {
+            elem.push_exif_orientation(exif_orientation);
+        }
         Ok(elem.pack().spanned(span))
     }
 }
@@ -191,7 +229,7 @@ fn layout_image(
     };
 
     // Construct the image itself.
-    let image = Image::with_fonts(
+    let mut image = Image::with_fonts(
         data.clone().into(),
         format,
         elem.alt(styles),
@@ -199,10 +237,19 @@ fn layout_image(
         &families(styles).map(|s| s.into()).collect::<Vec<_>>(),
     )
     .at(span)?;
+    if !elem.exif_orientation(styles) {
+        image = image.without_orientation();
+    }
+
+    // Determine the part of the image that is shown.
+    let size = Axes::new(image.width(), image.height());
+    let crop = elem.crop(styles).map(|crop| crop.within(size)).transpose().at(span)?;
 
     // Determine the image's pixel aspect ratio.
-    let pxw = image.width();
-    let pxh = image.height();
+    let (pxw, pxh) = match crop {
+        Some((_, px)) => (px.x, px.y),
+        None => (size.x, size.y),
+    };
     let px_ratio = pxw / pxh;
 
     // Determine the region's aspect ratio.
@@ -249,7 +296,18 @@ fn layout_image(
     // the frame to the target size, center aligning the image in the
     // process.
     let mut frame = Frame::soft(fitted);
-    frame.push(Point::zero(), FrameItem::Image(image, fitted, span));
+    match crop {
+        // Scale and shift the whole image such that only the cropped part
+        // covers the frame and clip away the rest.
+        Some((offset, px)) => {
+            let scale = Axes::new(fitted.x / px.x, fitted.y / px.y);
+            let pos = Point::new(-scale.x * offset.x, -scale.y * offset.y);
+            let full = Size::new(scale.x * size.x, scale.y * size.y);
+            frame.push(pos, FrameItem::Image(image, full, span));
+            frame.clip(Path::rect(fitted));
+        }
+        None => frame.push(Point::zero(), FrameItem::Image(image, fitted, span)),
+    }
     frame.resize(target, Axes::splat(FixedAlignment::Center));
 
     // Create a clipping group if only part of the image should be visible.
@@ -300,6 +358,58 @@ pub enum ImageFit {
     Stretch,
 }
 
+/// A rectangular part of an image, measured in pixels from its top-left
+/// corner.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ImageCrop {
+    /// The position of the rectangle's top-left corner.
+    pub origin: Axes<Scalar>,
+    /// The rectangle's width and height.
+    pub size: Axes<Scalar>,
+}
+
+impl ImageCrop {
+    /// Clamp the rectangle to an image of the given size in pixels.
+    ///
+    /// Returns the clamped rectangle's origin and size.
+    fn within(self, bounds: Axes<f64>) -> StrResult<(Axes<f64>, Axes<f64>)> {
+        let start = self.origin.zip_map(bounds, |v, b| v.get().clamp(0.0, b));
+        let end = self
+            .origin
+            .zip(self.size)
+            .zip_map(bounds, |(v, s), b| (v.get() + s.get()).clamp(0.0, b));
+        let size = end.zip_map(start, |e, s| e - s);
+        if size.x <= 0.0 || size.y <= 0.0 {
+            bail!("crop rectangle lies outside of the image");
+        }
+        Ok((start, size))
+    }
+}
+
+cast! {
+    ImageCrop,
+    self => array![
+        self.origin.x.get(),
+        self.origin.y.get(),
+        self.size.x.get(),
+        self.size.y.get(),
+    ].into_value(),
+    array: Array => {
+        let mut iter = array.into_iter();
+        let (Some(x), Some(y), Some(w), Some(h), None) =
+            (iter.next(), iter.next(), iter.next(), iter.next(), iter.next())
+        else {
+            bail!("crop array must contain exactly four entries");
+        };
+        let [x, y, w, h] = [x, y, w, h].map(|v| v.cast::<f64>().map(Scalar::new));
+        let (origin, size) = (Axes::new(x?, y?), Axes::new(w?, h?));
+        if size.any(|v| v.get() <= 0.0) {
+            bail!("crop width and height must be positive");
+        }
+        Self { origin, size }
+    },
+}
+
 /// A loaded raster or vector image.
 ///
 /// Values of this type are cheap to clone and hash.
@@ -374,6 +484,21 @@ impl Image {
         Ok(Self(Arc::new(LazyHash::new(Repr { kind, alt }))))
     }
 
+    /// This image without the orientation from its EXIF metadata. Vector
+    /// images are returned unchanged.
+    ///
+    /// This is memoized so that repeated layouts share the same image.
+    #[comemo::memoize]
+    pub fn without_orientation(&self) -> Image {
+        match &self.0.kind {
+            ImageKind::Raster(raster) => Self(Arc::new(LazyHash::new(Repr {
+                kind: ImageKind::Raster(raster.without_orientation()),
+                alt: self.0.alt.clone(),
+            }))),
+            ImageKind::Svg(_) => self.clone(),
+        }
+    }
+
     /// The raw image data.
     pub fn data(&self) -> &Bytes {
         match &self.0.kind {
@@ -13,7 +13,12 @@ use image::{guess_format, DynamicImage, ImageDecoder, ImageResult};
 use crate::diag::{bail, StrResult};
 use crate::foundations::{Bytes, Cast};
 
-/// A decoded raster image.
+/// A raster image.
+///
+/// Only the encoded data and the information from the image's headers are kept
+/// around. The pixel data is decoded on demand, e.g. during export, and cached
+/// like other memoized results, so that compiling a document with many large
+/// images doesn't decode them all just to lay them out.
 #[derive(Clone, Hash)]
 pub struct RasterImage(Arc<Repr>);
 
@@ -21,30 +26,33 @@ pub struct RasterImage(Arc<Repr>);
 struct Repr {
     data: Bytes,
     format: RasterFormat,
-    dynamic: image::DynamicImage,
+    width: u32,
+    height: u32,
+    rotation: Option<u32>,
+    color: image::ColorType,
     icc: Option<Vec<u8>>,
     dpi: Option<f64>,
 }
 
 impl RasterImage {
-    /// Decode a raster image.
+    /// Read a raster image's headers without decoding its pixel data.
     #[comemo::memoize]
     pub fn new(data: Bytes, format: RasterFormat) -> StrResult<RasterImage> {
-        fn decode_with<'a, T: ImageDecoder<'a>>(
+        #[allow(clippy::type_complexity)]
+        fn read_with<'a, T: ImageDecoder<'a>>(
             decoder: ImageResult<T>,
-        ) -> ImageResult<(image::DynamicImage, Option<Vec<u8>>)> {
+        ) -> ImageResult<((u32, u32), image::ColorType, Option<Vec<u8>>)> {
             let mut decoder = decoder?;
             let icc = decoder.icc_profile().filter(|icc| !icc.is_empty());
             decoder.set_limits(Limits::default())?;
-            let dynamic = image::DynamicImage::from_decoder(decoder)?;
-            Ok((dynamic, icc))
+            Ok((decoder.dimensions(), decoder.color_type(), icc))
         }
 
         let cursor = io::Cursor::new(&data);
-        let (mut dynamic, icc) = match format {
-            RasterFormat::Jpg => decode_with(JpegDecoder::new(cursor)),
-            RasterFormat::Png => decode_with(PngDecoder::new(cursor)),
-            RasterFormat::Gif => decode_with(GifDecoder::new(cursor)),
+        let ((width, height), color, icc) = match format {
+            RasterFormat::Jpg => read_with(JpegDecoder::new(cursor)),
+            RasterFormat::Png => read_with(PngDecoder::new(cursor)),
+            RasterFormat::Gif => read_with(GifDecoder::new(cursor)),
         }
         .map_err(format_image_error)?;
 
@@ -52,15 +60,27 @@ impl RasterImage {
             .read_from_container(&mut std::io::Cursor::new(&data))
             .ok();
 
-        // Apply rotation from EXIF metadata.
-        if let Some(rotation) = exif.as_ref().and_then(exif_rotation) {
-            apply_rotation(&mut dynamic, rotation);
-        }
+        // Rotations from EXIF metadata that turn the image by a quarter swap
+        // its width and height.
+        let rotation = exif.as_ref().and_then(exif_rotation);
+        let (width, height) = match rotation {
+            Some(rotation) if swaps_axes(rotation) => (height, width),
+            _ => (width, height),
+        };
 
         // Extract pixel density.
         let dpi = determine_dpi(&data, exif.as_ref());
 
-        Ok(Self(Arc::new(Repr { data, format, dynamic, icc, dpi })))
+        Ok(Self(Arc::new(Repr {
+            data,
+            format,
+            width,
+            height,
+            rotation,
+            color,
+            icc,
+            dpi,
+        })))
     }
 
     /// The raw image data.
@@ -75,12 +95,12 @@ impl RasterImage {
 
     /// The image's pixel width.
     pub fn width(&self) -> u32 {
-        self.dynamic().width()
+        self.0.width
     }
 
     /// The image's pixel height.
     pub fn height(&self) -> u32 {
-        self.dynamic().height()
+        self.0.height
     }
 
     /// The image's pixel density in pixels per inch, if known.
@@ -88,9 +108,56 @@ impl RasterImage {
         self.0.dpi
     }
 
-    /// Access the underlying dynamic image.
-    pub fn dynamic(&self) -> &image::DynamicImage {
-        &self.0.dynamic
+    /// This image without the rotation from its EXIF metadata, such that its
+    /// pixels are shown as they are stored.
+    pub fn without_orientation(&self) -> RasterImage {
+        let Some(rotation) = self.0.rotation else { return self.clone() };
+        let (width, height) = if swaps_axes(rotation) {
+            (self.0.height, self.0.width)
+        } else {
+            (self.0.width, self.0.height)
+        };
+
+        Self(Arc::new(Repr {
+            data: self.0.data.clone(),
+            format: self.0.format,
+            width,
+            height,
+            rotation: None,
+            color: self.0.color,
+            icc: self.0.icc.clone(),
+            dpi: self.0.dpi,
+        }))
+    }
+
+    /// Whether the image's data carries an EXIF rotation that isn't applied
+    /// to its pixels because it was removed with
+    /// [`without_orientation`](Self::without_orientation).
+    ///
+    /// Exporters that embed the raw data must then make sure that viewers
+    /// don't apply the rotation either.
+    pub fn ignores_orientation(&self) -> bool {
+        self.0.rotation.is_none()
+            && exif::Reader::new()
+                .read_from_container(&mut io::Cursor::new(&self.0.data))
+                .ok()
+                .as_ref()
+                .and_then(exif_rotation)
+                .is_some_and(|rotation| rotation != 1)
+    }
+
+    /// The image's color type.
+    pub fn color(&self) -> image::ColorType {
+        self.0.color
+    }
+
+    /// Decode the image's pixel data and apply the rotation from its EXIF
+    /// metadata.
+    ///
+    /// Fails if the pixel data is corrupted, which is only detected here as
+    /// creating the image just reads its headers.
+    pub fn dynamic(&self) -> StrResult<Arc<DynamicImage>> {
+        decode(&self.0.data, self.0.format, self.0.rotation)
     }
 
     /// Access the ICC profile, if any.
@@ -99,11 +166,44 @@ impl RasterImage {
     }
 }
 
+/// Decode the pixel data of an image and apply an EXIF rotation to it.
+#[comemo::memoize]
+#[typst_macros::time(name = "decode image")]
+fn decode(
+    data: &Bytes,
+    format: RasterFormat,
+    rotation: Option<u32>,
+) -> StrResult<Arc<DynamicImage>> {
+    fn decode_with<'a, T: ImageDecoder<'a>>(
+        decoder: ImageResult<T>,
+    ) -> ImageResult<DynamicImage> {
+        let mut decoder = decoder?;
+        decoder.set_limits(Limits::default())?;
+        DynamicImage::from_decoder(decoder)
+    }
+
+    let cursor = io::Cursor::new(data);
+    let mut dynamic = match format {
+        RasterFormat::Jpg => decode_with(JpegDecoder::new(cursor)),
+        RasterFormat::Png => decode_with(PngDecoder::new(cursor)),
+        RasterFormat::Gif => decode_with(GifDecoder::new(cursor)),
+    }
+    .map_err(format_image_error)?;
+
+    if let Some(rotation) = rotation {
+        apply_rotation(&mut dynamic, rotation);
+    }
+
+    Ok(Arc::new(dynamic))
+}
+
 impl Hash for Repr {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // The image is fully defined by data and format.
+        // The image is fully defined by data, format, and whether its
+        // rotation is applied.
         self.data.hash(state);
         self.format.hash(state);
+        self.rotation.hash(state);
     }
 }
 
@@ -155,6 +255,11 @@ fn exif_rotation(exif: &exif::Exif) -> Option<u32> {
         .get_uint(0)
 }
 
+/// Whether an EXIF rotation turns the image by a quarter.
+fn swaps_axes(rotation: u32) -> bool {
+    matches!(rotation, 5..=8)
+}
+
 /// Apply an EXIF rotation to a dynamic image.
 fn apply_rotation(image: &mut DynamicImage, rotation: u32) {
     use image::imageops as ops;
@@ -267,7 +372,9 @@ fn format_image_error(error: image::ImageError) -> EcoString {
 
 #[cfg(test)]
 mod tests {
-    use super::{RasterFormat, RasterImage};
+    use std::sync::Arc;
+
+    use super::{apply_rotation, swaps_axes, RasterFormat, RasterImage};
     use crate::foundations::Bytes;
 
     #[test]
@@ -284,4 +391,72 @@ mod tests {
         test("images/tiger.jpg", RasterFormat::Jpg, 72.0);
         test("images/graph.png", RasterFormat::Png, 144.0);
     }
+
+    #[test]
+    fn test_image_size_before_decoding() {
+        #[track_caller]
+        fn test(path: &str, format: RasterFormat) {
+            let data = typst_dev_assets::get(path).unwrap();
+            let bytes = Bytes::from_static(data);
+            let image = RasterImage::new(bytes, format).unwrap();
+            let size = (image.width(), image.height());
+            let dynamic = image.dynamic().unwrap();
+            assert_eq!(size, (dynamic.width(), dynamic.height()));
+        }
+
+        test("images/f2t.jpg", RasterFormat::Jpg);
+        test("images/tiger.jpg", RasterFormat::Jpg);
+        test("images/graph.png", RasterFormat::Png);
+    }
+
+    #[test]
+    fn test_image_header_colors() {
+        let data = typst_dev_assets::get("images/tiger.jpg").unwrap();
+        let image =
+            RasterImage::new(Bytes::from_static(data), RasterFormat::Jpg).unwrap();
+        assert_eq!(image.color(), image.dynamic().unwrap().color());
+    }
+
+    #[test]
+    fn test_image_decoding_is_cached() {
+        let data = typst_dev_assets::get("images/graph.png").unwrap();
+        let image =
+            RasterImage::new(Bytes::from_static(data), RasterFormat::Png).unwrap();
+        let (a, b) = (image.dynamic().unwrap(), image.dynamic().unwrap());
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_image_corrupted_pixel_data() {
+        // The headers are intact, but the pixel data is cut off. This is only
+        // noticed once the pixels are needed.
+        let data = typst_dev_assets::get("images/graph.png").unwrap();
+        let truncated = Bytes::from_static(&data[..data.len() / 2]);
+        let image = RasterImage::new(truncated, RasterFormat::Png).unwrap();
+        assert!(image.dynamic().unwrap_err().starts_with("failed to decode image"));
+    }
+
+    #[test]
+    fn test_image_corrupted_headers() {
+        let data = typst_dev_assets::get("images/graph.png").unwrap();
+        let truncated = Bytes::from_static(&data[..8]);
+        assert!(RasterImage::new(truncated, RasterFormat::Png).is_err());
+    }
+
+    #[test]
+    fn test_exif_rotation() {
+        // Where the stored top-left pixel of a 3x2 image ends up for each
+        // orientation.
+        let expected = [(0, 0), (2, 0), (2, 1), (0, 1), (0, 0), (1, 0), (1, 2), (0, 2)];
+        for (rotation, (x, y)) in (1..=8).zip(expected) {
+            let mut buffer = image::RgbaImage::new(3, 2);
+            buffer.put_pixel(0, 0, image::Rgba([255, 0, 0, 255]));
+            let mut image = image::DynamicImage::ImageRgba8(buffer);
+            apply_rotation(&mut image, rotation);
+
+            let size = if swaps_axes(rotation) { (2, 3) } else { (3, 2) };
+            assert_eq!((image.width(), image.height()), size, "rotation {rotation}");
+            assert_eq!(image.to_rgba8().get_pixel(x, y).0, [255, 0, 0, 255]);
+        }
+    }
 }
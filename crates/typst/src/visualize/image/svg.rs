@@ -6,7 +6,7 @@ use comemo::Tracked;
 use ecow::EcoString;
 use siphasher::sip128::{Hasher128, SipHasher13};
 
-use crate::diag::{format_xml_like_error, StrResult};
+use crate::diag::{bail, format_xml_like_error, StrResult};
 use crate::foundations::Bytes;
 use crate::layout::Axes;
 use crate::text::{
@@ -30,6 +30,7 @@ impl SvgImage {
     /// Decode an SVG image without fonts.
     #[comemo::memoize]
     pub fn new(data: Bytes) -> StrResult<SvgImage> {
+        check_references(&data)?;
         let tree =
             usvg::Tree::from_data(&data, &base_options()).map_err(format_usvg_error)?;
         Ok(Self(Arc::new(Repr { data, size: tree_size(&tree), font_hash: 0, tree })))
@@ -42,6 +43,7 @@ impl SvgImage {
         world: Tracked<dyn World + '_>,
         families: &[String],
     ) -> StrResult<SvgImage> {
+        check_references(&data)?;
         let book = world.book();
         let resolver = Mutex::new(FontResolver::new(world, book, families));
         let tree = usvg::Tree::from_data(
@@ -117,6 +119,53 @@ fn base_options() -> usvg::Options<'static> {
     }
 }
 
+/// Reject references to other files.
+///
+/// usvg resolves `<use>` elements and style sheets within the SVG itself, but
+/// silently drops anything that refers to another file. Since we don't load
+/// such files, we report them instead of rendering an incomplete image.
+fn check_references(data: &[u8]) -> StrResult<()> {
+    // Data that can't be read here is left to usvg, which reports a proper
+    // error for it.
+    let decompressed;
+    let mut data = data;
+    if data.starts_with(&[0x1f, 0x8b]) {
+        let Ok(inflated) = usvg::decompress_svgz(data) else { return Ok(()) };
+        decompressed = inflated;
+        data = &decompressed;
+    }
+
+    let Ok(text) = std::str::from_utf8(data) else { return Ok(()) };
+    let options = roxmltree::ParsingOptions { allow_dtd: true, ..Default::default() };
+    let Ok(document) = roxmltree::Document::parse_with_options(text, options) else {
+        return Ok(());
+    };
+
+    for node in document.descendants() {
+        match node.tag_name().name() {
+            "use" => {
+                let href = node
+                    .attribute(("http://www.w3.org/1999/xlink", "href"))
+                    .or_else(|| node.attribute("href"))
+                    .unwrap_or_default()
+                    .trim();
+                if !href.is_empty() && !href.starts_with('#') {
+                    bail!(
+                        "failed to parse SVG (reference to external file {href:?} \
+                         is not supported)"
+                    );
+                }
+            }
+            "style" if node.text().is_some_and(|text| text.contains("@import")) => {
+                bail!("failed to parse SVG (style sheet imports are not supported)");
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
 /// The pixel size of an SVG.
 fn tree_size(tree: &usvg::Tree) -> Axes<f64> {
     Axes::new(tree.size().width() as f64, tree.size().height() as f64)
@@ -1,12 +1,16 @@
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
-use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
+use crate::foundations::{elem, Content, NativeElement, Packed, Show, Smart, StyleChain};
 use crate::introspection::Locator;
 use crate::layout::{
-    Abs, Angle, Axes, BlockElem, Frame, FrameItem, Length, Region, Rel, Size,
+    Abs, Angle, Axes, BlockElem, Frame, FrameItem, Length, Point, Ratio, Region, Rel,
+    Size, Transform,
 };
 use crate::utils::Numeric;
-use crate::visualize::{Geometry, Stroke};
+use crate::visualize::{
+    mark_line, Geometry, Gradient, LineCap, Marker, Paint, RatioOrAngle, RelativeTo,
+    Stroke,
+};
 
 /// A line from one point to another.
 ///
@@ -57,6 +61,60 @@ pub struct LineElem {
     #[resolve]
     #[fold]
     pub stroke: Stroke,
+
+    /// Whether a linear [gradient] stroke runs along the line's length.
+    ///
+    /// By default, gradients on lines behave like on other shapes: They are
+    /// laid out in the line's bounding box. When this is enabled, a linear
+    /// gradient relative to the line itself instead starts at the line's start
+    /// and ends at its end, whatever the line's direction.
+    ///
+    /// ```example
+    /// #set line(
+    ///   stroke: 2pt + gradient.linear(red, blue),
+    ///   stroke-along: true,
+    /// )
+    /// #line(length: 100%)
+    /// #line(end: (60%, 1cm))
+    /// ```
+    #[default(false)]
+    pub stroke_along: bool,
+
+    /// How the ends of the line are rendered. This is a shortcut for the
+    /// `cap` of the [`stroke`]($line.stroke) and takes precedence over it.
+    ///
+    /// If set to `{auto}`, the stroke's cap is used, defaulting to `{"butt"}`.
+    ///
+    /// ```example
+    /// #set line(length: 100%, stroke: 6pt + eastern)
+    /// #stack(
+    ///   spacing: 1em,
+    ///   line(cap: "butt"),
+    ///   line(cap: "round"),
+    ///   line(cap: "square"),
+    /// )
+    /// ```
+    pub cap: Smart<LineCap>,
+
+    /// A marker to draw at the start of the line.
+    ///
+    /// Markers are scaled with the stroke's thickness and take the stroke's
+    /// color. If they don't fit onto a short line, they are shrunk.
+    ///
+    /// ```example
+    /// #set line(length: 100%)
+    /// #stack(
+    ///   spacing: 1em,
+    ///   line(marker-end: "arrow"),
+    ///   line(stroke: 2pt, marker-start: "circle", marker-end: "barb"),
+    ///   line(stroke: blue, marker-start: "bar", marker-end: "bar"),
+    /// )
+    /// ```
+    pub marker_start: Option<Marker>,
+
+    /// A marker to draw at the end of the line. See the
+    /// [`marker-start`]($line.marker-start) field for more details.
+    pub marker_end: Option<Marker>,
 }
 
 impl Show for Packed<LineElem> {
@@ -86,7 +144,10 @@ fn layout_line(
         resolve(Axes::new(x, y))
     });
 
-    let stroke = elem.stroke(styles).unwrap_or_default();
+    let mut stroke = elem.stroke(styles).unwrap_or_default();
+    if let Smart::Custom(cap) = elem.cap(styles) {
+        stroke.cap = cap;
+    }
     let size = start.max(start + delta).max(Size::zero());
 
     if !size.is_finite() {
@@ -94,7 +155,46 @@ fn layout_line(
     }
 
     let mut frame = Frame::soft(size);
-    let shape = Geometry::Line(delta.to_point()).stroked(stroke);
-    frame.push(start.to_point(), FrameItem::Shape(shape, elem.span()));
+    let along = elem.stroke_along(styles)
+        && match &stroke.paint {
+            Paint::Gradient(gradient @ Gradient::Linear(_)) => {
+                gradient.unwrap_relative(false) == RelativeTo::Self_
+            }
+            _ => false,
+        };
+
+    // Shorten the line such that it doesn't poke out of its markers.
+    let markers = (elem.marker_start(styles), elem.marker_end(styles));
+    let (start, end, markers) =
+        mark_line(start.to_point(), (start + delta).to_point(), markers, &stroke);
+    let delta = end - start;
+
+    if !along {
+        let shape = Geometry::Line(delta).stroked(stroke);
+        frame.push(start, FrameItem::Shape(shape, elem.span()));
+    } else if delta.hypot().approx_empty() {
+        // A gradient along the line is drawn on a horizontal line that is
+        // rotated into place. For a degenerate line, there is no direction,
+        // so we fall back to the gradient's first color.
+        if let Paint::Gradient(gradient) = &stroke.paint {
+            let color = gradient.sample(RatioOrAngle::Ratio(Ratio::zero()));
+            stroke.paint = Paint::Solid(color);
+        }
+        let shape = Geometry::Line(delta).stroked(stroke);
+        frame.push(start, FrameItem::Shape(shape, elem.span()));
+    } else {
+        let length = delta.hypot();
+        let angle = Angle::rad(delta.y.to_raw().atan2(delta.x.to_raw()));
+        let mut line = Frame::soft(Size::new(length, Abs::zero()));
+        let shape = Geometry::Line(Point::with_x(length)).stroked(stroke);
+        line.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+        line.transform(Transform::rotate(angle));
+        frame.push_frame(start, line);
+    }
+
+    for (pos, shape) in markers {
+        frame.push(pos, FrameItem::Shape(shape, elem.span()));
+    }
+
     Ok(frame)
 }
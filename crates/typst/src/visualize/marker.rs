@@ -0,0 +1,243 @@
+use std::f64::consts::PI;
+
+use kurbo::{CubicBez, ParamCurve, ParamCurveArclen};
+
+use crate::foundations::Cast;
+use crate::layout::{Abs, Angle, Point, Ratio, Size, Transform};
+use crate::visualize::{
+    ellipse, FixedStroke, Geometry, LineJoin, Paint, Path, PathItem, RatioOrAngle, Shape,
+};
+
+/// The accuracy, in points, with which arc lengths of curves are computed.
+const ACCURACY: f64 = 0.01;
+
+/// A shape drawn at an end of a line or path.
+///
+/// The size of a marker is a multiple of the stroke's thickness.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Marker {
+    /// A filled, triangular arrowhead.
+    Arrow,
+    /// An open arrowhead drawn with two strokes.
+    Barb,
+    /// A filled circle centered on the end.
+    Circle,
+    /// A short stroke across the end.
+    Bar,
+}
+
+impl Marker {
+    /// How far the marker extends back along the stroke from the end, in
+    /// multiples of the stroke's thickness.
+    fn length(self) -> f64 {
+        match self {
+            Self::Arrow => 5.0,
+            Self::Barb => 4.0,
+            Self::Circle => 2.0,
+            Self::Bar => 0.5,
+        }
+    }
+
+    /// How far the stroke must be shortened to not poke out of the marker, in
+    /// multiples of the stroke's thickness.
+    fn inset(self) -> f64 {
+        match self {
+            Self::Arrow => 4.5,
+            Self::Barb => 0.5,
+            Self::Circle | Self::Bar => 0.0,
+        }
+    }
+
+    /// Create the marker's shape for a stroke that ends at `tip` in the
+    /// direction of `angle`. The marker is shrunk by the given `scale` and
+    /// painted with the stroke's color at the given end.
+    fn shape(
+        self,
+        tip: Point,
+        angle: Angle,
+        stroke: &FixedStroke,
+        scale: f64,
+        at_end: bool,
+    ) -> (Point, Shape) {
+        // Markers are solid and take the stroke's color at their end if the
+        // stroke is a gradient.
+        let paint = match &stroke.paint {
+            Paint::Gradient(gradient) => {
+                let t = if at_end { Ratio::one() } else { Ratio::zero() };
+                Paint::Solid(gradient.sample(RatioOrAngle::Ratio(t)))
+            }
+            paint => paint.clone(),
+        };
+        let stroke = FixedStroke {
+            paint: paint.clone(),
+            dash_paints: vec![],
+            join: LineJoin::Round,
+            dash: None,
+            ..stroke.clone()
+        };
+
+        let unit = stroke.thickness * scale;
+        let ts = Transform::translate(tip.x, tip.y).pre_concat(Transform::rotate(angle));
+        let point = |x: f64, y: f64| Point::new(unit * x, unit * y).transform(ts);
+
+        match self {
+            Self::Arrow => {
+                let mut path = Path::new();
+                path.move_to(point(0.0, 0.0));
+                path.line_to(point(-5.0, 2.0));
+                path.line_to(point(-5.0, -2.0));
+                path.close_path();
+                (Point::zero(), Geometry::Path(path).filled(paint))
+            }
+            Self::Barb => {
+                let mut path = Path::new();
+                path.move_to(point(-4.0, 3.0));
+                path.line_to(point(0.0, 0.0));
+                path.line_to(point(-4.0, -3.0));
+                (Point::zero(), Geometry::Path(path).stroked(stroke))
+            }
+            Self::Circle => {
+                let radius = unit * 2.0;
+                let shape = ellipse(Size::splat(2.0 * radius), Some(paint), None);
+                (tip - Point::splat(radius), shape)
+            }
+            Self::Bar => {
+                let from = point(0.0, -3.0);
+                let shape = Geometry::Line(point(0.0, 3.0) - from).stroked(stroke);
+                (from, shape)
+            }
+        }
+    }
+}
+
+/// Create the markers for a straight line from `start` to `end` and shorten
+/// the line such that it doesn't poke out of them.
+///
+/// Returns the new ends of the line and the markers' shapes.
+pub fn mark_line(
+    start: Point,
+    end: Point,
+    markers: (Option<Marker>, Option<Marker>),
+    stroke: &FixedStroke,
+) -> (Point, Point, Vec<(Point, Shape)>) {
+    let delta = end - start;
+    let length = delta.hypot();
+    if length.approx_empty() {
+        return (start, end, vec![]);
+    }
+
+    let scale = fit(markers, stroke.thickness, length);
+    let dir = delta / length.to_raw();
+    let angle = Angle::rad(delta.y.to_raw().atan2(delta.x.to_raw()));
+
+    let mut shapes = vec![];
+    let (mut from, mut to) = (start, end);
+    if let Some(marker) = markers.0 {
+        shapes.push(marker.shape(start, angle + Angle::rad(PI), stroke, scale, false));
+        from += dir * (marker.inset() * stroke.thickness.to_raw() * scale);
+    }
+    if let Some(marker) = markers.1 {
+        shapes.push(marker.shape(end, angle, stroke, scale, true));
+        to -= dir * (marker.inset() * stroke.thickness.to_raw() * scale);
+    }
+
+    (from, to, shapes)
+}
+
+/// Create the markers for an open path that consists of cubic Bézier curves
+/// and shorten its first and last curve such that it doesn't poke out of
+/// them.
+///
+/// Returns the markers' shapes, which are empty if the path is closed or has
+/// no curves.
+pub fn mark_path(
+    path: &mut Path,
+    markers: (Option<Marker>, Option<Marker>),
+    stroke: &FixedStroke,
+) -> Vec<(Point, Shape)> {
+    if markers.0.is_none() && markers.1.is_none() {
+        return vec![];
+    }
+
+    let to_kurbo = |p: Point| kurbo::Point::new(p.x.to_pt(), p.y.to_pt());
+    let from_kurbo = |p: kurbo::Point| Point::new(Abs::pt(p.x), Abs::pt(p.y));
+
+    let mut cubics = vec![];
+    let mut cursor = None;
+    for item in &path.0 {
+        match *item {
+            PathItem::MoveTo(p) if cursor.is_none() => cursor = Some(to_kurbo(p)),
+            PathItem::CubicTo(p1, p2, p3) => {
+                let Some(p0) = cursor else { return vec![] };
+                cubics.push(CubicBez::new(p0, to_kurbo(p1), to_kurbo(p2), to_kurbo(p3)));
+                cursor = Some(to_kurbo(p3));
+            }
+            _ => return vec![],
+        }
+    }
+
+    let (Some(&first), Some(&last)) = (cubics.first(), cubics.last()) else {
+        return vec![];
+    };
+
+    let length = Abs::pt(cubics.iter().map(|c| c.arclen(ACCURACY)).sum());
+    let scale = fit(markers, stroke.thickness, length);
+    let inset = |marker: Marker| marker.inset() * stroke.thickness.to_pt() * scale;
+
+    let mut shapes = vec![];
+    if let Some(marker) = markers.0 {
+        let angle = tangent([first.p0, first.p1, first.p2, first.p3]);
+        let tip = from_kurbo(first.p0);
+        shapes.push(marker.shape(tip, angle, stroke, scale, false));
+
+        let length = first.arclen(ACCURACY);
+        let t = first.inv_arclen(inset(marker).min(length), ACCURACY);
+        cubics[0] = first.subsegment(t..1.0);
+    }
+    if let Some(marker) = markers.1 {
+        let angle = tangent([last.p3, last.p2, last.p1, last.p0]);
+        let tip = from_kurbo(last.p3);
+        shapes.push(marker.shape(tip, angle, stroke, scale, true));
+
+        let last = cubics[cubics.len() - 1];
+        let length = last.arclen(ACCURACY);
+        let t = last.inv_arclen((length - inset(marker)).max(0.0), ACCURACY);
+        *cubics.last_mut().unwrap() = last.subsegment(0.0..t);
+    }
+
+    *path = Path::new();
+    path.move_to(from_kurbo(cubics[0].p0));
+    for cubic in cubics {
+        path.cubic_to(from_kurbo(cubic.p1), from_kurbo(cubic.p2), from_kurbo(cubic.p3));
+    }
+
+    shapes
+}
+
+/// The factor by which markers must shrink to fit onto a stroke of the given
+/// length.
+fn fit(markers: (Option<Marker>, Option<Marker>), thickness: Abs, length: Abs) -> f64 {
+    let needed = [markers.0, markers.1]
+        .into_iter()
+        .flatten()
+        .map(|marker| thickness * marker.length())
+        .sum::<Abs>();
+    if needed > length {
+        length / needed
+    } else {
+        1.0
+    }
+}
+
+/// The direction in which a curve arrives at its first point, given its
+/// points from that end. Coinciding control points are skipped.
+fn tangent(points: [kurbo::Point; 4]) -> Angle {
+    let tip = points[0];
+    let from = points[1..]
+        .iter()
+        .copied()
+        .find(|p| (tip - *p).hypot() > 1e-6)
+        .unwrap_or(tip);
+    let d = tip - from;
+    Angle::rad(d.y.atan2(d.x))
+}
@@ -1,24 +1,30 @@
 //! Drawing and visualization.
 
+mod boolean;
 mod color;
 mod gradient;
 mod image;
 mod line;
+mod marker;
 mod paint;
 mod path;
 mod pattern;
 mod polygon;
+mod shadow;
 mod shape;
 mod stroke;
 
+pub use self::boolean::*;
 pub use self::color::*;
 pub use self::gradient::*;
 pub use self::image::*;
 pub use self::line::*;
+pub use self::marker::*;
 pub use self::paint::*;
 pub use self::path::*;
 pub use self::pattern::*;
 pub use self::polygon::*;
+pub use self::shadow::*;
 pub use self::shape::*;
 pub use self::stroke::*;
 
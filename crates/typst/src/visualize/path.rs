@@ -3,14 +3,17 @@ use kurbo::{CubicBez, ParamCurveExtrema};
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    array, cast, elem, Array, Content, NativeElement, Packed, Reflect, Resolve, Show,
-    Smart, StyleChain,
+    array, cast, elem, func, scope, Array, Content, NativeElement, Packed, Reflect,
+    Resolve, Show, Smart, StyleChain,
 };
 use crate::introspection::Locator;
 use crate::layout::{
     Abs, Axes, BlockElem, Frame, FrameItem, Length, Point, Region, Rel, Size,
 };
-use crate::visualize::{FixedStroke, Geometry, Paint, Shape, Stroke};
+use crate::syntax::Span;
+use crate::visualize::{
+    mark_path, BooleanOp, FixedStroke, Geometry, Marker, Paint, Shape, Stroke,
+};
 
 use PathVertex::{AllControlPoints, MirroredControlPoint, Vertex};
 
@@ -27,7 +30,11 @@ use PathVertex::{AllControlPoints, MirroredControlPoint, Vertex};
 ///   ((50%, 0pt), (40pt, 0pt)),
 /// )
 /// ```
-#[elem(Show)]
+///
+/// Paths can be combined into new ones with [`path.union`]($path.union),
+/// [`path.intersect`]($path.intersect), and
+/// [`path.difference`]($path.difference).
+#[elem(scope, Show)]
 pub struct PathElem {
     /// How to fill the path.
     ///
@@ -53,6 +60,27 @@ pub struct PathElem {
     #[default(false)]
     pub closed: bool,
 
+    /// A marker to draw at the start of the path. This works like the
+    /// [line's markers]($line.marker-start), with the marker following the
+    /// direction of the path's first curve.
+    ///
+    /// Markers are only drawn on stroked paths that aren't closed.
+    ///
+    /// ```example
+    /// #path(
+    ///   marker-start: "circle",
+    ///   marker-end: "arrow",
+    ///   (0pt, 30pt),
+    ///   ((60pt, 0pt), (-30pt, 0pt)),
+    ///   (100pt, 30pt),
+    /// )
+    /// ```
+    pub marker_start: Option<Marker>,
+
+    /// A marker to draw at the end of the path. See the
+    /// [`marker-start`]($path.marker-start) field for more details.
+    pub marker_end: Option<Marker>,
+
     /// The vertices of the path.
     ///
     /// Each vertex can be defined in 3 ways:
@@ -69,6 +97,149 @@ pub struct PathElem {
     ///   respectively).
     #[variadic]
     pub vertices: Vec<PathVertex>,
+
+    /// The boolean operation whose result this path is, if any. If set, the
+    /// vertices are ignored.
+    #[internal]
+    #[default]
+    #[parse(None)]
+    pub op: Option<PathOp>,
+}
+
+#[scope]
+impl PathElem {
+    /// Combines two paths into one that covers the area of either.
+    ///
+    /// Both paths are treated as closed. Curves are approximated by straight
+    /// line segments.
+    ///
+    /// ```example
+    /// #path.union(
+    ///   fill: aqua,
+    ///   path(closed: true, (0pt, 0pt), (40pt, 0pt), (40pt, 20pt), (0pt, 20pt)),
+    ///   path(closed: true, (10pt, 10pt), (30pt, 10pt), (20pt, 40pt)),
+    /// )
+    /// ```
+    #[func]
+    pub fn union(
+        /// The call span of this function.
+        span: Span,
+        /// The first path.
+        a: Packed<PathElem>,
+        /// The second path.
+        b: Packed<PathElem>,
+        /// How to fill the resulting path. See the general
+        /// [path's documentation]($path.fill) for more details.
+        #[named]
+        fill: Option<Option<Paint>>,
+        /// How to stroke the resulting path. See the general
+        /// [path's documentation]($path.stroke) for more details.
+        #[named]
+        stroke: Option<Smart<Option<Stroke>>>,
+    ) -> Content {
+        combine(span, BooleanOp::Union, a, b, fill, stroke)
+    }
+
+    /// Combines two paths into one that covers the area of both.
+    ///
+    /// ```example
+    /// #path.intersect(
+    ///   fill: aqua,
+    ///   path(closed: true, (0pt, 0pt), (40pt, 0pt), (40pt, 20pt), (0pt, 20pt)),
+    ///   path(closed: true, (10pt, 10pt), (30pt, 10pt), (20pt, 40pt)),
+    /// )
+    /// ```
+    #[func]
+    pub fn intersect(
+        /// The call span of this function.
+        span: Span,
+        /// The first path.
+        a: Packed<PathElem>,
+        /// The second path.
+        b: Packed<PathElem>,
+        /// How to fill the resulting path. See the general
+        /// [path's documentation]($path.fill) for more details.
+        #[named]
+        fill: Option<Option<Paint>>,
+        /// How to stroke the resulting path. See the general
+        /// [path's documentation]($path.stroke) for more details.
+        #[named]
+        stroke: Option<Smart<Option<Stroke>>>,
+    ) -> Content {
+        combine(span, BooleanOp::Intersection, a, b, fill, stroke)
+    }
+
+    /// Subtracts the area of the second path from the first one.
+    ///
+    /// ```example
+    /// #let disc = path(
+    ///   closed: true,
+    ///   ((20pt, 5pt), (-8pt, 0pt)),
+    ///   ((35pt, 20pt), (0pt, -8pt)),
+    ///   ((20pt, 35pt), (8pt, 0pt)),
+    ///   ((5pt, 20pt), (0pt, 8pt)),
+    /// )
+    ///
+    /// #path.difference(
+    ///   fill: aqua,
+    ///   path(closed: true, (0pt, 0pt), (40pt, 0pt), (40pt, 40pt), (0pt, 40pt)),
+    ///   disc,
+    /// )
+    /// ```
+    #[func]
+    pub fn difference(
+        /// The call span of this function.
+        span: Span,
+        /// The path to subtract from.
+        a: Packed<PathElem>,
+        /// The path to subtract.
+        b: Packed<PathElem>,
+        /// How to fill the resulting path. See the general
+        /// [path's documentation]($path.fill) for more details.
+        #[named]
+        fill: Option<Option<Paint>>,
+        /// How to stroke the resulting path. See the general
+        /// [path's documentation]($path.stroke) for more details.
+        #[named]
+        stroke: Option<Smart<Option<Stroke>>>,
+    ) -> Content {
+        combine(span, BooleanOp::Difference, a, b, fill, stroke)
+    }
+}
+
+/// Create a path that is the result of a boolean operation.
+fn combine(
+    span: Span,
+    kind: BooleanOp,
+    a: Packed<PathElem>,
+    b: Packed<PathElem>,
+    fill: Option<Option<Paint>>,
+    stroke: Option<Smart<Option<Stroke>>>,
+) -> Content {
+    let mut elem = PathElem::new(vec![]).with_op(Some(PathOp { kind, a, b }));
+    if let Some(fill) = fill {
+        elem.push_fill(fill);
+    }
+    if let Some(stroke) = stroke {
+        elem.push_stroke(stroke);
+    }
+    elem.pack().spanned(span)
+}
+
+/// A boolean operation on two paths.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct PathOp {
+    /// How to combine the paths.
+    kind: BooleanOp,
+    /// The first path.
+    a: Packed<PathElem>,
+    /// The second path.
+    b: Packed<PathElem>,
+}
+
+cast! {
+    PathElem,
+    v: Content => v.unpack::<Self>().map_err(|_| "expected path")?,
 }
 
 impl Show for Packed<PathElem> {
@@ -88,8 +259,67 @@ fn layout_path(
     styles: StyleChain,
     region: Region,
 ) -> SourceResult<Frame> {
+    let closed = elem.closed(styles);
+    let Some((mut path, size)) = build_path(elem, styles, region.size, closed) else {
+        return Ok(Frame::soft(Size::zero()));
+    };
+
+    // Prepare fill and stroke.
+    let fill = elem.fill(styles);
+    let stroke = match elem.stroke(styles) {
+        Smart::Auto if fill.is_none() => Some(FixedStroke::default()),
+        Smart::Auto => None,
+        Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
+    };
+
+    // Add markers to the ends of an open path.
+    let mut markers = vec![];
+    if let Some(stroke) = &stroke {
+        if !elem.closed(styles) && elem.op(styles).is_none() {
+            let ends = (elem.marker_start(styles), elem.marker_end(styles));
+            markers = mark_path(&mut path, ends, stroke);
+        }
+    }
+
+    let mut frame = Frame::soft(size);
+    let shape = Shape { geometry: Geometry::Path(path), stroke, fill };
+    frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+    for (pos, shape) in markers {
+        frame.push(pos, FrameItem::Shape(shape, elem.span()));
+    }
+    Ok(frame)
+}
+
+/// Build the geometry of a path and the size of its frame, resolving relative
+/// vertices against the size of the region. Returns `None` if the path has no
+/// vertices.
+///
+/// The operands of a boolean operation only take their own properties into
+/// account, not the path properties of the context the result is placed in.
+/// They are always closed.
+fn build_path(
+    elem: &Packed<PathElem>,
+    styles: StyleChain,
+    region: Size,
+    closed: bool,
+) -> Option<(Path, Size)> {
+    if let Some(PathOp { kind, a, b }) = elem.op(styles) {
+        let operand = |elem| build_path(elem, styles, region, true).unwrap_or_default().0;
+        let path = operand(&a).boolean(&operand(&b), kind);
+
+        let mut size = Size::zero();
+        for item in &path.0 {
+            if let PathItem::MoveTo(p) | PathItem::LineTo(p) = item {
+                size.x.set_max(p.x);
+                size.y.set_max(p.y);
+            }
+        }
+
+        return Some((path, size));
+    }
+
     let resolve = |axes: Axes<Rel<Length>>| {
-        axes.resolve(styles).zip_map(region.size, Rel::relative_to).to_point()
+        axes.resolve(styles).zip_map(region, Rel::relative_to).to_point()
     };
 
     let vertices = elem.vertices();
@@ -97,7 +327,7 @@ fn layout_path(
 
     let mut size = Size::zero();
     if points.is_empty() {
-        return Ok(Frame::soft(size));
+        return None;
     }
 
     // Only create a path if there are more than zero points.
@@ -135,7 +365,7 @@ fn layout_path(
         add_cubic(from_point, to_point, from, to);
     }
 
-    if elem.closed(styles) {
+    if closed {
         let from = *vertices.last().unwrap(); // We checked that we have at least one element.
         let to = vertices[0];
         let from_point = *points.last().unwrap();
@@ -145,18 +375,7 @@ fn layout_path(
         path.close_path();
     }
 
-    // Prepare fill and stroke.
-    let fill = elem.fill(styles);
-    let stroke = match elem.stroke(styles) {
-        Smart::Auto if fill.is_none() => Some(FixedStroke::default()),
-        Smart::Auto => None,
-        Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
-    };
-
-    let mut frame = Frame::soft(size);
-    let shape = Shape { geometry: Geometry::Path(path), stroke, fill };
-    frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
-    Ok(frame)
+    Some((path, size))
 }
 
 /// A component used for path creation.
@@ -7,7 +7,7 @@ use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{func, repr, scope, ty, Content, Smart, StyleChain};
 use crate::introspection::Locator;
-use crate::layout::{Abs, Axes, Frame, Length, Regions, Size};
+use crate::layout::{Abs, Angle, Axes, Frame, Length, Regions, Size, Transform};
 use crate::syntax::{Span, Spanned};
 use crate::utils::{LazyHash, Numeric};
 use crate::visualize::RelativeTo;
@@ -78,6 +78,21 @@ use crate::World;
 /// )
 /// ```
 ///
+/// The grid of tiles can be rotated with [`rotation`]($pattern.rotation) and
+/// shifted with [`phase`]($pattern.phase). Both are applied around the origin
+/// of the pattern's container, so the filled shape itself stays in place and
+/// only the tiles inside of it move.
+///
+/// ```example
+/// #let pat = pattern(
+///   size: (10pt, 10pt),
+///   rotation: 45deg,
+///   place(line(start: (0%, 0%), end: (0%, 100%))),
+/// )
+///
+/// #rect(width: 100%, height: 40pt, fill: pat)
+/// ```
+///
 /// # Relativeness
 /// The location of the starting point of the pattern is dependent on the
 /// dimensions of a container. This container can either be the shape that it is
@@ -107,6 +122,10 @@ struct Repr {
     size: Size,
     /// The pattern's tile spacing.
     spacing: Size,
+    /// The rotation of the pattern's tile grid.
+    rotation: Angle,
+    /// The offset of the pattern's tile grid.
+    phase: Axes<Abs>,
     /// The pattern's relative transform.
     relative: Smart<RelativeTo>,
 }
@@ -132,6 +151,7 @@ impl Pattern {
     /// #rect(width: 100%, height: 60pt, fill: pat)
     /// ```
     #[func(constructor)]
+    #[allow(clippy::too_many_arguments)]
     pub fn construct(
         engine: &mut Engine,
         /// The callsite span.
@@ -144,6 +164,27 @@ impl Pattern {
         #[named]
         #[default(Spanned::new(Axes::splat(Length::zero()), Span::detached()))]
         spacing: Spanned<Axes<Length>>,
+        /// How much to rotate the grid of tiles, clockwise around the origin
+        /// of the pattern's container.
+        #[named]
+        #[default(Angle::zero())]
+        rotation: Angle,
+        /// How far to shift the grid of tiles horizontally and vertically. The
+        /// shift is applied after the rotation, along the page's axes.
+        ///
+        /// ```example
+        /// #let pat(phase) = pattern(
+        ///   size: (20pt, 20pt),
+        ///   phase: phase,
+        ///   circle(radius: 5pt, fill: teal),
+        /// )
+        ///
+        /// #rect(width: 100%, height: 20pt, fill: pat((0pt, 0pt)))
+        /// #rect(width: 100%, height: 20pt, fill: pat((10pt, 10pt)))
+        /// ```
+        #[named]
+        #[default(Spanned::new(Axes::splat(Length::zero()), Span::detached()))]
+        phase: Spanned<Axes<Length>>,
         /// The [relative placement](#relativeness) of the pattern.
         ///
         /// For an element placed at the root/top level of the document, the
@@ -183,6 +224,15 @@ impl Pattern {
             bail!(spacing.span, "pattern tile spacing must be finite");
         }
 
+        // Ensure that the phase is absolute and finite.
+        if !phase.v.x.em.is_zero() || !phase.v.y.em.is_zero() {
+            bail!(phase.span, "pattern phase must be absolute");
+        }
+
+        if !phase.v.x.is_finite() || !phase.v.y.is_finite() {
+            bail!(phase.span, "pattern phase must be finite");
+        }
+
         // The size of the frame
         let size = size.v.map(|l| l.map(|a| a.abs));
         let region = size.unwrap_or_else(|| Axes::splat(Abs::inf()));
@@ -212,6 +262,8 @@ impl Pattern {
             size: frame.size(),
             frame: LazyHash::new(frame),
             spacing: spacing.v.map(|l| l.abs),
+            rotation,
+            phase: phase.v.map(|l| l.abs),
             relative,
         })))
     }
@@ -247,6 +299,23 @@ impl Pattern {
         self.0.spacing
     }
 
+    /// Return the rotation of the pattern's tile grid.
+    pub fn rotation(&self) -> Angle {
+        self.0.rotation
+    }
+
+    /// Return the offset of the pattern's tile grid in absolute units.
+    pub fn phase(&self) -> Axes<Abs> {
+        self.0.phase
+    }
+
+    /// Return the transform from the pattern's tile space into the space of
+    /// its container, combining the phase and the rotation.
+    pub fn transform(&self) -> Transform {
+        Transform::translate(self.0.phase.x, self.0.phase.y)
+            .pre_concat(Transform::rotate(self.0.rotation))
+    }
+
     /// Returns the relative placement of the pattern.
     pub fn relative(&self) -> Smart<RelativeTo> {
         self.0.relative
@@ -0,0 +1,127 @@
+use crate::diag::bail;
+use crate::foundations::{array, cast, dict, Array, Dict, Resolve, StyleChain, Value};
+use crate::layout::{Abs, Axes, Corners, Length, Point, Radius, Rel, Size};
+use crate::utils::Numeric;
+use crate::visualize::{clamp_radius, styled_rect, Color, Shape};
+
+/// A drop shadow behind a box or block.
+///
+/// A shadow is specified as a dictionary with the following keys, all of
+/// which are optional:
+/// - `offset`: How far the shadow is shifted from its box. Either a length
+///   that applies to both axes or an array of two lengths. Defaults to
+///   `{0pt}`.
+/// - `blur`: The width of the shadow's soft edge. Defaults to `{0pt}`.
+/// - `color`: The color of the shadow. Defaults to a translucent black.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct Shadow<T: Numeric + 'static = Length> {
+    /// How far the shadow is shifted from its box.
+    pub offset: Axes<T>,
+    /// The width of the shadow's soft edge.
+    pub blur: T,
+    /// The shadow's color.
+    pub color: Color,
+}
+
+impl Shadow<Abs> {
+    /// The number of layers with which a blurred shadow is approximated.
+    const LAYERS: usize = 8;
+
+    /// Create the shapes of the shadow for a box of the given size and corner
+    /// radii, positioned relative to the box's top-left corner.
+    ///
+    /// Blur is approximated by stacking translucent rectangles that shrink
+    /// from the outer to the inner edge of the blurred area. Each layer is
+    /// just transparent enough for the stack to reach the shadow's full
+    /// opacity where all layers overlap.
+    pub fn shapes(
+        &self,
+        size: Size,
+        radius: &Corners<Radius<Rel<Abs>>>,
+    ) -> Vec<(Point, Shape)> {
+        let origin = self.offset.to_point();
+        if self.blur <= Abs::zero() {
+            return styled_rect(
+                size,
+                radius,
+                Some(self.color.into()),
+                &Default::default(),
+            )
+            .into_iter()
+            .map(|shape| (origin, shape))
+            .collect();
+        }
+
+        let alpha = self.color.alpha().unwrap_or(1.0);
+        let layer_alpha = 1.0 - (1.0 - alpha).powf(1.0 / Self::LAYERS as f32);
+        let color = self.color.with_alpha(layer_alpha);
+
+        // Resolve the radii like `styled_rect` does, so that they can grow
+        // and shrink with each layer.
+        let radius = clamp_radius(size, radius, Abs::zero());
+
+        let mut shapes = vec![];
+        for i in 0..Self::LAYERS {
+            // Grow from half the blur outside to half the blur inside.
+            let t = i as f64 / (Self::LAYERS - 1) as f64;
+            let grow = self.blur * (0.5 - t);
+            let layer = Size::new(size.x + 2.0 * grow, size.y + 2.0 * grow);
+            if layer.x <= Abs::zero() || layer.y <= Abs::zero() {
+                break;
+            }
+
+            let radius = radius.map(|r| {
+                Radius::new(r.x, r.y).map(|v| (v + grow).max(Abs::zero()).into())
+            });
+            let pos = origin - Point::splat(grow);
+            shapes.extend(
+                styled_rect(layer, &radius, Some(color.into()), &Default::default())
+                    .into_iter()
+                    .map(|shape| (pos, shape)),
+            );
+        }
+        shapes
+    }
+}
+
+impl Resolve for Shadow {
+    type Output = Shadow<Abs>;
+
+    fn resolve(self, styles: StyleChain) -> Self::Output {
+        Shadow {
+            offset: self.offset.resolve(styles),
+            blur: self.blur.resolve(styles),
+            color: self.color,
+        }
+    }
+}
+
+cast! {
+    Shadow,
+    self => dict! {
+        "offset" => array![self.offset.x, self.offset.y],
+        "blur" => self.blur,
+        "color" => self.color,
+    }.into_value(),
+    mut dict: Dict => {
+        let offset = match dict.take("offset").ok() {
+            None => Axes::splat(Length::zero()),
+            Some(Value::Length(offset)) => Axes::splat(offset),
+            Some(offset) => {
+                let mut iter = offset.cast::<Array>()?.into_iter();
+                match (iter.next(), iter.next(), iter.next()) {
+                    (Some(x), Some(y), None) => Axes::new(x.cast()?, y.cast()?),
+                    _ => bail!("offset array must contain exactly two entries"),
+                }
+            }
+        };
+        let blur = dict.take("blur").ok().map(Value::cast).transpose()?;
+        let color = dict.take("color").ok().map(Value::cast).transpose()?;
+        dict.finish(&["offset", "blur", "color"])?;
+        Self {
+            offset,
+            blur: blur.unwrap_or_default(),
+            color: color.unwrap_or(Color::BLACK.with_alpha(0.25)),
+        }
+    },
+}
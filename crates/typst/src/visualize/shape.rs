@@ -5,11 +5,11 @@ use crate::engine::Engine;
 use crate::foundations::{elem, Content, NativeElement, Packed, Show, Smart, StyleChain};
 use crate::introspection::Locator;
 use crate::layout::{
-    Abs, Axes, BlockElem, Corner, Corners, Frame, FrameItem, Length, Point, Ratio,
-    Region, Regions, Rel, Sides, Size,
+    Abs, Axes, BlockElem, Corner, Corners, Frame, FrameItem, Length, Point, Radius,
+    Ratio, Region, Regions, Rel, Sides, Size,
 };
 use crate::syntax::Span;
-use crate::utils::Get;
+use crate::utils::{Get, Numeric};
 use crate::visualize::{FixedStroke, Paint, Path, Stroke};
 
 /// A rectangle with optional content.
@@ -91,6 +91,15 @@ pub struct RectElem {
     ///   - `rest`: The radii for all corners except those for which the
     ///     dictionary explicitly sets a size.
     ///
+    /// Each radius can also be given as a dictionary with `x` and `y` keys to
+    /// round the corner elliptically, with `x` along the horizontal and `y`
+    /// along the vertical side. A corner whose radii exceed half of the
+    /// shape's sides is shrunk while keeping its shape.
+    ///
+    /// ```example
+    /// #rect(radius: (top-left: (x: 20pt, y: 10pt), rest: 4pt))
+    /// ```
+    ///
     /// ```example
     /// #set rect(stroke: 4pt)
     /// #rect(
@@ -109,7 +118,7 @@ pub struct RectElem {
     /// ```
     #[resolve]
     #[fold]
-    pub radius: Corners<Option<Rel<Length>>>,
+    pub radius: Corners<Option<Radius<Rel<Length>>>>,
 
     /// How much to pad the rectangle's content.
     /// See the [box's documentation]($box.outset) for more details.
@@ -218,7 +227,7 @@ pub struct SquareElem {
     /// [rectangle's documentation]($rect.radius) for more details.
     #[resolve]
     #[fold]
-    pub radius: Corners<Option<Rel<Length>>>,
+    pub radius: Corners<Option<Radius<Rel<Length>>>>,
 
     /// How much to pad the square's content. See the
     /// [box's documentation]($box.inset) for more details.
@@ -474,7 +483,7 @@ fn layout_shape(
     stroke: Smart<Sides<Option<Option<Stroke<Abs>>>>>,
     inset: Sides<Option<Rel<Abs>>>,
     outset: Sides<Option<Rel<Abs>>>,
-    radius: Corners<Option<Rel<Abs>>>,
+    radius: Corners<Option<Radius<Rel<Abs>>>>,
     span: Span,
 ) -> SourceResult<Frame> {
     let mut frame;
@@ -587,6 +596,20 @@ pub struct Shape {
     pub stroke: Option<FixedStroke>,
 }
 
+impl Shape {
+    /// Split a shape whose stroke cycles through multiple dash paints into
+    /// shapes that can be drawn one after another: First the fill, then one
+    /// stroke per paint. Returns `None` if the shape doesn't need splitting.
+    pub fn split_dash_paints(&self) -> Option<Vec<Shape>> {
+        let strokes = self.stroke.as_ref()?.split_dash_paints()?;
+        let fill = self.fill.clone().map(|fill| self.geometry.clone().filled(fill));
+        let strokes = strokes
+            .into_iter()
+            .map(|stroke| self.geometry.clone().stroked(stroke));
+        Some(fill.into_iter().chain(strokes).collect())
+    }
+}
+
 /// A shape's geometry.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Geometry {
@@ -647,17 +670,15 @@ pub(crate) fn ellipse(
 /// Creates a new rectangle as a path.
 pub(crate) fn clip_rect(
     size: Size,
-    radius: &Corners<Rel<Abs>>,
+    radius: &Corners<Radius<Rel<Abs>>>,
     stroke: &Sides<Option<FixedStroke>>,
 ) -> Path {
     let stroke_widths = stroke
         .as_ref()
         .map(|s| s.as_ref().map_or(Abs::zero(), |s| s.thickness / 2.0));
 
-    let max_radius = (size.x.min(size.y)) / 2.0
-        + stroke_widths.iter().cloned().min().unwrap_or(Abs::zero());
-
-    let radius = radius.map(|side| side.relative_to(max_radius * 2.0).min(max_radius));
+    let min_width = stroke_widths.iter().cloned().min().unwrap_or(Abs::zero());
+    let radius = clamp_radius(size, radius, min_width);
     let corners = corners_control_points(size, &radius, stroke, &stroke_widths);
 
     let mut path = Path::new();
@@ -666,13 +687,19 @@ pub(crate) fn clip_rect(
             corners.top_left.start_inner(),
             corners.top_left.center_inner(),
             corners.top_left.end_inner(),
+            corners.top_left.radii_inner(),
         );
     } else {
         path.move_to(corners.top_left.center_inner());
     }
     for corner in [&corners.top_right, &corners.bottom_right, &corners.bottom_left] {
         if corner.arc_inner() {
-            path.arc_line(corner.start_inner(), corner.center_inner(), corner.end_inner())
+            path.arc_line(
+                corner.start_inner(),
+                corner.center_inner(),
+                corner.end_inner(),
+                corner.radii_inner(),
+            )
         } else {
             path.line_to(corner.center_inner());
         }
@@ -687,11 +714,11 @@ pub(crate) fn clip_rect(
 /// - use fill for sides for best looks
 pub(crate) fn styled_rect(
     size: Size,
-    radius: &Corners<Rel<Abs>>,
+    radius: &Corners<Radius<Rel<Abs>>>,
     fill: Option<Paint>,
     stroke: &Sides<Option<FixedStroke>>,
 ) -> Vec<Shape> {
-    if stroke.is_uniform() && radius.iter().cloned().all(Rel::is_zero) {
+    if stroke.is_uniform() && radius.iter().all(|r| r.x.is_zero() && r.y.is_zero()) {
         simple_rect(size, fill, stroke.top.clone())
     } else {
         segmented_rect(size, radius, fill, stroke)
@@ -707,9 +734,32 @@ fn simple_rect(
     vec![Shape { geometry: Geometry::Rect(size), fill, stroke }]
 }
 
+/// Resolve the corner radii of a rectangle with the given size.
+///
+/// Relative radii are resolved against the rectangle's shorter side. A corner
+/// whose radii exceed half of the side they lie on, extended by the given
+/// stroke width, is shrunk while keeping its shape, so that the radii of two
+/// neighbouring corners never overlap.
+pub(crate) fn clamp_radius(
+    size: Size,
+    radius: &Corners<Radius<Rel<Abs>>>,
+    stroke_width: Abs,
+) -> Corners<Axes<Abs>> {
+    let max = size.map(|s| s / 2.0 + stroke_width);
+    radius.map(|r| {
+        let r = Axes::new(r.x, r.y)
+            .map(|v| v.relative_to(max.min_by_side() * 2.0).max(Abs::zero()));
+        if r.x.is_zero() || r.y.is_zero() {
+            return Axes::splat(Abs::zero());
+        }
+        let scale = (max.x / r.x).min(max.y / r.y).min(1.0);
+        r.map(|v| v * scale)
+    })
+}
+
 fn corners_control_points(
     size: Size,
-    radius: &Corners<Abs>,
+    radius: &Corners<Axes<Abs>>,
     strokes: &Sides<Option<FixedStroke>>,
     stroke_widths: &Sides<Abs>,
 ) -> Corners<ControlPoints> {
@@ -739,7 +789,7 @@ fn corners_control_points(
 /// Use stroke and fill for the rectangle
 fn segmented_rect(
     size: Size,
-    radius: &Corners<Rel<Abs>>,
+    radius: &Corners<Radius<Rel<Abs>>>,
     fill: Option<Paint>,
     strokes: &Sides<Option<FixedStroke>>,
 ) -> Vec<Shape> {
@@ -748,10 +798,8 @@ fn segmented_rect(
         .as_ref()
         .map(|s| s.as_ref().map_or(Abs::zero(), |s| s.thickness / 2.0));
 
-    let max_radius = (size.x.min(size.y)) / 2.0
-        + stroke_widths.iter().cloned().min().unwrap_or(Abs::zero());
-
-    let radius = radius.map(|side| side.relative_to(max_radius * 2.0).min(max_radius));
+    let min_width = stroke_widths.iter().cloned().min().unwrap_or(Abs::zero());
+    let radius = clamp_radius(size, radius, min_width);
     let corners = corners_control_points(size, &radius, strokes, &stroke_widths);
 
     // insert stroked sides below filled sides
@@ -762,7 +810,7 @@ fn segmented_rect(
         let mut path = Path::new();
         let c = corners.get_ref(Corner::TopLeft);
         if c.arc() {
-            path.arc_move(c.start(), c.center(), c.end());
+            path.arc_move(c.start(), c.center(), c.end(), c.radii());
         } else {
             path.move_to(c.center());
         };
@@ -770,7 +818,7 @@ fn segmented_rect(
         for corner in [Corner::TopRight, Corner::BottomRight, Corner::BottomLeft] {
             let c = corners.get_ref(corner);
             if c.arc() {
-                path.arc_line(c.start(), c.center(), c.end());
+                path.arc_line(c.start(), c.center(), c.end(), c.radii());
             } else {
                 path.line_to(c.center());
             }
@@ -826,7 +874,7 @@ fn path_segment(
     if start == end || !c.arc() {
         path.move_to(c.end());
     } else {
-        path.arc_move(c.mid(), c.center(), c.end());
+        path.arc_move(c.mid(), c.center(), c.end(), c.radii());
     }
 
     // create corners between start and end
@@ -834,7 +882,7 @@ fn path_segment(
     while current != end {
         let c = corners.get_ref(current);
         if c.arc() {
-            path.arc_line(c.start(), c.center(), c.end());
+            path.arc_line(c.start(), c.center(), c.end(), c.radii());
         } else {
             path.line_to(c.end());
         }
@@ -846,9 +894,9 @@ fn path_segment(
     if !c.arc() {
         path.line_to(c.start());
     } else if start == end {
-        path.arc_line(c.start(), c.center(), c.end());
+        path.arc_line(c.start(), c.center(), c.end(), c.radii());
     } else {
-        path.arc_line(c.start(), c.center(), c.mid());
+        path.arc_line(c.start(), c.center(), c.mid(), c.radii());
     }
 }
 
@@ -861,7 +909,7 @@ fn segment(
 ) -> (Shape, bool) {
     fn fill_corner(corner: &ControlPoints) -> bool {
         corner.stroke_before != corner.stroke_after
-            || corner.radius() < corner.stroke_before
+            || corner.radius().min_by_side() < corner.stroke_before
     }
 
     fn fill_corners(
@@ -940,13 +988,23 @@ fn fill_segment(
         let c = corners.get_ref(start);
 
         if c.arc_inner() {
-            path.arc_move(c.end_inner(), c.center_inner(), c.mid_inner());
+            path.arc_move(
+                c.end_inner(),
+                c.center_inner(),
+                c.mid_inner(),
+                c.radii_inner(),
+            );
         } else {
             path.move_to(c.end_inner());
         }
 
         if c.arc_outer() {
-            path.arc_line(c.mid_outer(), c.center_outer(), c.end_outer());
+            path.arc_line(
+                c.mid_outer(),
+                c.center_outer(),
+                c.end_outer(),
+                c.radii_outer(),
+            );
         } else {
             path.line_to(c.outer());
             path.line_to(c.end_outer());
@@ -958,7 +1016,12 @@ fn fill_segment(
     while current != end {
         let c = corners.get_ref(current);
         if c.arc_outer() {
-            path.arc_line(c.start_outer(), c.center_outer(), c.end_outer());
+            path.arc_line(
+                c.start_outer(),
+                c.center_outer(),
+                c.end_outer(),
+                c.radii_outer(),
+            );
         } else {
             path.line_to(c.outer());
         }
@@ -972,25 +1035,45 @@ fn fill_segment(
     if start == end {
         let c = corners.get_ref(end);
         if c.arc_outer() {
-            path.arc_line(c.start_outer(), c.center_outer(), c.end_outer());
+            path.arc_line(
+                c.start_outer(),
+                c.center_outer(),
+                c.end_outer(),
+                c.radii_outer(),
+            );
         } else {
             path.line_to(c.outer());
             path.line_to(c.end_outer());
         }
         if c.arc_inner() {
-            path.arc_line(c.end_inner(), c.center_inner(), c.start_inner());
+            path.arc_line(
+                c.end_inner(),
+                c.center_inner(),
+                c.start_inner(),
+                c.radii_inner(),
+            );
         } else {
             path.line_to(c.center_inner());
         }
     } else {
         let c = corners.get_ref(end);
         if c.arc_outer() {
-            path.arc_line(c.start_outer(), c.center_outer(), c.mid_outer());
+            path.arc_line(
+                c.start_outer(),
+                c.center_outer(),
+                c.mid_outer(),
+                c.radii_outer(),
+            );
         } else {
             path.line_to(c.outer());
         }
         if c.arc_inner() {
-            path.arc_line(c.mid_inner(), c.center_inner(), c.start_inner());
+            path.arc_line(
+                c.mid_inner(),
+                c.center_inner(),
+                c.start_inner(),
+                c.radii_inner(),
+            );
         } else {
             path.line_to(c.center_inner());
         }
@@ -1001,7 +1084,12 @@ fn fill_segment(
     while current != start {
         let c = corners.get_ref(current);
         if c.arc_inner() {
-            path.arc_line(c.end_inner(), c.center_inner(), c.start_inner());
+            path.arc_line(
+                c.end_inner(),
+                c.center_inner(),
+                c.start_inner(),
+                c.radii_inner(),
+            );
         } else {
             path.line_to(c.center_inner());
         }
@@ -1037,7 +1125,7 @@ fn fill_segment(
 ///    |-------r--------|
 /// ```
 struct ControlPoints {
-    radius: Abs,
+    radius: Axes<Abs>,
     stroke_after: Abs,
     stroke_before: Abs,
     corner: Corner,
@@ -1058,6 +1146,14 @@ impl ControlPoints {
         }
     }
 
+    /// Rotate radii from top-left to the required corner and vice versa.
+    fn rotate_radius(&self, radius: Axes<Abs>) -> Axes<Abs> {
+        match self.corner {
+            Corner::TopLeft | Corner::BottomRight => radius,
+            Corner::TopRight | Corner::BottomLeft => Axes::new(radius.y, radius.x),
+        }
+    }
+
     /// Outside intersection of the sides.
     pub fn outer(&self) -> Point {
         self.rotate(Point { x: -self.stroke_before, y: -self.stroke_after })
@@ -1067,15 +1163,15 @@ impl ControlPoints {
     pub fn center_outer(&self) -> Point {
         let r = self.radius_outer();
         self.rotate(Point {
-            x: r - self.stroke_before,
-            y: r - self.stroke_after,
+            x: r.x - self.stroke_before,
+            y: r.y - self.stroke_after,
         })
     }
 
     /// Center for the middle arc.
     pub fn center(&self) -> Point {
         let r = self.radius();
-        self.rotate(Point { x: r, y: r })
+        self.rotate(Point { x: r.x, y: r.y })
     }
 
     /// Center for the inner arc.
@@ -1083,24 +1179,39 @@ impl ControlPoints {
         let r = self.radius_inner();
 
         self.rotate(Point {
-            x: self.stroke_before + r,
-            y: self.stroke_after + r,
+            x: self.stroke_before + r.x,
+            y: self.stroke_after + r.y,
         })
     }
 
-    /// Radius of the outer arc.
-    pub fn radius_outer(&self) -> Abs {
+    /// Radii of the outer arc, as seen from the top-left corner.
+    pub fn radius_outer(&self) -> Axes<Abs> {
+        self.rotate_radius(self.radius)
+    }
+
+    /// Radii of the middle arc, as seen from the top-left corner.
+    pub fn radius(&self) -> Axes<Abs> {
+        shrink(self.radius_outer(), self.stroke_before.min(self.stroke_after))
+    }
+
+    /// Radii of the inner arc, as seen from the top-left corner.
+    pub fn radius_inner(&self) -> Axes<Abs> {
+        shrink(self.radius_outer(), 2.0 * self.stroke_before.max(self.stroke_after))
+    }
+
+    /// Radii of the outer arc along the page's axes.
+    pub fn radii_outer(&self) -> Axes<Abs> {
         self.radius
     }
 
-    /// Radius of the middle arc.
-    pub fn radius(&self) -> Abs {
-        (self.radius - self.stroke_before.min(self.stroke_after)).max(Abs::zero())
+    /// Radii of the middle arc along the page's axes.
+    pub fn radii(&self) -> Axes<Abs> {
+        self.rotate_radius(self.radius())
     }
 
-    /// Radius of the inner arc.
-    pub fn radius_inner(&self) -> Abs {
-        (self.radius - 2.0 * self.stroke_before.max(self.stroke_after)).max(Abs::zero())
+    /// Radii of the inner arc along the page's axes.
+    pub fn radii_inner(&self) -> Axes<Abs> {
+        self.rotate_radius(self.radius_inner())
     }
 
     /// Middle of the corner on the outside of the stroke.
@@ -1108,131 +1219,159 @@ impl ControlPoints {
         let c_i = self.center_inner();
         let c_o = self.center_outer();
         let o = self.outer();
-        let r = self.radius_outer();
+        let r = self.radii_outer();
 
         // https://math.stackexchange.com/a/311956
-        // intersection between the line from inner center to outside and the outer arc
-        let a = (o.x - c_i.x).to_raw().powi(2) + (o.y - c_i.y).to_raw().powi(2);
-        let b = 2.0 * (o.x - c_i.x).to_raw() * (c_i.x - c_o.x).to_raw()
-            + 2.0 * (o.y - c_i.y).to_raw() * (c_i.y - c_o.y).to_raw();
-        let c = (c_i.x - c_o.x).to_raw().powi(2) + (c_i.y - c_o.y).to_raw().powi(2)
-            - r.to_raw().powi(2);
+        // intersection between the line from inner center to outside and the
+        // outer arc, after scaling the arc to a unit circle
+        let unit = |p: Point| ((p.x - c_o.x) / r.x, (p.y - c_o.y) / r.y);
+        let (i_x, i_y) = unit(c_i);
+        let (o_x, o_y) = unit(o);
+        let a = (o_x - i_x).powi(2) + (o_y - i_y).powi(2);
+        let b = 2.0 * (o_x - i_x) * i_x + 2.0 * (o_y - i_y) * i_y;
+        let c = i_x.powi(2) + i_y.powi(2) - 1.0;
         let t = (-b + (b * b - 4.0 * a * c).max(0.0).sqrt()) / (2.0 * a);
         c_i + t * (o - c_i)
     }
 
     /// Middle of the corner in the middle of the stroke.
     pub fn mid(&self) -> Point {
-        let center = self.center_outer();
-        let outer = self.outer();
-        let diff = outer - center;
-        center + diff / diff.hypot().to_raw() * self.radius().to_raw()
+        ellipse_point(self.center_outer(), self.radii(), self.outer())
     }
 
     /// Middle of the corner on the inside of the stroke.
     pub fn mid_inner(&self) -> Point {
-        let center = self.center_inner();
-        let outer = self.outer();
-        let diff = outer - center;
-        center + diff / diff.hypot().to_raw() * self.radius_inner().to_raw()
+        ellipse_point(self.center_inner(), self.radii_inner(), self.outer())
     }
 
     /// If an outer arc is required.
     pub fn arc_outer(&self) -> bool {
-        self.radius_outer() > Abs::zero()
+        self.radius_outer().all(|r| *r > Abs::zero())
     }
 
     pub fn arc(&self) -> bool {
-        self.radius() > Abs::zero()
+        self.radius().all(|r| *r > Abs::zero())
     }
 
     /// If an inner arc is required.
     pub fn arc_inner(&self) -> bool {
-        self.radius_inner() > Abs::zero()
+        self.radius_inner().all(|r| *r > Abs::zero())
     }
 
     /// Start of the corner on the outside of the stroke.
     pub fn start_outer(&self) -> Point {
         self.rotate(Point {
             x: -self.stroke_before,
-            y: self.radius_outer() - self.stroke_after,
+            y: self.radius_outer().y - self.stroke_after,
         })
     }
 
     /// Start of the corner in the center of the stroke.
     pub fn start(&self) -> Point {
-        self.rotate(Point::with_y(self.radius()))
+        self.rotate(Point::with_y(self.radius().y))
     }
 
     /// Start of the corner on the inside of the stroke.
     pub fn start_inner(&self) -> Point {
         self.rotate(Point {
             x: self.stroke_before,
-            y: self.stroke_after + self.radius_inner(),
+            y: self.stroke_after + self.radius_inner().y,
         })
     }
 
     /// End of the corner on the outside of the stroke.
     pub fn end_outer(&self) -> Point {
         self.rotate(Point {
-            x: self.radius_outer() - self.stroke_before,
+            x: self.radius_outer().x - self.stroke_before,
             y: -self.stroke_after,
         })
     }
 
     /// End of the corner in the center of the stroke.
     pub fn end(&self) -> Point {
-        self.rotate(Point::with_x(self.radius()))
+        self.rotate(Point::with_x(self.radius().x))
     }
 
     /// End of the corner on the inside of the stroke.
     pub fn end_inner(&self) -> Point {
         self.rotate(Point {
-            x: self.stroke_before + self.radius_inner(),
+            x: self.stroke_before + self.radius_inner().x,
             y: self.stroke_after,
         })
     }
 }
 
+/// Shrink both radii of an arc by the same amount. The arc degenerates into a
+/// sharp corner as soon as one of them vanishes.
+fn shrink(radius: Axes<Abs>, amount: Abs) -> Axes<Abs> {
+    let radius = radius.map(|r| r - amount);
+    if radius.all(|r| *r > Abs::zero()) {
+        radius
+    } else {
+        Axes::splat(Abs::zero())
+    }
+}
+
+/// The point on an axis-aligned ellipse that lies in the direction of
+/// `toward` as seen from the ellipse's center.
+fn ellipse_point(center: Point, radius: Axes<Abs>, toward: Point) -> Point {
+    if !radius.all(|r| *r > Abs::zero()) {
+        return center;
+    }
+
+    let diff = toward - center;
+    let (x, y) = (diff.x / radius.x, diff.y / radius.y);
+    let length = x.hypot(y);
+    center + Point::new(radius.x * (x / length), radius.y * (y / length))
+}
+
 /// Helper to draw arcs with bezier curves.
 trait PathExt {
-    fn arc(&mut self, start: Point, center: Point, end: Point);
-    fn arc_move(&mut self, start: Point, center: Point, end: Point);
-    fn arc_line(&mut self, start: Point, center: Point, end: Point);
+    fn arc(&mut self, start: Point, center: Point, end: Point, radius: Axes<Abs>);
+    fn arc_move(&mut self, start: Point, center: Point, end: Point, radius: Axes<Abs>);
+    fn arc_line(&mut self, start: Point, center: Point, end: Point, radius: Axes<Abs>);
 }
 
 impl PathExt for Path {
-    fn arc(&mut self, start: Point, center: Point, end: Point) {
-        let arc = bezier_arc_control(start, center, end);
+    fn arc(&mut self, start: Point, center: Point, end: Point, radius: Axes<Abs>) {
+        let arc = bezier_arc_control(start, center, end, radius);
         self.cubic_to(arc[0], arc[1], end);
     }
 
-    fn arc_move(&mut self, start: Point, center: Point, end: Point) {
+    fn arc_move(&mut self, start: Point, center: Point, end: Point, radius: Axes<Abs>) {
         self.move_to(start);
-        self.arc(start, center, end);
+        self.arc(start, center, end, radius);
     }
 
-    fn arc_line(&mut self, start: Point, center: Point, end: Point) {
+    fn arc_line(&mut self, start: Point, center: Point, end: Point, radius: Axes<Abs>) {
         self.line_to(start);
-        self.arc(start, center, end);
+        self.arc(start, center, end, radius);
     }
 }
 
-/// Get the control points for a bezier curve that approximates a circular arc for
-/// a start point, an end point and a center of the circle whose arc connects
-/// the two.
-fn bezier_arc_control(start: Point, center: Point, end: Point) -> [Point; 2] {
+/// Get the control points for a bezier curve that approximates an elliptical
+/// arc for a start point, an end point and the center and radii of the
+/// axis-aligned ellipse whose arc connects the two.
+fn bezier_arc_control(
+    start: Point,
+    center: Point,
+    end: Point,
+    radius: Axes<Abs>,
+) -> [Point; 2] {
+    // Approximate the arc on the unit circle and scale the control points back
+    // to the ellipse.
     // https://stackoverflow.com/a/44829356/1567835
-    let a = start - center;
-    let b = end - center;
+    let a = ((start.x - center.x) / radius.x, (start.y - center.y) / radius.y);
+    let b = ((end.x - center.x) / radius.x, (end.y - center.y) / radius.y);
 
-    let q1 = a.x.to_raw() * a.x.to_raw() + a.y.to_raw() * a.y.to_raw();
-    let q2 = q1 + a.x.to_raw() * b.x.to_raw() + a.y.to_raw() * b.y.to_raw();
-    let k2 = (4.0 / 3.0) * ((2.0 * q1 * q2).sqrt() - q2)
-        / (a.x.to_raw() * b.y.to_raw() - a.y.to_raw() * b.x.to_raw());
+    let q1 = a.0 * a.0 + a.1 * a.1;
+    let q2 = q1 + a.0 * b.0 + a.1 * b.1;
+    let k2 = (4.0 / 3.0) * ((2.0 * q1 * q2).sqrt() - q2) / (a.0 * b.1 - a.1 * b.0);
 
-    let control_1 = Point::new(center.x + a.x - k2 * a.y, center.y + a.y + k2 * a.x);
-    let control_2 = Point::new(center.x + b.x + k2 * b.y, center.y + b.y - k2 * b.x);
+    let scale =
+        |x: f64, y: f64| Point::new(center.x + radius.x * x, center.y + radius.y * y);
+    let control_1 = scale(a.0 - k2 * a.1, a.1 + k2 * a.0);
+    let control_2 = scale(b.0 + k2 * b.1, b.1 - k2 * b.0);
 
     [control_1, control_2]
 }
@@ -1,9 +1,9 @@
 use ecow::EcoString;
 
-use crate::diag::{HintedStrResult, SourceResult};
+use crate::diag::{bail, HintedStrResult, SourceResult};
 use crate::foundations::{
-    cast, dict, func, scope, ty, Args, Cast, Dict, Fold, FromValue, NoneValue, Repr,
-    Resolve, Smart, StyleChain, Value,
+    cast, dict, func, repr, scope, ty, Args, Cast, Dict, Fold, FromValue, NoneValue,
+    Repr, Resolve, Smart, StyleChain, Value,
 };
 use crate::layout::{Abs, Length};
 use crate::utils::{Numeric, Scalar};
@@ -23,6 +23,7 @@ use crate::visualize::{Color, Gradient, Paint, Pattern};
 ///   line(stroke: 2pt + red),
 ///   line(stroke: (paint: blue, thickness: 4pt, cap: "round")),
 ///   line(stroke: (paint: blue, thickness: 1pt, dash: "dashed")),
+///   line(stroke: (paint: (red, blue), thickness: 1pt, dash: "dashed")),
 ///   line(stroke: 2pt + gradient.linear(..color.map.rainbow)),
 /// )
 /// ```
@@ -53,6 +54,9 @@ use crate::visualize::{Color, Gradient, Paint, Pattern};
 pub struct Stroke<T: Numeric = Length> {
     /// The stroke's paint.
     pub paint: Smart<Paint>,
+    /// The paints cycled across the dashes of the dash pattern. Only
+    /// meaningful if `paint` is set. If empty, all dashes use `paint`.
+    pub(crate) dash_paints: Vec<Paint>,
     /// The stroke's thickness.
     pub thickness: Smart<T>,
     /// The stroke's line cap.
@@ -103,7 +107,22 @@ impl Stroke {
 
         /// The color or gradient to use for the stroke.
         ///
+        /// If an [array] of paints is given, the dashes of the
+        /// [`dash`]($stroke.dash) pattern cycle through them. Without a dash
+        /// pattern, only the first paint is used.
+        ///
         /// If set to `{auto}`, the value is inherited, defaulting to `{black}`.
+        ///
+        /// ```example
+        /// #line(
+        ///   length: 100%,
+        ///   stroke: (
+        ///     paint: (red, blue, green),
+        ///     thickness: 2pt,
+        ///     dash: "dashed",
+        ///   ),
+        /// )
+        /// ```
         #[external]
         paint: Smart<Paint>,
 
@@ -191,18 +210,40 @@ impl Stroke {
             Ok(args.named::<Smart<T>>(arg)?.unwrap_or(Smart::Auto))
         }
 
-        let paint = take::<Paint>(args, "paint")?;
+        let (paint, dash_paints) = StrokePaint::split(take(args, "paint")?);
         let thickness = take::<Length>(args, "thickness")?;
         let cap = take::<LineCap>(args, "cap")?;
         let join = take::<LineJoin>(args, "join")?;
         let dash = take::<Option<DashPattern>>(args, "dash")?;
         let miter_limit = take::<f64>(args, "miter-limit")?.map(Scalar::new);
 
-        Ok(Self { paint, thickness, cap, join, dash, miter_limit })
+        Ok(Self {
+            paint,
+            dash_paints,
+            thickness,
+            cap,
+            join,
+            dash,
+            miter_limit,
+        })
     }
 }
 
 impl<T: Numeric> Stroke<T> {
+    /// All of the stroke's paints.
+    ///
+    /// If multiple paints were given, the dashes of the dash pattern cycle
+    /// through them and `paint` is the first one.
+    pub fn paints(&self) -> Smart<&[Paint]> {
+        self.paint.as_ref().map(|paint| {
+            if self.dash_paints.is_empty() {
+                std::slice::from_ref(paint)
+            } else {
+                self.dash_paints.as_slice()
+            }
+        })
+    }
+
     /// Map the contained lengths with `f`.
     pub fn map<F, U: Numeric>(self, f: F) -> Stroke<U>
     where
@@ -210,6 +251,7 @@ impl<T: Numeric> Stroke<T> {
     {
         Stroke {
             paint: self.paint,
+            dash_paints: self.dash_paints,
             thickness: self.thickness.map(&f),
             cap: self.cap,
             join: self.join,
@@ -249,8 +291,12 @@ impl Stroke<Abs> {
             })
             .unwrap_or(default.dash);
 
+        let dash_paints =
+            if self.paint.is_custom() { self.dash_paints } else { default.dash_paints };
+
         FixedStroke {
             paint: self.paint.unwrap_or(default.paint),
+            dash_paints,
             thickness,
             cap: self.cap.unwrap_or(default.cap),
             join: self.join.unwrap_or(default.join),
@@ -270,8 +316,21 @@ impl Stroke<Abs> {
 impl<T: Numeric + Repr> Repr for Stroke<T> {
     fn repr(&self) -> EcoString {
         let mut r = EcoString::new();
-        let Self { paint, thickness, cap, join, dash, miter_limit } = &self;
-        if cap.is_auto() && join.is_auto() && dash.is_auto() && miter_limit.is_auto() {
+        let Self {
+            paint,
+            dash_paints,
+            thickness,
+            cap,
+            join,
+            dash,
+            miter_limit,
+        } = &self;
+        if dash_paints.is_empty()
+            && cap.is_auto()
+            && join.is_auto()
+            && dash.is_auto()
+            && miter_limit.is_auto()
+        {
             match (&self.paint, &self.thickness) {
                 (Smart::Custom(paint), Smart::Custom(thickness)) => {
                     r.push_str(&thickness.repr());
@@ -288,7 +347,12 @@ impl<T: Numeric + Repr> Repr for Stroke<T> {
             if let Smart::Custom(paint) = &paint {
                 r.push_str(sep);
                 r.push_str("paint: ");
-                r.push_str(&paint.repr());
+                if dash_paints.is_empty() {
+                    r.push_str(&paint.repr());
+                } else {
+                    let parts: Vec<_> = dash_paints.iter().map(Repr::repr).collect();
+                    r.push_str(&repr::pretty_array_like(&parts, false));
+                }
                 sep = ", ";
             }
             if let Smart::Custom(thickness) = &thickness {
@@ -332,8 +396,13 @@ impl<T: Numeric + Repr> Repr for Stroke<T> {
 
 impl<T: Numeric + Fold> Fold for Stroke<T> {
     fn fold(self, outer: Self) -> Self {
+        // The dash paints belong to the paint they were specified with.
+        let dash_paints =
+            if self.paint.is_custom() { self.dash_paints } else { outer.dash_paints };
+
         Self {
             paint: self.paint.or(outer.paint),
+            dash_paints,
             thickness: self.thickness.or(outer.thickness),
             cap: self.cap.or(outer.cap),
             join: self.join.or(outer.join),
@@ -349,6 +418,7 @@ impl Resolve for Stroke {
     fn resolve(self, styles: StyleChain) -> Self::Output {
         Stroke {
             paint: self.paint,
+            dash_paints: self.dash_paints,
             thickness: self.thickness.resolve(styles),
             cap: self.cap,
             join: self.join,
@@ -383,7 +453,7 @@ cast! {
                 .transpose()?.unwrap_or(Smart::Auto))
         }
 
-        let paint = take::<Paint>(&mut dict, "paint")?;
+        let (paint, dash_paints) = StrokePaint::split(take(&mut dict, "paint")?);
         let thickness = take::<Length>(&mut dict, "thickness")?;
         let cap = take::<LineCap>(&mut dict, "cap")?;
         let join = take::<LineJoin>(&mut dict, "join")?;
@@ -393,6 +463,7 @@ cast! {
 
         Self {
             paint,
+            dash_paints,
             thickness,
             cap,
             join,
@@ -407,6 +478,37 @@ cast! {
     self => self.map(Length::from).into_value(),
 }
 
+/// The paint of a stroke: Either a single paint or an array of paints that is
+/// cycled across the dashes.
+struct StrokePaint(Vec<Paint>);
+
+impl StrokePaint {
+    /// Split into the primary paint and the dash paints.
+    fn split(this: Smart<Self>) -> (Smart<Paint>, Vec<Paint>) {
+        match this {
+            Smart::Auto => (Smart::Auto, Vec::new()),
+            Smart::Custom(Self(mut paints)) => {
+                let paint = paints[0].clone();
+                if paints.len() == 1 {
+                    paints.clear();
+                }
+                (Smart::Custom(paint), paints)
+            }
+        }
+    }
+}
+
+cast! {
+    StrokePaint,
+    paint: Paint => Self(vec![paint]),
+    paints: Vec<Paint> => {
+        if paints.is_empty() {
+            bail!("array of paints must not be empty");
+        }
+        Self(paints)
+    },
+}
+
 /// The line cap of a stroke
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum LineCap {
@@ -580,6 +682,9 @@ cast! {
 pub struct FixedStroke {
     /// The stroke's paint.
     pub paint: Paint,
+    /// The paints cycled across the dashes of the dash pattern. If empty, all
+    /// dashes use `paint`.
+    pub(crate) dash_paints: Vec<Paint>,
     /// The stroke's thickness.
     pub thickness: Abs,
     /// The stroke's line cap.
@@ -601,12 +706,99 @@ impl FixedStroke {
             ..Default::default()
         }
     }
+
+    /// All of the stroke's paints.
+    ///
+    /// If multiple paints were given, the dashes of the dash pattern cycle
+    /// through them and `paint` is the first one.
+    pub fn paints(&self) -> &[Paint] {
+        if self.dash_paints.is_empty() {
+            std::slice::from_ref(&self.paint)
+        } else {
+            &self.dash_paints
+        }
+    }
+
+    /// Split a stroke with multiple dash paints into one stroke per paint.
+    ///
+    /// Each of the resulting strokes only draws the dashes of its own paint
+    /// and turns the other dashes into gaps, so that drawing all of them on
+    /// top of each other alternates the paint from dash to dash. Returns
+    /// `None` if the stroke doesn't need to be split.
+    pub fn split_dash_paints(&self) -> Option<Vec<FixedStroke>> {
+        let n = self.dash_paints.len();
+        if n < 2 {
+            return None;
+        }
+
+        let pattern = self.dash.as_ref().filter(|pattern| !pattern.array.is_empty())?;
+
+        // An odd dash array is repeated to alternate between dashes and gaps.
+        let mut array = pattern.array.clone();
+        if array.len() % 2 == 1 {
+            array.extend_from_within(..);
+        }
+
+        let period: Abs = array.iter().sum();
+        if period <= Abs::zero() {
+            return None;
+        }
+
+        // After `n` repetitions of the array, the paints line up again.
+        let dashes = array.len() / 2;
+        let total = period * n as f64;
+        let strokes = self
+            .dash_paints
+            .iter()
+            .enumerate()
+            .map(|(i, paint)| {
+                let mut own = vec![];
+                let mut start = None;
+                let mut leading = Abs::zero();
+                let mut pos = Abs::zero();
+                for k in 0..n * dashes {
+                    let (dash, gap) =
+                        (array[2 * (k % dashes)], array[2 * (k % dashes) + 1]);
+                    if k % n == i {
+                        start.get_or_insert(pos);
+                        own.extend([dash, gap]);
+                    } else if let Some(last) = own.last_mut() {
+                        *last += dash + gap;
+                    } else {
+                        leading += dash + gap;
+                    }
+                    pos += dash + gap;
+                }
+
+                // The array starts with the first own dash, so the gaps before
+                // it wrap around to the end and the phase is shifted.
+                if let Some(last) = own.last_mut() {
+                    *last += leading;
+                }
+
+                let mut phase = (pattern.phase - start.unwrap_or_default()) % total;
+                if phase < Abs::zero() {
+                    phase += total;
+                }
+
+                FixedStroke {
+                    paint: paint.clone(),
+                    dash_paints: vec![],
+                    dash: Some(DashPattern { array: own, phase }),
+                    ..self.clone()
+                }
+            })
+            .collect();
+
+        Some(strokes)
+    }
 }
 
 impl Default for FixedStroke {
     fn default() -> Self {
         Self {
             paint: Paint::Solid(Color::BLACK),
+            dash_paints: vec![],
             thickness: Abs::pt(1.0),
             cap: LineCap::Butt,
             join: LineJoin::Miter,
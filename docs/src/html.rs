@@ -259,11 +259,9 @@ impl<'a> Handler<'a> {
             }
 
             // Example with preview.
-            md::Event::Text(text) => {
-                if self.lang.is_some() {
-                    self.code.push_str(text);
-                    return false;
-                }
+            md::Event::Text(text) if self.lang.is_some() => {
+                self.code.push_str(text);
+                return false;
             }
 
             _ => {}
@@ -151,13 +151,14 @@ impl Collector {
         }
     }
 
-    /// Walks through all reference images and ensure that a test exists for
-    /// each one.
+    /// Walks through all reference images and HTML files and ensure that a
+    /// test exists for each one.
     fn walk_references(&mut self) {
         for entry in walkdir::WalkDir::new(crate::REF_PATH).sort_by_file_name() {
             let entry = entry.unwrap();
             let path = entry.path();
-            if !path.extension().is_some_and(|ext| ext == "png") {
+            let Some(ext) = path.extension() else { continue };
+            if ext != "png" && ext != "html" {
                 continue;
             }
 
@@ -167,11 +168,19 @@ impl Collector {
             let Some(pos) = self.seen.get(name) else {
                 self.errors.push(TestParseError {
                     pos: FilePos::new(path, 0),
-                    message: "dangling reference image".into(),
+                    message: if ext == "png" {
+                        "dangling reference image".into()
+                    } else {
+                        "dangling reference HTML".into()
+                    },
                 });
                 continue;
             };
 
+            if ext == "html" {
+                continue;
+            }
+
             let len = path.metadata().unwrap().len() as usize;
             if !self.large.contains(name) && len > crate::REF_LIMIT {
                 self.errors.push(TestParseError {
@@ -0,0 +1,555 @@
+//! Tests for properties of exported files that reference images can't show.
+
+mod world;
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use base64::Engine;
+use typst::diag::SourceResult;
+use typst::foundations::Smart;
+use typst::layout::{Frame, FrameItem, GroupItem};
+use typst::model::Document;
+use typst::syntax::Source;
+use typst::text::{Font, TextItem};
+use typst_pdf::{PdfExportOptions, PdfStandard};
+use typst_svg::{SvgExportOptions, SvgFonts};
+
+use self::world::TestWorld;
+
+/// Compile a document from markup.
+fn compile(text: &str) -> Document {
+    let world = TestWorld::new(Source::detached(text));
+    typst::compile(&world).output.expect("document should compile")
+}
+
+/// Stream the pages of a document and compare them with the compiled ones.
+fn check_streamed(text: &str) -> usize {
+    let world = TestWorld::new(Source::detached(text));
+    let document = typst::compile(&world).output.expect("document should compile");
+
+    let mut hashes = vec![];
+    let count = typst::compile_pages(&world, |page| {
+        hashes.push(typst::utils::hash128(&page.frame));
+    })
+    .output
+    .expect("pages should compile");
+
+    let expected: Vec<_> = document
+        .pages
+        .iter()
+        .map(|page| typst::utils::hash128(&page.frame))
+        .collect();
+    assert_eq!(count, expected.len());
+    assert_eq!(hashes, expected);
+    count
+}
+
+/// Export a document as a PDF conforming to the given standard.
+fn pdf_with(text: &str, standard: PdfStandard) -> SourceResult<Vec<u8>> {
+    let options = PdfExportOptions { standard };
+    typst_pdf::pdf_with(&compile(text), Smart::Auto, None, None, &options)
+}
+
+/// Export a document as a plain PDF.
+fn pdf(text: &str) -> Vec<u8> {
+    pdf_with(text, PdfStandard::V_1_7).expect("document should export")
+}
+
+/// Export the first page of a document as an SVG with the given fonts.
+fn svg(text: &str, fonts: SvgFonts) -> (String, Font) {
+    let document = compile(text);
+    let frame = &document.pages[0].frame;
+    let font = first_font(frame).expect("page should contain text");
+    (typst_svg::svg_with(frame, &SvgExportOptions { fonts }), font)
+}
+
+/// The font of the first text run in a frame.
+fn first_font(frame: &Frame) -> Option<Font> {
+    frame.items().find_map(|(_, item)| match item {
+        FrameItem::Group(group) => first_font(&group.frame),
+        FrameItem::Text(text) => Some(text.font.clone()),
+        _ => None,
+    })
+}
+
+/// Whether the bytes contain the given string.
+fn contains(data: &[u8], needle: &str) -> bool {
+    data.windows(needle.len()).any(|window| window == needle.as_bytes())
+}
+
+/// The decompressed streams of a PDF file, joined together.
+fn streams(data: &[u8]) -> String {
+    let mut out = String::new();
+    let mut rest = data;
+    while let Some(start) = rest.windows(7).position(|w| w == b"stream\n") {
+        rest = &rest[start + 7..];
+        let end = rest.windows(10).position(|w| w == b"\nendstream").unwrap();
+        if let Ok(inflated) = miniz_oxide::inflate::decompress_to_vec_zlib(&rest[..end]) {
+            out.push_str(&String::from_utf8_lossy(&inflated));
+            out.push('\n');
+        }
+        rest = &rest[end + 10..];
+    }
+    out
+}
+
+/// The dictionaries of a PDF file's indirect objects, by object number.
+fn objects(data: &[u8]) -> HashMap<usize, String> {
+    let text = String::from_utf8_lossy(data);
+    let mut objects = HashMap::new();
+    for chunk in text.split("endobj") {
+        let Some(start) = chunk.rfind(" 0 obj") else { continue };
+        let head = &chunk[..start];
+        let digits = head.rsplit(|c: char| !c.is_ascii_digit()).next().unwrap();
+        if let Ok(id) = digits.parse() {
+            objects.insert(id, chunk[start + 6..].to_string());
+        }
+    }
+    objects
+}
+
+/// The value of a key in a PDF dictionary, up to the end of its line.
+fn value<'a>(dict: &'a str, key: &str) -> Option<&'a str> {
+    dict.lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix(key)?.strip_prefix(' '))
+}
+
+/// The structure type of each structure element in a PDF file together with
+/// the structure type of its parent.
+fn structure(data: &[u8]) -> Vec<(String, String)> {
+    let objects = objects(data);
+    let kind = |dict: &str| {
+        let kind = value(dict, "/S").unwrap_or_default();
+        kind.trim_start_matches('/').to_string()
+    };
+    let reference = |value: &str| value.split(' ').next()?.parse::<usize>().ok();
+
+    let mut elems = vec![];
+    let mut ids: Vec<usize> = objects
+        .iter()
+        .filter(|(_, dict)| dict.contains("/Type /StructElem"))
+        .map(|(&id, _)| id)
+        .collect();
+    ids.sort();
+
+    for id in ids {
+        let dict = &objects[&id];
+        let parent = value(dict, "/P").and_then(reference).map(|p| kind(&objects[&p]));
+        elems.push((kind(dict), parent.unwrap_or_default()));
+    }
+    elems
+}
+
+#[test]
+fn test_pdf_structure_tree() {
+    let data = pdf("= Intro\n\
+         Some text.\n\
+         - A\n\
+         - B\n\n\
+         + C\n\n\
+         #figure(table(columns: 2, [D], [E]), caption: [F])\n\
+         = Outro\n\
+         More text.");
+
+    // Each element ends with its content instead of containing everything
+    // that follows it.
+    let elems = structure(&data);
+    let count = |kind: &str, parent: &str| {
+        elems.iter().filter(|(k, p)| k == kind && p == parent).count()
+    };
+    assert_eq!(count("H1", "Document"), 2);
+    assert_eq!(count("L", "Document"), 2);
+    assert_eq!(count("LI", "L"), 3);
+    assert_eq!(count("Figure", "Document"), 1);
+    assert_eq!(count("Table", "Figure"), 1);
+    assert_eq!(elems.iter().filter(|(_, p)| p == "H1").count(), 0);
+
+    // Text outside of any other element forms paragraphs.
+    assert_eq!(count("P", "Document"), 2);
+
+    let content = streams(&data);
+    assert!(content.contains("/LI <<"));
+
+    // Marked-content sequences with identifiers are never nested and all text
+    // is marked.
+    let mut stack = vec![];
+    for line in content.lines() {
+        if line == "BT" {
+            assert!(!stack.is_empty());
+        } else if line.ends_with("BDC") || line.ends_with("BMC") {
+            let mcid = line.contains("/MCID");
+            assert!(!(mcid && stack.contains(&true)));
+            stack.push(mcid);
+        } else if line.ends_with("EMC") {
+            stack.pop().unwrap();
+        }
+    }
+    assert!(stack.is_empty());
+}
+
+#[test]
+fn test_pdf_structure_across_pages() {
+    let data = pdf("#set page(height: 80pt)\n\
+         - A\n\
+         - B\n\
+         - C\n\
+         - D\n\
+         - E\n\
+         - F");
+
+    // A list broken across pages is a single element that holds the items of
+    // all pages.
+    let elems = structure(&data);
+    assert_eq!(elems.iter().filter(|(k, _)| k == "L").count(), 1);
+    assert_eq!(elems.iter().filter(|(k, p)| k == "LI" && p == "L").count(), 6);
+}
+
+#[test]
+fn test_pdf_alt_text() {
+    // A figure whose alt text is taken from its image body, followed by a
+    // decorative image.
+    let data =
+        pdf("#figure(image(\"/assets/images/cylinder.svg\", alt: \"A cylinder\"))\n\
+         #image(\"/assets/images/cylinder.svg\", alt: \"\")");
+
+    let elems = structure(&data);
+    assert_eq!(elems, vec![("Figure".to_string(), "Document".to_string())]);
+    let objects = objects(&data);
+    assert!(objects.values().any(|dict| dict.contains("/Alt (A cylinder)")));
+
+    // The image's own alt text is attached to its marked content, while the
+    // decorative image is an artifact outside of the figure's content.
+    let content = streams(&data);
+    assert!(content.contains("/Alt (A cylinder)"));
+    let mut depth = 0;
+    let mut artifacts = 0;
+    for line in content.lines() {
+        if line.ends_with("BDC") || line.ends_with("BMC") {
+            if line.starts_with("/Artifact") {
+                assert_eq!(depth, 0);
+                artifacts += 1;
+            }
+            depth += 1;
+        } else if line.ends_with("EMC") {
+            depth -= 1;
+        }
+    }
+    assert_eq!(artifacts, 1);
+}
+
+#[test]
+fn test_pdf_footnote_backlink() {
+    let links =
+        |text| objects(&pdf(text)).values().filter(|d| d.contains("/Link")).count();
+
+    // The marker links to the entry and the entry number back to the marker.
+    assert_eq!(links("A #footnote[B]"), 2);
+    assert_eq!(links("#set footnote.entry(backlink: false)\nA #footnote[B]"), 1);
+}
+
+#[test]
+fn test_pdf_standard_plain() {
+    let data = pdf("Hello");
+    assert!(!contains(&data, "pdfaid:part"));
+    assert!(!contains(&data, "/OutputIntents"));
+}
+
+#[test]
+fn test_pdf_standard_pdfa() {
+    let data = pdf_with("Hello", PdfStandard::A_2b).unwrap();
+    assert!(contains(&data, "<pdfaid:part>2</pdfaid:part>"));
+    assert!(contains(&data, "<pdfaid:conformance>B</pdfaid:conformance>"));
+    assert!(contains(&data, "/OutputIntents"));
+    assert!(contains(&data, "/GTS_PDFA1"));
+
+    let data = pdf_with("Hello", PdfStandard::A_3b).unwrap();
+    assert!(contains(&data, "<pdfaid:part>3</pdfaid:part>"));
+}
+
+#[test]
+fn test_pdf_standard_pdfa_cmyk() {
+    let text = "#set text(fill: cmyk(0%, 0%, 0%, 100%))\nHello";
+    assert!(pdf_with(text, PdfStandard::V_1_7).is_ok());
+    let errors = pdf_with(text, PdfStandard::A_2b).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains("CMYK"));
+}
+
+/// A tiny, uniformly colored JPEG with the given number of color components
+/// and additional segments before the frame header.
+fn jpeg(components: u8, segments: &[(u8, &[u8])]) -> Vec<u8> {
+    let mut data = vec![0xFF, 0xD8];
+    let mut segment = |marker: u8, body: &[u8]| {
+        data.extend([0xFF, marker]);
+        data.extend((body.len() as u16 + 2).to_be_bytes());
+        data.extend(body);
+    };
+    for (marker, body) in segments {
+        segment(*marker, body);
+    }
+    let ids = 1..=components;
+    segment(0xDB, &[&[0][..], &[1; 64]].concat());
+    let specs: Vec<_> = ids.clone().flat_map(|id| [id, 0x11, 0]).collect();
+    segment(0xC0, &[&[8, 0, 8, 0, 8, components][..], &specs].concat());
+    segment(0xC4, &[&[0x00, 1][..], &[0; 15], &[0]].concat());
+    segment(0xC4, &[&[0x10, 1][..], &[0; 15], &[0]].concat());
+    let specs: Vec<_> = ids.flat_map(|id| [id, 0]).collect();
+    segment(0xDA, &[&[components][..], &specs, &[0, 63, 0]].concat());
+
+    // Each component's block has a zero DC difference and ends right away,
+    // which takes two bits with the single-code Huffman tables. The rest of
+    // the last byte is padded with ones.
+    let bits = 2 * u32::from(components);
+    let padding = (8 - bits % 8) % 8;
+    let mut scan = vec![0; (bits as usize).div_ceil(8)];
+    if let Some(last) = scan.last_mut() {
+        *last = ((1u16 << padding) - 1) as u8;
+    }
+    data.extend(scan);
+    data.extend([0xFF, 0xD9]);
+    data
+}
+
+/// A Typst expression for the given bytes.
+fn bytes(data: &[u8]) -> String {
+    let items: Vec<_> = data.iter().map(u8::to_string).collect();
+    format!("bytes(({},))", items.join(", "))
+}
+
+/// An Adobe segment that marks a four-component JPEG as CMYK.
+const ADOBE_CMYK: (u8, &[u8]) = (0xEE, b"Adobe\x00\x64\x00\x00\x00\x00\x00");
+
+#[test]
+fn test_pdf_standard_pdfa_cmyk_image() {
+    let text = format!("#image.decode({})", bytes(&jpeg(4, &[ADOBE_CMYK])));
+    assert!(pdf_with(&text, PdfStandard::V_1_7).is_ok());
+    let errors = pdf_with(&text, PdfStandard::A_2b).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains("CMYK images"));
+}
+
+/// An EXIF segment that rotates the image by 90 degrees.
+const EXIF_ROTATED: (u8, &[u8]) =
+    (0xE1, b"Exif\0\0MM\0*\0\0\0\x08\0\x01\x01\x12\0\x03\0\0\0\x01\0\x06\0\0\0\0\0\0");
+
+#[test]
+fn test_svg_image_exif_orientation() {
+    let data = bytes(&jpeg(1, &[EXIF_ROTATED]));
+    let export = |orientation: bool| {
+        let text = format!("#image.decode({data}, exif-orientation: {orientation})");
+        typst_svg::svg(&compile(&text).pages[0].frame)
+    };
+
+    // The original data is only embedded when viewers may apply the rotation.
+    assert!(export(true).contains("data:image/jpeg"));
+    assert!(export(false).contains("data:image/png"));
+}
+
+/// Add an `opsz` axis ranging from 8pt to 72pt to a font.
+///
+/// The axis comes without variation data, so the instances look like the
+/// original font, but exporters still have to treat them as instances.
+fn with_opsz_axis(font: &Font) -> Font {
+    let data = font.data().as_slice();
+    let read = |at: usize| u32::from_be_bytes(data[at..at + 4].try_into().unwrap());
+    let count = u16::from_be_bytes([data[4], data[5]]) as usize;
+
+    let mut fvar = vec![];
+    for value in [0x0001_0000, 0x0010_0002, 0x0001_0014, 0x0000_0008] {
+        fvar.extend_from_slice(&u32::to_be_bytes(value));
+    }
+    fvar.extend_from_slice(b"opsz");
+    for value in [8u32 << 16, 12 << 16, 72 << 16, 0x0000_0100] {
+        fvar.extend_from_slice(&value.to_be_bytes());
+    }
+
+    let mut tables: Vec<([u8; 4], &[u8])> = (0..count)
+        .map(|i| {
+            let record = 12 + 16 * i;
+            let (offset, len) = (read(record + 8) as usize, read(record + 12) as usize);
+            (data[record..record + 4].try_into().unwrap(), &data[offset..offset + len])
+        })
+        .collect();
+    tables.push((*b"fvar", &fvar));
+    tables.sort_by_key(|&(tag, _)| tag);
+
+    let mut out = data[..12].to_vec();
+    out[4..6].copy_from_slice(&(tables.len() as u16).to_be_bytes());
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, table) in &tables {
+        out.extend_from_slice(tag);
+        out.extend_from_slice(&[0; 4]);
+        out.extend_from_slice(&(offset as u32).to_be_bytes());
+        out.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += table.len().next_multiple_of(4);
+    }
+    for (_, table) in &tables {
+        out.extend_from_slice(table);
+        out.resize(out.len().next_multiple_of(4), 0);
+    }
+
+    Font::new(out.into(), font.index()).expect("font should parse")
+}
+
+/// Replace the fonts of all text runs in a frame.
+fn map_fonts(frame: &Frame, f: &impl Fn(&Font) -> Font) -> Frame {
+    let mut mapped = Frame::new(frame.size(), frame.kind());
+    for (pos, item) in frame.items() {
+        let item = match item {
+            FrameItem::Group(group) => FrameItem::Group(GroupItem {
+                frame: map_fonts(&group.frame, f),
+                ..group.clone()
+            }),
+            FrameItem::Text(text) => {
+                FrameItem::Text(TextItem { font: f(&text.font), ..text.clone() })
+            }
+            item => item.clone(),
+        };
+        mapped.push(*pos, item);
+    }
+    mapped
+}
+
+#[test]
+fn test_pdf_optical_size() {
+    // Fonts at an optical size are instanced for layout only, so their glyphs
+    // are drawn as outlines instead of embedding the font's default instance.
+    let mut document = compile("Hello");
+    let instanced = |font: &Font| with_opsz_axis(font).with_optical_size(48.0);
+    document.pages[0].frame = map_fonts(&document.pages[0].frame, &instanced);
+    assert!(first_font(&document.pages[0].frame).unwrap().optical_size().is_some());
+
+    let data = typst_pdf::pdf(&document, Smart::Auto, None, None);
+    assert!(contains(&data, "/Type3"));
+    assert!(!contains(&data, "/FontFile"));
+
+    // The outlines still map back to the text.
+    assert!(contains(&data, "/ToUnicode"));
+
+    let options = SvgExportOptions { fonts: SvgFonts::Embed };
+    let svg = typst_svg::svg_with(&document.pages[0].frame, &options);
+    assert!(!svg.contains("<text"));
+    assert!(!svg.contains("@font-face"));
+    assert!(svg.contains("<symbol"));
+}
+
+/// An image whose headers are intact, so that it lays out fine, but whose pixel
+/// data is cut off, which is only noticed once it is exported.
+const CORRUPTED_IMAGE: &str =
+    "#let data = read(\"/assets/images/graph.png\", encoding: none)\n\
+     #image.decode(data.slice(0, calc.quo(data.len(), 2)))";
+
+#[test]
+fn test_pdf_corrupted_image() {
+    assert_eq!(compile(CORRUPTED_IMAGE).pages.len(), 1);
+    let errors = pdf_with(CORRUPTED_IMAGE, PdfStandard::V_1_7).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.starts_with("failed to decode image"));
+}
+
+#[test]
+fn test_png_corrupted_image() {
+    let document = compile(CORRUPTED_IMAGE);
+    let errors = typst_render::check_images(&document.pages[0].frame).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.starts_with("failed to decode image"));
+}
+
+#[test]
+fn test_svg_fonts_outline() {
+    let (svg, _) = svg("Hello", SvgFonts::Outline);
+    assert!(svg.contains("<symbol"));
+    assert!(!svg.contains("<text"));
+    assert!(!svg.contains("@font-face"));
+}
+
+#[test]
+fn test_svg_fonts_reference() {
+    let (svg, font) = svg("Hello", SvgFonts::Reference);
+    let family = format!("font-family=\"{}\"", font.info().family);
+    assert!(svg.contains(&family));
+    assert!(svg.contains("<tspan"));
+    assert!(svg.lines().any(|line| line.trim() == "H"));
+    assert!(!svg.contains("<symbol"));
+    assert!(!svg.contains("@font-face"));
+}
+
+#[test]
+fn test_svg_fonts_embed() {
+    let (svg, font) = svg("Hello", SvgFonts::Embed);
+    assert!(svg.contains("font-family=\"typst-font-0\""));
+    assert!(!svg.contains("<symbol"));
+
+    // The font is embedded as a small WOFF2 subset.
+    let prefix = "data:font/woff2;base64,";
+    let start = svg.find(prefix).unwrap() + prefix.len();
+    let end = start + svg[start..].find('"').unwrap();
+    let woff = base64::engine::general_purpose::STANDARD
+        .decode(&svg[start..end])
+        .unwrap();
+    assert_eq!(&woff[..4], b"wOF2");
+    assert_eq!(u32::from_be_bytes(woff[8..12].try_into().unwrap()), woff.len() as u32);
+    assert!(woff.len() < font.data().len() / 10);
+
+    // The subset has a character map.
+    let tables = u16::from_be_bytes(woff[12..14].try_into().unwrap());
+    let directory = &woff[48..];
+    let mut offset = 0;
+    let mut entries = vec![];
+    for _ in 0..tables {
+        assert_eq!(directory[offset] & 63, 63);
+        let tag = &directory[offset + 1..offset + 5];
+        offset += 5;
+        let mut len = 0;
+        while directory[offset] & 0x80 != 0 {
+            len = (len << 7) | usize::from(directory[offset] & 0x7F);
+            offset += 1;
+        }
+        len = (len << 7) | usize::from(directory[offset]);
+        offset += 1;
+        entries.push((tag, len));
+    }
+    let tags: Vec<_> = entries.iter().map(|&(tag, _)| tag).collect();
+    for tag in [b"cmap", b"head", b"hmtx", b"name", b"OS/2", b"post"] {
+        assert!(tags.contains(&&tag[..]));
+    }
+
+    // The tables decompress with a reference Brotli decoder.
+    let compressed_len = u32::from_be_bytes(woff[20..24].try_into().unwrap());
+    let compressed = &directory[offset..offset + compressed_len as usize];
+    let mut stream = vec![];
+    brotli::Decompressor::new(compressed, 4096)
+        .read_to_end(&mut stream)
+        .unwrap();
+    assert_eq!(stream.len(), entries.iter().map(|&(_, len)| len).sum::<usize>());
+
+    // The tables are laid out in the order of the directory.
+    let mut start = 0;
+    for (tag, len) in entries {
+        if tag == b"head" {
+            let head = &stream[start..start + len];
+            assert_eq!(&head[..4], &[0, 1, 0, 0]);
+            assert_eq!(&head[12..16], &0x5F0F3CF5_u32.to_be_bytes());
+        }
+        start += len;
+    }
+}
+
+#[test]
+fn test_compile_pages() {
+    assert!(check_streamed("#set page(height: 80pt)\n#lorem(100)") > 1);
+    assert_eq!(check_streamed("A\n#pagebreak()\n#set page(flipped: true)\nB"), 2);
+}
+
+#[test]
+fn test_compile_pages_introspection() {
+    // The outline and final page count need another layout pass.
+    let text = "#set page(height: 80pt)\n\
+         #outline()\n\
+         #context counter(page).final()\n\
+         = A\n#lorem(30)\n\
+         = B\n#lorem(30)";
+    assert!(check_streamed(text) > 1);
+}
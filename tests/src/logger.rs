@@ -11,6 +11,7 @@ pub struct Logger<'a> {
     failed: usize,
     skipped: usize,
     mismatched_image: bool,
+    mismatched_html: bool,
     active: Vec<&'a Test>,
     last_change: Instant,
     temp_lines: usize,
@@ -26,6 +27,7 @@ impl<'a> Logger<'a> {
             failed: 0,
             skipped,
             mismatched_image: false,
+            mismatched_html: false,
             active: vec![],
             temp_lines: 0,
             last_change: Instant::now(),
@@ -65,6 +67,7 @@ impl<'a> Logger<'a> {
         }
 
         self.mismatched_image |= result.mismatched_image;
+        self.mismatched_html |= result.mismatched_html;
         self.last_change = Instant::now();
 
         self.print(move |out| {
@@ -95,6 +98,10 @@ impl<'a> Logger<'a> {
             eprintln!("  pass the --update flag to update the reference images");
         }
 
+        if self.mismatched_html {
+            eprintln!("  pass the --update flag to update the reference HTML");
+        }
+
         self.failed == 0
     }
 
@@ -29,6 +29,8 @@ pub struct TestResult {
     pub infos: String,
     /// Whether the image was mismatched.
     pub mismatched_image: bool,
+    /// Whether the HTML was mismatched.
+    pub mismatched_html: bool,
 }
 
 impl TestResult {
@@ -68,6 +70,7 @@ impl<'a> Runner<'a> {
                 errors: String::new(),
                 infos: String::new(),
                 mismatched_image: false,
+                mismatched_html: false,
             },
             not_annotated: String::new(),
         }
@@ -143,6 +146,13 @@ impl<'a> Runner<'a> {
             return;
         };
 
+        // HTML tests are compared against reference HTML instead of a
+        // rendering.
+        if self.test.name.starts_with("html-") {
+            self.check_html(document);
+            return;
+        }
+
         let skippable = match document.pages.as_slice() {
             [page] => skippable(page),
             _ => false,
@@ -235,6 +245,49 @@ impl<'a> Runner<'a> {
         }
     }
 
+    /// Export the document as HTML and compare it against the reference.
+    fn check_html(&mut self, document: &Document) {
+        let live_path = format!("{}/html/{}.html", crate::STORE_PATH, self.test.name);
+        let ref_path = format!("{}/html/{}.html", crate::REF_PATH, self.test.name);
+
+        let Warned { output, warnings } = typst_html::html(&self.world, document);
+        for warning in &warnings {
+            self.check_diagnostic(NoteKind::Warning, warning);
+        }
+
+        let html = match output {
+            Ok(html) => html,
+            Err(errors) => {
+                for error in &errors {
+                    self.check_diagnostic(NoteKind::Error, error);
+                }
+                return;
+            }
+        };
+
+        std::fs::write(&live_path, &html).unwrap();
+
+        let ref_html = std::fs::read_to_string(&ref_path).ok();
+        if ref_html.as_deref() == Some(html.as_str()) {
+            return;
+        }
+
+        if crate::ARGS.update {
+            std::fs::write(&ref_path, &html).unwrap();
+            log!(into: self.result.infos, "Updated reference HTML ({ref_path})");
+        } else {
+            self.result.mismatched_html = true;
+            if ref_html.is_some() {
+                log!(self, "mismatched HTML");
+                log!(self, "  live      | {live_path}");
+                log!(self, "  ref       | {ref_path}");
+            } else {
+                log!(self, "missing reference HTML");
+                log!(self, "  live      | {live_path}");
+            }
+        }
+    }
+
     /// Compare a subset of notes with a given kind against diagnostics of
     /// that same kind.
     fn check_diagnostic(&mut self, kind: NoteKind, diag: &SourceDiagnostic) {
@@ -315,6 +315,7 @@
 #test((1, 2, 3).chunks(10), ((1, 2, 3),))
 #test((1, 2, 3, 4, 5, 6).chunks(3), ((1, 2, 3), (4, 5, 6)))
 #test((1, 2, 3, 4, 5, 6, 7, 8).chunks(3), ((1, 2, 3), (4, 5, 6), (7, 8)))
+#test((1, 2, 3, 4, 5).chunks(2), ((1, 2), (3, 4), (5,)))
 
 #test(().chunks(10, exact: true), ())
 #test((1, 2, 3).chunks(10, exact: true), ())
@@ -259,3 +259,19 @@
 --- calc-max-uncomparable ---
 // Error: 16-19 cannot compare 1pt with 1em
 #calc.max(1em, 1pt)
+
+--- calc-random ---
+#set document(seed: 7)
+#context {
+  let a = calc.random()
+  test(a, calc.random())
+  test(a != calc.random(1), true)
+  test(calc.random(1), calc.random(1))
+  test(0.0 <= a and a < 1.0, true)
+}
+
+--- calc-random-no-context ---
+// Error: 2-15 can only be used when context is known
+// Hint: 2-15 try wrapping this in a `context` expression
+// Hint: 2-15 the `context` expression should wrap everything that depends on this function
+#calc.random()
@@ -130,3 +130,92 @@
 --- content-try-to-access-internal-field ---
 // Error: 9-15 hide does not have field "hidden"
 #hide[].hidden
+
+--- content-flatten ---
+#test([a #[b #[c]]].flatten().children.len(), 5)
+#test([].flatten().children, ())
+#test(emph[a].flatten().children, (emph[a],))
+
+--- content-flatten-styled ---
+// Styled content is kept intact.
+#let body = [a #[#set text(red); b c]]
+#test(body.flatten().children.len(), 3)
+
+--- content-flatten-show-rule ---
+// Walk the flattened body of an element in a show rule.
+#show strong: it => {
+  let words = it.body.flatten().children.filter(c => c.func() == text)
+  test(words.map(c => c.text), ("A", "B", "C"))
+  it
+}
+*A #[B #[C]]*
+
+--- content-to-string ---
+#let body = [
+  = Introduction
+  This  is #strong[important]ly
+  "quoted".
+
+  - One
+  - Two
+
+  / Term: Description
+]
+#test(
+  body.to-string(),
+  "Introduction\n\nThis is importantly \"quoted\".\n\nOne\n\nTwo\n\nTerm Description",
+)
+#test(body.to-string(separator: " ").split(" ").len(), 9)
+
+--- content-to-string-math ---
+#let body = [The sum $a + b$ is $ c = d $ known.]
+#test(body.to-string(), "The sum a + b is\n\nc = d\n\nknown.")
+#test(body.to-string(math: false), "The sum is known.")
+
+--- content-to-string-raw ---
+#let body = [
+  Code:
+  ```rust
+  fn main() {
+      run();
+  }
+  ```
+  Inline `x  y`.
+]
+#test(
+  body.to-string(separator: "\n"),
+  "Code:\nfn main() {\n    run();\n}\nInline x y.",
+)
+
+--- content-words ---
+#let body = [
+  = Well-known facts
+  The sky is *blue* -- mostly.
+
+  - Grass is green.
+]
+#test(body.words(), 10)
+#test(body.characters(), 48)
+#test([Hello, 世界！こんにちは].words(), 8)
+#test([Hello, 世界！こんにちは].characters(), 14)
+
+--- content-words-punctuation ---
+// Punctuation separates words, except for hyphens and apostrophes within them.
+#test([well-known].words(), 1)
+#test([don't rock'n'roll].words(), 2)
+#test([either/or].words(), 2)
+#test([e.g. this].words(), 3)
+#test([A (B), C; D.].words(), 4)
+#test([pre- and post-war].words(), 3)
+#test(["quoted" -- 'single'].words(), 2)
+
+--- content-words-raw ---
+#let body = [
+  Run `cargo build` to compile:
+  ```sh
+  cargo build --release
+  ```
+]
+#test(body.words(), 3)
+#test(body.words(raw: true), 8)
+#test(body.characters(raw: true), 42)
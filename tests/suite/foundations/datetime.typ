@@ -64,6 +64,46 @@
 #test(datetime.today(offset: auto).display(), "1970-01-01")
 #test(datetime.today(offset: 2).display(), "1970-01-01")
 
+--- datetime-iso ---
+// Test parsing and displaying ISO 8601 strings.
+#test(datetime(iso: "2024-02-29"), datetime(year: 2024, month: 2, day: 29))
+#test(datetime(iso: "12:30:15"), datetime(hour: 12, minute: 30, second: 15))
+#test(
+  datetime(iso: "2024-02-29T12:00:00Z"),
+  datetime(year: 2024, month: 2, day: 29, hour: 12, minute: 0, second: 0),
+)
+#test(
+  datetime(iso: "2024-03-01T01:30:00.250+02:00").display("iso"),
+  "2024-02-29T23:30:00",
+)
+#test(datetime(iso: "12:00:00-01:30").display("iso"), "13:30:00")
+#test(datetime(year: 2024, month: 2, day: 29).display("iso"), "2024-02-29")
+
+// Round trip.
+#let d = datetime(year: 1999, month: 12, day: 31, hour: 23, minute: 59, second: 59)
+#test(datetime(iso: d.display("iso")), d)
+
+--- datetime-iso-partial ---
+// Partial dates cannot be represented.
+// Error: 2-26 date is incomplete
+#datetime(iso: "2024-02")
+
+--- datetime-iso-leap-second ---
+// Error: 2-39 leap seconds are not supported
+#datetime(iso: "2016-12-31T23:59:60Z")
+
+--- datetime-iso-invalid-date ---
+// Error: 2-29 date is invalid
+#datetime(iso: "2023-02-29")
+
+--- datetime-iso-bad-digits ---
+// Error: 2-28 expected 2 digits, found "2"
+#datetime(iso: "2024-2-29")
+
+--- datetime-iso-with-components ---
+// Error: 2-41 cannot combine `iso` with individual components
+#datetime(iso: "2024-02-29", year: 2024)
+
 --- datetime-ordinal ---
 // Test date methods.
 #test(datetime(day: 1, month: 1, year: 2000).ordinal(), 1);
@@ -264,3 +264,31 @@
 --- issue-3232-dict-empty ---
 #block(outset: (:), [Hi]) // Ok
 #box(radius: (:), [Hi]) // Ok
+
+--- dict-merge ---
+#let a = (x: 1, y: 2, nested: (p: 1))
+#let b = (y: 3, z: 4, nested: (q: 2))
+#test(a.merge(b), (x: 1, y: 3, nested: (q: 2), z: 4))
+#test(a.merge(b).keys(), ("x", "y", "nested", "z"))
+#test(a.merge((:)), a)
+
+--- dict-merge-combine ---
+#let defaults = (margin: 10, columns: 1, title: "Report")
+#let config = (margin: 5, columns: 2)
+#test(
+  defaults.merge(config, combine: (x, y) => x + y),
+  (margin: 15, columns: 3, title: "Report"),
+)
+
+--- dict-merge-deep ---
+#let a = (page: (width: 10, height: 20), count: 1)
+#let b = (page: (height: 30), count: 2)
+#test(a.merge(b, deep: true), (page: (width: 10, height: 30), count: 2))
+#test(
+  a.merge(b, deep: true, combine: (x, y) => x + y),
+  (page: (width: 10, height: 50), count: 3),
+)
+
+--- dict-merge-combine-error ---
+// Error: 44-49 cannot add integer and string
+#(a: 1).merge((a: "b"), combine: (x, y) => x + y)
@@ -101,3 +101,21 @@
 #test(duration(minutes: 20) < duration(minutes: 10), false)
 #test(duration(minutes: 20) <= duration(minutes: 10), false)
 #test(duration(minutes: 20) == duration(minutes: 10), false)
+
+--- duration-add-out-of-range ---
+// Error: 3-63 resulting date is out of range
+#(datetime(year: 9999, month: 12, day: 31) + duration(days: 1))
+
+--- duration-display ---
+#let d = datetime(year: 2024, month: 1, day: 15) + duration(days: 90)
+#test(d.display(), "2024-04-14")
+#test(duration(days: 90).display(), "12 weeks, 6 days")
+#test(duration(days: 90).display(unit: "days"), "90 days")
+#test(duration(days: 90).display(unit: "hours"), "2160 hours")
+#test(duration(hours: 36).display(unit: "days"), "1.5 days")
+#test(duration(hours: 1, seconds: 1).display(), "1 hour, 1 second")
+#test(duration().display(), "0 seconds")
+
+--- duration-display-bad-unit ---
+// Error: 34-41 expected "weeks", "days", "hours", "minutes", "seconds", or auto
+#duration(days: 1).display(unit: "years")
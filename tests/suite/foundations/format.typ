@@ -0,0 +1,45 @@
+// Test number formatting.
+
+--- format-basic ---
+#test(format(123), "123")
+#test(format(1234567), "1234567")
+#test(format(1234567.89, group: ","), "1,234,567.89")
+#test(format(999, group: ","), "999")
+#test(format(1000, group: "'"), "1'000")
+#test(format(0.5, decimal: ","), "0,5")
+
+--- format-digits ---
+#test(format(12, digits: 2), "12.00")
+#test(format(3.14159, digits: 2), "3.14")
+#test(format(2.4, digits: 0), "2")
+#test(format(1234.5, digits: 2, group: ","), "1,234.50")
+
+--- format-negative ---
+#test(format(-1234567, group: ","), "\u{2212}1,234,567")
+#test(format(-0.25), "\u{2212}0.25")
+#test(format(-0.001, digits: 2), "0.00")
+
+--- format-locale ---
+#test(format(1234567.89, locale: "en"), "1,234,567.89")
+#test(format(1234567.89, locale: "de"), "1.234.567,89")
+#test(format(1234567.89, locale: "fr"), "1\u{202F}234\u{202F}567,89")
+#test(format(1234567.89, locale: "de", group: ""), "1234567,89")
+
+--- format-locale-groups ---
+// Each group of languages with the same separators.
+#test(format(1234567.89, locale: "gr"), "1.234.567,89")
+#test(format(1234567.89, locale: "it"), "1.234.567,89")
+#test(format(1234567.89, locale: "ua"), "1\u{A0}234\u{A0}567,89")
+#test(format(1234567.89, locale: "pl"), "1\u{A0}234\u{A0}567,89")
+#test(format(1234567.89, locale: "ja"), "1,234,567.89")
+
+--- format-scientific ---
+#test(format(1e21), "1e21")
+#test(format(6.022e23, digits: 2), "6.02e23")
+#test(format(-1.5e-7, decimal: ","), "\u{2212}1,5e-7")
+#test(format(1e20, group: ","), "100,000,000,000,000,000,000")
+#test(format(0.0), "0")
+
+--- format-bad-locale ---
+// Error: 22-31 expected two or three letter language code (ISO 639-1/2/3)
+#format(1.5, locale: "english")
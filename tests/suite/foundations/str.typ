@@ -169,15 +169,15 @@
 #test("Is there a".match("for this?"), none)
 #test(
   "The time of my life.".match(regex("[mit]+e")),
-  (start: 4, end: 8, text: "time", captures: ()),
+  (start: 4, end: 8, text: "time", captures: (), named: (:)),
 )
 
 --- string-matches ---
 // Test the `matches` method.
 #test("Hello there".matches("\d"), ())
 #test("Day by Day.".matches("Day"), (
-  (start: 0, end: 3, text: "Day", captures: ()),
-  (start: 7, end: 10, text: "Day", captures: ()),
+  (start: 0, end: 3, text: "Day", captures: (), named: (:)),
+  (start: 7, end: 10, text: "Day", captures: (), named: (:)),
 ))
 
 // Compute the sum of all timestamps in the text.
@@ -194,6 +194,14 @@
 #test(timesum("2:70"), "3:10")
 #test(timesum("1:20, 2:10, 0:40"), "4:10")
 
+--- string-match-named-captures ---
+// Test named capturing groups.
+#let m = "2024-03".match(regex("(?<year>\d+)-(?<month>\d+)(-(?<day>\d+))?"))
+#test(m.named, (year: "2024", month: "03", day: none))
+#test(m.captures, ("2024", "03", none, none))
+#test("a1".match(regex("(\w)(\d)")).named, (:))
+#test("b2 c3".matches(regex("(?<l>\w)(?<d>\d)")).map(m => m.named.d), ("2", "3"))
+
 --- stgring-replace ---
 // Test the `replace` method with `Str` replacements.
 #test("ABC".replace("", "-"), "-A-B-C-")
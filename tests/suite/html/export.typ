@@ -0,0 +1,35 @@
+// Test HTML export.
+
+--- html-basic ---
+= Introduction <intro>
+This is *strong* and _emphasized_ text with a #link("https://typst.app")[link].
+
+- One
+- Two
+
++ First
++ Second
+
+--- html-rules ---
+// Set and show rules and context apply to the exported HTML.
+#set document(title: "Rules")
+#set heading(offset: 1)
+#show emph: it => strong(it.body)
+#show "Typst": name => link("https://typst.app", name)
+= Rules
+_Typst_ is set in #context text.lang.
+
+--- html-figure ---
+#figure(
+  image("/assets/images/tiger.jpg", alt: "A tiger"),
+  caption: [A tiger & its \<stripes\>.],
+)
+
+```rust
+let x = 1 < 2;
+```
+
+--- html-unsupported ---
+// Warning: 2-12 box was ignored during HTML export
+// Hint: 2-12 HTML export only supports basic document elements
+#box[Boxed] text
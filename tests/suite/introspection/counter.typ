@@ -77,6 +77,52 @@ At Beta, it was #context {
 // Hint: 2-28 the `context` expression should wrap everything that depends on this function
 #counter("key").at(<label>)
 
+--- counter-update-array ---
+// Set all levels of a counter at once.
+#let c = counter("section")
+#c.update((1, 2, 3))
+#c.update((2, 5))
+#context test(c.get(), (2, 5))
+#c.step(level: 3)
+#context test(c.get(), (2, 5, 1))
+#c.update((3,))
+#context test(c.get(), (3,))
+
+--- counter-step-by ---
+#let c = counter("interleaved")
+#c.step(by: 3)
+#context test(c.get(), (3,))
+#c.step()
+#context test(c.get(), (4,))
+#c.step(level: 2, by: 2)
+#context test(c.get(), (4, 2))
+#c.step(by: 2)
+#context test(c.get(), (6,))
+
+--- counter-display-at ---
+#let c = counter("display")
+#c.update((3, 2))
+#metadata(none) <display>
+#context {
+  let (value, number) = c.display-at(<display>, "I.a")
+  test(value, (3, 2))
+  test(number, "III.b")
+
+  // The output of a numbering function is kept as is.
+  let (_, body) = c.display-at(<display>, (..n) => strong(n.pos().map(str).join("-")))
+  test(body, strong("3-2"))
+}
+
+--- counter-display-at-show-rule ---
+#set heading(numbering: "1.a")
+#show heading: it => context {
+  let (value, number) = counter(heading).display-at(here())
+  test(number, numbering("1.a", ..value))
+}
+
+= One
+== Two
+
 --- issue-2480-counter-reset ---
 #let q = counter("question")
 #let step-show =  q.step() + q.display("1")
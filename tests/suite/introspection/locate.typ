@@ -37,3 +37,15 @@
 
 // Error: 10-25 selector matches multiple elements
 #context locate(heading)
+
+--- locate-position-page-size ---
+// Test reading the absolute position and page size of a marker.
+#set page(width: 120pt, height: 80pt, margin: 10pt)
+#place(dx: 15pt, dy: 25pt)[#metadata(none) <marker>]
+#context {
+  let loc = locate(<marker>)
+  test(loc.page(), 1)
+  test(loc.position().x, 25pt)
+  test(loc.position().y, 35pt)
+  test(loc.page-size(), (width: 120pt, height: 80pt))
+}
@@ -0,0 +1,20 @@
+--- metadata-key ---
+// Stash and retrieve a typed value by key.
+#metadata(key: "chapter-color", value: red)
+#context {
+  let found = query(metadata.where(key: "chapter-color"))
+  test(found.len(), 1)
+  test(type(found.first().value), color)
+  test(found.first().value, red)
+}
+
+--- metadata-key-duplicate ---
+// Duplicate keys are all returned in document order.
+#metadata(key: "step", value: 1)
+#metadata("other")
+#metadata(key: "step", value: 2)
+#context test(query(metadata.where(key: "step")).map(it => it.value), (1, 2))
+
+--- metadata-value-positional ---
+#metadata(key: "note", "A note") <note>
+#context test(query(<note>).first().key, "note")
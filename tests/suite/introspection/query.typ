@@ -266,6 +266,60 @@
   ([Frog], [GiraffeCat], [Iguana])
 )
 
+--- query-region ---
+#set page(height: 160pt)
+#figure(rect[A], caption: [Top]) <top>
+#v(1fr)
+#figure(rect[B], caption: [Bottom]) <bottom>
+
+#context {
+  let region(y, height) = (page: 1, x: 0pt, y: y, width: 200pt, height: height)
+  test(query(figure, region: region(0pt, 60pt)).map(it => it.label), (<top>,))
+  test(query(figure, region: region(80pt, 80pt)).map(it => it.label), (<bottom>,))
+  test(query(figure, region: region(0pt, 160pt)).len(), 2)
+  test(query(figure, region: (..region(0pt, 160pt), page: 2)).len(), 0)
+}
+
+--- query-region-extent ---
+// An element is found in a region that it doesn't start in, but runs into.
+#set page(height: 160pt)
+#figure(rect(height: 80pt), caption: [Tall]) <tall>
+
+#context {
+  let region(y, height) = (page: 1, x: 0pt, y: y, width: 200pt, height: height)
+  test(query(figure, region: region(90pt, 10pt)).map(it => it.label), (<tall>,))
+  test(query(figure, region: region(150pt, 10pt)).len(), 0)
+}
+
+--- query-region-extent-pages ---
+// An element that spans multiple pages is found on each of them.
+#set page(height: 50pt)
+#block(breakable: true, lorem(20)) <long>
+
+#context {
+  let region(page) = (page: page, x: 0pt, y: 0pt, width: 1000pt, height: 50pt)
+  test(query(<long>, region: region(1)).len(), 1)
+  test(query(<long>, region: region(2)).len(), 1)
+}
+
+--- query-region-em ---
+// The region's lengths are resolved with the current font size.
+#set page(height: 160pt)
+#set text(size: 10pt)
+#figure(rect[A], caption: [Top]) <top>
+#v(1fr)
+#figure(rect[B], caption: [Bottom]) <bottom>
+
+#context {
+  let region(y, height) = (page: 1, x: 0em, y: y, width: 20em, height: height)
+  test(query(figure, region: region(0em, 6em)).map(it => it.label), (<top>,))
+  test(query(figure, region: region(8em, 8em)).map(it => it.label), (<bottom>,))
+}
+
+--- query-region-bad-key ---
+// Error: 32-90 unexpected key "z", valid keys are "page", "x", "y", "width", and "height"
+#context query(figure, region: (page: 1, x: 0pt, y: 0pt, width: 1pt, height: 1pt, z: 0pt))
+
 --- issue-3726-query-show-set ---
 // Test that show rules apply to queried elements, i.e. that the content
 // returned from `query` isn't yet marked as prepared.
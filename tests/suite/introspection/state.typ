@@ -61,3 +61,34 @@ Was: #context {
 // Hint: 2-26 try wrapping this in a `context` expression
 // Hint: 2-26 the `context` expression should wrap everything that depends on this function
 #state("key").at(<label>)
+
+--- state-strict ---
+// Reading after updates yields the latest value.
+#let s = state("strict", strict: true)
+#s.update(1)
+#context test(s.get(), 1)
+#s.update(x => x + 1)
+#context test(s.get(), 2)
+#context test(s.final(), 2)
+
+--- state-strict-show-rule ---
+// Conditional updates inside of show rules are applied in layout order.
+#let s = state("strict-show", strict: true)
+#show heading: it => {
+  if it.level == 1 { s.update(it.body) }
+  it
+}
+
+= First
+#context test(s.get(), [First])
+== Nested
+#context test(s.get(), [First])
+= Second
+#context test(s.get(), [Second])
+
+--- state-strict-read-before-update ---
+#let s = state("strict-early", strict: true)
+// Error: 10-17 state "strict-early" was read before it was updated
+// Hint: 10-17 strict states have no initial value
+#context s.get()
+#s.update(1)
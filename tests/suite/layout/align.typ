@@ -105,6 +105,7 @@ To the right! Where the sunlight peeks behind the mountain.
 #test(top.inv(), bottom)
 #test(bottom.inv(), top)
 #test(horizon.inv(), horizon)
+#test(baseline.inv(), baseline)
 #test((start + top).inv(), (end + bottom))
 #test((end + top).inv(), (start + bottom))
 #test((left + top).inv(), (right + bottom))
@@ -111,6 +111,27 @@ This is a normal page. Very normal.
 #colbreak()
 In flow.
 
+--- columns-balance ---
+// Test balancing the heights of columns.
+#set page(height: 120pt, width: 7.05cm)
+#columns(2, balance: true)[
+  #lorem(20) #metadata(none) <end>
+]
+Below the columns. #metadata(none) <below>
+
+// The text ends in the second column and the columns leave room below them.
+#context test(locate(<end>).position().x > 100pt, true)
+#context test(locate(<below>).page(), 1)
+
+--- columns-balance-overflow ---
+// Content that doesn't fit into one region is not balanced.
+#set page(height: 60pt, width: 7.05cm)
+#columns(2, balance: true)[
+  #lorem(40) #metadata(none) <end>
+]
+
+#context test(locate(<end>).page() > 1, true)
+
 --- issue-columns-heading ---
 // The well-known columns bug.
 #set page(height: 70pt)
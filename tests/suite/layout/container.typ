@@ -197,7 +197,46 @@ First!
 
 #stack(dir: ltr, spacing: 1fr, check(box), check(block))
 
+--- block-align-content ---
+#set block(height: 40pt, width: 100%, fill: aqua)
+#block(align-content: center + horizon)[#box[]#metadata(none)<centered>Centered]
+#block(align-content: bottom)[#box[]#metadata(none)<bottom>Bottom]
+#block(height: 10pt, clip: true, align-content: horizon)[
+  Overflowing \ content \ is clipped
+]
+
+#context {
+  // The empty boxes keep the tags inside of the paragraphs. Unaligned, the
+  // content would start at the top-left corner of its block, that is at
+  // y = 10pt for the first block and y = 62pt for the second.
+  let centered = locate(<centered>).position()
+  test(centered.x > 25pt, true)
+  test(centered.y > 20pt, true)
+  test(locate(<bottom>).position().y > 85pt, true)
+}
+
 --- issue-2128-block-width-box ---
 // Test box in 100% width block.
 #block(width: 100%, fill: red, box("a box"))
 #block(width: 100%, fill: red, [#box("a box") #box()])
+
+--- container-shadow ---
+#set page(fill: luma(240))
+#block(
+  fill: white,
+  inset: 8pt,
+  radius: 4pt,
+  shadow: (offset: 2pt, blur: 4pt),
+)[A block with a shadow.]
+A #box(fill: white, outset: 2pt, shadow: (offset: (1pt, 2pt)))[box] with a hard shadow.
+
+--- container-shadow-breakable ---
+#set page(height: 80pt)
+#v(50pt)
+#block(fill: white, shadow: (blur: 3pt, color: blue))[
+  #lorem(15)
+]
+
+--- container-shadow-invalid ---
+// Error: 16-29 unexpected key "spread", valid keys are "offset", "blur", and "color"
+#block(shadow: (spread: 2pt))[Hi]
@@ -127,6 +127,61 @@
   )
 }
 
+--- table-cell-align-baseline ---
+// Test aligning the first baselines of cells with differently sized text.
+#table(
+  columns: 4,
+  align: baseline,
+  [#set text(10pt); #metadata(none)<small>Small],
+  [#set text(20pt); #metadata(none)<large>Large],
+  table.cell(align: top)[#set text(10pt); #metadata(none)<top>Top],
+  [#set text(10pt); #metadata(none)<lines>Two \ lines],
+)
+
+#context {
+  let (small, large, top, lines) = (<small>, <large>, <top>, <lines>)
+    .map(label => locate(label).position().y)
+  test(small > large, true)
+  test(top, large)
+  test(lines, small)
+}
+
+--- table-cell-align-baseline-no-text ---
+// Cells without text fall back to top alignment.
+#table(
+  columns: 3,
+  align: baseline,
+  [#set text(20pt); #metadata(none)<large>Large],
+  [#metadata(none)<rect>#rect(width: 1em, height: 1em, fill: aqua)],
+  [#set text(10pt); #metadata(none)<small>Small],
+)
+
+#context {
+  let (large, rect, small) = (<large>, <rect>, <small>)
+    .map(label => locate(label).position().y)
+  test(rect, large)
+  test(small > large, true)
+}
+
+--- table-cell-align-baseline-transformed ---
+// The first baseline of scaled text is where the text ends up after scaling.
+#table(
+  columns: (auto, 1fr),
+  align: baseline,
+  [#set text(16pt); Large],
+  scale(150%, origin: top + left, reflow: true)[Scaled],
+)
+
+--- table-cell-align-baseline-breakable ---
+// Cells are also aligned in rows that break across pages.
+#set page(height: 60pt)
+#table(
+  columns: 2,
+  align: baseline,
+  [#set text(16pt); Large],
+  [Small \ text \ that \ breaks \ across \ pages],
+)
+
 --- table-cell-in-grid ---
 // Error: 7-19 cannot use `table.cell` as a grid cell
 // Hint: 7-19 use `grid.cell` instead
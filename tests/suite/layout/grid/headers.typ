@@ -368,3 +368,62 @@
     [b]
   )
 )
+
+--- table-repeat-header-limit ---
+// The header is repeated on the second page only.
+#set page(height: 6em)
+#table(
+  repeat-header: 1,
+  table.header[*Header*],
+  ..range(1, 12).map(n => [#metadata(n)#n]),
+)
+
+#context {
+  let top(page) = query(metadata)
+    .filter(m => m.location().page() == page)
+    .first()
+    .location()
+    .position()
+    .y
+  test(top(2) > 15pt, true)
+  test(top(3), 15pt)
+}
+
+--- table-repeat-header-false ---
+#set page(height: 6em)
+#table(
+  repeat-header: false,
+  table.header[*Header*],
+  ..range(1, 8).map(n => [#metadata(n)#n]),
+)
+
+// Without a repeated header, the second page starts with a regular row right
+// below the top margin and the cell's inset.
+#context {
+  let second = query(metadata).filter(m => m.location().page() == 2)
+  test(second.first().location().position().y, 15pt)
+}
+
+--- table-repeat-header-limit-too-tall ---
+// A header taller than the region still obeys the limit.
+#set page(height: 6em)
+#table(
+  repeat-header: 1,
+  table.header(block(height: 5em)[*Tall*]),
+  ..range(1, 6).map(str),
+)
+
+--- table-repeat-footer-false ---
+#set page(height: 6em)
+#table(
+  repeat-footer: false,
+  ..range(1, 8).map(str),
+  table.footer[#metadata(none)<footer>*Footer*],
+)
+
+// The footer is only laid out once, at the end of the table.
+#context {
+  let last = counter(page).final().first()
+  test(last > 1, true)
+  test(locate(<footer>).page(), last)
+}
@@ -488,3 +488,34 @@
   table.cell(rowspan: 15, align: horizon, lets-repeat((rotate(-90deg, reflow: true)[*All Tests*]), 3)),
   ..([123], [456], [789]) * 15
 )
+
+--- grid-rowspan-balanced ---
+// The tall rowspan grows all three rows instead of only the last one.
+#table(
+  columns: 2,
+  balance-spans: true,
+  table.cell(rowspan: 3, block(height: 90pt, width: 20pt, fill: aqua)),
+  [#metadata(none)<a>A], [#metadata(none)<b>B], [#metadata(none)<c>C],
+)
+
+#context {
+  let (a, b, c) = (<a>, <b>, <c>).map(label => locate(label).position().y)
+  test(b - a > 30pt, true)
+  test(calc.abs((c - b - (b - a)).pt()) < 0.01, true)
+}
+
+--- grid-rowspan-balanced-fixed-rows ---
+// Rows with a fixed height are excluded from balancing.
+#table(
+  columns: 2,
+  rows: (auto, 20pt, auto),
+  balance-spans: true,
+  table.cell(rowspan: 3, block(height: 90pt, width: 20pt, fill: aqua)),
+  [#metadata(none)<a>A], [#metadata(none)<fixed>Fixed], [#metadata(none)<c>C],
+)
+
+#context {
+  let (a, fixed, c) = (<a>, <fixed>, <c>).map(label => locate(label).position().y)
+  test(fixed - a > 30pt, true)
+  test(calc.abs((c - fixed - 20pt).pt()) < 0.01, true)
+}
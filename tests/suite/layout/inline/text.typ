@@ -15,6 +15,18 @@ a vs #text(alternates: true)[a] \
 // Test ligatures.
 fi vs. #text(ligatures: false)[No fi]
 
+--- text-ligatures-named ---
+// Test named ligature modes.
+fi vs. #text(ligatures: "none")[No fi] vs. #text(ligatures: "all")[ffi, st, ct]
+
+#set text(ligatures: "none")
+No fi \
+#text(ligatures: "common")[fi]
+
+--- text-ligatures-invalid ---
+// Error: 18-24 expected boolean, "none", "common", or "all"
+#text(ligatures: "some")[fi]
+
 --- text-number-type ---
 // Test number type.
 #set text(number-type: "old-style")
@@ -67,6 +79,32 @@ I saw Zoe yӛsterday, on the tram.
 // Test tracking for only part of paragraph.
 I'm in#text(tracking: 0.15em + 1.5pt)[ spaace]!
 
+--- text-tracking-ratio ---
+// Test tracking relative to the font size.
+#set text(tracking: 2%)
+#text(10pt)[Tracked text] \
+#text(40pt)[Tracked text]
+
+#context {
+  let extra(size) = {
+    let tracked = measure(text(size)[Tracked text]).width
+    tracked - measure(text(size, tracking: 0pt)[Tracked text]).width
+  }
+  test(extra(10pt) > 0pt, true)
+  test(calc.abs((extra(40pt) - 4 * extra(10pt)).pt()) < 1e-6, true)
+}
+
+--- text-tracking-ratio-negative ---
+// Test negative relative tracking for tight display type.
+#set text(40pt, tracking: -5% + 1pt)
+Display
+
+// Each of the six gaps between the glyphs shrinks by 2pt - 1pt.
+#context {
+  let extra = measure[Display].width - measure(text(tracking: 0pt)[Display]).width
+  test(calc.abs((extra + 6pt).pt()) < 1e-6, true)
+}
+
 --- text-tracking-mark-placement ---
 // Test that tracking doesn't disrupt mark placement.
 #set text(font: ("PT Sans", "Noto Serif Hebrew"))
@@ -157,6 +157,45 @@ answer it.
 #set page(header: none, height: auto, margin: (top: 15pt, bottom: 25pt))
 The END.
 
+--- page-marginals-func ---
+#set page(
+  height: 80pt,
+  numbering: "i",
+  background: n => if calc.odd(n) {
+    [#metadata(n) <background>]
+    rotate(-30deg, text(24pt, fill: luma(85%))[*DRAFT*])
+  },
+  foreground: n => place(top + right, dx: -5pt, dy: 5pt)[#metadata(n) <foreground>#n],
+)
+
+First
+
+#pagebreak()
+Second
+
+#pagebreak()
+Third
+
+#context {
+  test(query(<background>).map(m => m.value), (1, 3))
+  test(query(<foreground>).map(m => m.value), (1, 2, 3))
+}
+
+--- page-marginals-func-introspection ---
+// The returned content can use context to query the document.
+#set page(
+  height: 60pt,
+  background: n => context {
+    test(counter(page).get().first(), n)
+    test(here().page(), n)
+    place(bottom + right)[#n / #counter(page).final().first()]
+  },
+)
+
+A
+#pagebreak()
+B
+
 --- page-number-align-top-right ---
 #set page(
   height: 100pt,
@@ -121,6 +121,40 @@ Second
   image("/assets/images/diagram.svg", width: 80%),
 )
 
+--- place-float-prefer-here ---
+#set page(height: 150pt, width: 150pt)
+
+Some introductory text.
+
+// Fits into the remaining space, so it stays in-flow.
+#figure(
+  placement: auto,
+  prefer: "here",
+  caption: [Here],
+  rect(width: 100%, height: 30pt)[#metadata(none) <here>],
+)
+
+#lorem(10)
+
+// Does not fit anymore, so it floats to the top of the next page.
+#figure(
+  placement: auto,
+  prefer: "here",
+  caption: [Next page],
+  rect(width: 100%, height: 60pt)[#metadata(none) <next>],
+)
+
+Some text after the figures.
+
+#context {
+  let here = locate(<here>).position()
+  let next = locate(<next>).position()
+  test(here.page, 1)
+  test(here.y > 15pt, true)
+  test(next.page, 2)
+  test(next.y < 25pt, true)
+}
+
 --- place-float-flush ---
 #set page(height: 150pt, width: 150pt)
 
@@ -187,6 +221,69 @@ Some conclusive text that must occur after the figure.
   #line(length: 50pt)
 ]
 
+--- place-float-parent-scope ---
+#set page(height: 200pt, width: 300pt)
+#show: columns.with(2)
+
+#metadata(none) <start>
+#lorem(15)
+#place(
+  top + center,
+  float: true,
+  scope: "parent",
+  rect(width: 80%)[Spanning the top #metadata(none) <top>],
+)
+#place(
+  bottom,
+  float: true,
+  scope: "parent",
+  rect(width: 100%)[Spanning the bottom #metadata(none) <bottom>],
+)
+#lorem(60)
+
+// Both floats are placed around the columns on the first page.
+#context {
+  let (start, top, bottom) = (<start>, <top>, <bottom>).map(l => locate(l).position())
+  test((top.page, bottom.page), (1, 1))
+  test(top.y < start.y, true)
+  test(bottom.y > start.y, true)
+}
+
+--- place-float-parent-scope-later-region ---
+// A float that occurs on the second page is placed on the second page.
+#set page(height: 120pt, width: 200pt)
+#show: columns.with(2)
+
+First
+#colbreak()
+Second
+#colbreak()
+Third
+#place(
+  top + center,
+  float: true,
+  scope: "parent",
+  rect(width: 80%)[Float #metadata(none) <float>],
+)
+#lorem(10)
+
+#context test(locate(<float>).page(), 2)
+
+--- place-float-parent-scope-too-large ---
+// Floats taller than the region don't make the column height negative.
+#set page(height: 80pt, width: 200pt)
+#show: columns.with(2)
+
+#place(
+  top,
+  float: true,
+  scope: "parent",
+  rect(width: 100%, height: 100pt)[Huge #metadata(none) <huge>],
+)
+Text
+
+#context test(locate(<huge>).page(), 1)
+
 --- issue-place-base ---
 // Test that placement is relative to container and not itself.
 #set page(height: 80pt, margin: 0pt)
@@ -38,6 +38,16 @@ A#box(width: 1fr, repeat(rect(width: 6em, height: 0.7em)))B
 #set text(dir: rtl)
 ريجين#box(width: 1fr, repeat(rect(width: 4em, height: 0.7em)))سون
 
+--- repeat-gap ---
+// Test the gap between instances.
+A#box(width: 1fr, repeat(gap: 0.5em)[.])B
+
+--- repeat-no-justify ---
+// Test instances without justification.
+#set repeat(gap: 0.3em, justify: false)
+A#box(width: 1fr, repeat[.])B \
+A#box(width: 1fr, align(end, repeat[.]))B
+
 --- repeat-unrestricted ---
 // Error: 2:2-2:13 repeat with no size restrictions
 #set page(width: auto)
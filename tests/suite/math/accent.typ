@@ -31,3 +31,12 @@ $ tilde(integral), tilde(integral)_a^b, tilde(integral_a^b) $
 --- math-accent-sized ---
 // Test accent size.
 $tilde(sum), tilde(sum, size: #50%), accent(H, hat, size: #200%)$
+
+--- math-accent-stretch ---
+// Test stretching accents to the width of the base.
+$hat(x + y), hat(x + y, stretch: #true)$ \
+$tilde(x + y + z, stretch: #true), accent(x + y + z, macron, stretch: #true)$
+
+--- math-accent-stretch-scaled ---
+// Test scaling accents beyond the font's widest variant.
+$hat(a + b + c + d + e + f + g, stretch: #true)$
@@ -12,6 +12,11 @@ $ f(x, y) := cases(
 #set math.cases(gap: 1em)
 $ x = cases(1, 2) $
 
+--- math-cases-align ---
+#set math.cases(align: right)
+$ x = cases(1, 100) $
+$ x = cases(1, 100, align: #(center, left)) $
+
 --- math-cases-delim ---
 #set math.cases(delim: sym.angle.l)
 $ cases(a, b, c) $
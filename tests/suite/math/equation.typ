@@ -256,6 +256,87 @@ $ q &= sum_k ln A \
   ],
 )
 
+--- math-equation-number-within ---
+#set page(width: 150pt)
+#set heading(numbering: "1.")
+#set math.equation(numbering: "(1.1)", number-within: 1)
+
+$ a = 0 $ <before>
+
+= Start
+$ b = 1 $
+$ c = 2 $ <second>
+
+== Sub
+$ d = 3 $ <sub>
+
+= End
+$ e = 4 $ <last>
+
+See @before, @second, and @last.
+
+#context {
+  let at(label) = counter(math.equation).at(label)
+  test(at(<before>), (0, 1))
+  test(at(<second>), (1, 2))
+  test(at(<sub>), (1, 3))
+  test(at(<last>), (2, 1))
+}
+
+--- math-equation-number-within-counter ---
+#set heading(numbering: "1.1")
+#set math.equation(numbering: "(1.1.a)", number-within: 2)
+
+= A
+$ x $
+#context test(counter(math.equation).get(), (1, 0, 1))
+== B
+$ y $
+$ z $
+#context test(counter(math.equation).get(), (1, 1, 2))
+= C
+$ w $
+#context test(counter(math.equation).get(), (2, 0, 1))
+
+--- math-equation-sub-numbering ---
+#set page(width: 180pt)
+#set math.equation(numbering: "(1a)", sub-numbering: true)
+
+$ a = b $ <single>
+
+$ (a + b)^2 &= (a + b)(a + b) \
+  &= a^2 + 2 a b + b^2 #[#math.equation.line()<expanded>] \
+  &= a^2 + b^2 + 2 a b $ <square>
+
+From @square, and especially @expanded, follows @single.
+
+#context {
+  let at(label) = counter(math.equation).at(label)
+  test(at(<single>), (1,))
+  test(at(<square>), (2,))
+  test(counter(math.equation.line).at(<expanded>), (2, 2))
+}
+
+--- math-equation-sub-numbering-counter ---
+#set math.equation(numbering: "(1a)", sub-numbering: true)
+$ a $
+$ b \ c #[#math.equation.line()<c>] $
+#context test(counter(math.equation.line).at(<c>), (2, 2))
+
+--- math-equation-sub-numbering-styled ---
+// Linebreaks in styled content count as rows, too.
+#set math.equation(numbering: "(1a)", sub-numbering: true)
+#place(hide[$ a \ #[#set text(red); b \ c #math.equation.line()<styled>] $])
+#context test(counter(math.equation.line).at(<styled>), (1, 3))
+
+--- math-equation-sub-numbering-disabled ---
+#set math.equation(numbering: "(1)")
+$ a \ b #[#math.equation.line()<b>] $
+
+// Error: 1-3 cannot reference line without numbering
+// Hint: 1-3 you can number the lines of equations with `#set math.equation(numbering: "(1a)", sub-numbering: true)`
+@b
+
 --- issue-4187-alignment-point-affects-row-height ---
 // In this bug, a row of "-" only should have a very small height; but
 // after adding an alignment point "&", the row gains a larger height.
@@ -284,3 +365,18 @@ Looks at the @quadratic formula.
 #set page(width: 150pt)
 #set text(lang: "he")
 תהא סדרה $a_n$: $[a_n: 1, 1/2, 1/3, dots]$
+
+--- math-equation-mirror ---
+#set page(width: 150pt)
+#set text(lang: "ar")
+#set math.equation(mirror: true)
+
+$ 0 -> 1 -> 2 -> 3 $
+$ س(ص) <= sqrt(2) / 2 + sum_(ن = 1)^10 ن $
+وبالتالي $[أ, ب) subset.eq ج$ صحيح.
+
+--- math-equation-mirror-ltr ---
+// Mirroring has no effect in left-to-right text.
+#set math.equation(mirror: true)
+$ 0 -> 1 -> 2 -> 3 $
+$ f(x) <= sqrt(2) / 2 $
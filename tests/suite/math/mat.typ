@@ -141,6 +141,19 @@ $ mat(-1&, 1&, 1&; 1&, -1&, 1&; 1&, 1&, -1&) $
 $ mat(-1&, 1&, 1&; 1, -1, 1; 1, 1, -1) $
 $ mat(&-1, &1, &1; 1, -1, 1; 1, 1, -1) $
 
+--- math-mat-align-columns ---
+// Test per-column alignment, cycling through the alignments.
+$ mat(1, 10, 100, 1000; 1000, 100, 10, 1; align: #(right, left)) $
+#math.equation(block: true, math.mat((1, 22), (333, 4), align: right))
+
+--- math-mat-align-columns-with-points ---
+// Cells with alignment points ignore the column alignment.
+$ mat(&1, 10; &100, 1; align: #right) $
+
+--- math-mat-align-columns-empty ---
+// Error: 27-29 alignment array must not be empty
+$ mat(1, 2; 3, 4; align: #()) $
+
 --- math-mat-bad-comma ---
 // This error message is bad.
 // Error: 13-14 expected array, found content
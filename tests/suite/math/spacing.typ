@@ -49,6 +49,41 @@ $integral f(x) thin dif x$,
 // Both are weak, collide
 $integral f(x) #h(0.166em, weak: true)dif x$
 
+--- math-spacing-custom ---
+// Test overriding the spacing around operators.
+$a = b + c, sum x$ \
+#set math.equation(spacing: (relation: 0.5em))
+$a = b + c, sum x$ \
+#set math.equation(spacing: (binary: 0em, large: 0.5em, punctuation: 1em))
+$a = b + c, sum x$ \
+// Scripts stay unspaced, fractions scale down.
+$x^(a = b) = 1/(a = 1/(b = c))$
+
+#context {
+  let width(spacing, body) = {
+    measure({ set math.equation(spacing: (relation: spacing)); body }).width
+  }
+
+  // The spacing is added on both sides of the relation.
+  let extra = width(1em, $a = b$) - width(0.5em, $a = b$)
+  test(calc.abs((extra - 1em.to-absolute()).pt()) < 1e-6, true)
+
+  // Relations in scripts stay unspaced.
+  test(width(1em, $x^(a = b)$), width(0.5em, $x^(a = b)$))
+}
+
+--- math-spacing-custom-fold ---
+#set math.equation(spacing: (relation: 0.5em))
+#set math.equation(spacing: (binary: 1pt))
+#context test(
+  math.equation.spacing,
+  (relation: 0.5em, binary: 1pt, large: 1em / 6, punctuation: 1em / 6),
+)
+
+--- math-spacing-custom-invalid ---
+// Error: 29-44 unexpected key "operator", valid keys are "relation", "binary", "large", and "punctuation"
+#set math.equation(spacing: (operator: 1em))
+
 --- issue-1052-math-number-spacing ---
 // Test spacing after numbers in math.
 $
@@ -53,3 +53,50 @@ Now we have multiple bibliographies containing @glacier-melt @keshav2007read
 
 @Zee04
 #bibliography("/assets/bib/works_too.bib", style: "mla")
+
+--- bibliography-format ---
+// Test formatting references with a custom function.
+#set page(width: 200pt)
+@netwok and @arrgh are cited.
+#bibliography(
+  "/assets/bib/works.bib",
+  format: it => [
+    #metadata(it.key)
+    *#it.key*:
+    #if it.authors != none { it.authors.map(a => a.family).join(", ") + ", " }
+    _#it.title;_
+    #if it.year != none [(#it.year)]
+    #if it.url == none [no URL]
+  ],
+)
+
+// Only the cited entries are formatted.
+#context test(query(metadata).map(m => m.value).sorted(), ("arrgh", "netwok"))
+
+--- bibliography-format-type ---
+// Test that entry types are exposed with their documented names.
+#place(hide[
+  @netwok @arrgh
+  #bibliography(
+    "/assets/bib/works.bib",
+    format: it => {
+      test(type(it.type), str)
+      if it.key == "netwok" { test(it.type, "article") }
+      it.key
+    },
+  )
+])
+
+--- bibliography-format-prefix ---
+// Test that the label rendered by a numeric style is passed to the format
+// function.
+#set page(width: 200pt)
+@netwok and @arrgh are cited.
+#bibliography(
+  "/assets/bib/works.bib",
+  style: "ieee",
+  format: it => {
+    test(type(it.prefix), content)
+    [#it.prefix #it.title]
+  },
+)
@@ -49,6 +49,31 @@ A @netwok @arrgh @quark, B.
 #show bibliography: none
 #bibliography("/assets/bib/works.bib")
 
+--- cite-collapse ---
+// Consecutive numbers collapse into a range.
+#cite(<netwok>)#cite(<arrgh>)#cite(<quark>)#metadata(none)<collapsed> \
+#cite(<netwok>)#cite(<quark>)#cite(<distress>)
+
+#set cite(collapse: false)
+#cite(<netwok>)#cite(<arrgh>)#cite(<quark>)#metadata(none)<separate>
+
+// The range is shorter than the numbers listed separately.
+#context test(locate(<collapsed>).position().x < locate(<separate>).position().x, true)
+
+#set text(0pt)
+#bibliography("/assets/bib/works.bib")
+
+--- cite-collapse-author-date ---
+// Author-date styles are unaffected.
+#cite(<netwok>)#cite(<arrgh>)#cite(<quark>)#metadata(none)<auto> \
+#set cite(collapse: true)
+#cite(<netwok>)#cite(<arrgh>)#cite(<quark>)#metadata(none)<collapse>
+
+#context test(locate(<collapse>).position().x, locate(<auto>).position().x)
+
+#set text(0pt)
+#bibliography("/assets/bib/works.bib", style: "apa")
+
 --- issue-785-cite-locate ---
 // Test citation in other introspection.
 #set page(width: 180pt)
@@ -62,6 +62,20 @@ a + 0.
 + First
   + Nested
 
+--- enum-numbering-full-start ---
+// Test full numbering with manual start values on multiple levels.
+#set enum(full: true)
+#enum(start: 3)[
+  Third
+  #enum(start: 5)[
+    Nested
+    + Deep
+    + Deeper
+  ]
+]
+2. Manual
+   + Nested
+
 --- enum-numbering-closure ---
 // Test numbering with closure.
 #enum(
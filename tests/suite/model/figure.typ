@@ -211,6 +211,56 @@ We can clearly see that @fig-cylinder and
   caption: [Ένας κύκλος.],
 )
 
+--- figure-subfigure ---
+// Test subfigures sharing their figure's number.
+#figure(rect[First], caption: [Before])
+
+#figure(
+  grid(
+    columns: 2,
+    gutter: 1em,
+    [#figure.subfigure(rect[A], caption: [Left]) <left>],
+    [#figure.subfigure(rect[B], caption: [Right]) <right>],
+  ),
+  caption: [Composite],
+) <both>
+
+#figure(
+  [#figure.subfigure(rect[C], caption: [Alone]) <alone>],
+  caption: [Another],
+)
+
+@both consists of @left and @right, unlike @alone.
+
+--- figure-subfigure-ref-numbering ---
+#set figure.subfigure(numbering: "i.", ref-numbering: "1.i")
+#figure(
+  stack(
+    dir: ltr,
+    spacing: 1em,
+    [#figure.subfigure(rect[A]) <a>],
+    [#figure.subfigure(rect[B]) <b>],
+  ),
+  caption: [Numbered],
+)
+
+See @a and @b.
+
+--- figure-subfigure-outside ---
+// Error: 2-30 subfigures must be placed within a figure
+#figure.subfigure(rect[Lost])
+
+--- figure-alt ---
+// The figure's alt text is available for introspection. An image with empty
+// alt text is decorative.
+#figure(
+  image("/assets/images/cylinder.svg", height: 1cm, alt: ""),
+  alt: "A cylinder",
+  caption: [A cylinder],
+)
+
+#context test(query(figure).first().alt, "A cylinder")
+
 --- issue-2165-figure-caption-panic ---
 #figure.caption[]
 
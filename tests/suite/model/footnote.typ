@@ -160,6 +160,364 @@ Ref @fn
     .map(v => upper(v) + footnote(v))
 )
 
+--- footnote-scope-page ---
+#set page(height: 100pt)
+#set footnote(scope: "page")
+
+A #footnote[One] <a>
+B #footnote[Two]
+#pagebreak()
+C #footnote[One again] <c>
+D #footnote(<a>)
+
+#context test(counter(footnote).at(<a>), (1,))
+#context test(counter(footnote).at(<c>), (1,))
+
+--- footnote-endnotes ---
+#set footnote(placement: "end")
+#show footnote.entry: it => [#metadata(it.note.body) <entry>] + it
+
+A #footnote[First] <first>
+B #footnote[Second]
+C #footnote(<first>)
+
+#endnotes() <notes>
+
+// All entries are deferred to the listing.
+#context test(
+  query(selector(<entry>).after(<notes>)).map(it => it.value),
+  ([First], [Second]),
+)
+#context test(query(<entry>).len(), 2)
+
+--- footnote-endnotes-per-chapter ---
+#set footnote(placement: "end")
+#show footnote.entry: it => [#metadata(it.note.body) <entry>] + it
+
+= One
+A #footnote[In one]
+#endnotes(level: 1) <one>
+
+= Two <two>
+B #footnote[In two]
+#endnotes(level: 1)
+
+#context {
+  let entries(sel) = query(sel).map(it => it.value)
+  test(entries(selector(<entry>).after(<one>).before(<two>)), ([In one],))
+  test(entries(selector(<entry>).after(<two>)), ([In two],))
+}
+
+--- footnote-ref-multiple-labels ---
+#show footnote: it => {
+  show super: it => [#metadata(it.body.to-string()) <marker>] + it
+  it
+}
+A #footnote[One] <a>
+B #footnote[Two] <b>
+C #footnote((<a>, <b>))
+
+// The reference shows the numbers of both footnotes without taking a number.
+#context test(query(<marker>).map(it => it.value), ("1", "2", "1,2"))
+#context test(counter(footnote).final(), (2,))
+
+--- footnote-ref-multiple-nested ---
+// Referencing a footnote that references several footnotes shows all of them.
+#show footnote: it => {
+  show super: it => [#metadata(it.body.to-string()) <marker>] + it
+  it
+}
+A #footnote[One] <a>
+B #footnote[Two] <b>
+C #footnote((<a>, <b>)) <c>
+D #footnote(<c>)
+
+#context test(query(<marker>).map(it => it.value), ("1", "2", "1,2", "1,2"))
+
+--- footnote-ref-multiple-delimiter ---
+#set footnote(delimiter: [;])
+#show footnote: it => {
+  show super: it => [#metadata(it.body.to-string()) <marker>] + it
+  it
+}
+A #footnote[One] <a>
+B #footnote[Two] <b>
+C #footnote((<a>, <b>))
+
+#context test(query(<marker>).map(it => it.value), ("1", "2", "1;2"))
+
+--- footnote-self-ref ---
+// Error: 2-15 footnote cannot reference itself
+#footnote(<a>) <a>
+
+--- footnote-marker-gap ---
+#set footnote(marker-gap: 0.1em)
+Spaced#footnote[Out] <n>#metadata(none) <a> \
+Spaced#footnote(marker-gap: 0pt, <n>)#metadata(none) <b>
+
+// The marker is moved by exactly the gap.
+#context test(
+  calc.round((locate(<a>).position().x - locate(<b>).position().x).pt(), digits: 3),
+  1.0,
+)
+
+--- footnote-entry-min-lines ---
+#set page(height: 100pt)
+#set footnote.entry(min-lines: 3)
+
+#v(35pt)
+A #footnote[#metadata(none) <start>#lorem(20)] <note>
+
+// The entry starts on the page of its marker.
+#context test(locate(<start>).page(), locate(<note>).page())
+
+--- footnote-entry-backlink ---
+// The entry number links back to the marker by default.
+With #footnote[Backlink]
+
+--- footnote-entry-no-backlink ---
+#set footnote.entry(backlink: false)
+Without #footnote[Backlink]
+
+--- footnote-numbering-dagger ---
+#set footnote(numbering: "†")
+#let markers = ("*", "†", "‡", "**", "††")
+#for i in range(5) [
+  A #footnote[B]
+  #context test(numbering(footnote.numbering, ..counter(footnote).get()), markers.at(i))
+]
+
+--- footnote-sidenote ---
+#set page(width: 200pt, margin: (right: 80pt, rest: 10pt))
+A #sidenote[#metadata(none) <a> A margin remark]
+B #sidenote[#metadata(none) <b> Another remark that is long enough to collide]
+C #footnote[#metadata(none) <c> At the bottom]
+
+// Sidenotes sit in the right margin, one below the other, while footnotes
+// stay at the bottom of the text area.
+#context {
+  let (a, b, c) = (<a>, <b>, <c>).map(l => locate(l).position())
+  test(a.x > 120pt, true)
+  test(b.x, a.x)
+  test(b.y > a.y, true)
+  test(c.x < 120pt, true)
+}
+
+--- footnote-sidenote-pull-up ---
+#set page(width: 200pt, height: 100pt, margin: (right: 80pt, rest: 10pt))
+#v(60pt)
+A #sidenote[#lorem(5) #metadata(none) <end>]
+
+// The note is pulled up so that it ends at the bottom margin.
+#context test(locate(<end>).position().y, 90pt)
+
+--- footnote-sidenote-overflow ---
+#set page(width: 200pt, height: 60pt, margin: (right: 80pt, rest: 10pt))
+// Warning: 4-24 margin notes do not fit on the page
+// Hint: 4-24 try shortening the notes or spreading them out
+A #sidenote[#lorem(20)]
+
+--- footnote-sidenote-narrow-margin ---
+#set page(width: 100pt, height: auto, margin: 10pt)
+// Warning: 4-18 margin is too narrow to hold the margin note
+// Hint: 4-18 increase the page margin or decrease the `margin-gap` of `footnote.entry`
+A #sidenote[Lost]
+
+--- footnote-entry-continuation ---
+#set page(height: 100pt)
+#set footnote.entry(continuation: [(cont.)])
+
+#v(30pt)
+Long #footnote[#lorem(40) #metadata(none) <end>] <note>
+
+// The entry breaks across pages.
+#context test(locate(<note>).page(), 1)
+#context test(locate(<end>).page() > 1, true)
+
+--- footnote-entry-continuation-separator ---
+#set page(height: 100pt)
+#set footnote.entry(continuation-separator: line(length: 100%))
+
+#v(30pt)
+Long #footnote[#lorem(40) #metadata(none) <end>] <note>
+
+// The entry breaks across pages.
+#context test(locate(<note>).page(), 1)
+#context test(locate(<end>).page() > 1, true)
+
+--- footnote-scope-column ---
+#set page(height: 120pt)
+#set footnote(scope: "column")
+#show: columns.with(2)
+
+A #footnote[One] <a> \
+B #footnote[Two]
+#colbreak()
+C #footnote[One again] <c>
+
+#context test(counter(footnote).at(<c>), (1,))
+
+--- footnote-scope-column-lower ---
+// A marker further down in the next column still restarts the numbering.
+#set page(height: 120pt)
+#set footnote(scope: "column")
+#show: columns.with(2)
+
+A #footnote[One]
+#colbreak()
+#v(40pt)
+B #footnote[One again] <b>
+
+#context test(counter(footnote).at(<b>), (1,))
+
+--- footnote-marker-style ---
+#show footnote: it => {
+  show super: it => metadata("super") + it
+  show sub: it => metadata("sub") + it
+  it
+}
+#for style in ("super", "sub", "bracket") {
+  set footnote(marker-style: style)
+  [A #footnote[B] ]
+}
+
+// Only the first two markers are set as scripts.
+#context test(query(metadata).map(m => m.value), ("super", "sub"))
+
+--- footnote-on-page ---
+#set page(height: 100pt)
+A #footnote[One]
+#pagebreak()
+B #footnote[Two] C #footnote[Three]
+
+#context test(footnote.on-page(1).len(), 1)
+#context test(footnote.on-page(2).map(n => n.body), ([Two], [Three]))
+
+--- footnote-entry-numbering ---
+#set footnote.entry(entry-numbering: "(a)")
+#show footnote.entry: it => {
+  show super: it => metadata(it.body.text) + it
+  it
+}
+A #footnote[One] <a>
+B #footnote(<a>)
+
+// The single entry is numbered differently from its markers.
+#context test(query(metadata).map(m => m.value), ("(a)",))
+#context test(counter(footnote).final(), (1,))
+
+--- footnote-entry-max-height ---
+#set page(height: 120pt)
+#set footnote.entry(max-height: 30%)
+
+A #footnote[#lorem(20) #metadata(none) <end>] <note>
+B #footnote[#lorem(20)]
+
+// The first entry is too tall for the limited footnote area and breaks.
+#context test(locate(<note>).page(), 1)
+#context test(locate(<end>).page() > 1, true)
+
+--- footnote-group ---
+#let text-of(it) = if it.has("text") {
+  it.text
+} else if it.has("children") {
+  it.children.map(text-of).join()
+} else if it.has("child") {
+  text-of(it.child)
+} else {
+  ""
+}
+#show footnote: it => {
+  show super: it => metadata(text-of(it.body)) + it
+  it
+}
+
+#set footnote(group: true)
+Grouped #footnote[One] #footnote[Two] and
+separate #footnote[Three].
+
+#context test(query(metadata).map(m => m.value), ("1", ",2", "3"))
+
+--- footnote-group-generated ---
+// Footnotes are grouped based on where they end up, not on the source.
+#let text-of(it) = if it.has("text") {
+  it.text
+} else if it.has("children") {
+  it.children.map(text-of).join()
+} else if it.has("child") {
+  text-of(it.child)
+} else {
+  ""
+}
+#show footnote: it => {
+  show super: it => metadata(text-of(it.body)) + it
+  it
+}
+
+#set footnote(group: true)
+Generated#for note in ([One], [Two]) { footnote(note) }
+
+#context test(query(metadata).map(m => m.value), ("1", ",2"))
+
+--- footnote-in-float-caption ---
+#set page(height: 120pt)
+First #footnote[In text].
+#figure(
+  placement: auto,
+  rect(height: 60pt),
+  caption: [Float #footnote[In caption]],
+)
+#lorem(10)
+Last #footnote[After].
+
+// Each footnote gets its own number, wherever the float ends up.
+#context test(
+  query(footnote).map(note => counter(footnote).at(note.location())).sorted(),
+  ((1,), (2,), (3,)),
+)
+
+--- footnote-in-float-top ---
+#set page(height: 200pt)
+#set heading(numbering: "1.")
+= Heading
+First #footnote[In text] <a>
+#figure(
+  placement: top,
+  rect(height: 20pt),
+  caption: [Float #footnote[In caption] <b>],
+) <fig>
+
+// Footnotes in floats at the top are numbered first, but the float itself
+// keeps its place in the document.
+#context test(counter(footnote).at(<b>), (1,))
+#context test(counter(footnote).at(<a>), (2,))
+#context test(counter(heading).at(<fig>), (1,))
+
+--- footnote-entry-separator-clearance-always ---
+#set page(height: 100pt)
+#set footnote.entry(separator-clearance-always: true)
+Note #footnote[Here]
+#pagebreak()
+No notes.
+
+--- footnote-mark ---
+Title #footnote(mark: [\*])[Title note] <title>
+and #footnote[First] <first> and again #footnote(<title>).
+
+// The footnote with a custom mark doesn't take up a number.
+#context test(counter(footnote).at(<first>), (1,))
+
+--- footnote-entry-separator-func ---
+#set page(width: 120pt, height: 100pt)
+#set footnote.entry(separator: width => {
+  test(width, 100pt)
+  path(
+    stroke: 0.5pt + blue,
+    ..range(13).map(i => (i * width / 12, calc.rem(i, 2) * 2pt)),
+  )
+})
+Wavy #footnote[A separator spanning the whole width.]
+
 --- issue-multiple-footnote-in-one-line ---
 // Test that the logic that keeps footnote entry together with
 // their markers also works for multiple footnotes in a single
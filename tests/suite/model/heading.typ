@@ -78,3 +78,22 @@ Not in heading
 // Error: 1:20-1:26 cannot reference heading without numbering
 // Hint: 1:20-1:26 you can enable heading numbering with `#set heading(numbering: "1.")`
 Can not be used as @intro
+
+--- heading-run-in ---
+#show heading.where(level: 4): set heading(run-in: true, run-in-separator: [.])
+#outline()
+
+==== Remark <remark>
+#metadata(none)<remark-body>This paragraph starts on the same line as its heading.
+
+==== Another remark <another>
+
+#metadata(none)<another-body>The heading merges even after a blank line.
+
+#context for (heading, body) in ((<remark>, <remark-body>), (<another>, <another-body>)) {
+  let heading = locate(heading).position()
+  let body = locate(body).position()
+  // The heading is tagged at the top of the line and the body at its text.
+  test(body.y - heading.y < 10pt, true)
+  test(body.x > heading.x, true)
+}
@@ -8,6 +8,18 @@
   [ for #i \ ]
 }
 
+--- numbering-dagger ---
+#test(
+  range(1, 11).map(i => numbering("†", i)),
+  ("*", "†", "‡", "**", "††", "‡‡", "***", "†††", "‡‡‡", "****"),
+)
+
+--- numbering-dagger-literal ---
+// Next to other counting symbols, the dagger is kept as-is.
+#test(numbering("1†", 2), "2†")
+#test(numbering("†a", 2), "†b")
+#test(numbering("*†", 2), "††")
+
 --- numbering-latin ---
 #for i in range(0, 4) {
   numbering("A", i)
@@ -112,4 +124,41 @@
 
 --- numbering-double-circled-number ---
 #assert.eq(numbering("⓵", 1), "⓵")
-#assert.eq(numbering("⓵", 10), "⓾")
\ No newline at end of file
+#assert.eq(numbering("⓵", 10), "⓾")
+
+--- numbering-roman-threshold ---
+#assert.eq(numbering("i|arabic>3999", 3999), "mmmcmxcix")
+#assert.eq(numbering("i|arabic>3999", 4000), "4000")
+#assert.eq(numbering("(I)|arabic>10", 10), "(X)")
+#assert.eq(numbering("(I)|arabic>10", 11), "(11)")
+#assert.eq(numbering("1.i|arabic>3", 5, 3, 4), "5.iii.4")
+
+--- numbering-roman-threshold-invalid ---
+// Error: 12-24 invalid numbering threshold
+#numbering("i|arabic>x", 1)
+
+--- numbering-words ---
+#assert.eq(numbering("one", 0), "zero")
+#assert.eq(numbering("one", 7), "seven")
+#assert.eq(numbering("one", 21), "twenty-one")
+#assert.eq(numbering("one", 100), "one hundred")
+#assert.eq(numbering("one", 1234), "one thousand two hundred thirty-four")
+#assert.eq(numbering("one", 2000005), "two million five")
+#assert.eq(numbering("(One)", 12), "(Twelve)")
+#assert.eq(numbering("Phone 1", 3), "Phone 3")
+
+--- numbering-ordinals ---
+#assert.eq(
+  range(1, 5).map(n => numbering("1st", n)),
+  ("1st", "2nd", "3rd", "4th"),
+)
+#assert.eq(numbering("1st", 11), "11th")
+#assert.eq(numbering("1st", 112), "112th")
+#assert.eq(numbering("1st", 122), "122nd")
+#assert.eq(numbering("1st.a)", 3, 1), "3rd.a)")
+#assert.eq(numbering("1.1st", 2, 12, 1), "2.12th.1st")
+
+--- numbering-words-literal ---
+// Next to other counting symbols and in other spellings, words are kept as-is.
+#assert.eq(numbering("one.1", 2), "one.2")
+#assert.eq(numbering("ONE-1", 3), "ONE-3")
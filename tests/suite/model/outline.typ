@@ -89,6 +89,62 @@ Ok ...
 
 = Heading
 
+--- outline-filter ---
+#set heading(numbering: "1.")
+#set page(height: 120pt)
+#show outline.entry: it => metadata(it.element.label) + it
+#outline(filter: it => it.has("label"), indent: auto)
+
+= Introduction <intro>
+= Background
+== History <history>
+#pagebreak()
+= Analysis <analysis>
+
+#context test(query(metadata).map(m => m.value), (<intro>, <history>, <analysis>))
+
+--- outline-filter-bad-return ---
+// Error: 2-26 expected boolean, found integer
+#outline(filter: it => 1)
+
+= Heading
+
+--- outline-columns ---
+#set page(width: 150pt)
+#set heading(numbering: "1.")
+#show outline.entry: it => metadata(none) + it
+#outline(columns: 2)
+
+= Introduction
+= Background
+== History
+= Methods
+= Results
+= Discussion
+
+// The entries are balanced across both columns, even on a page without a
+// fixed height.
+#context {
+  let xs = query(metadata).map(m => m.location().position().x)
+  test(xs.len(), 6)
+  test(xs.first() < xs.last(), true)
+}
+
+--- outline-entry-leader ---
+// The dots of entries with different indents line up.
+#set heading(numbering: "1.")
+#set outline.entry(leader: (glyph: [·], gap: 0.4em, aligned: true))
+#outline(indent: auto)
+
+= Introduction
+== Motivation
+=== Background of the work
+= Conclusion
+
+--- outline-entry-leader-bad-key ---
+// Error: 28-40 unexpected key "dots", valid keys are "glyph", "gap", "aligned", and "page-width"
+#set outline.entry(leader: (dots: true))
+
 --- outline-first-line-indent ---
 #set par(first-line-indent: 1.5em)
 #set heading(numbering: "1.1.a.")
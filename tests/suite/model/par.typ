@@ -78,3 +78,134 @@ Welcome \ here. Does this work well?
 #set text(dir: rtl)
 لآن وقد أظلم الليل وبدأت النجوم
 تنضخ وجه الطبيعة التي أعْيَتْ من طول ما انبعثت في النهار
+
+--- par-leading-mode-grid ---
+#set page(width: 120pt, background: context {
+  let step = 1em + 0.65em
+  for i in range(12) {
+    place(dy: 10pt + 0.8em + i * step, line(length: 100%, stroke: 0.2pt + red))
+  }
+})
+#set par(leading-mode: "grid")
+#box[]#metadata(none)<first>Plain lines \
+with $x^2$ and with
+$sum_(i=0)^n i$ and tall $display(integral_0^1 x dif x)$ math \
+#box[]#metadata(none)<last>stay on the grid.
+
+#context {
+  // The empty boxes keep the tags at the start of their lines. The first and
+  // last line have the same height, so the distance between them is the one
+  // between their baselines. The taller lines in between take up more than
+  // one grid row each.
+  let step = (1em + 0.65em).to-absolute()
+  let rows = (locate(<last>).position().y - locate(<first>).position().y) / step
+  test(calc.abs(rows - calc.round(rows)) < 1e-6, true)
+  test(rows > 2, true)
+}
+
+--- par-hanging-punctuation ---
+#set page(width: 160pt)
+#set par(justify: true, hanging-punctuation: true)
+#set text(lang: "en")
+"#metadata(none)<quote>Quotes hang", they said, "into the margin.
+So do trailing dots, commas, and closing quotes."
+
+// The opening quote hangs into the margin entirely, so the text after it
+// starts right at the margin.
+#context test(calc.abs((locate(<quote>).position().x - 10pt).pt()) < 0.01, true)
+
+#set text(dir: rtl, lang: "he")
+"ציטוט" בתחילת השורה, וגם נקודה בסוף.
+
+--- par-first-line-indent-scope ---
+// The empty boxes keep the tags inside of the paragraphs.
+#set par(first-line-indent: (amount: 1em, scope: "after-heading"))
+#box[]#metadata(false)First paragraph of the document.
+= Heading
+#box[]#metadata(true)Indented after the heading.
+
+#box[]#metadata(true)Indented as usual.
+= Heading
+- A list
+#box[]#metadata(false)Not indented after the list.
+
+#set par(first-line-indent: (amount: 1em, scope: "always"))
+#box[]#metadata(true)Always indented.
+
+#context for m in query(metadata) {
+  test(m.location().position().x, if m.value { 20pt } else { 10pt })
+}
+
+--- par-first-line-indent-invalid ---
+// Error: 29-53 unexpected key "size", valid keys are "amount" and "scope"
+#set par(first-line-indent: (amount: 1em, size: 2em))
+
+--- par-widows-orphans ---
+// Three lines are kept together at either end, so a paragraph of six lines
+// that would break after its fourth line breaks after its third one.
+#set page(height: 74pt)
+#set par(widows: 3, orphans: 3)
+One #metadata(1) \
+Two #metadata(2) \
+Three #metadata(3) \
+Four #metadata(4) \
+Five #metadata(5) \
+Six #metadata(6)
+
+#context test(query(metadata).map(m => m.location().page()), (1, 1, 1, 2, 2, 2))
+
+--- par-widows-orphans-short ---
+#set page(height: 74pt)
+One \
+Two
+
+// Too short to be split, moves wholesale.
+One #metadata(1) \
+Two #metadata(2) \
+Three #metadata(3)
+
+#context test(query(metadata).map(m => m.location().page()), (2, 2, 2))
+
+--- par-widows-orphans-costs ---
+// Zero costs disable the prevention for automatic line counts, so a single
+// widow is left on the next page.
+#set page(height: 74pt)
+#set text(costs: (widow: 0%, orphan: 0%))
+One #metadata(1) \
+Two #metadata(2) \
+Three #metadata(3) \
+Four #metadata(4) \
+Five #metadata(5)
+
+#context test(query(metadata).map(m => m.location().page()), (1, 1, 1, 1, 2))
+
+--- par-widows-orphans-costs-lines ---
+// With positive costs, the last two lines are kept together, so only these
+// two lines move.
+#set page(height: 74pt)
+One #metadata(1) \
+Two #metadata(2) \
+Three #metadata(3) \
+Four #metadata(4) \
+Five #metadata(5)
+
+#context test(query(metadata).map(m => m.location().page()), (1, 1, 1, 2, 2))
+
+--- par-widows-orphans-costs-explicit ---
+// Explicit line counts take precedence over zero costs.
+#set page(height: 74pt)
+#set par(widows: 3, orphans: 3)
+#set text(costs: (widow: 0%, orphan: 0%))
+One #metadata(1) \
+Two #metadata(2) \
+Three #metadata(3) \
+Four #metadata(4) \
+Five #metadata(5) \
+Six #metadata(6)
+
+#context test(query(metadata).map(m => m.location().page()), (1, 1, 1, 2, 2, 2))
+
+--- par-widows-orphans-auto ---
+#context test((par.widows, par.orphans), (auto, auto))
+#set par(orphans: 3)
+#context test(par.orphans, 3)
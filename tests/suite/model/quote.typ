@@ -24,6 +24,25 @@ And I quote: #quote(attribution: [René Descartes])[cogito, ergo sum].
 #quote(lorem(10))
 #lorem(10)
 
+--- quote-attribution-position ---
+// Test the placement of block quote attributions.
+#set quote(block: true)
+#quote(attribution: [#metadata(none)<above>Author], attribution-position: "above")[
+  #metadata(none)<above-body>A quote with its attribution above.
+]
+#quote(attribution: [#metadata(none)<below>Author], attribution-position: "below")[
+  #metadata(none)<below-body>A quote with its attribution below.
+]
+#quote(attribution: [Author], attribution-position: "inline")[
+  A quote whose attribution flows with the last line.
+]
+
+#context {
+  let y(label) = locate(label).position().y
+  test(y(<above>) < y(<above-body>), true)
+  test(y(<below>) > y(<below-body>), true)
+}
+
 --- quote-inline ---
 // Inline citation
 #set text(8pt)
@@ -47,6 +47,53 @@ $ A = 1 $ <eq2>
 #set ref(supplement: none)
 @fig1, @fig2, @eq1, @eq2
 
+--- ref-group ---
+// Test pluralized references to multiple elements of the same kind.
+#set heading(numbering: "1.")
+#set math.equation(numbering: "(1)")
+
+= Start <s1>
+= Middle <s2>
+= End <s3>
+
+#figure(rect[A], caption: [A]) <f1>
+#figure(rect[B], caption: [B]) <f2>
+$ a = b $ <e1>
+
+@(f1, f2) \
+@(s1,s2, s3) \
+@(f1, f2)[Figs.]
+
+#set text(lang: "de")
+@(f1, f2)
+
+#set text(lang: "fr")
+@(s1, s2)
+
+--- ref-group-mixed ---
+// Mixed element kinds are referenced one by one.
+#set heading(numbering: "1.")
+#set math.equation(numbering: "(1)")
+
+= Start <s1>
+$ a = b $ <e1>
+
+@(s1, e1)
+
+--- ref-group-custom-supplement ---
+// A custom supplement of the referenced elements is not pluralized.
+#figure(rect[A], caption: [A], supplement: [Plate]) <f1>
+#figure(rect[B], caption: [B], supplement: [Plate]) <f2>
+@(f1, f2)
+
+--- ref-group-empty ---
+// Error: 1-3 expected label
+@()
+
+--- ref-group-unclosed ---
+// Error: 1-7 unclosed reference group
+@(a, b
+
 --- ref-ambigious ---
 // Test ambiguous reference.
 = Introduction <arrgh>
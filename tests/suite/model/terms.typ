@@ -24,6 +24,13 @@
 
   And healthy!
 
+--- terms-separator-hanging-indent ---
+// Test a custom separator with long descriptions and multi-line terms.
+#set text(8pt)
+#set terms(separator: [ — ], hanging-indent: 2em)
+/ Ligature: #lorem(12)
+/ A rather long term that wraps across lines: #lorem(8)
+
 --- terms-style-change-interrupted ---
 // Test style change.
 #set text(8pt)
@@ -6,6 +6,21 @@
 #test(upper(memes), "ARE MEMES GREAT?")
 #test(upper("Ελλάδα"), "ΕΛΛΆΔΑ")
 
+--- upper-lower-lang ---
+#test(upper("i"), "I")
+#test(upper("i", lang: "tr"), "İ")
+#test(upper("ılık", lang: "az"), "ILIK")
+#test(lower("DİYARBAKIR", lang: "tr"), "diyarbakır")
+#test(lower("I\u{307}", lang: "tr"), "i")
+#test(lower("I", lang: "de"), "i")
+#test(lower("ΟΔΟΣ ΚΑΙ ΙΣΤΑΝΜΠΟΥΛ", lang: "tr"), "οδος και ιστανμπουλ")
+#test(upper("istanbul", lang: "tr", region: "cy"), "İSTANBUL")
+
+--- upper-eszett ---
+#test(upper("straße"), "STRASSE")
+#test(upper("straße", eszett: true), "STRAẞE")
+#test(lower("STRAẞE"), "straße")
+
 --- upper-bad-type ---
 // Error: 8-9 expected string or content, found integer
 #upper(1)
@@ -31,6 +31,26 @@ There might be #highlight-custom[redacted] things.
 #set underline(stroke: 2pt, offset: 2pt)
 #underline(text(red, [DANGER!]))
 
+--- underline-overline-gap ---
+// Test the gap around evaded glyphs.
+#underline[gyp] #underline(gap: 0.25em)[gyp] #underline(gap: 0pt)[gyp] \
+#underline(evade: false)[gyp] #overline(gap: 0.25em)[Hold]
+
+--- strike-style ---
+// Test strikethrough styles.
+#strike(style: "double")[Double] \
+#strike(style: "wavy")[Wavy] \
+#strike(style: "wavy", stroke: 1pt + red)[Thick and wavy]
+
+--- strike-style-wavy-wrap ---
+// The waves continue across runs and lines.
+#set page(width: 80pt)
+#strike(style: "wavy")[A wavy line *across* a line break.]
+
+--- strike-style-invalid ---
+// Error: 16-24 expected "single", "double", or "wavy"
+#strike(style: "dotted")[Text]
+
 --- underline-background ---
 // Test underline background
 #set underline(background: true, stroke: (thickness: 0.5em, paint: red, cap: "round"))
@@ -78,6 +98,14 @@ We can also specify a customized value
 #highlight(radius: 3pt)[abc],
 #highlight(radius: 1em)[#lorem(5)]
 
+--- highlight-radius-wrap ---
+// Only the outer corners of a highlight that spans multiple runs and lines
+// are rounded.
+#set page(width: 100pt)
+#highlight(radius: 3pt, extent: 2pt, stroke: 0.5pt)[
+  A long *highlighted* text that wraps across several lines.
+]
+
 --- highlight-stroke ---
 // Test highlight stroke
 #highlight(stroke: 2pt + blue)[abc]
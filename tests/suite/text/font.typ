@@ -76,3 +76,42 @@ I
 // Warning: 23-56 unknown font family: non-existing-fonts
 #let var = text(font: ("list-of", "non-existing-fonts"))[don't]
 #var
+
+--- text-font-script-map ---
+// The CJK font also has Latin glyphs, but Latin runs prefer their own font.
+#set text(font: (han: "Noto Serif CJK SC", latin: "IBM Plex Serif"))
+Latin 中文，text「引号」and 汉字.
+
+#context test(
+  text.font,
+  (han: "noto serif cjk sc", latin: "ibm plex serif"),
+)
+
+--- text-font-script-map-list ---
+#set text(font: (latin: ("Roboto", "IBM Plex Serif"), hani: "Noto Serif CJK SC"))
+Roboto 和 中文
+
+--- text-font-script-map-rest ---
+// Families that aren't preferred for a script round-trip under `rest`.
+#set text(font: (latin: "Roboto", rest: ("IBM Plex Serif", "Noto Serif CJK SC")))
+#context {
+  let font = text.font
+  test(font, (latin: "roboto", rest: ("ibm plex serif", "noto serif cjk sc")))
+  set text(font: font)
+  context test(text.font, font)
+}
+
+--- text-font-script-map-unknown ---
+// Error: 17-47 unknown script: klingon
+#set text(font: (klingon: "Noto Serif CJK SC"))
+
+--- text-optical-size ---
+// Optical sizing is opt-in.
+#context test(text.optical-size, none)
+
+// Fonts without an optical size axis ignore it.
+#set text(size: 6pt)
+Default \
+#text(optical-size: auto)[Automatic] \
+#text(optical-size: 6)[Six] \
+#text(optical-size: 48)[Forty-eight]
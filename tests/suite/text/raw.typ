@@ -654,6 +654,47 @@ fn main() {
 #let foo = "bar"
 ```
 
+--- raw-line-numbers ---
+#show raw.where(block: true): set raw(line-numbers: true, line-number-start: 10)
+#show regex("\d+"): it => metadata(it.text) + it
+```rust
+fn main() {
+    println!("Hello World!");
+}
+```
+
+#context test(query(metadata).map(m => m.value), ("10", "11", "12"))
+
+--- raw-line-numbers-step-wrapped ---
+#set page(width: 120pt)
+#set raw(line-numbers: true, line-number-step: 2)
+#show regex("\d+"): it => metadata(it.text) + it
+```
+short
+a rather long line that needs to wrap across lines
+short
+short
+```
+
+// The wrapped line only takes up one number.
+#context test(query(metadata).map(m => m.value), ("1", "3"))
+
+--- raw-theme-dict ---
+// Scopes that are not mentioned keep their default style.
+#set raw(theme: (
+  "keyword": red,
+  "string": (fill: olive, italic: true),
+))
+```py
+def hello():
+    # Comments are styled as usual.
+    return "Hello World"
+```
+
+--- raw-theme-dict-bad-style ---
+// Error: 17-31 expected color or dictionary, found integer
+#set raw(theme: ("keyword": 1))
+
 --- raw-unclosed ---
 // Test unterminated raw text.
 //
@@ -10,3 +10,15 @@
 
 #show smallcaps: set text(fill: red)
 #smallcaps[Smallcaps]
+
+--- smallcaps-synthesize ---
+// PT Sans has no `smcp` feature, so the small capitals are synthesized.
+#set text(font: "PT Sans")
+#smallcaps(synthesize: true)[Synthesized Smallcaps, NOT DOUBLE-SCALED]
+
+--- smallcaps-synthesize-nested ---
+// Text inside of other elements is synthesized, too.
+#set text(font: "PT Sans")
+#smallcaps(synthesize: true)[
+  Plain, *strong*, _emph_, #link("https://typst.app")[link], and #box[boxed]
+]
@@ -586,6 +586,39 @@ $ A = mat(
 #test(gradient.linear(red, green, dir: rtl).angle(), 180deg)
 #test(gradient.linear(red, green, dir: ttb).angle(), 90deg)
 #test(gradient.linear(red, green, dir: btt).angle(), 270deg)
+#test(gradient.radial(red, green).angle(), none)
+#test(gradient.conic(red, green, angle: 45deg).angle(), 45deg)
+
+--- gradient-radial-focal ---
+#let g = gradient.radial(
+  red, green,
+  center: (30%, 40%),
+  radius: 70%,
+  focal-center: (20%, 60%),
+  focal-radius: 10%,
+)
+#test(g.center(), (30%, 40%))
+#test(g.radius(), 70%)
+#test(g.focal-center(), (20%, 60%))
+#test(g.focal-radius(), 10%)
+#test(gradient.radial(red, green).focal-center(), (50%, 50%))
+#test(gradient.radial(red, green).focal-radius(), 0%)
+#test(gradient.conic(red, green, center: (20%, 10%)).center(), (20%, 10%))
+#test(gradient.conic(red, green).radius(), none)
+#test(gradient.linear(red, green).center(), none)
+#test(gradient.linear(red, green).focal-center(), none)
+
+--- gradient-radial-focal-outside ---
+// The focal point may lie outside of the filled shape as long as it is
+// inside of the end circle.
+#circle(
+  radius: 25pt,
+  fill: gradient.radial(
+    ..color.map.rainbow,
+    radius: 100%,
+    focal-center: (110%, 50%),
+  ),
+)
 
 --- gradient-repeat ---
 #test(
@@ -92,6 +92,31 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // Error: 2-168 failed to parse SVG (missing root node)
 #image.decode(`<svg height="140" width="500"><ellipse cx="200" cy="80" rx="100" ry="50" style="fill:yellow;stroke:purple;stroke-width:2" /></svg>`.text, format: "svg")
 
+--- image-svg-style-and-use ---
+// Inline style sheets apply with their specificity and `<use>` elements
+// resolve references within the SVG.
+#image.decode(```
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="60" height="20">
+  <style>
+    rect { fill: red; }
+    .cell { fill: blue; }
+    #special { fill: green; }
+  </style>
+  <defs><rect id="base" class="cell" width="18" height="18"/></defs>
+  <use href="#base" x="1" y="1"/>
+  <use xlink:href="#base" x="21" y="1"/>
+  <rect id="special" class="cell" x="41" y="1" width="18" height="18"/>
+</svg>
+```.text, width: 60pt)
+
+--- image-svg-use-external ---
+// Error: 2-122 failed to parse SVG (reference to external file "sprites.svg#icon" is not supported)
+#image.decode(`<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20"><use href="sprites.svg#icon"/></svg>`.text)
+
+--- image-svg-style-import ---
+// Error: 2-130 failed to parse SVG (style sheet imports are not supported)
+#image.decode(`<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20"><style>@import url(theme.css);</style></svg>`.text)
+
 --- image-decode-detect-format ---
 // Test format auto detect
 #image.decode(read("/assets/images/tiger.jpg", encoding: none), width: 80%)
@@ -100,6 +125,61 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // Test format manual
 #image.decode(read("/assets/images/tiger.jpg", encoding: none), format: "jpg", width: 80%)
 
+--- image-crop-sprite-sheet ---
+// Show each cell of a sprite sheet on its own.
+#let sheet = ```
+<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20">
+  <rect width="10" height="10" fill="red"/>
+  <rect x="10" width="10" height="10" fill="green"/>
+  <rect y="10" width="10" height="10" fill="blue"/>
+  <rect x="10" y="10" width="10" height="10" fill="orange"/>
+</svg>
+```.text
+
+#stack(
+  dir: ltr,
+  spacing: 4pt,
+  ..range(4).map(i => image.decode(
+    sheet,
+    width: 20pt,
+    crop: (calc.rem(i, 2) * 10, calc.quo(i, 2) * 10, 10, 10),
+  )),
+)
+
+// Parts of the crop outside of the image are cut off.
+#image.decode(sheet, width: 20pt, crop: (10, 5, 50, 50))
+
+// The cropped area determines the aspect ratio.
+#context {
+  let height(crop) = measure(image.decode(sheet, width: 20pt, crop: crop)).height
+  test(calc.abs((height((10, 10, 10, 10)) - 20pt).pt()) < 1e-6, true)
+  test(calc.abs((height((10, 5, 50, 50)) - 30pt).pt()) < 1e-6, true)
+}
+
+--- image-crop-natural-size ---
+#context test(
+  measure(image("/assets/images/tiger.jpg", crop: (100, 100, 300, 200))),
+  (width: 300pt, height: 200pt),
+)
+
+--- image-crop-outside ---
+#let sheet = `<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20"/>`.text
+
+// Error: 2-44 crop rectangle lies outside of the image
+#image.decode(sheet, crop: (30, 0, 10, 10))
+
+--- image-crop-bad-array ---
+#let sheet = `<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20"/>`.text
+
+// Error: 28-38 crop array must contain exactly four entries
+#image.decode(sheet, crop: (0, 0, 10))
+
+--- image-crop-negative-size ---
+#let sheet = `<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20"/>`.text
+
+// Error: 28-42 crop width and height must be positive
+#image.decode(sheet, crop: (0, 0, 10, -5))
+
 --- image-decode-specify-wrong-format ---
 // Error: 2-91 failed to decode image (Format error decoding Png: Invalid PNG signature.)
 #image.decode(read("/assets/images/tiger.jpg", encoding: none), format: "png", width: 80%)
@@ -110,6 +190,10 @@ A #box(image("/assets/images/tiger.jpg", height: 1cm, width: 80%)) B
 // File is from https://magnushoff.com/articles/jpeg-orientation/
 #image("/assets/images/f2t.jpg", width: 10pt)
 
+--- image-exif-orientation-ignored ---
+// Show the pixels as they are stored.
+#image("/assets/images/f2t.jpg", width: 10pt, exif-orientation: false)
+
 --- issue-measure-image ---
 // Test that image measurement doesn't turn `inf / some-value` into 0pt.
 #context {
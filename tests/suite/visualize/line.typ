@@ -61,6 +61,32 @@
 #v(3pt)
 #line(length: 60pt, stroke: (dash: none))
 
+--- line-stroke-cap ---
+// Line caps from the stroke and the shortcut
+#set line(length: 3cm)
+#line(stroke: (cap: "butt", thickness: 3pt))
+#v(3pt)
+#line(stroke: (cap: "round", thickness: 3pt))
+#v(3pt)
+#line(stroke: (cap: "square", thickness: 3pt))
+#v(3pt)
+#line(stroke: (cap: "square", thickness: 3pt), cap: "round")
+#v(3pt)
+#line(stroke: (paint: red, thickness: 3pt, dash: "dashed"), cap: "round")
+
+--- line-cap-invalid ---
+// Error: 12-18 expected "butt", "round", "square", or auto
+#line(cap: "flat")
+
+--- line-stroke-gradient ---
+// Gradients run along the line's length.
+#set line(stroke: 3pt + gradient.linear(red, blue), stroke-along: true)
+#line(length: 5cm)
+#line(length: 2cm, angle: 90deg)
+#line(start: (5cm, 0pt), end: (-5cm, 1cm))
+#line(length: 1pt)
+#line(length: 0pt, stroke: (cap: "round"))
+
 --- line-stroke-dash ---
 // Dashing
 #line(length: 60pt, stroke: (paint: red, thickness: 1pt, dash: ("dot", 1pt)))
@@ -73,6 +99,25 @@
 #v(3pt)
 #line(length: 60pt, stroke: (paint: red, thickness: 1pt, dash: (1pt, 3pt, 9pt)))
 
+--- line-marker ---
+#set page(width: 120pt, height: auto)
+#set line(length: 100%)
+#line(stroke: 2pt, marker-end: "arrow")
+#line(marker-start: "arrow", marker-end: "barb")
+#line(stroke: 1.5pt + blue, marker-start: "circle", marker-end: "bar")
+#line(end: (60%, 30pt), stroke: 2pt + gradient.linear(red, blue), marker-end: "arrow")
+#line(stroke: (thickness: 1pt, dash: "dashed"), marker-end: "barb")
+
+--- line-marker-short ---
+// Markers shrink when they are longer than the line.
+#line(length: 6pt, stroke: 2pt, marker-end: "arrow")
+#line(length: 6pt, stroke: 2pt, marker-start: "arrow", marker-end: "arrow")
+#line(length: 0pt, stroke: 2pt, marker-end: "arrow")
+
+--- line-marker-invalid ---
+// Error: 19-25 expected "arrow", "barb", "circle", "bar", or none
+#line(marker-end: "arrw")
+
 --- line-stroke-field-typo ---
 // Error: 29-56 unexpected key "thicknes", valid keys are "paint", "thickness", "cap", "join", "dash", and "miter-limit"
 #line(length: 60pt, stroke: (paint: red, thicknes: 1pt))
@@ -51,6 +51,73 @@
 // Error: 7-31 point array must contain exactly two entries
 #path(((0%, 0%), (0%, 0%, 0%)))
 
+--- path-boolean ---
+#let square = path(closed: true, (0pt, 0pt), (40pt, 0pt), (40pt, 40pt), (0pt, 40pt))
+#let disc(x, y, r) = {
+  let k = 0.5523 * r
+  path(
+    closed: true,
+    ((x, y - r), (-k, 0pt)),
+    ((x + r, y), (0pt, -k)),
+    ((x, y + r), (k, 0pt)),
+    ((x - r, y), (0pt, k)),
+  )
+}
+
+#set path(fill: teal, stroke: 0.5pt)
+#stack(
+  dir: ltr,
+  spacing: 10pt,
+  path.union(square, disc(40pt, 40pt, 15pt)),
+  path.intersect(square, disc(40pt, 40pt, 15pt)),
+  path.difference(square, disc(40pt, 40pt, 15pt)),
+  // A hole in the middle of the rectangle.
+  path.difference(square, disc(20pt, 20pt, 10pt)),
+  // A disc that covers the rectangle entirely.
+  path.difference(square, disc(20pt, 20pt, 40pt)),
+)
+
+--- path-boolean-self-intersecting ---
+// A self-intersecting star is filled in the middle under the non-zero winding
+// rule, and so is the result of combining it.
+#let star = path(
+  closed: true,
+  (20pt, 0pt), (32pt, 38pt), (0pt, 14pt), (40pt, 14pt), (8pt, 38pt),
+)
+#let bar = path(closed: true, (0pt, 18pt), (40pt, 18pt), (40pt, 24pt), (0pt, 24pt))
+#stack(
+  dir: ltr,
+  spacing: 10pt,
+  star,
+  path.difference(star, bar, fill: red),
+  path.union(path.intersect(star, bar), path.difference(bar, star), fill: blue),
+)
+
+--- path-boolean-relative ---
+// Relative vertices of the operands are resolved against the region of the
+// resulting path.
+#block(width: 60pt, height: 30pt, path.difference(
+  fill: aqua,
+  path(closed: true, (0%, 0%), (100%, 0%), (100%, 100%), (0%, 100%)),
+  path(closed: true, (25%, 25%), (75%, 25%), (75%, 75%), (25%, 75%)),
+))
+
+--- path-boolean-bad-operand ---
+// Error: 13-19 expected path
+#path.union(rect(), path((0pt, 0pt)))
+
+--- path-marker ---
+#path(
+  stroke: 1.5pt,
+  marker-start: "circle",
+  marker-end: "arrow",
+  (0pt, 40pt),
+  ((50pt, 0pt), (-30pt, 0pt)),
+  (100pt, 40pt),
+)
+// Markers are not drawn on closed paths.
+#path(closed: true, marker-end: "arrow", (0pt, 0pt), (20pt, 0pt), (10pt, 10pt))
+
 --- issue-path-in-sized-container ---
 // Paths used to implement `LayoutMultiple` rather than `LayoutSingle` without
 // fulfilling the necessary contract of respecting region expansion.
@@ -89,6 +89,40 @@
 
 #rect(fill: pat(spacing: (10pt, 10pt,)), width: 100%, height: 100%, stroke: 1pt)
 
+--- pattern-rotation ---
+// Test a pattern whose tile grid is rotated by 45 degrees.
+#set page(width: auto, height: auto, margin: 0pt)
+#let pat = pattern(size: (10pt, 10pt), rotation: 45deg)[
+  #place(line(stroke: 2pt, start: (50%, 0%), end: (50%, 100%)))
+]
+#rect(width: 50pt, height: 50pt, fill: pat)
+
+--- pattern-rotation-clip ---
+// Test that a rotated pattern is clipped to its container.
+#set page(width: auto, height: auto, margin: 5pt)
+#let pat = pattern(size: (10pt, 10pt), rotation: 30deg)[
+  #square(size: 5pt, fill: blue)
+]
+#block(width: 60pt, height: 40pt, radius: 10pt, clip: true, fill: pat)[
+  #place(bottom + right, circle(radius: 20pt, fill: pat))
+]
+
+--- pattern-phase ---
+#set page(width: auto, height: auto, margin: 0pt)
+#let pat(..args) = pattern(size: (10pt, 10pt), ..args)[
+  #circle(radius: 3pt, fill: red)
+]
+#stack(
+  dir: ltr,
+  rect(width: 30pt, height: 30pt, fill: pat()),
+  rect(width: 30pt, height: 30pt, fill: pat(phase: (5pt, 5pt))),
+  rect(width: 30pt, height: 30pt, fill: pat(phase: (2pt, 0pt), rotation: 15deg)),
+)
+
+--- pattern-phase-relative ---
+// Error: 37-47 pattern phase must be absolute
+#pattern(size: (10pt, 10pt), phase: (1em, 0pt))[]
+
 --- pattern-stroke ---
 // Test pattern on strokes
 #align(
@@ -87,6 +87,33 @@
 // Error: 15-38 unexpected key "cake", valid keys are "top-left", "top-right", "bottom-right", "bottom-left", "left", "top", "right", "bottom", and "rest"
 #rect(radius: (left: 10pt, cake: 5pt))
 
+--- rect-radius-elliptical ---
+#set rect(stroke: 2pt)
+#stack(
+  dir: ltr,
+  spacing: 6pt,
+  rect(radius: (top-left: (x: 4pt, y: 8pt))),
+  rect(radius: (x: 20pt, y: 8pt), fill: eastern),
+  rect(
+    radius: (left: (x: 10pt, y: 15pt), right: 5pt),
+    stroke: (left: red, rest: blue),
+  ),
+)
+
+--- rect-radius-elliptical-clamp ---
+// Radii that exceed the sides shrink while keeping their shape.
+#rect(width: 60pt, height: 20pt, radius: (x: 100pt, y: 20pt), fill: aqua)
+#rect(width: 60pt, height: 20pt, radius: (top: (x: 40pt, y: 5pt)), fill: aqua)
+
+--- rect-radius-elliptical-field ---
+#let r = rect(radius: (top-left: (x: 4pt, y: 8pt), rest: 2pt))
+#test(r.radius.top-left, (x: 4pt, y: 8pt))
+#test(r.radius.top-right, 2pt)
+
+--- rect-radius-elliptical-missing-key ---
+// Error: 15-35 dictionary does not contain key "y"
+#rect(radius: (top-left: (x: 4pt)))
+
 --- issue-1825-rect-overflow ---
 #set page(width: 17.8cm)
 #set par(justify: true)
@@ -160,6 +160,30 @@
 	square(radius: (top-left: 0pt, rest: 100pt)),
 )
 
+--- stroke-dash-paints ---
+// Test cycling paints across dashes.
+#set line(length: 100%)
+#stack(
+  spacing: 1em,
+  line(stroke: (paint: (red, blue), thickness: 2pt, dash: "dashed")),
+  line(stroke: (paint: (red, green, blue), thickness: 2pt, dash: "dash-dotted")),
+  line(stroke: (paint: (red, blue), thickness: 2pt, dash: (6pt, 2pt, 2pt))),
+  line(stroke: (paint: (red, blue), thickness: 2pt)),
+)
+#rect(
+  width: 100%,
+  fill: yellow,
+  stroke: (paint: (red, blue), thickness: 2pt, dash: "dotted", cap: "round"),
+)
+
+#test(stroke(paint: (red, blue)).paint, (red, blue))
+#test(stroke(paint: (red,)).paint, red)
+#test(repr(stroke(paint: (red, blue))), "(paint: (rgb(\"#ff4136\"), rgb(\"#0074d9\")))")
+
+--- stroke-dash-paints-empty ---
+// Error: 16-18 array of paints must not be empty
+#stroke(paint: ())
+
 --- issue-3700-deformed-stroke ---
 // Test shape fill & stroke for specific values that used to make the stroke
 // deformed.